@@ -0,0 +1,39 @@
+//! Serve the Shopify stub used by the integration tests on a fixed address,
+//! so that the application can be run against it without a real shop.
+//!
+//! ```sh
+//! cargo run --example shopify_stub -- [ADDRESS] [RESPONSES]
+//! ```
+//!
+//! `ADDRESS` defaults to `127.0.0.1:8090`. `RESPONSES` is a JSON file that maps operation names
+//! to the `data` of their responses, such as `tests/fixtures/shopify/stub/responses.json`.
+//! Point the application at the stub with `SHOPIFY_SHOP_DOMAIN=http://127.0.0.1:8090` and
+//! `ACCESS_TOKEN=shpat_stub`.
+
+#[allow(dead_code)]
+#[path = "../tests/common/shopify_stub.rs"]
+mod shopify_stub;
+
+use std::{env, fs};
+
+use serde_json::Value;
+use shopify_stub::ShopifyStubServer;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8090";
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+
+    let stub = ShopifyStubServer::start_on(&address).await;
+    if let Some(path) = args.next() {
+        let responses: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        for (operation_name, data) in responses.as_object().into_iter().flatten() {
+            stub.respond(operation_name, data.clone());
+        }
+    }
+
+    println!("Shopify stub is listening on {}", stub.url());
+    tokio::signal::ctrl_c().await
+}
@@ -9,6 +9,16 @@ mod m20250119_081745_create_role_resource_permission;
 mod m20250621_055742_create_user_group;
 mod m20250621_061224_create_user_group_role;
 mod m20250621_061502_create_user_user_group;
+mod m20261018_090000_add_return_resource;
+mod m20261018_100000_add_customer_order_delete_permission;
+mod m20261018_110000_create_customer_segment;
+mod m20261018_120000_create_audit_log;
+mod m20261018_130000_create_user_customer;
+mod m20261018_140000_add_location_resource;
+mod m20261018_150000_create_tenant;
+mod m20261018_150100_add_default_tenant;
+mod m20261018_160000_add_fulfillment_resource;
+mod m20261018_170000_add_customer_segment_resource;
 
 pub struct Migrator;
 
@@ -25,6 +35,16 @@ impl MigratorTrait for Migrator {
             Box::new(m20250621_055742_create_user_group::Migration),
             Box::new(m20250621_061224_create_user_group_role::Migration),
             Box::new(m20250621_061502_create_user_user_group::Migration),
+            Box::new(m20261018_090000_add_return_resource::Migration),
+            Box::new(m20261018_100000_add_customer_order_delete_permission::Migration),
+            Box::new(m20261018_110000_create_customer_segment::Migration),
+            Box::new(m20261018_120000_create_audit_log::Migration),
+            Box::new(m20261018_130000_create_user_customer::Migration),
+            Box::new(m20261018_140000_add_location_resource::Migration),
+            Box::new(m20261018_150000_create_tenant::Migration),
+            Box::new(m20261018_150100_add_default_tenant::Migration),
+            Box::new(m20261018_160000_add_fulfillment_resource::Migration),
+            Box::new(m20261018_170000_add_customer_segment_resource::Migration),
         ]
     }
 }
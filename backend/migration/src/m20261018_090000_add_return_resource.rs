@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Customers can only read their own returns, approval and refunds are left to operators.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "resource" (id, name)
+        VALUES (6, 'Return');
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (29, 1, 6, 1);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (30, 2, 6, 5);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (31, 2, 6, 6);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (32, 2, 6, 7);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (33, 3, 6, 2);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "role_resource_permission" WHERE resource_id = 6;
+        DELETE FROM "resource" WHERE id = 6;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
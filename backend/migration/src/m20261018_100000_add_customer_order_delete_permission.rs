@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Canceling an order requires the delete permission, so customers can cancel only their own orders.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (34, 3, 2, 4);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "role_resource_permission" WHERE id = 34;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomerSegment::Table)
+                    .if_not_exists()
+                    .col(pk_auto(CustomerSegment::Id))
+                    .col(string(CustomerSegment::Name))
+                    .col(json_binary(CustomerSegment::Criteria))
+                    .col(timestamp_with_time_zone(CustomerSegment::CreatedAt))
+                    .col(timestamp_with_time_zone(CustomerSegment::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CustomerSegment::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum CustomerSegment {
+    Table,
+    Id,
+    Name,
+    Criteria,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .if_not_exists()
+                    .col(pk_auto(AuditLog::Id))
+                    .col(string(AuditLog::ActorUserId))
+                    .col(string(AuditLog::Action))
+                    .col(string(AuditLog::ResourceType))
+                    .col(string(AuditLog::ResourceId))
+                    .col(timestamp_with_time_zone(AuditLog::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_resource")
+                    .table(AuditLog::Table)
+                    .col(AuditLog::ResourceType)
+                    .col(AuditLog::ResourceId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum AuditLog {
+    Table,
+    Id,
+    ActorUserId,
+    Action,
+    ResourceType,
+    ResourceId,
+    CreatedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Users are not always registered locally before signing in, so no foreign key to the user table is set.
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserCustomer::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserCustomer::UserId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(string_uniq(UserCustomer::CustomerId))
+                    .col(timestamp_with_time_zone(UserCustomer::CreatedAt))
+                    .col(timestamp_with_time_zone(UserCustomer::UpdatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserCustomer::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum UserCustomer {
+    Table,
+    UserId,
+    CustomerId,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Locations are public like products, managing warehouses and stores is left to operators.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "resource" (id, name)
+        VALUES (7, 'Location');
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (35, 1, 7, 1);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (36, 2, 7, 5);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (37, 2, 7, 6);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (38, 2, 7, 7);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (39, 3, 7, 5);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (40, 4, 7, 5);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "role_resource_permission" WHERE resource_id = 7;
+        DELETE FROM "resource" WHERE id = 7;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
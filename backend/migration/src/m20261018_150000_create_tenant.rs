@@ -0,0 +1,113 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20250119_080141_create_user_role::UserRole,
+    m20250621_061502_create_user_user_group::UserUserGroup,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tenant::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Tenant::Id))
+                    .col(string_uniq(Tenant::ShopDomain))
+                    .col(text(Tenant::EncryptedAccessToken))
+                    .col(string(Tenant::ApiVersion))
+                    .col(boolean(Tenant::IsEnabled).default(true))
+                    .col(timestamp_with_time_zone(Tenant::CreatedAt))
+                    .col(timestamp_with_time_zone(Tenant::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Role assignments without a tenant apply to every tenant.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserRole::Table)
+                    .add_column(integer_null(TenantScope::TenantId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_user_role_tenant_id")
+                            .from_tbl(UserRole::Table)
+                            .from_col(TenantScope::TenantId)
+                            .to_tbl(Tenant::Table)
+                            .to_col(Tenant::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserUserGroup::Table)
+                    .add_column(integer_null(TenantScope::TenantId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_user_user_group_tenant_id")
+                            .from_tbl(UserUserGroup::Table)
+                            .from_col(TenantScope::TenantId)
+                            .to_tbl(Tenant::Table)
+                            .to_col(Tenant::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserUserGroup::Table)
+                    .drop_foreign_key(Alias::new("fk_user_user_group_tenant_id"))
+                    .drop_column(TenantScope::TenantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserRole::Table)
+                    .drop_foreign_key(Alias::new("fk_user_role_tenant_id"))
+                    .drop_column(TenantScope::TenantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Tenant::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub(crate) enum Tenant {
+    Table,
+    Id,
+    ShopDomain,
+    EncryptedAccessToken,
+    ApiVersion,
+    IsEnabled,
+    CreatedAt,
+    UpdatedAt,
+}
+
+/// Column added to the role assignments to scope them to a tenant.
+#[derive(DeriveIden)]
+enum TenantScope {
+    TenantId,
+}
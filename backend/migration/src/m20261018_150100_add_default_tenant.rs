@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The default tenant stands for the shop served without tenant routing, and owns the assignments made before tenants.
+        // It is disabled so that the registry never resolves it. To route requests to that shop, set its domain, token and API version and enable it.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "tenant" (id, shop_domain, encrypted_access_token, api_version, is_enabled, created_at, updated_at)
+        VALUES (1, 'default', '', '', false, now(), now());
+        SELECT setval(pg_get_serial_sequence('tenant', 'id'), (SELECT MAX(id) FROM "tenant"));
+        UPDATE "user_role" SET tenant_id = 1 WHERE tenant_id IS NULL;
+        UPDATE "user_user_group" SET tenant_id = 1 WHERE tenant_id IS NULL;
+        "#,
+            )
+            .await?;
+
+        // A user is linked to a customer in each shop.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        ALTER TABLE "user_customer" ADD COLUMN tenant_id integer NOT NULL DEFAULT 1;
+        ALTER TABLE "user_customer" ALTER COLUMN tenant_id DROP DEFAULT;
+        ALTER TABLE "user_customer" ADD CONSTRAINT fk_user_customer_tenant_id
+            FOREIGN KEY (tenant_id) REFERENCES "tenant" (id) ON DELETE CASCADE ON UPDATE CASCADE;
+        ALTER TABLE "user_customer" DROP CONSTRAINT user_customer_pkey;
+        ALTER TABLE "user_customer" ADD CONSTRAINT user_customer_pkey PRIMARY KEY (tenant_id, user_id);
+        ALTER TABLE "user_customer" DROP CONSTRAINT user_customer_customer_id_key;
+        ALTER TABLE "user_customer" ADD CONSTRAINT user_customer_tenant_id_customer_id_key UNIQUE (tenant_id, customer_id);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "user_customer" WHERE tenant_id <> 1;
+        ALTER TABLE "user_customer" DROP CONSTRAINT user_customer_tenant_id_customer_id_key;
+        ALTER TABLE "user_customer" ADD CONSTRAINT user_customer_customer_id_key UNIQUE (customer_id);
+        ALTER TABLE "user_customer" DROP CONSTRAINT user_customer_pkey;
+        ALTER TABLE "user_customer" ADD CONSTRAINT user_customer_pkey PRIMARY KEY (user_id);
+        ALTER TABLE "user_customer" DROP COLUMN tenant_id;
+        UPDATE "user_role" SET tenant_id = NULL WHERE tenant_id = 1;
+        UPDATE "user_user_group" SET tenant_id = NULL WHERE tenant_id = 1;
+        DELETE FROM "tenant" WHERE id = 1;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
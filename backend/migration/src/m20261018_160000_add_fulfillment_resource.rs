@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Fulfilling orders is left to operators, customers only follow them through their orders.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "resource" (id, name)
+        VALUES (8, 'Fulfillment');
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (41, 1, 8, 1);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (42, 2, 8, 5);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (43, 2, 8, 6);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (44, 2, 8, 7);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "role_resource_permission" WHERE resource_id = 8;
+        DELETE FROM "resource" WHERE id = 8;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
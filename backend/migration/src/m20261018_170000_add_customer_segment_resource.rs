@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Customer segments are an operator feature, customers hold no permission on them.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        INSERT INTO "resource" (id, name)
+        VALUES (9, 'CustomerSegment');
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (45, 1, 9, 1);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (46, 2, 9, 5);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (47, 2, 9, 6);
+        INSERT INTO "role_resource_permission" (id, role_id, resource_id, permission_id)
+        VALUES (48, 2, 9, 7);
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+        DELETE FROM "role_resource_permission" WHERE resource_id = 9;
+        DELETE FROM "resource" WHERE id = 9;
+        "#,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
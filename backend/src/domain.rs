@@ -1,9 +1,14 @@
 pub mod address;
+pub mod audit_log;
 pub mod authorized_resource;
+pub mod coordinates;
 pub mod customer;
+pub mod customer_segment;
 pub mod draft_order;
 pub mod email;
 pub mod error;
+pub mod fulfillment;
+pub mod fulfillment_order;
 pub mod inventory_item;
 pub mod inventory_level;
 pub mod line_item;
@@ -11,6 +16,8 @@ pub mod location;
 pub mod media;
 pub mod money;
 pub mod order;
+pub mod order_return;
 pub mod phone;
 pub mod product;
+pub mod refund;
 pub mod user;
@@ -1,60 +1,142 @@
 use derive_getters::Getters;
+use regex::Regex;
 
-use crate::domain::error::error::DomainError;
+use crate::{
+    domain::{coordinates::coordinates::Coordinates, error::error::DomainError},
+    log_error,
+};
+
+pub type Id = String;
 
 /// Represent the address that each entity has.
 ///
+/// Only the addresses in the address book of a customer are identified by an id.
+///
 /// # Fields
+/// * `id` - The unique identifier of the address, if it is managed individually.
 /// * `address1` - The first line of the address.
 /// * `address2` - The second line of the address.
 /// * `city` - The city of the address.
 /// * `coordinates_validated` - Whether the coordinates of the address are validated.
+/// * `coordinates` - The geographic coordinates of the address, if they are known.
 /// * `country` - The country of the address.
+/// * `country_code` - The ISO 3166-1 alpha-2 code of the country.
 /// * `first_name` - The first name of the address.
 /// * `last_name` - The last name of the address.
 /// * `province` - The province of the address.
+/// * `province_code` - The ISO 3166-2 subdivision code of the province, without the country prefix.
 /// * `zip` - The zip code of the address.
 /// * `phone` - The phone number of the address.
 #[derive(Debug, Getters, Clone, PartialEq)]
 pub struct Address {
+    id: Option<Id>,
     address1: Option<String>,
     address2: Option<String>,
     city: Option<String>,
     coordinates_validated: bool,
+    coordinates: Option<Coordinates>,
     country: Option<String>,
+    country_code: Option<String>,
     first_name: Option<String>,
     last_name: Option<String>,
     province: Option<String>,
+    province_code: Option<String>,
     zip: Option<String>,
     phone: Option<String>,
 }
 
+/// Values to construct an address from, validated by `Address::new`.
+///
+/// See `Address` for the meaning of each field.
+#[derive(Debug, Default)]
+pub struct AddressParams {
+    pub id: Option<Id>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub city: Option<String>,
+    pub coordinates_validated: bool,
+    pub country: Option<String>,
+    pub country_code: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub province: Option<String>,
+    pub province_code: Option<String>,
+    pub zip: Option<String>,
+    pub phone: Option<String>,
+}
+
 impl Address {
-    pub fn new(
-        address1: Option<impl Into<String>>,
-        address2: Option<impl Into<String>>,
-        city: Option<impl Into<String>>,
-        coordinates_validated: bool,
-        country: Option<impl Into<String>>,
-        first_name: Option<impl Into<String>>,
-        last_name: Option<impl Into<String>>,
-        province: Option<impl Into<String>>,
-        zip: Option<impl Into<String>>,
-        phone: Option<impl Into<String>>,
-    ) -> Result<Self, DomainError> {
+    pub fn new(params: AddressParams) -> Result<Self, DomainError> {
+        let AddressParams {
+            id,
+            address1,
+            address2,
+            city,
+            coordinates_validated,
+            country,
+            country_code,
+            first_name,
+            last_name,
+            province,
+            province_code,
+            zip,
+            phone,
+        } = params;
+
+        if id.as_ref().is_some_and(|i| i.is_empty()) {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if let Some(code) = &country_code {
+            if !Regex::new(r"^[A-Z]{2}$").unwrap().is_match(code) {
+                log_error!("Invalid country code.", "country_code" => code);
+                return Err(DomainError::ValidationError);
+            }
+        }
+        if let Some(code) = &province_code {
+            if country_code.is_none() {
+                log_error!("Province code requires a country code.", "province_code" => code);
+                return Err(DomainError::ValidationError);
+            }
+            if !Regex::new(r"^[A-Z0-9]{1,3}$").unwrap().is_match(code) {
+                log_error!("Invalid province code.", "province_code" => code);
+                return Err(DomainError::ValidationError);
+            }
+        }
+
         Ok(Self {
-            address1: address1.map(|a| a.into()),
-            address2: address2.map(|a| a.into()),
-            city: city.map(|a| a.into()),
+            id,
+            address1,
+            address2,
+            city,
             coordinates_validated,
-            country: country.map(|a| a.into()),
-            first_name: first_name.map(|a| a.into()),
-            last_name: last_name.map(|a| a.into()),
-            province: province.map(|a| a.into()),
-            zip: zip.map(|a| a.into()),
-            phone: phone.map(|a| a.into()),
+            coordinates: None,
+            country,
+            country_code,
+            first_name,
+            last_name,
+            province,
+            province_code,
+            zip,
+            phone,
         })
     }
+
+    /// Return the same address identified by the given id.
+    pub fn with_id(self, id: impl Into<Id>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    /// Return the same address located at the given coordinates.
+    pub fn with_coordinates(self, coordinates: Coordinates) -> Self {
+        Self {
+            coordinates: Some(coordinates),
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -63,30 +145,110 @@ mod tests {
 
     #[test]
     fn test_new_success() {
-        let address = Address::new(
-            Some("123 Main St"), // address1
-            None::<String>,      // address2
-            Some("City"),        // city
-            true,                // coordinates_validated
-            Some("Country"),     // country
-            Some("John"),        // first_name
-            Some("Doe"),         // last_name
-            Some("Province"),    // province
-            Some("12345"),       // zip
-            Some("+1234567890"), // phone
-        );
+        let address = Address::new(AddressParams {
+            id: Some("1".to_string()),
+            address1: Some("123 Main St".to_string()),
+            address2: None,
+            city: Some("City".to_string()),
+            coordinates_validated: true,
+            country: Some("Japan".to_string()),
+            country_code: Some("JP".to_string()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            province: Some("Tokyo".to_string()),
+            province_code: Some("13".to_string()),
+            zip: Some("12345".to_string()),
+            phone: Some("+1234567890".to_string()),
+        });
 
         assert!(address.is_ok());
 
         let address = address.unwrap();
+        assert_eq!(address.id().clone().unwrap(), "1");
         assert_eq!(address.address1().clone().unwrap(), "123 Main St");
         assert_eq!(address.city().clone().unwrap(), "City");
         assert_eq!(*address.coordinates_validated(), true);
-        assert_eq!(address.country().clone().unwrap(), "Country");
+        assert_eq!(address.country().clone().unwrap(), "Japan");
+        assert_eq!(address.country_code().clone().unwrap(), "JP");
         assert_eq!(address.first_name().clone().unwrap(), "John");
         assert_eq!(address.last_name().clone().unwrap(), "Doe");
-        assert_eq!(address.province().clone().unwrap(), "Province");
+        assert_eq!(address.province().clone().unwrap(), "Tokyo");
+        assert_eq!(address.province_code().clone().unwrap(), "13");
         assert_eq!(address.zip().clone().unwrap(), "12345");
         assert_eq!(address.phone().clone().unwrap(), "+1234567890");
     }
+
+    fn address_with_codes(
+        country_code: Option<&str>,
+        province_code: Option<&str>,
+    ) -> Result<Address, DomainError> {
+        Address::new(AddressParams {
+            address1: Some("123 Main St".to_string()),
+            city: Some("City".to_string()),
+            coordinates_validated: false,
+            country_code: country_code.map(str::to_string),
+            province_code: province_code.map(str::to_string),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_with_id() {
+        let address = address_with_codes(Some("JP"), Some("13"))
+            .unwrap()
+            .with_id("2");
+
+        assert_eq!(address.id().clone().unwrap(), "2");
+        assert_eq!(address.address1().clone().unwrap(), "123 Main St");
+    }
+
+    #[test]
+    fn test_with_coordinates() {
+        let address = address_with_codes(Some("JP"), Some("13"))
+            .unwrap()
+            .with_coordinates(Coordinates::new(35.6812, 139.7671).unwrap());
+
+        assert_eq!(
+            address.coordinates().clone().unwrap(),
+            Coordinates::new(35.6812, 139.7671).unwrap()
+        );
+        assert_eq!(address.address1().clone().unwrap(), "123 Main St");
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let address = Address::new(AddressParams {
+            id: Some("".to_string()),
+            address1: Some("123 Main St".to_string()),
+            coordinates_validated: false,
+            ..Default::default()
+        });
+
+        assert_eq!(address.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_invalid_country_code_should_fail() {
+        for code in ["jp", "JPN", "J1", ""] {
+            let address = address_with_codes(Some(code), None);
+
+            assert_eq!(address.unwrap_err(), DomainError::ValidationError);
+        }
+    }
+
+    #[test]
+    fn test_new_with_invalid_province_code_should_fail() {
+        for code in ["ca", "CAL1", "JP-13", ""] {
+            let address = address_with_codes(Some("US"), Some(code));
+
+            assert_eq!(address.unwrap_err(), DomainError::ValidationError);
+        }
+    }
+
+    #[test]
+    fn test_new_with_province_code_without_country_code_should_fail() {
+        let address = address_with_codes(None, Some("13"));
+
+        assert_eq!(address.unwrap_err(), DomainError::ValidationError);
+    }
 }
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::ResourceType, error::error::DomainError,
+        user::user::Id as UserId,
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Actions recorded in the audit trail.
+///
+/// # Variants
+/// - `ExportCustomerData` - All data held about a customer was exported.
+/// - `EraseCustomerData` - The personal data of a customer was erased.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditAction {
+    ExportCustomerData,
+    EraseCustomerData,
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            AuditAction::ExportCustomerData => "ExportCustomerData",
+            AuditAction::EraseCustomerData => "EraseCustomerData",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+/// Represents a record of an operation that must remain traceable.
+///
+/// # Fields
+/// - `id` - The unique identifier of the record. Empty until the record is saved.
+/// - `actor_user_id` - The user who performed the operation.
+/// - `action` - The operation performed.
+/// - `resource_type` - The type of the resource the operation was performed on.
+/// - `resource_id` - The identifier of the resource the operation was performed on.
+/// - `created_at` - The date and time the operation was performed.
+#[derive(Debug, Getters, Clone)]
+pub struct AuditLog {
+    id: Id,
+    actor_user_id: UserId,
+    action: AuditAction,
+    resource_type: ResourceType,
+    resource_id: String,
+    created_at: DateTime<Utc>,
+}
+
+impl AuditLog {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        actor_user_id: impl Into<UserId>,
+        action: AuditAction,
+        resource_type: ResourceType,
+        resource_id: impl Into<String>,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        let mut audit_log = Self::create(actor_user_id, action, resource_type, resource_id)?;
+        audit_log.id = id;
+        audit_log.created_at = created_at;
+        Ok(audit_log)
+    }
+
+    /// Create an entity in its initial state.
+    pub fn create(
+        actor_user_id: impl Into<UserId>,
+        action: AuditAction,
+        resource_type: ResourceType,
+        resource_id: impl Into<String>,
+    ) -> Result<Self, DomainError> {
+        let actor_user_id = actor_user_id.into();
+        if actor_user_id.is_empty() {
+            log_error!("Actor user id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        let resource_id = resource_id.into();
+        if resource_id.is_empty() {
+            log_error!("Resource id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: String::new(),
+            actor_user_id,
+            action,
+            resource_type,
+            resource_id,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_success() {
+        let audit_log = AuditLog::new(
+            "1",
+            "user",
+            AuditAction::ExportCustomerData,
+            ResourceType::Customer,
+            "100",
+            Utc::now(),
+        )
+        .expect("Failed to create audit log");
+
+        assert_eq!(audit_log.id(), "1");
+        assert_eq!(audit_log.actor_user_id(), "user");
+        assert_eq!(audit_log.action(), &AuditAction::ExportCustomerData);
+        assert_eq!(audit_log.resource_type(), &ResourceType::Customer);
+        assert_eq!(audit_log.resource_id(), "100");
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = AuditLog::new(
+            "",
+            "user",
+            AuditAction::ExportCustomerData,
+            ResourceType::Customer,
+            "100",
+            Utc::now(),
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_success() {
+        let audit_log = AuditLog::create(
+            "user",
+            AuditAction::EraseCustomerData,
+            ResourceType::Customer,
+            "100",
+        )
+        .expect("Failed to create audit log");
+
+        assert_eq!(audit_log.id(), "");
+        assert_eq!(audit_log.action(), &AuditAction::EraseCustomerData);
+    }
+
+    #[test]
+    fn test_create_with_empty_actor_should_fail() {
+        let result = AuditLog::create(
+            "",
+            AuditAction::EraseCustomerData,
+            ResourceType::Customer,
+            "100",
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_with_empty_resource_id_should_fail() {
+        let result = AuditLog::create(
+            "user",
+            AuditAction::EraseCustomerData,
+            ResourceType::Customer,
+            "",
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
@@ -58,6 +58,10 @@ impl AuthorizedResource for Resource {
 /// - `Customer` - Customer resource.
 /// - `Inventory` - Inventory resource.
 /// - `DraftOrder` - Draft order resource.
+/// - `Return` - Return and refund resource of orders.
+/// - `Location` - Location resource such as warehouses and stores.
+/// - `Fulfillment` - Fulfillment resource of orders.
+/// - `CustomerSegment` - Customer segment resource used by operators.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ResourceType {
     Product = 1,
@@ -65,6 +69,10 @@ pub enum ResourceType {
     Customer,
     Inventory,
     DraftOrder,
+    Return,
+    Location,
+    Fulfillment,
+    CustomerSegment,
 }
 
 impl fmt::Display for ResourceType {
@@ -75,6 +83,10 @@ impl fmt::Display for ResourceType {
             ResourceType::Customer => "Customer",
             ResourceType::Inventory => "Inventory",
             ResourceType::DraftOrder => "DraftOrder",
+            ResourceType::Return => "Return",
+            ResourceType::Location => "Location",
+            ResourceType::Fulfillment => "Fulfillment",
+            ResourceType::CustomerSegment => "CustomerSegment",
         };
         write!(f, "{}", value)
     }
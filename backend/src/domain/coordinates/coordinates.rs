@@ -0,0 +1,91 @@
+use derive_getters::Getters;
+
+use crate::{domain::error::error::DomainError, log_error};
+
+/// Mean radius of the earth used for distance computation.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Geographic coordinates value object.
+///
+/// # Examples
+///
+/// ```
+/// use backend::domain::coordinates::coordinates::Coordinates;
+///
+/// let tokyo = Coordinates::new(35.6812, 139.7671).unwrap();
+/// let osaka = Coordinates::new(34.7025, 135.4959).unwrap();
+/// assert!((tokyo.distance_km(&osaka) - 403.0).abs() < 5.0);
+/// ```
+///
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct Coordinates {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Coordinates {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, DomainError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            log_error!("Latitude must be between -90 and 90.", "latitude" => latitude);
+            return Err(DomainError::ValidationError);
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            log_error!("Longitude must be between -180 and 180.", "longitude" => longitude);
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Great-circle distance to the other coordinates in kilometers, computed with the haversine formula.
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let delta_lat = (other.latitude - self.latitude).to_radians();
+        let delta_lng = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lng / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_success() {
+        let coordinates = Coordinates::new(35.6812, 139.7671).unwrap();
+
+        assert_eq!(coordinates.latitude(), &35.6812);
+        assert_eq!(coordinates.longitude(), &139.7671);
+    }
+
+    #[test]
+    fn test_new_with_invalid_latitude_should_fail() {
+        let result = Coordinates::new(90.1, 0.0);
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_invalid_longitude_should_fail() {
+        let result = Coordinates::new(0.0, -180.1);
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_distance_km() {
+        let tokyo = Coordinates::new(35.6812, 139.7671).unwrap();
+        let osaka = Coordinates::new(34.7025, 135.4959).unwrap();
+
+        assert!((tokyo.distance_km(&osaka) - 403.0).abs() < 5.0);
+        assert_eq!(tokyo.distance_km(&tokyo), 0.0);
+    }
+}
@@ -1 +1,2 @@
 pub mod customer;
+pub mod search_criteria;
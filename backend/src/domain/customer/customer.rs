@@ -3,7 +3,7 @@ use derive_getters::Getters;
 
 use crate::{
     domain::{
-        address::address::Address,
+        address::address::{Address, Id as AddressId},
         authorized_resource::authorized_resource::{AuthorizedResource, ResourceType},
         email::email::Email,
         error::error::DomainError,
@@ -47,6 +47,7 @@ pub enum CustomerStatus {
 /// - `note` - Additional notes about the customer (optional).
 /// - `status` - The current status of the customer (e.g., `Active`, `Inactive`).
 /// - `verified_email` - A flag indicating whether the customer's email is verified.
+/// - `email_marketing_consent` - A flag indicating whether the customer agreed to receive marketing emails.
 /// - `created_at` - The date and time the customer record was created.
 /// - `updated_at` - The date and time the customer record was last updated.
 #[derive(Debug, Getters)]
@@ -64,35 +65,60 @@ pub struct Customer {
     note: Option<String>,
     status: CustomerStatus,
     verified_email: bool,
+    email_marketing_consent: bool,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+/// Values to construct a customer from, validated by `Customer::new`.
+///
+/// See `Customer` for the meaning of each field.
+#[derive(Debug)]
+pub struct CustomerParams {
+    pub id: Id,
+    pub user_id: UserId,
+    pub addresses: Vec<Address>,
+    pub default_address: Option<Address>,
+    pub display_name: String,
+    pub email: Option<Email>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub image: Option<Image>,
+    pub phone: Option<Phone>,
+    pub note: Option<String>,
+    pub status: CustomerStatus,
+    pub verified_email: bool,
+    pub email_marketing_consent: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 impl Customer {
     /// Constructor to be used from the repository.
-    pub fn new(
-        id: impl Into<Id>,
-        user_id: impl Into<String>,
-        addresses: Vec<Address>,
-        default_address: Option<Address>,
-        display_name: impl Into<String>,
-        email: Option<Email>,
-        first_name: Option<impl Into<String>>,
-        last_name: Option<impl Into<String>>,
-        image: Option<Image>,
-        phone: Option<Phone>,
-        note: Option<impl Into<String>>,
-        status: CustomerStatus,
-        verified_email: bool,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
-    ) -> Result<Self, DomainError> {
-        let id = id.into();
+    pub fn new(params: CustomerParams) -> Result<Self, DomainError> {
+        let CustomerParams {
+            id,
+            user_id,
+            addresses,
+            default_address,
+            display_name,
+            email,
+            first_name,
+            last_name,
+            image,
+            phone,
+            note,
+            status,
+            verified_email,
+            email_marketing_consent,
+            created_at,
+            updated_at,
+        } = params;
+
         if id.is_empty() {
             log_error!("Id cannot be empty");
             return Err(DomainError::ValidationError);
         }
-        let display_name = display_name.into();
         if display_name.is_empty() {
             log_error!("Display name cannot be empty");
             return Err(DomainError::ValidationError);
@@ -109,22 +135,250 @@ impl Customer {
 
         Ok(Self {
             id,
-            user_id: user_id.into(),
+            user_id,
             addresses,
             default_address,
             display_name,
             email,
-            first_name: first_name.map(|f| f.into()),
-            last_name: last_name.map(|l| l.into()),
+            first_name,
+            last_name,
             image,
             phone,
-            note: note.map(|n| n.into()),
+            note,
             status,
             verified_email,
+            email_marketing_consent,
             created_at,
             updated_at,
         })
     }
+
+    /// Create an entity in its initial state.
+    ///
+    /// A customer must be reachable by either email or phone.
+    /// The display name is derived from the names, falling back to the email or phone.
+    pub fn create(
+        user_id: impl Into<UserId>,
+        email: Option<Email>,
+        first_name: Option<String>,
+        last_name: Option<String>,
+        phone: Option<Phone>,
+        note: Option<String>,
+        email_marketing_consent: bool,
+    ) -> Result<Self, DomainError> {
+        if email.is_none() && phone.is_none() {
+            log_error!("Either email or phone is required");
+            return Err(DomainError::ValidationError);
+        }
+        if email_marketing_consent && email.is_none() {
+            log_error!("Email marketing consent requires an email");
+            return Err(DomainError::ValidationError);
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: String::new(),
+            user_id: user_id.into(),
+            addresses: vec![],
+            default_address: None,
+            display_name: Self::display_name_of(&email, &first_name, &last_name, &phone),
+            email,
+            first_name,
+            last_name,
+            image: None,
+            phone,
+            note,
+            status: CustomerStatus::Active,
+            verified_email: false,
+            email_marketing_consent,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Update the profile of the customer.
+    ///
+    /// The email cannot be changed here since it follows the email registered with the IdP.
+    pub fn update_profile(
+        &mut self,
+        first_name: Option<String>,
+        last_name: Option<String>,
+        phone: Option<Phone>,
+        note: Option<String>,
+        email_marketing_consent: bool,
+    ) -> Result<(), DomainError> {
+        if self.status == CustomerStatus::Inactive {
+            log_error!("Inactive customer cannot be updated.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+        if self.email.is_none() && phone.is_none() {
+            log_error!("Either email or phone is required", "id" => self.id);
+            return Err(DomainError::ValidationError);
+        }
+        if email_marketing_consent && self.email.is_none() {
+            log_error!("Email marketing consent requires an email", "id" => self.id);
+            return Err(DomainError::ValidationError);
+        }
+
+        self.display_name = Self::display_name_of(&self.email, &first_name, &last_name, &phone);
+        self.first_name = first_name;
+        self.last_name = last_name;
+        self.phone = phone;
+        self.note = note;
+        self.email_marketing_consent = email_marketing_consent;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Link the customer to an IdP user and take over the email registered with the IdP.
+    ///
+    /// A customer already linked to another user is refused, so that nobody can take over it by its email.
+    /// Returns whether the customer was changed and needs to be saved.
+    pub fn link_user(
+        &mut self,
+        user_id: impl Into<UserId>,
+        email: Email,
+    ) -> Result<bool, DomainError> {
+        let user_id = user_id.into();
+        if !self.user_id.is_empty() && self.user_id != user_id {
+            log_error!(
+                "Customer is already linked to another user.",
+                "id" => self.id,
+                "user_id" => user_id
+            );
+            return Err(DomainError::AuthorizationError);
+        }
+        if self.user_id == user_id && self.email.as_ref() == Some(&email) {
+            return Ok(false);
+        }
+
+        self.user_id = user_id;
+        self.email = Some(email);
+        self.display_name =
+            Self::display_name_of(&self.email, &self.first_name, &self.last_name, &self.phone);
+        self.updated_at = Utc::now();
+        Ok(true)
+    }
+
+    /// Deactivate the customer.
+    pub fn deactivate(&mut self) -> Result<(), DomainError> {
+        if self.status == CustomerStatus::Inactive {
+            log_error!("Customer has already been deactivated.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+
+        self.status = CustomerStatus::Inactive;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Add an address to the address book of the customer.
+    ///
+    /// The first address always becomes the default address.
+    pub fn add_address(&mut self, address: Address, set_default: bool) -> Result<(), DomainError> {
+        self.ensure_address_book_editable()?;
+        if address.id().is_some() {
+            log_error!("New address cannot have an id.", "id" => self.id, "address_id" => address.id());
+            return Err(DomainError::ValidationError);
+        }
+
+        if set_default || self.default_address.is_none() {
+            self.default_address = Some(address.clone());
+        }
+        self.addresses.push(address);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Replace the address with the given id, keeping the default address in sync.
+    pub fn update_address(
+        &mut self,
+        address_id: &AddressId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<(), DomainError> {
+        self.ensure_address_book_editable()?;
+        let index = self.address_index(address_id)?;
+
+        let address = address.with_id(address_id.clone());
+        if set_default || self.is_default_address(&self.addresses[index]) {
+            self.default_address = Some(address.clone());
+        }
+        self.addresses[index] = address;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Delete the address with the given id.
+    ///
+    /// If the default address is deleted, the first remaining address becomes the default.
+    pub fn delete_address(&mut self, address_id: &AddressId) -> Result<(), DomainError> {
+        self.ensure_address_book_editable()?;
+        let index = self.address_index(address_id)?;
+
+        let deleted = self.addresses.remove(index);
+        if self.is_default_address(&deleted) {
+            self.default_address = self.addresses.first().cloned();
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Set the address with the given id as the default address.
+    pub fn set_default_address(&mut self, address_id: &AddressId) -> Result<(), DomainError> {
+        self.ensure_address_book_editable()?;
+        let index = self.address_index(address_id)?;
+
+        self.default_address = Some(self.addresses[index].clone());
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Whether the given address is the default address of the customer.
+    pub fn is_default_address(&self, address: &Address) -> bool {
+        self.default_address.as_ref() == Some(address)
+    }
+
+    fn ensure_address_book_editable(&self) -> Result<(), DomainError> {
+        if self.status == CustomerStatus::Inactive {
+            log_error!("Address book of inactive customer cannot be changed.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+        Ok(())
+    }
+
+    fn address_index(&self, address_id: &AddressId) -> Result<usize, DomainError> {
+        self.addresses
+            .iter()
+            .position(|a| a.id().as_ref() == Some(address_id))
+            .ok_or_else(|| {
+                log_error!("Address not found.", "id" => self.id, "address_id" => address_id);
+                DomainError::NotFound
+            })
+    }
+
+    fn display_name_of(
+        email: &Option<Email>,
+        first_name: &Option<String>,
+        last_name: &Option<String>,
+        phone: &Option<Phone>,
+    ) -> String {
+        let name = [first_name, last_name]
+            .iter()
+            .filter_map(|n| n.as_deref())
+            .filter(|n| !n.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !name.is_empty() {
+            return name;
+        }
+
+        match (email, phone) {
+            (Some(email), _) => email.value().to_owned(),
+            (None, Some(phone)) => phone.value().to_owned(),
+            (None, None) => String::new(),
+        }
+    }
 }
 
 impl AuthorizedResource for Customer {
@@ -140,43 +394,48 @@ impl AuthorizedResource for Customer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::address::address::AddressParams;
     use chrono::Utc;
 
     fn mock_address() -> Address {
-        Address::new(
-            Some("123 Main St"),
-            None::<String>,
-            Some("City"),
-            true,
-            Some("Country"),
-            Some("John"),
-            Some("Doe"),
-            Some("Province"),
-            Some("12345"),
-            Some("+1234567890"),
-        )
+        Address::new(AddressParams {
+            id: Some("1".to_string()),
+            address1: Some("123 Main St".to_string()),
+            city: Some("City".to_string()),
+            coordinates_validated: true,
+            country: Some("Country".to_string()),
+            country_code: Some("JP".to_string()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            province: Some("Province".to_string()),
+            province_code: Some("13".to_string()),
+            zip: Some("12345".to_string()),
+            phone: Some("+1234567890".to_string()),
+            ..Default::default()
+        })
         .expect("Failed to create mock address")
     }
 
     #[test]
     fn test_new_success() {
-        let customer = Customer::new(
-            "123",
-            "user123",
-            vec![mock_address()],
-            Some(mock_address()),
-            "John Doe",
-            Some(Email::new("john@example.com").unwrap()),
-            Some("John"),
-            Some("Doe"),
-            None,
-            Some(Phone::new("+1234567890").unwrap()),
-            Some("Note"),
-            CustomerStatus::Active,
-            true,
-            Utc::now(),
-            Utc::now(),
-        );
+        let customer = Customer::new(CustomerParams {
+            id: "123".to_string(),
+            user_id: "user123".to_string(),
+            addresses: vec![mock_address()],
+            default_address: Some(mock_address()),
+            display_name: "John Doe".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: Some(Phone::new("+1234567890").unwrap()),
+            note: Some("Note".to_string()),
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
 
         assert!(customer.is_ok());
 
@@ -198,87 +457,494 @@ mod tests {
 
     #[test]
     fn test_new_error_empty_id() {
-        let customer = Customer::new(
-            "",
+        let customer = Customer::new(CustomerParams {
+            id: "".to_string(),
+            user_id: "user123".to_string(),
+            addresses: vec![mock_address()],
+            default_address: Some(mock_address()),
+            display_name: "John Doe".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: Some(Phone::new("+1234567890").unwrap()),
+            note: Some("Note".to_string()),
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
+
+        assert!(customer.is_err());
+        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_error_empty_display_name() {
+        let customer = Customer::new(CustomerParams {
+            id: "123".to_string(),
+            user_id: "user123".to_string(),
+            addresses: vec![mock_address()],
+            default_address: Some(mock_address()),
+            display_name: "".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: Some(Phone::new("+1234567890").unwrap()),
+            note: Some("Note".to_string()),
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
+
+        assert!(customer.is_err());
+        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_error_invalid_default_address() {
+        let customer = Customer::new(CustomerParams {
+            id: "123".to_string(),
+            user_id: "user123".to_string(),
+            addresses: vec![mock_address()],
+            default_address: Some(
+                Address::new(AddressParams {
+                    id: Some("1".to_string()),
+                    address1: None,
+                    city: Some("City".to_string()),
+                    coordinates_validated: true,
+                    country: Some("Country".to_string()),
+                    country_code: Some("JP".to_string()),
+                    first_name: Some("John".to_string()),
+                    last_name: Some("Doe".to_string()),
+                    province: Some("Province".to_string()),
+                    province_code: Some("13".to_string()),
+                    zip: Some("12345".to_string()),
+                    phone: Some("+1234567890".to_string()),
+                    ..Default::default()
+                })
+                .expect("Failed to create mock address"),
+            ),
+            display_name: "John Doe".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: Some(Phone::new("+1234567890").unwrap()),
+            note: Some("Note".to_string()),
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
+
+        assert!(customer.is_err());
+        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+    }
+
+    fn mock_customer() -> Customer {
+        Customer::new(CustomerParams {
+            id: "123".to_string(),
+            user_id: "user123".to_string(),
+            addresses: vec![mock_address()],
+            default_address: Some(mock_address()),
+            display_name: "John Doe".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: Some(Phone::new("+1234567890").unwrap()),
+            note: Some("Note".to_string()),
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .expect("Failed to create mock customer")
+    }
+
+    #[test]
+    fn test_create_success() {
+        let customer = Customer::create(
             "user123",
-            vec![mock_address()],
-            Some(mock_address()),
-            "John Doe",
             Some(Email::new("john@example.com").unwrap()),
-            Some("John"),
-            Some("Doe"),
+            Some("John".to_string()),
+            Some("Doe".to_string()),
+            None,
             None,
-            Some(Phone::new("+1234567890").unwrap()),
-            Some("Note"),
-            CustomerStatus::Active,
             true,
-            Utc::now(),
-            Utc::now(),
+        )
+        .expect("Failed to create customer");
+
+        assert_eq!(customer.id(), "");
+        assert_eq!(customer.user_id(), "user123");
+        assert_eq!(customer.display_name(), "John Doe");
+        assert_eq!(customer.status(), &CustomerStatus::Active);
+        assert!(!customer.verified_email());
+        assert!(customer.email_marketing_consent());
+        assert!(customer.addresses().is_empty());
+    }
+
+    #[test]
+    fn test_create_display_name_falls_back_to_contact() {
+        let customer = Customer::create(
+            "user123",
+            None,
+            None,
+            None,
+            Some(Phone::new("+1234567890").unwrap()),
+            None,
+            false,
+        )
+        .expect("Failed to create customer");
+
+        assert_eq!(customer.display_name(), "+1234567890");
+    }
+
+    #[test]
+    fn test_create_without_email_and_phone_should_fail() {
+        let result = Customer::create(
+            "user123",
+            None,
+            Some("John".to_string()),
+            None,
+            None,
+            None,
+            false,
         );
 
-        assert!(customer.is_err());
-        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+        assert!(matches!(result, Err(DomainError::ValidationError)));
     }
 
     #[test]
-    fn test_new_error_empty_display_name() {
-        let customer = Customer::new(
-            "123",
+    fn test_create_marketing_consent_without_email_should_fail() {
+        let result = Customer::create(
             "user123",
-            vec![mock_address()],
-            Some(mock_address()),
-            "",
-            Some(Email::new("john@example.com").unwrap()),
-            Some("John"),
-            Some("Doe"),
+            None,
+            None,
             None,
             Some(Phone::new("+1234567890").unwrap()),
-            Some("Note"),
-            CustomerStatus::Active,
+            None,
             true,
-            Utc::now(),
-            Utc::now(),
         );
 
-        assert!(customer.is_err());
-        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+        assert!(matches!(result, Err(DomainError::ValidationError)));
     }
 
     #[test]
-    fn test_new_error_invalid_default_address() {
-        let customer = Customer::new(
-            "123",
+    fn test_update_profile_success() {
+        let mut customer = mock_customer();
+
+        customer
+            .update_profile(
+                Some("Jane".to_string()),
+                Some("Smith".to_string()),
+                None,
+                None,
+                true,
+            )
+            .expect("Failed to update profile");
+
+        assert_eq!(customer.display_name(), "Jane Smith");
+        assert_eq!(customer.first_name(), &Some("Jane".to_string()));
+        assert_eq!(customer.phone(), &None);
+        assert_eq!(customer.note(), &None);
+        assert!(customer.email_marketing_consent());
+    }
+
+    #[test]
+    fn test_update_profile_inactive_should_fail() {
+        let mut customer = mock_customer();
+        customer
+            .deactivate()
+            .expect("Failed to deactivate customer");
+
+        let result = customer.update_profile(None, None, None, None, false);
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+    }
+
+    #[test]
+    fn test_link_user_takes_over_email() {
+        let mut customer = mock_customer();
+
+        let changed = customer
+            .link_user("user123", Email::new("new@example.com").unwrap())
+            .expect("Failed to link user");
+
+        assert!(changed);
+        assert_eq!(customer.user_id(), "user123");
+        assert_eq!(
+            customer.email().as_ref().unwrap().value(),
+            "new@example.com"
+        );
+    }
+
+    #[test]
+    fn test_link_user_to_unlinked_customer() {
+        let mut customer = mock_customer();
+        customer.user_id = String::new();
+
+        let changed = customer
+            .link_user("user456", Email::new("john@example.com").unwrap())
+            .expect("Failed to link user");
+
+        assert!(changed);
+        assert_eq!(customer.user_id(), "user456");
+    }
+
+    #[test]
+    fn test_link_user_unchanged() {
+        let mut customer = mock_customer();
+
+        let changed = customer
+            .link_user("user123", Email::new("john@example.com").unwrap())
+            .expect("Failed to link user");
+
+        assert!(!changed);
+    }
+
+    #[test]
+    fn test_link_user_linked_to_another_user_should_fail() {
+        let mut customer = mock_customer();
+
+        let result = customer.link_user("user456", Email::new("john@example.com").unwrap());
+
+        assert!(matches!(result, Err(DomainError::AuthorizationError)));
+        assert_eq!(customer.user_id(), "user123");
+    }
+
+    #[test]
+    fn test_deactivate_success() {
+        let mut customer = mock_customer();
+
+        customer
+            .deactivate()
+            .expect("Failed to deactivate customer");
+
+        assert_eq!(customer.status(), &CustomerStatus::Inactive);
+    }
+
+    #[test]
+    fn test_deactivate_inactive_should_fail() {
+        let mut customer = mock_customer();
+        customer
+            .deactivate()
+            .expect("Failed to deactivate customer");
+
+        let result = customer.deactivate();
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+    }
+
+    fn mock_new_address(address1: &str) -> Address {
+        Address::new(AddressParams {
+            address1: Some(address1.to_string()),
+            city: Some("City".to_string()),
+            coordinates_validated: false,
+            country: Some("Country".to_string()),
+            country_code: Some("JP".to_string()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            province: Some("Province".to_string()),
+            province_code: Some("13".to_string()),
+            zip: Some("12345".to_string()),
+            phone: Some("+1234567890".to_string()),
+            ..Default::default()
+        })
+        .expect("Failed to create mock address")
+    }
+
+    fn mock_customer_with_addresses() -> Customer {
+        let addresses = vec![
+            mock_address(),
+            mock_new_address("456 Second St").with_id("2"),
+        ];
+        Customer::new(CustomerParams {
+            id: "123".to_string(),
+            user_id: "user123".to_string(),
+            addresses: addresses.clone(),
+            default_address: Some(addresses[0].clone()),
+            display_name: "John Doe".to_string(),
+            email: Some(Email::new("john@example.com").unwrap()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            image: None,
+            phone: None,
+            note: None,
+            status: CustomerStatus::Active,
+            verified_email: true,
+            email_marketing_consent: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .expect("Failed to create mock customer")
+    }
+
+    #[test]
+    fn test_add_address_first_becomes_default() {
+        let mut customer = Customer::create(
             "user123",
-            vec![mock_address()],
-            Some(
-                Address::new(
-                    None::<String>, // Different value from mock_address()
-                    None::<String>,
-                    Some("City"),
-                    true,
-                    Some("Country"),
-                    Some("John"),
-                    Some("Doe"),
-                    Some("Province"),
-                    Some("12345"),
-                    Some("+1234567890"),
-                )
-                .expect("Failed to create mock address"),
-            ),
-            "John Doe",
             Some(Email::new("john@example.com").unwrap()),
-            Some("John"),
-            Some("Doe"),
             None,
-            Some(Phone::new("+1234567890").unwrap()),
-            Some("Note"),
-            CustomerStatus::Active,
-            true,
-            Utc::now(),
-            Utc::now(),
+            None,
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+
+        customer
+            .add_address(mock_new_address("123 Main St"), false)
+            .expect("Failed to add address");
+
+        assert_eq!(customer.addresses().len(), 1);
+        assert!(customer.is_default_address(&customer.addresses()[0]));
+    }
+
+    #[test]
+    fn test_add_address_set_default() {
+        let mut customer = mock_customer();
+
+        customer
+            .add_address(mock_new_address("456 Second St"), true)
+            .expect("Failed to add address");
+
+        assert_eq!(customer.addresses().len(), 2);
+        assert!(customer.is_default_address(&customer.addresses()[1]));
+    }
+
+    #[test]
+    fn test_add_address_keeps_default() {
+        let mut customer = mock_customer();
+
+        customer
+            .add_address(mock_new_address("456 Second St"), false)
+            .expect("Failed to add address");
+
+        assert!(customer.is_default_address(&customer.addresses()[0]));
+    }
+
+    #[test]
+    fn test_add_address_with_id_should_fail() {
+        let mut customer = mock_customer();
+
+        let result = customer.add_address(mock_address(), false);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_add_address_inactive_should_fail() {
+        let mut customer = mock_customer();
+        customer.deactivate().unwrap();
+
+        let result = customer.add_address(mock_new_address("456 Second St"), false);
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+    }
+
+    #[test]
+    fn test_update_address_keeps_default_in_sync() {
+        let mut customer = mock_customer_with_addresses();
+
+        customer
+            .update_address(&"1".to_string(), mock_new_address("789 Third St"), false)
+            .expect("Failed to update address");
+
+        let default_address = customer.default_address().clone().unwrap();
+        assert_eq!(default_address.id(), &Some("1".to_string()));
+        assert_eq!(
+            default_address.address1(),
+            &Some("789 Third St".to_string())
         );
+        assert!(customer.is_default_address(&customer.addresses()[0]));
+    }
 
-        assert!(customer.is_err());
-        assert_eq!(customer.unwrap_err(), DomainError::ValidationError);
+    #[test]
+    fn test_update_address_set_default() {
+        let mut customer = mock_customer_with_addresses();
+
+        customer
+            .update_address(&"2".to_string(), mock_new_address("789 Third St"), true)
+            .expect("Failed to update address");
+
+        assert!(customer.is_default_address(&customer.addresses()[1]));
+        assert_eq!(
+            customer.addresses()[1].address1(),
+            &Some("789 Third St".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_address_not_found() {
+        let mut customer = mock_customer_with_addresses();
+
+        let result =
+            customer.update_address(&"3".to_string(), mock_new_address("789 Third St"), false);
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[test]
+    fn test_delete_address_default_falls_back_to_first() {
+        let mut customer = mock_customer_with_addresses();
+
+        customer
+            .delete_address(&"1".to_string())
+            .expect("Failed to delete address");
+
+        assert_eq!(customer.addresses().len(), 1);
+        assert_eq!(
+            customer.default_address().clone().unwrap().id(),
+            &Some("2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_delete_address_last_clears_default() {
+        let mut customer = mock_customer();
+
+        customer
+            .delete_address(&"1".to_string())
+            .expect("Failed to delete address");
+
+        assert!(customer.addresses().is_empty());
+        assert!(customer.default_address().is_none());
+    }
+
+    #[test]
+    fn test_delete_address_not_found() {
+        let mut customer = mock_customer();
+
+        let result = customer.delete_address(&"3".to_string());
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[test]
+    fn test_set_default_address_success() {
+        let mut customer = mock_customer_with_addresses();
+
+        customer
+            .set_default_address(&"2".to_string())
+            .expect("Failed to set default address");
+
+        assert!(customer.is_default_address(&customer.addresses()[1]));
+    }
+
+    #[test]
+    fn test_set_default_address_not_found() {
+        let mut customer = mock_customer_with_addresses();
+
+        let result = customer.set_default_address(&"3".to_string());
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
     }
 }
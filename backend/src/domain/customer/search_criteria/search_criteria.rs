@@ -0,0 +1,214 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        email::email::Email, error::error::DomainError, money::amount::amount::Amount,
+        phone::phone::Phone,
+    },
+    log_error,
+};
+
+/// Represents the order in which searched customers are listed.
+///
+/// # Variants
+/// - `CreatedAt` - Sort by the date and time the customer was created.
+/// - `Id` - Sort by the customer id.
+/// - `Name` - Sort by the customer name.
+/// - `Relevance` - Sort by the relevance to the search criteria.
+/// - `UpdatedAt` - Sort by the date and time the customer was last updated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomerSortKey {
+    CreatedAt,
+    Id,
+    Name,
+    Relevance,
+    UpdatedAt,
+}
+
+/// Criteria to search customers.
+///
+/// All specified conditions must be satisfied. Ranges are inclusive.
+///
+/// # Fields
+/// - `name` - A fragment of the first or last name of the customer.
+/// - `email` - The exact email of the customer.
+/// - `phone` - The exact phone number of the customer.
+/// - `tags` - Tags that the customer must have.
+/// - `created_at_min` - The lower bound of the creation date and time.
+/// - `created_at_max` - The upper bound of the creation date and time.
+/// - `orders_count_min` - The lower bound of the number of orders.
+/// - `orders_count_max` - The upper bound of the number of orders.
+/// - `amount_spent_min` - The lower bound of the total amount spent.
+/// - `amount_spent_max` - The upper bound of the total amount spent.
+#[derive(Debug, Getters, Clone, PartialEq, Default)]
+pub struct CustomerSearchCriteria {
+    name: Option<String>,
+    email: Option<Email>,
+    phone: Option<Phone>,
+    tags: Vec<String>,
+    created_at_min: Option<DateTime<Utc>>,
+    created_at_max: Option<DateTime<Utc>>,
+    orders_count_min: Option<u32>,
+    orders_count_max: Option<u32>,
+    amount_spent_min: Option<Amount>,
+    amount_spent_max: Option<Amount>,
+}
+
+/// Values to construct search criteria from, validated by `CustomerSearchCriteria::new`.
+///
+/// See `CustomerSearchCriteria` for the meaning of each field.
+#[derive(Debug, Default)]
+pub struct CustomerSearchCriteriaParams {
+    pub name: Option<String>,
+    pub email: Option<Email>,
+    pub phone: Option<Phone>,
+    pub tags: Vec<String>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub orders_count_min: Option<u32>,
+    pub orders_count_max: Option<u32>,
+    pub amount_spent_min: Option<Amount>,
+    pub amount_spent_max: Option<Amount>,
+}
+
+impl CustomerSearchCriteria {
+    pub fn new(params: CustomerSearchCriteriaParams) -> Result<Self, DomainError> {
+        let CustomerSearchCriteriaParams {
+            name,
+            email,
+            phone,
+            tags,
+            created_at_min,
+            created_at_max,
+            orders_count_min,
+            orders_count_max,
+            amount_spent_min,
+            amount_spent_max,
+        } = params;
+
+        let name = name.map(|n| n.trim().to_string());
+        if name.as_ref().is_some_and(|n| n.is_empty()) {
+            log_error!("Name fragment cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if tags.iter().any(|t| t.trim().is_empty()) {
+            log_error!("Tag cannot be empty", "tags" => tags);
+            return Err(DomainError::ValidationError);
+        }
+        if let (Some(min), Some(max)) = (&created_at_min, &created_at_max) {
+            if min > max {
+                log_error!("Invalid creation date range.", "min" => min, "max" => max);
+                return Err(DomainError::ValidationError);
+            }
+        }
+        if let (Some(min), Some(max)) = (&orders_count_min, &orders_count_max) {
+            if min > max {
+                log_error!("Invalid orders count range.", "min" => min, "max" => max);
+                return Err(DomainError::ValidationError);
+            }
+        }
+        if let (Some(min), Some(max)) = (&amount_spent_min, &amount_spent_max) {
+            if min.value() > max.value() {
+                log_error!("Invalid amount spent range.", "min" => min, "max" => max);
+                return Err(DomainError::ValidationError);
+            }
+        }
+
+        Ok(Self {
+            name,
+            email,
+            phone,
+            tags: tags.into_iter().map(|t| t.trim().to_string()).collect(),
+            created_at_min,
+            created_at_max,
+            orders_count_min,
+            orders_count_max,
+            amount_spent_min,
+            amount_spent_max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_new_success() {
+        let now = Utc::now();
+        let criteria = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: Some(" John ".to_string()),
+            email: Some(Email::new("john@example.com").unwrap()),
+            tags: vec![" vip ".to_string()],
+            created_at_min: Some(now - Duration::days(30)),
+            created_at_max: Some(now),
+            orders_count_min: Some(1),
+            orders_count_max: Some(10),
+            amount_spent_min: Some(Amount::new(100.0).unwrap()),
+            amount_spent_max: Some(Amount::new(1000.0).unwrap()),
+            ..Default::default()
+        })
+        .expect("Failed to create criteria");
+
+        assert_eq!(criteria.name(), &Some("John".to_string()));
+        assert_eq!(criteria.tags(), &vec!["vip".to_string()]);
+        assert_eq!(criteria.orders_count_min(), &Some(1));
+    }
+
+    #[test]
+    fn test_new_with_empty_name_should_fail() {
+        let result = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: Some("  ".to_string()),
+            ..Default::default()
+        });
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_empty_tag_should_fail() {
+        let result = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            tags: vec!["".to_string()],
+            ..Default::default()
+        });
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_invalid_created_at_range_should_fail() {
+        let now = Utc::now();
+        let result = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            created_at_min: Some(now),
+            created_at_max: Some(now - Duration::days(1)),
+            ..Default::default()
+        });
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_invalid_orders_count_range_should_fail() {
+        let result = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            orders_count_min: Some(5),
+            orders_count_max: Some(1),
+            ..Default::default()
+        });
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_new_with_invalid_amount_spent_range_should_fail() {
+        let result = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            amount_spent_min: Some(Amount::new(100.0).unwrap()),
+            amount_spent_max: Some(Amount::new(10.0).unwrap()),
+            ..Default::default()
+        });
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+}
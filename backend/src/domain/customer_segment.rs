@@ -0,0 +1 @@
+pub mod customer_segment;
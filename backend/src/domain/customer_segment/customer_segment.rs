@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::{AuthorizedResource, ResourceType},
+        customer::search_criteria::search_criteria::CustomerSearchCriteria,
+        error::error::DomainError,
+        user::user::Id as UserId,
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents a named set of criteria to search customers repeatedly.
+///
+/// # Fields
+/// - `id` - The unique identifier of the segment. Empty until the segment is saved.
+/// - `name` - The name of the segment.
+/// - `criteria` - The criteria of the customers belonging to the segment.
+/// - `created_at` - The date and time the segment was created.
+/// - `updated_at` - The date and time the segment was last updated.
+#[derive(Debug, Getters, Clone)]
+pub struct CustomerSegment {
+    id: Id,
+    name: String,
+    criteria: CustomerSearchCriteria,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl CustomerSegment {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        name: impl Into<String>,
+        criteria: CustomerSearchCriteria,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        let name = Self::validate_name(name.into())?;
+
+        Ok(Self {
+            id,
+            name,
+            criteria,
+            created_at,
+            updated_at,
+        })
+    }
+
+    /// Create an entity in its initial state.
+    pub fn create(
+        name: impl Into<String>,
+        criteria: CustomerSearchCriteria,
+    ) -> Result<Self, DomainError> {
+        let name = Self::validate_name(name.into())?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: String::new(),
+            name,
+            criteria,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    fn validate_name(name: String) -> Result<String, DomainError> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            log_error!("Name cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        Ok(name)
+    }
+}
+
+impl AuthorizedResource for CustomerSegment {
+    fn resource_type(&self) -> ResourceType {
+        ResourceType::CustomerSegment
+    }
+
+    fn owner_user_id(&self) -> Option<UserId> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_success() {
+        let segment = CustomerSegment::new(
+            "1",
+            "VIP",
+            CustomerSearchCriteria::default(),
+            Utc::now(),
+            Utc::now(),
+        )
+        .expect("Failed to create segment");
+
+        assert_eq!(segment.id(), "1");
+        assert_eq!(segment.name(), "VIP");
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = CustomerSegment::new(
+            "",
+            "VIP",
+            CustomerSearchCriteria::default(),
+            Utc::now(),
+            Utc::now(),
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_success() {
+        let segment = CustomerSegment::create(" VIP ", CustomerSearchCriteria::default())
+            .expect("Failed to create segment");
+
+        assert_eq!(segment.id(), "");
+        assert_eq!(segment.name(), "VIP");
+    }
+
+    #[test]
+    fn test_create_with_empty_name_should_fail() {
+        let result = CustomerSegment::create(" ", CustomerSearchCriteria::default());
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
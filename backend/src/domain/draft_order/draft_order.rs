@@ -30,6 +30,19 @@ pub enum DraftOrderStatus {
     Canceled,
 }
 
+impl DraftOrderStatus {
+    /// Returns whether the status can transition to the given status.
+    ///
+    /// Only an open draft order can be completed or canceled.
+    pub fn can_transition_to(&self, next: &DraftOrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (DraftOrderStatus::Open, DraftOrderStatus::Completed)
+                | (DraftOrderStatus::Open, DraftOrderStatus::Canceled)
+        )
+    }
+}
+
 /// Representing Draft Orders.
 ///
 /// Unlike regular orders, they serve as Admin Orders, which are created from the application or admin screen.
@@ -55,7 +68,9 @@ pub enum DraftOrderStatus {
 /// - `presentment_currency_code` - Currency code used for the order. May differ from the store's default currency code.
 /// - `order_id` - An optional identifier for the associated order, if the draft was converted to a finalized order.
 /// - `owner_user_id` - Data owner user ID.
+/// - `cancel_reason` - The reason the draft order was canceled, if it has been canceled.
 /// - `completed_at` - An optional timestamp indicating when the order was completed.
+/// - `canceled_at` - An optional timestamp indicating when the order was canceled.
 /// - `created_at` - The timestamp when the draft order was initially created.
 /// - `update_at` - The timestamp when the draft order was last updated.
 #[derive(Debug, Getters)]
@@ -99,48 +114,76 @@ pub struct DraftOrder {
 
     owner_user_id: UserId,
 
+    cancel_reason: Option<String>,
+
     completed_at: Option<DateTime<Utc>>,
+    canceled_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+/// Values to construct a draft order from, validated by `DraftOrder::new`.
+///
+/// See `DraftOrder` for the meaning of each field.
+#[derive(Debug)]
+pub struct DraftOrderParams {
+    pub id: Id,
+    pub name: String,
+    pub status: DraftOrderStatus,
+    pub customer_id: Option<CustomerId>,
+    pub billing_address: Option<Address>,
+    pub shipping_address: Option<Address>,
+    pub note: Option<String>,
+    pub line_items: Vec<LineItem>,
+    pub reserve_inventory_until: Option<DateTime<Utc>>,
+    pub discount: Option<Discount>,
+    pub subtotal_price_set: Money,
+    pub taxes_included: bool,
+    pub tax_exempt: bool,
+    pub total_tax_set: Money,
+    pub total_discounts_set: Money,
+    pub total_shipping_price_set: Money,
+    pub total_price_set: Money,
+    pub presentment_currency_code: CurrencyCode,
+    pub order_id: Option<OrderId>,
+    pub owner_user_id: UserId,
+    pub cancel_reason: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub canceled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 impl DraftOrder {
     /// Constructor to be used from the repository.
-    pub fn new(
-        id: impl Into<Id>,
-        name: impl Into<String>,
-        status: DraftOrderStatus,
-        customer_id: Option<CustomerId>,
-        billing_address: Option<Address>,
-        shipping_address: Option<Address>,
-        note: Option<String>,
-        line_items: Vec<LineItem>,
-        reserve_inventory_until: Option<DateTime<Utc>>,
-        discount: Option<Discount>,
-        subtotal_price_set: Money,
-        taxes_included: bool,
-        tax_exempt: bool,
-        total_tax_set: Money,
-        total_discounts_set: Money,
-        total_shipping_price_set: Money,
-        total_price_set: Money,
-        presentment_currency_code: CurrencyCode,
-        order_id: Option<OrderId>,
-        owner_user_id: impl Into<UserId>,
-        completed_at: Option<DateTime<Utc>>,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
-    ) -> Result<Self, DomainError> {
-        let id = id.into();
-        if id.is_empty() {
-            log_error!("Id cannot be empty");
-            return Err(DomainError::ValidationError);
-        }
-        let name = name.into();
-        if name.is_empty() {
-            log_error!("Name cannot be empty");
-            return Err(DomainError::ValidationError);
-        }
+    pub fn new(params: DraftOrderParams) -> Result<Self, DomainError> {
+        let DraftOrderParams {
+            id,
+            name,
+            status,
+            customer_id,
+            billing_address,
+            shipping_address,
+            note,
+            line_items,
+            reserve_inventory_until,
+            discount,
+            subtotal_price_set,
+            taxes_included,
+            tax_exempt,
+            total_tax_set,
+            total_discounts_set,
+            total_shipping_price_set,
+            total_price_set,
+            presentment_currency_code,
+            order_id,
+            owner_user_id,
+            cancel_reason,
+            completed_at,
+            canceled_at,
+            created_at,
+            updated_at,
+        } = params;
 
         let instance = Self {
             id,
@@ -162,8 +205,10 @@ impl DraftOrder {
             total_price_set,
             presentment_currency_code,
             order_id,
-            owner_user_id: owner_user_id.into(),
+            owner_user_id,
+            cancel_reason,
             completed_at,
+            canceled_at,
             created_at,
             updated_at,
         };
@@ -225,21 +270,59 @@ impl DraftOrder {
             presentment_currency_code,
             order_id: None,
             owner_user_id: owner_user_id.into(),
+            cancel_reason: None,
             completed_at: None,
+            canceled_at: None,
             created_at: now,
             updated_at: now,
         })
     }
 
+    /// Complete the draft order.
+    ///
+    /// The order ID is assigned by the EC platform when the completion is persisted,
+    /// so a completed draft order without an order ID is one that has yet to be converted.
     pub fn complete(&mut self) -> Result<(), DomainError> {
-        if self.status == DraftOrderStatus::Completed {
-            log_error!("Draft order is already completed");
+        self.transition_to(DraftOrderStatus::Completed)?;
+
+        let now = Utc::now();
+        self.completed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Cancel the draft order with the given reason.
+    pub fn cancel(&mut self, reason: impl Into<String>) -> Result<(), DomainError> {
+        let reason = reason.into();
+        if reason.is_empty() {
+            log_error!("Cancel reason cannot be empty");
             return Err(DomainError::ValidationError);
         }
-        self.status = DraftOrderStatus::Completed;
+        self.transition_to(DraftOrderStatus::Canceled)?;
+
+        let now = Utc::now();
+        self.cancel_reason = Some(reason);
+        self.canceled_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
 
-        let default_date = DateTime::<Utc>::default();
-        self.completed_at = Some(default_date);
+    /// Returns whether the draft order has been completed but not yet converted to an order.
+    pub fn is_pending_completion(&self) -> bool {
+        self.status == DraftOrderStatus::Completed && self.order_id.is_none()
+    }
+
+    fn transition_to(&mut self, next: DraftOrderStatus) -> Result<(), DomainError> {
+        if !self.status.can_transition_to(&next) {
+            log_error!(
+                "Invalid draft order status transition.",
+                "id" => self.id,
+                "from" => self.status,
+                "to" => next
+            );
+            return Err(DomainError::InvalidStateTransition);
+        }
+        self.status = next;
         Ok(())
     }
 }
@@ -257,7 +340,7 @@ impl AuthorizedResource for DraftOrder {
 #[cfg(test)]
 mod tests {
     use crate::domain::{
-        address::address::Address,
+        address::address::{Address, AddressParams},
         line_item::discount::discount::{Discount, DiscountValueType},
         money::{amount::amount::Amount, money::CurrencyCode},
     };
@@ -300,49 +383,53 @@ mod tests {
 
     fn mock_address() -> Option<Address> {
         Some(
-            Address::new(
-                Some("123 Main St"),
-                None::<String>,
-                Some("City"),
-                true,
-                Some("Country"),
-                Some("John"),
-                Some("Doe"),
-                Some("Province"),
-                Some("12345"),
-                Some("+1234567890"),
-            )
+            Address::new(AddressParams {
+                address1: Some("123 Main St".to_string()),
+                city: Some("City".to_string()),
+                coordinates_validated: true,
+                country: Some("Country".to_string()),
+                country_code: Some("JP".to_string()),
+                first_name: Some("John".to_string()),
+                last_name: Some("Doe".to_string()),
+                province: Some("Province".to_string()),
+                province_code: Some("13".to_string()),
+                zip: Some("12345".to_string()),
+                phone: Some("+1234567890".to_string()),
+                ..Default::default()
+            })
             .expect("Failed to create mock address"),
         )
     }
 
     /// Helper to create a valid `DraftOrder` for testing.
     fn mock_draft_order() -> DraftOrder {
-        DraftOrder::new(
-            "0",
-            "Test Order",
-            DraftOrderStatus::Open,
-            None,
-            mock_address(),
-            mock_address(),
-            None,
-            mock_line_items(2),
-            None,
-            None,
-            mock_money(),
-            true,
-            false,
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            CurrencyCode::default(),
-            None,
-            "Owner".to_string(),
-            None,
-            Utc::now(),
-            Utc::now(),
-        )
+        DraftOrder::new(DraftOrderParams {
+            id: "0".to_string(),
+            name: "Test Order".to_string(),
+            status: DraftOrderStatus::Open,
+            customer_id: None,
+            billing_address: mock_address(),
+            shipping_address: mock_address(),
+            note: None,
+            line_items: mock_line_items(2),
+            reserve_inventory_until: None,
+            discount: None,
+            subtotal_price_set: mock_money(),
+            taxes_included: true,
+            tax_exempt: false,
+            total_tax_set: mock_money(),
+            total_discounts_set: mock_money(),
+            total_shipping_price_set: mock_money(),
+            total_price_set: mock_money(),
+            presentment_currency_code: CurrencyCode::default(),
+            order_id: None,
+            owner_user_id: "Owner".to_string(),
+            cancel_reason: None,
+            completed_at: None,
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
         .expect("Failed to create mock draft order")
     }
 
@@ -355,62 +442,66 @@ mod tests {
 
     #[test]
     fn test_new_with_empty_id_should_fail() {
-        let result = DraftOrder::new(
-            "",
-            "Test Order",
-            DraftOrderStatus::Open,
-            None,
-            mock_address(),
-            mock_address(),
-            None,
-            mock_line_items(1),
-            None,
-            None,
-            mock_money(),
-            true,
-            false,
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            CurrencyCode::default(),
-            None,
-            "Owner".to_string(),
-            None,
-            Utc::now(),
-            Utc::now(),
-        );
+        let result = DraftOrder::new(DraftOrderParams {
+            id: "".to_string(),
+            name: "Test Order".to_string(),
+            status: DraftOrderStatus::Open,
+            customer_id: None,
+            billing_address: mock_address(),
+            shipping_address: mock_address(),
+            note: None,
+            line_items: mock_line_items(1),
+            reserve_inventory_until: None,
+            discount: None,
+            subtotal_price_set: mock_money(),
+            taxes_included: true,
+            tax_exempt: false,
+            total_tax_set: mock_money(),
+            total_discounts_set: mock_money(),
+            total_shipping_price_set: mock_money(),
+            total_price_set: mock_money(),
+            presentment_currency_code: CurrencyCode::default(),
+            order_id: None,
+            owner_user_id: "Owner".to_string(),
+            cancel_reason: None,
+            completed_at: None,
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
 
         assert!(result.is_err());
     }
 
     #[test]
     fn test_new_with_empty_name_should_fail() {
-        let result = DraftOrder::new(
-            "valid_id",
-            "",
-            DraftOrderStatus::Open,
-            None,
-            mock_address(),
-            mock_address(),
-            None,
-            mock_line_items(1),
-            None,
-            None,
-            mock_money(),
-            true,
-            false,
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            mock_money(),
-            CurrencyCode::default(),
-            None,
-            "Owner".to_string(),
-            None,
-            Utc::now(),
-            Utc::now(),
-        );
+        let result = DraftOrder::new(DraftOrderParams {
+            id: "valid_id".to_string(),
+            name: "".to_string(),
+            status: DraftOrderStatus::Open,
+            customer_id: None,
+            billing_address: mock_address(),
+            shipping_address: mock_address(),
+            note: None,
+            line_items: mock_line_items(1),
+            reserve_inventory_until: None,
+            discount: None,
+            subtotal_price_set: mock_money(),
+            taxes_included: true,
+            tax_exempt: false,
+            total_tax_set: mock_money(),
+            total_discounts_set: mock_money(),
+            total_shipping_price_set: mock_money(),
+            total_price_set: mock_money(),
+            presentment_currency_code: CurrencyCode::default(),
+            order_id: None,
+            owner_user_id: "Owner".to_string(),
+            cancel_reason: None,
+            completed_at: None,
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        });
 
         assert!(result.is_err());
     }
@@ -438,10 +529,91 @@ mod tests {
     #[test]
     fn test_complete() {
         let mut draft_order = mock_draft_order();
+        let before = Utc::now();
         draft_order
             .complete()
             .expect("Failed to complete draft order");
 
         assert_eq!(draft_order.status(), &DraftOrderStatus::Completed);
+        assert!(draft_order.completed_at().unwrap() >= before);
+        assert!(draft_order.is_pending_completion());
+    }
+
+    #[test]
+    fn test_complete_already_completed_should_fail() {
+        let mut draft_order = mock_draft_order();
+        draft_order
+            .complete()
+            .expect("Failed to complete draft order");
+
+        let result = draft_order.complete();
+
+        assert_eq!(result, Err(DomainError::InvalidStateTransition));
+        assert_eq!(draft_order.status(), &DraftOrderStatus::Completed);
+    }
+
+    #[test]
+    fn test_complete_canceled_should_fail() {
+        let mut draft_order = mock_draft_order();
+        draft_order
+            .cancel("Customer request")
+            .expect("Failed to cancel draft order");
+
+        let result = draft_order.complete();
+
+        assert_eq!(result, Err(DomainError::InvalidStateTransition));
+        assert_eq!(draft_order.status(), &DraftOrderStatus::Canceled);
+        assert_eq!(draft_order.completed_at(), &None);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut draft_order = mock_draft_order();
+        let before = Utc::now();
+        draft_order
+            .cancel("Customer request")
+            .expect("Failed to cancel draft order");
+
+        assert_eq!(draft_order.status(), &DraftOrderStatus::Canceled);
+        assert_eq!(
+            draft_order.cancel_reason(),
+            &Some("Customer request".to_string())
+        );
+        assert!(draft_order.canceled_at().unwrap() >= before);
+        assert!(!draft_order.is_pending_completion());
+    }
+
+    #[test]
+    fn test_cancel_with_empty_reason_should_fail() {
+        let mut draft_order = mock_draft_order();
+
+        let result = draft_order.cancel("");
+
+        assert_eq!(result, Err(DomainError::ValidationError));
+        assert_eq!(draft_order.status(), &DraftOrderStatus::Open);
+    }
+
+    #[test]
+    fn test_cancel_completed_should_fail() {
+        let mut draft_order = mock_draft_order();
+        draft_order
+            .complete()
+            .expect("Failed to complete draft order");
+
+        let result = draft_order.cancel("Customer request");
+
+        assert_eq!(result, Err(DomainError::InvalidStateTransition));
+        assert_eq!(draft_order.cancel_reason(), &None);
+    }
+
+    #[test]
+    fn test_status_can_transition_to() {
+        assert!(DraftOrderStatus::Open.can_transition_to(&DraftOrderStatus::Completed));
+        assert!(DraftOrderStatus::Open.can_transition_to(&DraftOrderStatus::Canceled));
+        assert!(!DraftOrderStatus::Open.can_transition_to(&DraftOrderStatus::Open));
+        assert!(!DraftOrderStatus::Completed.can_transition_to(&DraftOrderStatus::Open));
+        assert!(!DraftOrderStatus::Completed.can_transition_to(&DraftOrderStatus::Canceled));
+        assert!(!DraftOrderStatus::Canceled.can_transition_to(&DraftOrderStatus::Open));
+        assert!(!DraftOrderStatus::Canceled.can_transition_to(&DraftOrderStatus::Completed));
     }
 }
@@ -1,3 +1,4 @@
+use derive_getters::Getters;
 use derive_more::{Display, Error};
 
 /// Entity of Errors.
@@ -19,6 +20,9 @@ use derive_more::{Display, Error};
 /// - `AuthenticationError` - Authentication failed.
 /// - `AuthenticationExpired` - Authentication expired.
 /// - `AuthorizationError` - Authorization failed.
+/// - `InvalidStateTransition` - The requested state transition is not allowed.
+/// - `InvalidInput` - The input was rejected, with the details of each invalid field.
+/// - `Throttled` - The request was throttled by an external service.
 ///
 /// # Example
 /// ```
@@ -77,4 +81,36 @@ pub enum DomainError {
     /// Authorization failed.
     #[display(fmt = "Authorization failed.")]
     AuthorizationError,
+
+    /// The requested state transition is not allowed.
+    #[display(fmt = "Invalid state transition.")]
+    InvalidStateTransition,
+
+    /// The input was rejected, with the details of each invalid field.
+    #[display(fmt = "Invalid input.")]
+    InvalidInput { errors: Vec<FieldError> },
+
+    /// The request was throttled by an external service.
+    #[display(fmt = "Request throttled.")]
+    Throttled,
+}
+
+/// Detail of an input field that was rejected.
+///
+/// `field` is the dot-separated path of the input, and `code` identifies the cause when known.
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct FieldError {
+    field: Option<String>,
+    code: Option<String>,
+    message: String,
+}
+
+impl FieldError {
+    pub fn new(field: Option<String>, code: Option<String>, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            code,
+            message: message.into(),
+        }
+    }
 }
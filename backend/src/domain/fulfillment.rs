@@ -0,0 +1,3 @@
+pub mod fulfillment;
+pub mod fulfillment_line_item;
+pub mod tracking_info;
@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment_order::{
+            fulfillment_order::FulfillmentOrder,
+            fulfillment_order_line_item::fulfillment_order_line_item::Id as FulfillmentOrderLineItemId,
+        },
+        location::location::Id as LocationId,
+        order::order::Id as OrderId,
+    },
+    log_error,
+};
+
+use super::{
+    fulfillment_line_item::fulfillment_line_item::FulfillmentLineItem,
+    tracking_info::tracking_info::TrackingInfo,
+};
+
+pub type Id = String;
+
+/// Represents the status of a fulfillment.
+///
+/// # Variants
+/// - `Pending` - The fulfillment has been requested but not yet processed.
+/// - `Open` - The fulfillment is being processed by a third party.
+/// - `Success` - The fulfillment was completed successfully.
+/// - `Cancelled` - The fulfillment was cancelled.
+/// - `Error` - An error occurred while processing the fulfillment.
+/// - `Failure` - The fulfillment request failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FulfillmentStatus {
+    Pending,
+    Open,
+    Success,
+    Cancelled,
+    Error,
+    Failure,
+}
+
+/// Represents a shipment of one or more line items of an order.
+///
+/// # Fields
+/// - `id` - The unique identifier for the fulfillment.
+/// - `order_id` - The identifier of the fulfilled order.
+/// - `status` - The current status of the fulfillment.
+/// - `line_items` - The fulfilled line items.
+/// - `tracking_info` - The tracking information of the shipment.
+/// - `created_at` - The timestamp when the fulfillment was created.
+/// - `update_at` - The timestamp when the fulfillment was last updated.
+#[derive(Debug, Getters)]
+pub struct Fulfillment {
+    id: Id,
+    order_id: OrderId,
+    status: FulfillmentStatus,
+    line_items: Vec<FulfillmentLineItem>,
+    tracking_info: Vec<TrackingInfo>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Fulfillment {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        order_id: impl Into<OrderId>,
+        status: FulfillmentStatus,
+        line_items: Vec<FulfillmentLineItem>,
+        tracking_info: Vec<TrackingInfo>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            order_id: order_id.into(),
+            status,
+            line_items,
+            tracking_info,
+            created_at,
+            updated_at,
+        })
+    }
+
+    /// Create an entity in its initial state.
+    ///
+    /// The requested line items must belong to fulfillment orders assigned to the given location,
+    /// and their quantities must not exceed the quantities remaining to be fulfilled.
+    pub fn create(
+        order_id: impl Into<OrderId>,
+        location_id: &LocationId,
+        fulfillment_orders: &[FulfillmentOrder],
+        line_items: Vec<(FulfillmentOrderLineItemId, u32)>,
+        tracking_info: Option<TrackingInfo>,
+    ) -> Result<Self, DomainError> {
+        if line_items.is_empty() {
+            log_error!("Line items to be fulfilled cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        // Aggregate the requested quantities so that duplicate entries cannot bypass the remaining check.
+        let mut requested: Vec<(FulfillmentOrderLineItemId, u32)> = Vec::new();
+        let mut indexes: HashMap<FulfillmentOrderLineItemId, usize> = HashMap::new();
+        for (id, quantity) in line_items {
+            match indexes.get(&id) {
+                Some(index) => {
+                    let total = &mut requested[*index].1;
+                    *total = total.checked_add(quantity).ok_or_else(|| {
+                        log_error!(
+                            "Requested quantity is too large.",
+                            "fulfillment_order_line_item_id" => id
+                        );
+                        DomainError::ValidationError
+                    })?;
+                }
+                None => {
+                    indexes.insert(id.clone(), requested.len());
+                    requested.push((id, quantity));
+                }
+            }
+        }
+
+        let fulfillment_line_items = requested
+            .into_iter()
+            .map(|(id, quantity)| {
+                let (fulfillment_order, line_item) = fulfillment_orders
+                    .iter()
+                    .filter(|f| f.is_fulfillable() && f.is_assigned_to(location_id))
+                    .find_map(|f| f.find_line_item(&id).map(|l| (f, l)))
+                    .ok_or_else(|| {
+                        log_error!(
+                            "Line item is not fulfillable at the location.",
+                            "fulfillment_order_line_item_id" => id,
+                            "location_id" => location_id
+                        );
+                        DomainError::ValidationError
+                    })?;
+
+                if quantity > *line_item.remaining_quantity() {
+                    log_error!(
+                        "Quantity exceeds the remaining quantity to be fulfilled.",
+                        "fulfillment_order_line_item_id" => id,
+                        "quantity" => quantity,
+                        "remaining_quantity" => line_item.remaining_quantity()
+                    );
+                    return Err(DomainError::ValidationError);
+                }
+
+                FulfillmentLineItem::new(
+                    String::new(),
+                    line_item.line_item_id(),
+                    Some(fulfillment_order.id().to_owned()),
+                    Some(id),
+                    quantity,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: String::new(),
+            order_id: order_id.into(),
+            status: FulfillmentStatus::Pending,
+            line_items: fulfillment_line_items,
+            tracking_info: tracking_info.into_iter().collect(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Replace the tracking information of the fulfillment.
+    pub fn update_tracking_info(&mut self, tracking_info: TrackingInfo) -> Result<(), DomainError> {
+        if self.status == FulfillmentStatus::Cancelled {
+            log_error!("Cannot update tracking info of a cancelled fulfillment.", "id" => self.id);
+            return Err(DomainError::ValidationError);
+        }
+
+        self.tracking_info = vec![tracking_info];
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::fulfillment_order::{
+        fulfillment_order::FulfillmentOrderStatus,
+        fulfillment_order_line_item::fulfillment_order_line_item::FulfillmentOrderLineItem,
+    };
+
+    use super::*;
+
+    fn mock_fulfillment_orders() -> Vec<FulfillmentOrder> {
+        vec![
+            FulfillmentOrder::new(
+                "1",
+                "1",
+                Some("location_1".to_string()),
+                FulfillmentOrderStatus::Open,
+                vec![
+                    FulfillmentOrderLineItem::new("11", "101", 5, 3).unwrap(),
+                    FulfillmentOrderLineItem::new("12", "102", 2, 2).unwrap(),
+                ],
+            )
+            .unwrap(),
+            FulfillmentOrder::new(
+                "2",
+                "1",
+                Some("location_2".to_string()),
+                FulfillmentOrderStatus::Open,
+                vec![FulfillmentOrderLineItem::new("21", "103", 1, 1).unwrap()],
+            )
+            .unwrap(),
+            FulfillmentOrder::new(
+                "3",
+                "1",
+                Some("location_1".to_string()),
+                FulfillmentOrderStatus::Closed,
+                vec![FulfillmentOrderLineItem::new("31", "104", 1, 1).unwrap()],
+            )
+            .unwrap(),
+        ]
+    }
+
+    fn mock_tracking_info() -> TrackingInfo {
+        TrackingInfo::new(Some("UPS"), "123", Some("https://example.com/123")).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let fulfillment = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("11".to_string(), 3), ("12".to_string(), 1)],
+            Some(mock_tracking_info()),
+        )
+        .expect("Failed to create fulfillment");
+
+        assert_eq!(fulfillment.id(), "");
+        assert_eq!(fulfillment.order_id(), "1");
+        assert_eq!(fulfillment.status(), &FulfillmentStatus::Pending);
+        assert_eq!(fulfillment.line_items().len(), 2);
+        assert_eq!(fulfillment.line_items()[0].line_item_id(), "101");
+        assert_eq!(
+            fulfillment.line_items()[0].fulfillment_order_id(),
+            &Some("1".to_string())
+        );
+        assert_eq!(fulfillment.line_items()[0].quantity(), &3);
+        assert_eq!(fulfillment.tracking_info(), &vec![mock_tracking_info()]);
+    }
+
+    #[test]
+    fn test_create_with_empty_line_items_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_exceeding_remaining_quantity_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("11".to_string(), 4)],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_with_duplicate_line_items_exceeding_remaining_quantity_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("11".to_string(), 2), ("11".to_string(), 2)],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_with_duplicate_line_items_overflowing_quantity_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("11".to_string(), u32::MAX), ("11".to_string(), 2)],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_at_other_location_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_2".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("11".to_string(), 1)],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_from_closed_fulfillment_order_should_fail() {
+        let result = Fulfillment::create(
+            "1",
+            &"location_1".to_string(),
+            &mock_fulfillment_orders(),
+            vec![("31".to_string(), 1)],
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_update_tracking_info() {
+        let mut fulfillment = Fulfillment::new(
+            "1",
+            "1",
+            FulfillmentStatus::Success,
+            vec![],
+            vec![],
+            Utc::now(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        fulfillment
+            .update_tracking_info(mock_tracking_info())
+            .expect("Failed to update tracking info");
+
+        assert_eq!(fulfillment.tracking_info(), &vec![mock_tracking_info()]);
+    }
+
+    #[test]
+    fn test_update_tracking_info_of_cancelled_should_fail() {
+        let mut fulfillment = Fulfillment::new(
+            "1",
+            "1",
+            FulfillmentStatus::Cancelled,
+            vec![],
+            vec![],
+            Utc::now(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        let result = fulfillment.update_tracking_info(mock_tracking_info());
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
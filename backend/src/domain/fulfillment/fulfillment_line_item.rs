@@ -0,0 +1 @@
+pub mod fulfillment_line_item;
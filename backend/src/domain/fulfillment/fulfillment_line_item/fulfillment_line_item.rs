@@ -0,0 +1,70 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment_order::{
+            fulfillment_order::Id as FulfillmentOrderId,
+            fulfillment_order_line_item::fulfillment_order_line_item::Id as FulfillmentOrderLineItemId,
+        },
+        line_item::line_item::Id as LineItemId,
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents a line item included in a fulfillment.
+///
+/// The fulfillment order references are only known for fulfillments created by this application,
+/// since the EC platform reports fulfilled items against the order line items.
+///
+/// # Fields
+/// - `id` - The unique identifier for the fulfillment line item. Empty until the fulfillment is created.
+/// - `line_item_id` - The identifier of the fulfilled order line item.
+/// - `fulfillment_order_id` - The identifier of the fulfillment order the item was fulfilled from.
+/// - `fulfillment_order_line_item_id` - The identifier of the fulfillment order line item.
+/// - `quantity` - The number of units fulfilled.
+#[derive(Debug, Getters, Clone)]
+pub struct FulfillmentLineItem {
+    id: Id,
+    line_item_id: LineItemId,
+    fulfillment_order_id: Option<FulfillmentOrderId>,
+    fulfillment_order_line_item_id: Option<FulfillmentOrderLineItemId>,
+    quantity: u32,
+}
+
+impl FulfillmentLineItem {
+    pub fn new(
+        id: impl Into<Id>,
+        line_item_id: impl Into<LineItemId>,
+        fulfillment_order_id: Option<FulfillmentOrderId>,
+        fulfillment_order_line_item_id: Option<FulfillmentOrderLineItemId>,
+        quantity: u32,
+    ) -> Result<Self, DomainError> {
+        if quantity == 0 {
+            log_error!("Fulfillment quantity must be greater than zero");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: id.into(),
+            line_item_id: line_item_id.into(),
+            fulfillment_order_id,
+            fulfillment_order_line_item_id,
+            quantity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_zero_quantity_should_fail() {
+        let result = FulfillmentLineItem::new("1", "1", None, None, 0);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
@@ -0,0 +1 @@
+pub mod tracking_info;
@@ -0,0 +1,72 @@
+use derive_getters::Getters;
+
+use crate::{domain::error::error::DomainError, log_error};
+
+/// Shipment tracking information for a fulfillment.
+///
+/// # Examples
+///
+/// ```
+/// use backend::domain::fulfillment::tracking_info::tracking_info::TrackingInfo;
+///
+/// let tracking_info = TrackingInfo::new(
+///     Some("UPS"),
+///     "1Z999AA10123456784",
+///     Some("https://www.ups.com/track?tracknum=1Z999AA10123456784"),
+/// )
+/// .unwrap();
+/// assert_eq!(tracking_info.number(), "1Z999AA10123456784");
+/// ```
+///
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct TrackingInfo {
+    company: Option<String>,
+    number: String,
+    url: Option<String>,
+}
+
+impl TrackingInfo {
+    pub fn new(
+        company: Option<impl Into<String>>,
+        number: impl Into<String>,
+        url: Option<impl Into<String>>,
+    ) -> Result<Self, DomainError> {
+        let number = number.into();
+        if number.is_empty() {
+            log_error!("Tracking number cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        let url = url.map(|u| u.into());
+        if let Some(url) = &url {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                log_error!("Tracking url must be an http(s) url.", "url" => url);
+                return Err(DomainError::ValidationError);
+            }
+        }
+
+        Ok(Self {
+            company: company.map(|c| c.into()),
+            number,
+            url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_empty_number_should_fail() {
+        let result = TrackingInfo::new(Some("UPS"), "", None::<String>);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_new_with_invalid_url_should_fail() {
+        let result = TrackingInfo::new(Some("UPS"), "123", Some("ftp://example.com"));
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
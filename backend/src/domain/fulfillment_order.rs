@@ -0,0 +1,2 @@
+pub mod fulfillment_order;
+pub mod fulfillment_order_line_item;
@@ -0,0 +1,169 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError, location::location::Id as LocationId,
+        order::order::Id as OrderId,
+    },
+    log_error,
+};
+
+use super::fulfillment_order_line_item::fulfillment_order_line_item::{
+    FulfillmentOrderLineItem, Id as FulfillmentOrderLineItemId,
+};
+
+pub type Id = String;
+
+/// Represents the status of a fulfillment order.
+///
+/// # Variants
+/// - `Open` - The fulfillment order is ready for fulfillment.
+/// - `InProgress` - The fulfillment order is being processed.
+/// - `Scheduled` - The fulfillment order is deferred to a later date.
+/// - `OnHold` - The fulfillment order is on hold.
+/// - `Incomplete` - The fulfillment order cannot be completed as requested.
+/// - `Closed` - The fulfillment order has been completed.
+/// - `Cancelled` - The fulfillment order has been cancelled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FulfillmentOrderStatus {
+    Open,
+    InProgress,
+    Scheduled,
+    OnHold,
+    Incomplete,
+    Closed,
+    Cancelled,
+}
+
+/// Represents a group of line items of an order that are fulfilled from the same location.
+///
+/// # Fields
+/// - `id` - The unique identifier for the fulfillment order.
+/// - `order_id` - The identifier of the order to which the fulfillment order belongs.
+/// - `assigned_location_id` - The identifier of the location assigned to fulfill the items, if any.
+/// - `status` - The current status of the fulfillment order.
+/// - `line_items` - The line items to be fulfilled.
+#[derive(Debug, Getters)]
+pub struct FulfillmentOrder {
+    id: Id,
+    order_id: OrderId,
+    assigned_location_id: Option<LocationId>,
+    status: FulfillmentOrderStatus,
+    line_items: Vec<FulfillmentOrderLineItem>,
+}
+
+impl FulfillmentOrder {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        order_id: impl Into<OrderId>,
+        assigned_location_id: Option<LocationId>,
+        status: FulfillmentOrderStatus,
+        line_items: Vec<FulfillmentOrderLineItem>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        let order_id = order_id.into();
+        if order_id.is_empty() {
+            log_error!("Order id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            order_id,
+            assigned_location_id,
+            status,
+            line_items,
+        })
+    }
+
+    /// Returns whether items can be fulfilled from this fulfillment order.
+    pub fn is_fulfillable(&self) -> bool {
+        matches!(
+            self.status,
+            FulfillmentOrderStatus::Open | FulfillmentOrderStatus::InProgress
+        )
+    }
+
+    /// Returns whether the fulfillment order is assigned to the given location.
+    pub fn is_assigned_to(&self, location_id: &LocationId) -> bool {
+        self.assigned_location_id.as_ref() == Some(location_id)
+    }
+
+    /// Find a line item by its id.
+    pub fn find_line_item(
+        &self,
+        id: &FulfillmentOrderLineItemId,
+    ) -> Option<&FulfillmentOrderLineItem> {
+        self.line_items
+            .iter()
+            .find(|line_item| line_item.id() == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_fulfillment_order(status: FulfillmentOrderStatus) -> FulfillmentOrder {
+        FulfillmentOrder::new(
+            "1",
+            "1",
+            Some("location_id".to_string()),
+            status,
+            vec![FulfillmentOrderLineItem::new("1", "1", 5, 3).unwrap()],
+        )
+        .expect("Failed to create fulfillment order")
+    }
+
+    #[test]
+    fn test_new() {
+        let fulfillment_order = mock_fulfillment_order(FulfillmentOrderStatus::Open);
+
+        assert_eq!(fulfillment_order.id(), "1");
+        assert_eq!(fulfillment_order.order_id(), "1");
+        assert_eq!(fulfillment_order.line_items().len(), 1);
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = FulfillmentOrder::new("", "1", None, FulfillmentOrderStatus::Open, vec![]);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_new_with_empty_order_id_should_fail() {
+        let result = FulfillmentOrder::new("1", "", None, FulfillmentOrderStatus::Open, vec![]);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_is_fulfillable() {
+        assert!(mock_fulfillment_order(FulfillmentOrderStatus::Open).is_fulfillable());
+        assert!(mock_fulfillment_order(FulfillmentOrderStatus::InProgress).is_fulfillable());
+        assert!(!mock_fulfillment_order(FulfillmentOrderStatus::Closed).is_fulfillable());
+        assert!(!mock_fulfillment_order(FulfillmentOrderStatus::OnHold).is_fulfillable());
+    }
+
+    #[test]
+    fn test_is_assigned_to() {
+        let fulfillment_order = mock_fulfillment_order(FulfillmentOrderStatus::Open);
+
+        assert!(fulfillment_order.is_assigned_to(&"location_id".to_string()));
+        assert!(!fulfillment_order.is_assigned_to(&"other".to_string()));
+    }
+
+    #[test]
+    fn test_find_line_item() {
+        let fulfillment_order = mock_fulfillment_order(FulfillmentOrderStatus::Open);
+
+        assert!(fulfillment_order.find_line_item(&"1".to_string()).is_some());
+        assert!(fulfillment_order.find_line_item(&"2".to_string()).is_none());
+    }
+}
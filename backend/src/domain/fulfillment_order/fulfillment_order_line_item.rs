@@ -0,0 +1 @@
+pub mod fulfillment_order_line_item;
@@ -0,0 +1,84 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{error::error::DomainError, line_item::line_item::Id as LineItemId},
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents a line item of a fulfillment order.
+///
+/// # Fields
+/// - `id` - The unique identifier for the fulfillment order line item.
+/// - `line_item_id` - The identifier of the order line item to be fulfilled.
+/// - `total_quantity` - The total number of units to be fulfilled.
+/// - `remaining_quantity` - The number of units remaining to be fulfilled.
+#[derive(Debug, Getters, Clone)]
+pub struct FulfillmentOrderLineItem {
+    id: Id,
+    line_item_id: LineItemId,
+    total_quantity: u32,
+    remaining_quantity: u32,
+}
+
+impl FulfillmentOrderLineItem {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        line_item_id: impl Into<LineItemId>,
+        total_quantity: u32,
+        remaining_quantity: u32,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if remaining_quantity > total_quantity {
+            log_error!(
+                "Remaining quantity cannot exceed total quantity.",
+                "total_quantity" => total_quantity,
+                "remaining_quantity" => remaining_quantity
+            );
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            line_item_id: line_item_id.into(),
+            total_quantity,
+            remaining_quantity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let line_item = FulfillmentOrderLineItem::new("1", "2", 5, 3)
+            .expect("Failed to create fulfillment order line item");
+
+        assert_eq!(line_item.id(), "1");
+        assert_eq!(line_item.line_item_id(), "2");
+        assert_eq!(line_item.total_quantity(), &5);
+        assert_eq!(line_item.remaining_quantity(), &3);
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = FulfillmentOrderLineItem::new("", "2", 5, 3);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_new_with_remaining_exceeding_total_should_fail() {
+        let result = FulfillmentOrderLineItem::new("1", "2", 5, 6);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
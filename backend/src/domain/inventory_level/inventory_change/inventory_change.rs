@@ -43,6 +43,7 @@ pub enum InventoryChangeReason {
     ReservationCreated,
     ReservationDeleted,
     ReservationUpdated,
+    Restock,
 }
 
 /// Represents a change made to the inventory.
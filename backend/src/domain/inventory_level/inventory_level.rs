@@ -60,6 +60,15 @@ impl InventoryLevel {
         })
     }
 
+    /// Quantity available for sale at the location.
+    pub fn available_quantity(&self) -> i32 {
+        self.quantities
+            .iter()
+            .filter(|q| q.inventory_type() == &InventoryType::Available)
+            .map(|q| *q.quantity())
+            .sum()
+    }
+
     pub fn create_inventory_change(
         &self,
         name: &InventoryType,
@@ -110,6 +119,18 @@ mod tests {
         assert!(inventory_level.is_err());
     }
 
+    #[test]
+    fn test_available_quantity() {
+        let quantities = vec![
+            Quantity::new(10, InventoryType::Available).unwrap(),
+            Quantity::new(3, InventoryType::Committed).unwrap(),
+        ];
+        let inventory_level =
+            InventoryLevel::new("level_id", "item_id", "location_id", quantities).unwrap();
+
+        assert_eq!(inventory_level.available_quantity(), 10);
+    }
+
     #[test]
     fn test_create_inventory_change() {
         let quantities = vec![Quantity::new(10, InventoryType::Available).unwrap()];
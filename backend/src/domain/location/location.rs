@@ -1,6 +1,12 @@
 use derive_getters::Getters;
 
-use crate::domain::{address::address::Address, error::error::DomainError};
+use crate::domain::{
+    address::address::Address,
+    authorized_resource::authorized_resource::{AuthorizedResource, ResourceType},
+    coordinates::coordinates::Coordinates,
+    error::error::DomainError,
+    user::user::Id as UserId,
+};
 use crate::log_error;
 
 pub type Id = String;
@@ -25,6 +31,7 @@ pub struct Location {
 }
 
 impl Location {
+    /// Constructor to be used from the repository.
     pub fn new(
         id: impl Into<Id>,
         name: impl Into<String>,
@@ -53,25 +60,137 @@ impl Location {
             suggested_addresses,
         })
     }
+
+    /// Create an entity in its initial state.
+    pub fn create(
+        name: impl Into<String>,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<Self, DomainError> {
+        let name = name.into();
+        if name.is_empty() {
+            log_error!("Name cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        Self::validate_address(&address)?;
+
+        Ok(Self {
+            id: String::new(),
+            name,
+            is_active: true,
+            fulfills_online_orders,
+            address,
+            suggested_addresses: vec![],
+        })
+    }
+
+    /// Update the name, address and whether online orders are fulfilled.
+    pub fn update(
+        &mut self,
+        name: impl Into<String>,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<(), DomainError> {
+        let name = name.into();
+        if name.is_empty() {
+            log_error!("Name cannot be empty", "id" => self.id);
+            return Err(DomainError::ValidationError);
+        }
+        Self::validate_address(&address)?;
+
+        self.name = name;
+        self.address = address;
+        self.fulfills_online_orders = fulfills_online_orders;
+        Ok(())
+    }
+
+    /// Deactivate the location.
+    ///
+    /// The inventory stocked at the location is checked by the EC platform when it is saved.
+    pub fn deactivate(&mut self) -> Result<(), DomainError> {
+        if !self.is_active {
+            log_error!("Location has already been deactivated.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+
+        self.is_active = false;
+        Ok(())
+    }
+
+    /// Activate the location.
+    pub fn activate(&mut self) -> Result<(), DomainError> {
+        if self.is_active {
+            log_error!("Location is already active.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+
+        self.is_active = true;
+        Ok(())
+    }
+
+    /// Distance in kilometers from the origin, if the coordinates of the location are known.
+    pub fn distance_km(&self, origin: &Coordinates) -> Option<f64> {
+        self.address
+            .coordinates()
+            .as_ref()
+            .map(|coordinates| coordinates.distance_km(origin))
+    }
+
+    /// Whether the location is in the postal code, ignoring case, spaces and hyphens.
+    pub fn is_in_postal_code(&self, postal_code: &str) -> bool {
+        let normalize = |zip: &str| {
+            zip.chars()
+                .filter(|c| !c.is_whitespace() && *c != '-')
+                .collect::<String>()
+                .to_uppercase()
+        };
+
+        self.address
+            .zip()
+            .as_deref()
+            .is_some_and(|zip| normalize(zip) == normalize(postal_code))
+    }
+
+    /// Locations are addressed by country, so the country code is required.
+    fn validate_address(address: &Address) -> Result<(), DomainError> {
+        if address.country_code().is_none() {
+            log_error!("Country code of the location address cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        Ok(())
+    }
+}
+
+impl AuthorizedResource for Location {
+    fn resource_type(&self) -> ResourceType {
+        ResourceType::Location
+    }
+
+    fn owner_user_id(&self) -> Option<UserId> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::address::address::AddressParams;
 
     fn mock_address() -> Address {
-        Address::new(
-            Some("123 Main St"),
-            None::<String>,
-            Some("City"),
-            true,
-            Some("Country"),
-            Some("John"),
-            Some("Doe"),
-            Some("Province"),
-            Some("12345"),
-            Some("+1234567890"),
-        )
+        Address::new(AddressParams {
+            address1: Some("123 Main St".to_string()),
+            city: Some("City".to_string()),
+            coordinates_validated: true,
+            country: Some("Country".to_string()),
+            country_code: Some("JP".to_string()),
+            first_name: Some("John".to_string()),
+            last_name: Some("Doe".to_string()),
+            province: Some("Province".to_string()),
+            province_code: Some("13".to_string()),
+            zip: Some("12345".to_string()),
+            phone: Some("+1234567890".to_string()),
+            ..Default::default()
+        })
         .expect("Failed to create mock address")
     }
 
@@ -109,4 +228,194 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), DomainError::ValidationError);
     }
+
+    #[test]
+    fn test_create_success() {
+        let location = Location::create("Store", mock_address(), true).unwrap();
+
+        assert_eq!(location.id(), "");
+        assert_eq!(location.name(), "Store");
+        assert!(location.is_active());
+        assert!(location.fulfills_online_orders());
+        assert!(location.suggested_addresses().is_empty());
+    }
+
+    #[test]
+    fn test_create_with_empty_name_should_fail() {
+        let result = Location::create("", mock_address(), true);
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_create_without_country_code_should_fail() {
+        let address = Address::new(AddressParams {
+            address1: Some("123 Main St".to_string()),
+            city: Some("City".to_string()),
+            coordinates_validated: true,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = Location::create("Store", address, true);
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+    }
+
+    #[test]
+    fn test_update_success() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        location
+            .update("Sub Warehouse", mock_address(), true)
+            .expect("Failed to update location");
+
+        assert_eq!(location.name(), "Sub Warehouse");
+        assert!(location.fulfills_online_orders());
+    }
+
+    #[test]
+    fn test_update_with_empty_name_should_fail() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        let result = location.update("", mock_address(), true);
+
+        assert_eq!(result.unwrap_err(), DomainError::ValidationError);
+        assert_eq!(location.name(), "Main Warehouse");
+    }
+
+    #[test]
+    fn test_deactivate_success() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        location
+            .deactivate()
+            .expect("Failed to deactivate location");
+
+        assert!(!location.is_active());
+    }
+
+    #[test]
+    fn test_deactivate_inactive_location_should_fail() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            false,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        let result = location.deactivate();
+
+        assert_eq!(result.unwrap_err(), DomainError::InvalidStateTransition);
+    }
+
+    #[test]
+    fn test_activate_success() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            false,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        location.activate().expect("Failed to activate location");
+
+        assert!(location.is_active());
+    }
+
+    #[test]
+    fn test_activate_active_location_should_fail() {
+        let mut location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        let result = location.activate();
+
+        assert_eq!(result.unwrap_err(), DomainError::InvalidStateTransition);
+    }
+
+    #[test]
+    fn test_distance_km() {
+        let origin = Coordinates::new(35.6812, 139.7671).unwrap();
+        let location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address().with_coordinates(Coordinates::new(34.7025, 135.4959).unwrap()),
+            vec![],
+        )
+        .unwrap();
+
+        assert!((location.distance_km(&origin).unwrap() - 403.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_distance_km_without_coordinates() {
+        let origin = Coordinates::new(35.6812, 139.7671).unwrap();
+        let location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(location.distance_km(&origin), None);
+    }
+
+    #[test]
+    fn test_is_in_postal_code() {
+        let location = Location::new(
+            "loc_1",
+            "Main Warehouse",
+            true,
+            false,
+            mock_address(),
+            vec![],
+        )
+        .unwrap();
+
+        assert!(location.is_in_postal_code("12345"));
+        assert!(location.is_in_postal_code(" 123-45 "));
+        assert!(!location.is_in_postal_code("54321"));
+    }
 }
@@ -2,3 +2,4 @@ pub mod associated_id;
 pub mod media;
 pub mod media_content;
 pub mod src;
+pub mod staged_upload;
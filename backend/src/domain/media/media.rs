@@ -22,16 +22,16 @@ pub enum MediaStatus {
 /// - `name` - An optional name for the media file.
 /// - `status` - The current status of the media (e.g., `Active`, `Inactive`, `InPreparation`).
 /// - `content` - The media content, which can be an image, video, or other media types.
-/// - `created_at` - The timestamp indicating when the media was created.
-/// - `updated_at` - The timestamp indicating when the media was last updated.
+/// - `created_at` - The timestamp indicating when the media was created, if known.
+/// - `updated_at` - The timestamp indicating when the media was last updated, if known.
 #[derive(Debug, Getters)]
 pub struct Media {
     id: Id,
     name: Option<String>,
     status: MediaStatus,
     content: Option<MediaContent>,
-    created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
 }
 
 impl Media {
@@ -40,8 +40,8 @@ impl Media {
         name: Option<impl Into<String>>,
         status: MediaStatus,
         content: Option<MediaContent>,
-        created_at: DateTime<Utc>,
-        updated_at: DateTime<Utc>,
+        created_at: Option<DateTime<Utc>>,
+        updated_at: Option<DateTime<Utc>>,
     ) -> Result<Self, DomainError> {
         let id = id.into();
         if id.is_empty() {
@@ -58,12 +58,59 @@ impl Media {
             updated_at,
         })
     }
+
+    /// Create an entity in its initial state.
+    ///
+    /// Media is processed by the EC platform after it is created, so it starts in preparation.
+    pub fn create(content: MediaContent) -> Self {
+        let now = Utc::now();
+
+        Media {
+            id: String::new(),
+            name: None,
+            status: MediaStatus::InPreparation,
+            content: Some(content),
+            created_at: Some(now),
+            updated_at: Some(now),
+        }
+    }
+
+    /// Update the alternative text of the media content.
+    pub fn update_alt(&mut self, alt: Option<impl Into<String>>) -> Result<(), DomainError> {
+        match &mut self.content {
+            Some(content) => content.update_alt(alt),
+            None => {
+                log_error!("Media without content has no alternative text.", "id" => self.id);
+                return Err(DomainError::ValidationError);
+            }
+        }
+
+        self.updated_at = Some(Utc::now());
+        Ok(())
+    }
+
+    /// Whether the media is still being processed by the EC platform.
+    pub fn is_in_preparation(&self) -> bool {
+        self.status == MediaStatus::InPreparation
+    }
+
+    /// Verify that the media can be featured as the first media of the entity.
+    ///
+    /// Media that failed to be processed cannot be featured.
+    pub fn verify_featurable(&self) -> Result<(), DomainError> {
+        if self.status == MediaStatus::Inactive {
+            log_error!("Inactive media cannot be featured.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::domain::media::{
-        associated_id::associated_id::AssociatedId, media_content::image::image::Image,
+        associated_id::associated_id::AssociatedId,
+        media_content::{image::image::Image, video::video::Video},
         src::src::Src,
     };
 
@@ -98,8 +145,8 @@ mod tests {
             name.to_owned(),
             status.to_owned(),
             content,
-            created_at,
-            updated_at,
+            Some(created_at),
+            Some(updated_at),
         );
 
         assert!(media.is_ok());
@@ -107,8 +154,8 @@ mod tests {
         assert_eq!(media.id(), id);
         assert_eq!(media.name(), &name);
         assert_eq!(media.status(), &status);
-        assert_eq!(media.created_at(), &created_at);
-        assert_eq!(media.updated_at(), &updated_at);
+        assert_eq!(media.created_at(), &Some(created_at));
+        assert_eq!(media.updated_at(), &Some(updated_at));
 
         let image = match &media.content() {
             Some(MediaContent::Image(image)) => image,
@@ -128,9 +175,144 @@ mod tests {
             Some("media_name".to_string()),
             MediaStatus::Active,
             None,
-            Utc::now(),
-            Utc::now(),
+            Some(Utc::now()),
+            Some(Utc::now()),
         );
         assert!(media.is_err());
     }
+
+    fn mock_image_media(status: MediaStatus) -> Media {
+        Media::new(
+            "media_id",
+            None::<String>,
+            status,
+            Some(MediaContent::Image(
+                Image::new(
+                    "image_id",
+                    Some(AssociatedId::Product("product_id".to_string())),
+                    Some("alt"),
+                    None,
+                    Some(Src::new("https://example.com/published.jpg").unwrap()),
+                )
+                .unwrap(),
+            )),
+            Some(Utc::now()),
+            Some(Utc::now()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_media() {
+        let uploaded_src = Src::new("https://example.com/uploaded.jpg").unwrap();
+        let image = Image::create(
+            Some(AssociatedId::Product("product_id".to_string())),
+            Some("alt"),
+            uploaded_src.clone(),
+        )
+        .unwrap();
+
+        let media = Media::create(MediaContent::Image(image));
+
+        assert_eq!(media.id(), "");
+        assert_eq!(media.status(), &MediaStatus::InPreparation);
+        assert!(media.is_in_preparation());
+        let image = match media.content() {
+            Some(MediaContent::Image(image)) => image,
+            _ => panic!("Expected MediaContent::Image"),
+        };
+        assert_eq!(image.id(), "");
+        assert_eq!(image.alt(), &Some("alt".to_string()));
+        assert_eq!(image.uploaded_src(), &Some(uploaded_src));
+        assert_eq!(image.published_src(), &None);
+    }
+
+    #[test]
+    fn test_create_image_with_empty_uploaded_src() {
+        let image = Image::create(None::<AssociatedId>, None::<String>, Src::new("").unwrap());
+
+        assert!(matches!(image, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_update_alt() {
+        let mut media = mock_image_media(MediaStatus::Active);
+
+        media.update_alt(Some("new alt")).unwrap();
+
+        let image = match media.content() {
+            Some(MediaContent::Image(image)) => image,
+            _ => panic!("Expected MediaContent::Image"),
+        };
+        assert_eq!(image.alt(), &Some("new alt".to_string()));
+
+        media.update_alt(None::<String>).unwrap();
+        let image = match media.content() {
+            Some(MediaContent::Image(image)) => image,
+            _ => panic!("Expected MediaContent::Image"),
+        };
+        assert_eq!(image.alt(), &None);
+    }
+
+    #[test]
+    fn test_update_alt_of_video() {
+        let mut media = Media::new(
+            "media_id",
+            None::<String>,
+            MediaStatus::Active,
+            Some(MediaContent::Video(
+                Video::new(
+                    "video_id",
+                    None::<AssociatedId>,
+                    Some("alt"),
+                    vec![],
+                    None,
+                    None,
+                )
+                .unwrap(),
+            )),
+            None,
+            None,
+        )
+        .unwrap();
+
+        media.update_alt(Some("new alt")).unwrap();
+
+        assert_eq!(
+            media.content().as_ref().unwrap().alt(),
+            &Some("new alt".to_string())
+        );
+        assert!(media.updated_at().is_some());
+    }
+
+    #[test]
+    fn test_update_alt_without_content() {
+        let mut media = Media::new(
+            "media_id",
+            None::<String>,
+            MediaStatus::Active,
+            None,
+            Some(Utc::now()),
+            Some(Utc::now()),
+        )
+        .unwrap();
+
+        let result = media.update_alt(Some("new alt"));
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_verify_featurable() {
+        assert!(mock_image_media(MediaStatus::Active)
+            .verify_featurable()
+            .is_ok());
+        assert!(mock_image_media(MediaStatus::InPreparation)
+            .verify_featurable()
+            .is_ok());
+        assert!(matches!(
+            mock_image_media(MediaStatus::Inactive).verify_featurable(),
+            Err(DomainError::InvalidStateTransition)
+        ));
+    }
 }
@@ -1,2 +1,4 @@
 pub mod image;
 pub mod media_content;
+pub mod model3d;
+pub mod video;
@@ -23,16 +23,13 @@ pub type Id = String;
 /// * `id` - A unique identifier for the image. This field is required and cannot be empty.
 /// * `associated_id` - An optional identifier for an entity associated with this image (e.g., product or user).
 /// * `alt` - Optional alternative text for the image, typically used for accessibility purposes.
-/// * `uploaded_src` - A URL to the image as originally uploaded. This field is currently not in use, as the
-///                    image registration endpoint has not been implemented.
+/// * `uploaded_src` - A URL to the image as originally uploaded, such as the resource URL of a staged upload.
 /// * `published_src` - The URL to the published version of the image.
 #[derive(Debug, Getters)]
 pub struct Image {
     id: Id,
     associated_id: Option<AssociatedId>,
     alt: Option<String>,
-    // Currently not in use because the image registration endpoint is not implemented.
-    #[allow(dead_code)]
     uploaded_src: Option<Src>,
     published_src: Option<Src>,
 }
@@ -59,4 +56,31 @@ impl Image {
             published_src,
         })
     }
+
+    /// Create an entity in its initial state.
+    ///
+    /// The published source is determined by the EC platform after the uploaded source is processed.
+    pub fn create(
+        associated_id: Option<impl Into<AssociatedId>>,
+        alt: Option<impl Into<String>>,
+        uploaded_src: Src,
+    ) -> Result<Self, DomainError> {
+        if uploaded_src.value().is_empty() {
+            log_error!("Uploaded source cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: String::new(),
+            associated_id: associated_id.map(|i| i.into()),
+            alt: alt.map(|a| a.into()),
+            uploaded_src: Some(uploaded_src),
+            published_src: None,
+        })
+    }
+
+    /// Update the alternative text of the image.
+    pub fn update_alt(&mut self, alt: Option<impl Into<String>>) {
+        self.alt = alt.map(|a| a.into());
+    }
 }
@@ -1,6 +1,39 @@
-use super::image::image::Image;
+use crate::domain::media::associated_id::associated_id::AssociatedId;
+
+use super::{image::image::Image, model3d::model3d::Model3d, video::video::Video};
 
 #[derive(Debug)]
 pub enum MediaContent {
     Image(Image),
+    Video(Video),
+    Model3d(Model3d),
+}
+
+impl MediaContent {
+    /// ID of the resource to which the content is tied.
+    pub fn associated_id(&self) -> &Option<AssociatedId> {
+        match self {
+            MediaContent::Image(image) => image.associated_id(),
+            MediaContent::Video(video) => video.associated_id(),
+            MediaContent::Model3d(model3d) => model3d.associated_id(),
+        }
+    }
+
+    /// Alternative text of the content.
+    pub fn alt(&self) -> &Option<String> {
+        match self {
+            MediaContent::Image(image) => image.alt(),
+            MediaContent::Video(video) => video.alt(),
+            MediaContent::Model3d(model3d) => model3d.alt(),
+        }
+    }
+
+    /// Update the alternative text of the content.
+    pub fn update_alt(&mut self, alt: Option<impl Into<String>>) {
+        match self {
+            MediaContent::Image(image) => image.update_alt(alt),
+            MediaContent::Video(video) => video.update_alt(alt),
+            MediaContent::Model3d(model3d) => model3d.update_alt(alt),
+        }
+    }
 }
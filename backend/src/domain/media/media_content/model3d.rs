@@ -0,0 +1 @@
+pub mod model3d;
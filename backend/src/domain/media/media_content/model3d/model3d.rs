@@ -0,0 +1,162 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        media::{associated_id::associated_id::AssociatedId, src::src::Src},
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents a 3D model associated with an entity such as a product.
+///
+/// # Fields
+///
+/// * `id` - A unique identifier for the 3D model. This field is required and cannot be empty.
+/// * `associated_id` - An optional identifier for an entity associated with this 3D model.
+/// * `alt` - Optional alternative text for the 3D model.
+/// * `sources` - The files of the 3D model in each format, which are empty until the model is processed.
+/// * `bounding_box` - The size of the box enclosing the 3D model.
+/// * `preview_src` - The URL to the preview image of the 3D model.
+#[derive(Debug, Getters)]
+pub struct Model3d {
+    id: Id,
+    associated_id: Option<AssociatedId>,
+    alt: Option<String>,
+    sources: Vec<Model3dSource>,
+    bounding_box: Option<BoundingBox>,
+    preview_src: Option<Src>,
+}
+
+impl Model3d {
+    pub fn new(
+        id: impl Into<String>,
+        associated_id: Option<impl Into<AssociatedId>>,
+        alt: Option<impl Into<String>>,
+        sources: Vec<Model3dSource>,
+        bounding_box: Option<BoundingBox>,
+        preview_src: Option<Src>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            associated_id: associated_id.map(|i| i.into()),
+            alt: alt.map(|a| a.into()),
+            sources,
+            bounding_box,
+            preview_src,
+        })
+    }
+
+    /// Update the alternative text of the 3D model.
+    pub fn update_alt(&mut self, alt: Option<impl Into<String>>) {
+        self.alt = alt.map(|a| a.into());
+    }
+}
+
+/// A file of a 3D model in a specific format.
+///
+/// # Fields
+///
+/// * `src` - The URL of the file.
+/// * `mime_type` - The MIME type of the file, such as `model/gltf-binary`.
+/// * `format` - The format of the file, such as `glb` or `usdz`.
+/// * `file_size` - The size of the file in bytes.
+#[derive(Debug, Getters)]
+pub struct Model3dSource {
+    src: Src,
+    mime_type: String,
+    format: String,
+    file_size: Option<u64>,
+}
+
+impl Model3dSource {
+    pub fn new(
+        src: Src,
+        mime_type: impl Into<String>,
+        format: impl Into<String>,
+        file_size: Option<u64>,
+    ) -> Result<Self, DomainError> {
+        let mime_type = mime_type.into();
+        if mime_type.is_empty() {
+            log_error!("MIME type cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            src,
+            mime_type,
+            format: format.into(),
+            file_size,
+        })
+    }
+}
+
+/// The size of the box enclosing a 3D model, in the units of the model.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct BoundingBox {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl BoundingBox {
+    pub fn new(x: f64, y: f64, z: f64) -> Result<Self, DomainError> {
+        if [x, y, z].iter().any(|v| !v.is_finite() || *v < 0.0) {
+            log_error!("Bounding box size must be a non-negative number.", "x" => x, "y" => y, "z" => z);
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_model3d() {
+        let source = Model3dSource::new(
+            Src::new("https://example.com/model.glb").unwrap(),
+            "model/gltf-binary",
+            "glb",
+            Some(1024),
+        )
+        .unwrap();
+        let model3d = Model3d::new(
+            "model_id",
+            Some(AssociatedId::Product("product_id".to_string())),
+            Some("alt"),
+            vec![source],
+            Some(BoundingBox::new(1.0, 2.0, 3.0).unwrap()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(model3d.id(), "model_id");
+        assert_eq!(model3d.sources()[0].format(), "glb");
+        assert_eq!(*model3d.bounding_box().as_ref().unwrap().z(), 3.0);
+    }
+
+    #[test]
+    fn test_new_model3d_invalid_id() {
+        let model3d = Model3d::new("", None::<AssociatedId>, None::<String>, vec![], None, None);
+
+        assert!(model3d.is_err());
+    }
+
+    #[test]
+    fn test_new_bounding_box_invalid() {
+        assert!(BoundingBox::new(-1.0, 1.0, 1.0).is_err());
+        assert!(BoundingBox::new(1.0, f64::NAN, 1.0).is_err());
+        assert!(BoundingBox::new(0.0, 0.0, 0.0).is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        media::{associated_id::associated_id::AssociatedId, src::src::Src},
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents a video associated with an entity such as a product.
+///
+/// # Fields
+///
+/// * `id` - A unique identifier for the video. This field is required and cannot be empty.
+/// * `associated_id` - An optional identifier for an entity associated with this video.
+/// * `alt` - Optional alternative text for the video.
+/// * `sources` - The renditions of the video, which are empty until the video is processed.
+/// * `duration_ms` - The length of the video in milliseconds.
+/// * `preview_src` - The URL to the preview image of the video.
+#[derive(Debug, Getters)]
+pub struct Video {
+    id: Id,
+    associated_id: Option<AssociatedId>,
+    alt: Option<String>,
+    sources: Vec<VideoSource>,
+    duration_ms: Option<u32>,
+    preview_src: Option<Src>,
+}
+
+impl Video {
+    pub fn new(
+        id: impl Into<String>,
+        associated_id: Option<impl Into<AssociatedId>>,
+        alt: Option<impl Into<String>>,
+        sources: Vec<VideoSource>,
+        duration_ms: Option<u32>,
+        preview_src: Option<Src>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            associated_id: associated_id.map(|i| i.into()),
+            alt: alt.map(|a| a.into()),
+            sources,
+            duration_ms,
+            preview_src,
+        })
+    }
+
+    /// Update the alternative text of the video.
+    pub fn update_alt(&mut self, alt: Option<impl Into<String>>) {
+        self.alt = alt.map(|a| a.into());
+    }
+}
+
+/// A rendition of a video in a specific format and resolution.
+///
+/// # Fields
+///
+/// * `src` - The URL of the rendition.
+/// * `mime_type` - The MIME type of the rendition, such as `video/mp4`.
+/// * `width` - The width of the rendition in pixels.
+/// * `height` - The height of the rendition in pixels.
+#[derive(Debug, Getters)]
+pub struct VideoSource {
+    src: Src,
+    mime_type: String,
+    width: u32,
+    height: u32,
+}
+
+impl VideoSource {
+    pub fn new(
+        src: Src,
+        mime_type: impl Into<String>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, DomainError> {
+        let mime_type = mime_type.into();
+        if mime_type.is_empty() {
+            log_error!("MIME type cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            src,
+            mime_type,
+            width,
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_video() {
+        let source = VideoSource::new(
+            Src::new("https://example.com/video.mp4").unwrap(),
+            "video/mp4",
+            1920,
+            1080,
+        )
+        .unwrap();
+        let video = Video::new(
+            "video_id",
+            Some(AssociatedId::Product("product_id".to_string())),
+            Some("alt"),
+            vec![source],
+            Some(15000),
+            Some(Src::new("https://example.com/preview.jpg").unwrap()),
+        )
+        .unwrap();
+
+        assert_eq!(video.id(), "video_id");
+        assert_eq!(video.sources()[0].mime_type(), "video/mp4");
+        assert_eq!(*video.sources()[0].width(), 1920);
+        assert_eq!(*video.duration_ms(), Some(15000));
+    }
+
+    #[test]
+    fn test_new_video_invalid_id() {
+        let video = Video::new("", None::<AssociatedId>, None::<String>, vec![], None, None);
+
+        assert!(video.is_err());
+    }
+
+    #[test]
+    fn test_new_video_source_with_empty_mime_type() {
+        let source = VideoSource::new(Src::new("https://example.com/video.mp4").unwrap(), "", 1, 1);
+
+        assert!(source.is_err());
+    }
+}
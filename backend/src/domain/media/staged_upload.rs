@@ -0,0 +1 @@
+pub mod staged_upload;
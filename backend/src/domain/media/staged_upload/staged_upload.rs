@@ -0,0 +1,159 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{error::error::DomainError, media::src::src::Src},
+    log_error,
+};
+
+/// A file to be uploaded to the EC platform before it is registered as media.
+///
+/// # Fields
+/// - `file_name` - The name of the file including its extension.
+/// - `mime_type` - The MIME type of the file. Only images are accepted.
+/// - `file_size` - The size of the file in bytes.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct StagedUpload {
+    file_name: String,
+    mime_type: String,
+    file_size: u64,
+}
+
+impl StagedUpload {
+    /// Maximum size of an image accepted by the EC platform.
+    pub const MAX_IMAGE_FILE_SIZE: u64 = 20 * 1024 * 1024;
+
+    pub fn new(
+        file_name: impl Into<String>,
+        mime_type: impl Into<String>,
+        file_size: u64,
+    ) -> Result<Self, DomainError> {
+        let file_name = file_name.into();
+        let mime_type = mime_type.into();
+
+        if file_name.is_empty() {
+            log_error!("File name cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if !mime_type.starts_with("image/") {
+            log_error!("Only images can be uploaded.", "mime_type" => mime_type);
+            return Err(DomainError::ValidationError);
+        }
+        if file_size == 0 || file_size > Self::MAX_IMAGE_FILE_SIZE {
+            log_error!("File size is out of range.", "file_size" => file_size);
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            file_name,
+            mime_type,
+            file_size,
+        })
+    }
+}
+
+/// A form parameter to be sent along with the file to the staged upload target.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct StagedUploadParameter {
+    name: String,
+    value: String,
+}
+
+impl StagedUploadParameter {
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Where to upload a file before registering it as media.
+///
+/// The file is posted to `url` as multipart form data with `parameters`,
+/// and `resource_url` is then used as the source of the media.
+///
+/// # Fields
+/// - `url` - The URL to upload the file to.
+/// - `resource_url` - The URL of the uploaded file to be registered as media.
+/// - `parameters` - The form parameters required by the upload target.
+#[derive(Debug, Getters, Clone, PartialEq)]
+pub struct StagedUploadTarget {
+    url: Src,
+    resource_url: Src,
+    parameters: Vec<StagedUploadParameter>,
+}
+
+impl StagedUploadTarget {
+    pub fn new(
+        url: Src,
+        resource_url: Src,
+        parameters: Vec<StagedUploadParameter>,
+    ) -> Result<Self, DomainError> {
+        if url.value().is_empty() || resource_url.value().is_empty() {
+            log_error!("Staged upload URLs cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            url,
+            resource_url,
+            parameters,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_staged_upload() {
+        let staged_upload = StagedUpload::new("image.png", "image/png", 1024).unwrap();
+
+        assert_eq!(staged_upload.file_name(), "image.png");
+        assert_eq!(staged_upload.mime_type(), "image/png");
+        assert_eq!(*staged_upload.file_size(), 1024);
+    }
+
+    #[test]
+    fn test_new_staged_upload_invalid() {
+        assert!(StagedUpload::new("", "image/png", 1024).is_err());
+        assert!(StagedUpload::new("video.mp4", "video/mp4", 1024).is_err());
+        assert!(StagedUpload::new("image.png", "image/png", 0).is_err());
+        assert!(StagedUpload::new(
+            "image.png",
+            "image/png",
+            StagedUpload::MAX_IMAGE_FILE_SIZE + 1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_new_staged_upload_target() {
+        let target = StagedUploadTarget::new(
+            Src::new("https://example.com/upload").unwrap(),
+            Src::new("https://example.com/resource").unwrap(),
+            vec![StagedUploadParameter::new("key", "value")],
+        )
+        .unwrap();
+
+        assert_eq!(target.url().value(), "https://example.com/upload");
+        assert_eq!(
+            target.resource_url().value(),
+            "https://example.com/resource"
+        );
+        assert_eq!(target.parameters()[0].name(), "key");
+        assert_eq!(target.parameters()[0].value(), "value");
+    }
+
+    #[test]
+    fn test_new_staged_upload_target_with_empty_url() {
+        let target = StagedUploadTarget::new(
+            Src::new("").unwrap(),
+            Src::new("https://example.com/resource").unwrap(),
+            vec![],
+        );
+
+        assert!(target.is_err());
+    }
+}
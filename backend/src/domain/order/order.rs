@@ -1 +1,401 @@
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        address::address::Address,
+        authorized_resource::authorized_resource::{AuthorizedResource, ResourceType},
+        customer::customer::Id as CustomerId,
+        error::error::DomainError,
+        line_item::line_item::LineItem,
+        money::money::{CurrencyCode, Money},
+        user::user::Id as UserId,
+    },
+    log_error,
+};
+
 pub type Id = String;
+
+/// Represents the payment status of an order.
+///
+/// # Variants
+/// - `Pending` - The payment is pending.
+/// - `Authorized` - The payment has been authorized but not yet captured.
+/// - `PartiallyPaid` - Only part of the order has been paid.
+/// - `Paid` - The order has been paid in full.
+/// - `PartiallyRefunded` - Part of the payment has been refunded.
+/// - `Refunded` - The payment has been refunded in full.
+/// - `Voided` - The authorized payment has been voided.
+/// - `Expired` - The payment authorization has expired.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FinancialStatus {
+    Pending,
+    Authorized,
+    PartiallyPaid,
+    Paid,
+    PartiallyRefunded,
+    Refunded,
+    Voided,
+    Expired,
+}
+
+/// Represents the fulfillment status of an order.
+///
+/// # Variants
+/// - `Unfulfilled` - None of the line items have been fulfilled.
+/// - `PartiallyFulfilled` - Some of the line items have been fulfilled.
+/// - `Fulfilled` - All of the line items have been fulfilled.
+/// - `Restocked` - All of the line items have been restocked.
+/// - `InProgress` - The fulfillment is in progress.
+/// - `OnHold` - The fulfillment is on hold.
+/// - `Scheduled` - The fulfillment is scheduled for a later date.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FulfillmentStatus {
+    Unfulfilled,
+    PartiallyFulfilled,
+    Fulfilled,
+    Restocked,
+    InProgress,
+    OnHold,
+    Scheduled,
+}
+
+/// Represents the reason an order was canceled.
+///
+/// # Variants
+/// - `Customer` - The customer wanted to cancel the order.
+/// - `Declined` - The payment was declined.
+/// - `Fraud` - The order was fraudulent.
+/// - `Inventory` - There was insufficient inventory.
+/// - `Staff` - Staff made an error.
+/// - `Other` - The order was canceled for an unlisted reason.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderCancelReason {
+    Customer,
+    Declined,
+    Fraud,
+    Inventory,
+    Staff,
+    Other,
+}
+
+/// Representing Orders.
+///
+/// Orders are created when a checkout or a draft order is completed.
+/// The application does not edit them, but an unfulfilled order can be canceled.
+///
+/// # Fields
+/// - `id` - A unique identifier for the order.
+/// - `name` - The name of the order.
+/// - `customer_id` - An optional identifier for the associated customer.
+/// - `billing_address` - An optional billing address for the order.
+/// - `shipping_address` - An optional shipping address for the order.
+/// - `note` - An optional note or memo related to the order.
+/// - `line_items` - The list of products or services associated with the order.
+/// - `financial_status` - The payment status of the order, if known.
+/// - `fulfillment_status` - The fulfillment status of the order.
+/// - `subtotal_price_set` - The subtotal price of all line items and applied discounts, excluding shipping and taxes.
+/// - `taxes_included` - A flag indicating whether taxes are included in the item prices.
+/// - `total_tax_set` - The total tax amount for the order.
+/// - `total_discounts_set` - The total amount of discounts applied to the order.
+/// - `total_shipping_price_set` - The total cost of shipping for the order.
+/// - `total_price_set` - The final total price of the order, including shipping, discounts, and taxes.
+/// - `presentment_currency_code` - Currency code used for the order. May differ from the store's default currency code.
+/// - `owner_user_id` - Data owner user ID.
+/// - `cancel_reason` - The reason the order was canceled, if it has been canceled.
+/// - `processed_at` - The timestamp when the order was processed.
+/// - `canceled_at` - An optional timestamp indicating when the order was canceled.
+/// - `created_at` - The timestamp when the order was created.
+/// - `update_at` - The timestamp when the order was last updated.
+#[derive(Debug, Getters)]
+pub struct Order {
+    id: Id,
+    name: String,
+
+    customer_id: Option<CustomerId>,
+    billing_address: Option<Address>,
+    shipping_address: Option<Address>,
+    note: Option<String>,
+
+    /// The list of the line items in the order.
+    line_items: Vec<LineItem>,
+
+    financial_status: Option<FinancialStatus>,
+    fulfillment_status: FulfillmentStatus,
+
+    /// The subtotal, of the line items and their discounts, excluding shipping charges, shipping discounts, and taxes.
+    subtotal_price_set: Money,
+    /// Whether the line item prices include taxes.
+    taxes_included: bool,
+    /// The total tax.
+    total_tax_set: Money,
+    /// Total discounts.
+    total_discounts_set: Money,
+    /// The total shipping price.
+    total_shipping_price_set: Money,
+    /// The total price, includes taxes, shipping charges, and discounts.
+    total_price_set: Money,
+    /// Currency code used for the order.
+    /// May differ from the store's default currency code.
+    presentment_currency_code: CurrencyCode,
+
+    owner_user_id: UserId,
+
+    cancel_reason: Option<OrderCancelReason>,
+
+    processed_at: DateTime<Utc>,
+    canceled_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Values to construct an order from, validated by `Order::new`.
+///
+/// See `Order` for the meaning of each field.
+#[derive(Debug)]
+pub struct OrderParams {
+    pub id: Id,
+    pub name: String,
+    pub customer_id: Option<CustomerId>,
+    pub billing_address: Option<Address>,
+    pub shipping_address: Option<Address>,
+    pub note: Option<String>,
+    pub line_items: Vec<LineItem>,
+    pub financial_status: Option<FinancialStatus>,
+    pub fulfillment_status: FulfillmentStatus,
+    pub subtotal_price_set: Money,
+    pub taxes_included: bool,
+    pub total_tax_set: Money,
+    pub total_discounts_set: Money,
+    pub total_shipping_price_set: Money,
+    pub total_price_set: Money,
+    pub presentment_currency_code: CurrencyCode,
+    pub owner_user_id: UserId,
+    pub cancel_reason: Option<OrderCancelReason>,
+    pub processed_at: DateTime<Utc>,
+    pub canceled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Order {
+    /// Constructor to be used from the repository.
+    pub fn new(params: OrderParams) -> Result<Self, DomainError> {
+        let OrderParams {
+            id,
+            name,
+            customer_id,
+            billing_address,
+            shipping_address,
+            note,
+            line_items,
+            financial_status,
+            fulfillment_status,
+            subtotal_price_set,
+            taxes_included,
+            total_tax_set,
+            total_discounts_set,
+            total_shipping_price_set,
+            total_price_set,
+            presentment_currency_code,
+            owner_user_id,
+            cancel_reason,
+            processed_at,
+            canceled_at,
+            created_at,
+            updated_at,
+        } = params;
+
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if name.is_empty() {
+            log_error!("Name cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            name,
+            customer_id,
+            billing_address,
+            shipping_address,
+            note,
+            line_items,
+            financial_status,
+            fulfillment_status,
+            subtotal_price_set,
+            taxes_included,
+            total_tax_set,
+            total_discounts_set,
+            total_shipping_price_set,
+            total_price_set,
+            presentment_currency_code,
+            owner_user_id,
+            cancel_reason,
+            processed_at,
+            canceled_at,
+            created_at,
+            updated_at,
+        })
+    }
+
+    /// Cancel the order with the given reason.
+    ///
+    /// Only an unfulfilled order can be canceled, since fulfilled items have already left the inventory.
+    pub fn cancel(&mut self, reason: OrderCancelReason) -> Result<(), DomainError> {
+        if self.is_canceled() {
+            log_error!("Order has already been canceled.", "id" => self.id);
+            return Err(DomainError::InvalidStateTransition);
+        }
+        if self.fulfillment_status != FulfillmentStatus::Unfulfilled {
+            log_error!(
+                "Only an unfulfilled order can be canceled.",
+                "id" => self.id,
+                "fulfillment_status" => self.fulfillment_status
+            );
+            return Err(DomainError::InvalidStateTransition);
+        }
+
+        let now = Utc::now();
+        self.cancel_reason = Some(reason);
+        self.canceled_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Returns whether the order has been canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.canceled_at.is_some()
+    }
+}
+
+impl AuthorizedResource for Order {
+    fn resource_type(&self) -> ResourceType {
+        ResourceType::Order
+    }
+
+    fn owner_user_id(&self) -> Option<UserId> {
+        Some(self.owner_user_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::money::amount::amount::Amount;
+
+    use super::*;
+
+    fn mock_money() -> Money {
+        let amount = Amount::new(100.0).unwrap();
+        Money::new(CurrencyCode::USD, amount).expect("Failed to create mock money")
+    }
+
+    fn mock_order(id: &str, name: &str, owner_user_id: &str) -> Result<Order, DomainError> {
+        Order::new(OrderParams {
+            id: id.to_string(),
+            name: name.to_string(),
+            customer_id: Some("customer_id".to_string()),
+            billing_address: None,
+            shipping_address: None,
+            note: Some("Test note".to_string()),
+            line_items: vec![],
+            financial_status: Some(FinancialStatus::Paid),
+            fulfillment_status: FulfillmentStatus::Unfulfilled,
+            subtotal_price_set: mock_money(),
+            taxes_included: true,
+            total_tax_set: mock_money(),
+            total_discounts_set: mock_money(),
+            total_shipping_price_set: mock_money(),
+            total_price_set: mock_money(),
+            presentment_currency_code: CurrencyCode::USD,
+            owner_user_id: owner_user_id.to_string(),
+            cancel_reason: None,
+            processed_at: Utc::now(),
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        let order = mock_order("0", "#1001", "Owner").expect("Failed to create order");
+
+        assert_eq!(order.id(), "0");
+        assert_eq!(order.name(), "#1001");
+        assert_eq!(order.financial_status(), &Some(FinancialStatus::Paid));
+        assert_eq!(order.fulfillment_status(), &FulfillmentStatus::Unfulfilled);
+        assert_eq!(order.owner_user_id(), "Owner");
+        assert!(!order.is_canceled());
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = mock_order("", "#1001", "Owner");
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_new_with_empty_name_should_fail() {
+        let result = mock_order("0", "", "Owner");
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut order = mock_order("0", "#1001", "Owner").expect("Failed to create order");
+        let before = Utc::now();
+
+        order
+            .cancel(OrderCancelReason::Customer)
+            .expect("Failed to cancel order");
+
+        assert!(order.is_canceled());
+        assert_eq!(order.cancel_reason(), &Some(OrderCancelReason::Customer));
+        assert!(order.canceled_at().unwrap() >= before);
+    }
+
+    #[test]
+    fn test_cancel_canceled_should_fail() {
+        let mut order = mock_order("0", "#1001", "Owner").expect("Failed to create order");
+        order
+            .cancel(OrderCancelReason::Customer)
+            .expect("Failed to cancel order");
+
+        let result = order.cancel(OrderCancelReason::Staff);
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+        assert_eq!(order.cancel_reason(), &Some(OrderCancelReason::Customer));
+    }
+
+    #[test]
+    fn test_cancel_fulfilled_should_fail() {
+        for status in [
+            FulfillmentStatus::PartiallyFulfilled,
+            FulfillmentStatus::Fulfilled,
+        ] {
+            let mut order = mock_order("0", "#1001", "Owner").expect("Failed to create order");
+            order.fulfillment_status = status;
+
+            let result = order.cancel(OrderCancelReason::Customer);
+
+            assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+            assert!(!order.is_canceled());
+        }
+    }
+
+    #[test]
+    fn test_authorized_resource() {
+        let order = mock_order("0", "#1001", "Owner").expect("Failed to create order");
+
+        assert_eq!(order.resource_type(), ResourceType::Order);
+        assert_eq!(
+            AuthorizedResource::owner_user_id(&order),
+            Some("Owner".to_string())
+        );
+    }
+}
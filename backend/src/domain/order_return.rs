@@ -0,0 +1,2 @@
+pub mod order_return;
+pub mod return_line_item;
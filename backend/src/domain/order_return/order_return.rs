@@ -0,0 +1,258 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{error::error::DomainError, order::order::Id as OrderId},
+    log_error,
+};
+
+use super::return_line_item::return_line_item::ReturnLineItem;
+
+pub type Id = String;
+
+/// Represents the status of a return.
+///
+/// # Variants
+/// - `Requested` - The return has been requested by the customer and awaits approval.
+/// - `Open` - The return has been approved and is in progress.
+/// - `Declined` - The return request has been declined.
+/// - `Closed` - The return has been completed.
+/// - `Canceled` - The return has been canceled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnStatus {
+    Requested,
+    Open,
+    Declined,
+    Closed,
+    Canceled,
+}
+
+impl ReturnStatus {
+    /// Returns whether the status can transition to the given status.
+    ///
+    /// Only a requested return can be approved or declined.
+    pub fn can_transition_to(&self, next: &ReturnStatus) -> bool {
+        matches!(
+            (self, next),
+            (ReturnStatus::Requested, ReturnStatus::Open)
+                | (ReturnStatus::Requested, ReturnStatus::Declined)
+        )
+    }
+}
+
+/// Represents the reason a return request was declined.
+///
+/// # Variants
+/// - `FinalSale` - The items were sold as final sale.
+/// - `ReturnPeriodEnded` - The return period has ended.
+/// - `Other` - Another reason not listed above.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnDeclineReason {
+    FinalSale,
+    ReturnPeriodEnded,
+    Other,
+}
+
+/// Represents a return of fulfilled items of an order.
+///
+/// Returns are requested by customers and approved or declined by operators.
+///
+/// # Fields
+/// - `id` - The unique identifier for the return.
+/// - `order_id` - The identifier of the order the items are returned from.
+/// - `status` - The current status of the return.
+/// - `line_items` - The items requested to be returned.
+/// - `decline_reason` - The reason the return was declined, if declined.
+/// - `decline_note` - An optional note to the customer about the decline.
+#[derive(Debug, Getters)]
+pub struct OrderReturn {
+    id: Id,
+    order_id: OrderId,
+    status: ReturnStatus,
+    line_items: Vec<ReturnLineItem>,
+    decline_reason: Option<ReturnDeclineReason>,
+    decline_note: Option<String>,
+}
+
+impl OrderReturn {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        order_id: impl Into<OrderId>,
+        status: ReturnStatus,
+        line_items: Vec<ReturnLineItem>,
+        decline_reason: Option<ReturnDeclineReason>,
+        decline_note: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let id = id.into();
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            order_id: order_id.into(),
+            status,
+            line_items,
+            decline_reason,
+            decline_note,
+        })
+    }
+
+    /// Create an entity in its initial state.
+    ///
+    /// A return starts as a request from the customer.
+    pub fn request(
+        order_id: impl Into<OrderId>,
+        line_items: Vec<ReturnLineItem>,
+    ) -> Result<Self, DomainError> {
+        if line_items.is_empty() {
+            log_error!("Line items to be returned cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: String::new(),
+            order_id: order_id.into(),
+            status: ReturnStatus::Requested,
+            line_items,
+            decline_reason: None,
+            decline_note: None,
+        })
+    }
+
+    /// Approve the return request.
+    pub fn approve(&mut self) -> Result<(), DomainError> {
+        self.transition_to(ReturnStatus::Open)
+    }
+
+    /// Decline the return request with the given reason.
+    pub fn decline(
+        &mut self,
+        reason: ReturnDeclineReason,
+        note: Option<String>,
+    ) -> Result<(), DomainError> {
+        self.transition_to(ReturnStatus::Declined)?;
+
+        self.decline_reason = Some(reason);
+        self.decline_note = note;
+        Ok(())
+    }
+
+    fn transition_to(&mut self, next: ReturnStatus) -> Result<(), DomainError> {
+        if !self.status.can_transition_to(&next) {
+            log_error!(
+                "Invalid return status transition.",
+                "id" => self.id,
+                "from" => self.status,
+                "to" => next
+            );
+            return Err(DomainError::InvalidStateTransition);
+        }
+        self.status = next;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::order_return::return_line_item::return_line_item::ReturnReason;
+
+    use super::*;
+
+    fn mock_line_items() -> Vec<ReturnLineItem> {
+        vec![ReturnLineItem::create("1", 1, ReturnReason::SizeTooSmall, None).unwrap()]
+    }
+
+    fn mock_return(status: ReturnStatus) -> OrderReturn {
+        OrderReturn::new("1", "1", status, mock_line_items(), None, None).unwrap()
+    }
+
+    #[test]
+    fn test_new_with_empty_id_should_fail() {
+        let result = OrderReturn::new(
+            "",
+            "1",
+            ReturnStatus::Requested,
+            mock_line_items(),
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_request() {
+        let order_return = OrderReturn::request("1", mock_line_items()).unwrap();
+
+        assert_eq!(order_return.id(), "");
+        assert_eq!(order_return.order_id(), "1");
+        assert_eq!(order_return.status(), &ReturnStatus::Requested);
+        assert_eq!(order_return.line_items().len(), 1);
+    }
+
+    #[test]
+    fn test_request_with_empty_line_items_should_fail() {
+        let result = OrderReturn::request("1", vec![]);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_approve() {
+        let mut order_return = mock_return(ReturnStatus::Requested);
+
+        order_return.approve().unwrap();
+
+        assert_eq!(order_return.status(), &ReturnStatus::Open);
+    }
+
+    #[test]
+    fn test_approve_declined_should_fail() {
+        let mut order_return = mock_return(ReturnStatus::Declined);
+
+        let result = order_return.approve();
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+    }
+
+    #[test]
+    fn test_decline() {
+        let mut order_return = mock_return(ReturnStatus::Requested);
+
+        order_return
+            .decline(
+                ReturnDeclineReason::ReturnPeriodEnded,
+                Some("Returns are accepted within 30 days.".to_string()),
+            )
+            .unwrap();
+
+        assert_eq!(order_return.status(), &ReturnStatus::Declined);
+        assert_eq!(
+            order_return.decline_reason(),
+            &Some(ReturnDeclineReason::ReturnPeriodEnded)
+        );
+        assert!(order_return.decline_note().is_some());
+    }
+
+    #[test]
+    fn test_decline_open_should_fail() {
+        let mut order_return = mock_return(ReturnStatus::Open);
+
+        let result = order_return.decline(ReturnDeclineReason::Other, None);
+
+        assert!(matches!(result, Err(DomainError::InvalidStateTransition)));
+        assert_eq!(order_return.decline_reason(), &None);
+    }
+
+    #[test]
+    fn test_status_can_transition_to() {
+        assert!(ReturnStatus::Requested.can_transition_to(&ReturnStatus::Open));
+        assert!(ReturnStatus::Requested.can_transition_to(&ReturnStatus::Declined));
+        assert!(!ReturnStatus::Requested.can_transition_to(&ReturnStatus::Closed));
+        assert!(!ReturnStatus::Open.can_transition_to(&ReturnStatus::Declined));
+        assert!(!ReturnStatus::Declined.can_transition_to(&ReturnStatus::Open));
+        assert!(!ReturnStatus::Closed.can_transition_to(&ReturnStatus::Open));
+    }
+}
@@ -0,0 +1 @@
+pub mod return_line_item;
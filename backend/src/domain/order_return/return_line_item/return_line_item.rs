@@ -0,0 +1,135 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment::fulfillment_line_item::fulfillment_line_item::Id as FulfillmentLineItemId,
+    },
+    log_error,
+};
+
+pub type Id = String;
+
+/// Represents the reason a customer returns an item.
+///
+/// # Variants
+/// - `Color` - The color of the item did not meet expectations.
+/// - `Defective` - The item is damaged or defective.
+/// - `NotAsDescribed` - The item is not as described.
+/// - `SizeTooLarge` - The item is too large.
+/// - `SizeTooSmall` - The item is too small.
+/// - `Style` - The style of the item did not meet expectations.
+/// - `Unwanted` - The customer changed their mind.
+/// - `WrongItem` - The customer received the wrong item.
+/// - `Other` - Another reason not listed above.
+/// - `Unknown` - The reason is unknown.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnReason {
+    Color,
+    Defective,
+    NotAsDescribed,
+    SizeTooLarge,
+    SizeTooSmall,
+    Style,
+    Unwanted,
+    WrongItem,
+    Other,
+    Unknown,
+}
+
+/// Represents a fulfilled item requested to be returned.
+///
+/// # Fields
+/// - `id` - The unique identifier for the return line item. Empty until the return is requested.
+/// - `fulfillment_line_item_id` - The identifier of the fulfilled item being returned.
+/// - `quantity` - The number of units being returned.
+/// - `reason` - The reason for returning the item.
+/// - `customer_note` - An optional note from the customer about the return.
+#[derive(Debug, Getters, Clone)]
+pub struct ReturnLineItem {
+    id: Id,
+    fulfillment_line_item_id: FulfillmentLineItemId,
+    quantity: u32,
+    reason: ReturnReason,
+    customer_note: Option<String>,
+}
+
+impl ReturnLineItem {
+    /// Constructor to be used from the repository.
+    pub fn new(
+        id: impl Into<Id>,
+        fulfillment_line_item_id: impl Into<FulfillmentLineItemId>,
+        quantity: u32,
+        reason: ReturnReason,
+        customer_note: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let fulfillment_line_item_id = fulfillment_line_item_id.into();
+        if fulfillment_line_item_id.is_empty() {
+            log_error!("Fulfillment line item id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+        if quantity == 0 {
+            log_error!("Return quantity must be greater than zero");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: id.into(),
+            fulfillment_line_item_id,
+            quantity,
+            reason,
+            customer_note,
+        })
+    }
+
+    /// Create an entity in its initial state.
+    pub fn create(
+        fulfillment_line_item_id: impl Into<FulfillmentLineItemId>,
+        quantity: u32,
+        reason: ReturnReason,
+        customer_note: Option<String>,
+    ) -> Result<Self, DomainError> {
+        Self::new(
+            String::new(),
+            fulfillment_line_item_id,
+            quantity,
+            reason,
+            customer_note,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create() {
+        let line_item = ReturnLineItem::create(
+            "1",
+            2,
+            ReturnReason::Defective,
+            Some("Broken on arrival".to_string()),
+        )
+        .expect("Failed to create return line item");
+
+        assert_eq!(line_item.id(), "");
+        assert_eq!(line_item.fulfillment_line_item_id(), "1");
+        assert_eq!(line_item.quantity(), &2);
+        assert_eq!(line_item.reason(), &ReturnReason::Defective);
+    }
+
+    #[test]
+    fn test_create_with_zero_quantity_should_fail() {
+        let result = ReturnLineItem::create("1", 0, ReturnReason::Other, None);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_create_with_empty_fulfillment_line_item_id_should_fail() {
+        let result = ReturnLineItem::create("", 1, ReturnReason::Other, None);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod refund;
+pub mod refund_line_item;
@@ -0,0 +1,546 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use derive_getters::Getters;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        line_item::line_item::Id as LineItemId,
+        location::location::Id as LocationId,
+        money::{amount::amount::Amount, money::Money},
+        order::order::{Id as OrderId, Order},
+    },
+    log_error,
+};
+
+use super::refund_line_item::refund_line_item::RefundLineItem;
+
+pub type Id = String;
+
+/// Represents a refund of an order.
+///
+/// When a restock location is given, the refunded units are added back to the available inventory of the location
+/// so they can be resold, in the same way as an inventory change with `InventoryChangeReason::Restock`.
+///
+/// # Fields
+/// - `id` - The unique identifier for the refund.
+/// - `order_id` - The identifier of the refunded order.
+/// - `note` - An optional note about the refund.
+/// - `line_items` - The refunded line items.
+/// - `shipping_set` - The refunded shipping amount.
+/// - `total_refunded_set` - The total amount refunded, the sum of the line items and shipping.
+/// - `restock_location_id` - The location to restock the refunded units to, if they are restocked.
+/// - `created_at` - The timestamp when the refund was created.
+#[derive(Debug, Getters)]
+pub struct Refund {
+    id: Id,
+    order_id: OrderId,
+    note: Option<String>,
+    line_items: Vec<RefundLineItem>,
+    shipping_set: Money,
+    total_refunded_set: Money,
+    restock_location_id: Option<LocationId>,
+    created_at: DateTime<Utc>,
+}
+
+/// Values to construct a refund from, validated by `Refund::new`.
+///
+/// See `Refund` for the meaning of each field.
+#[derive(Debug)]
+pub struct RefundParams {
+    pub id: Id,
+    pub order_id: OrderId,
+    pub note: Option<String>,
+    pub line_items: Vec<RefundLineItem>,
+    pub shipping_set: Money,
+    pub total_refunded_set: Money,
+    pub restock_location_id: Option<LocationId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Refund {
+    /// Constructor to be used from the repository.
+    pub fn new(params: RefundParams) -> Result<Self, DomainError> {
+        let RefundParams {
+            id,
+            order_id,
+            note,
+            line_items,
+            shipping_set,
+            total_refunded_set,
+            restock_location_id,
+            created_at,
+        } = params;
+
+        if id.is_empty() {
+            log_error!("Id cannot be empty");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id,
+            order_id,
+            note,
+            line_items,
+            shipping_set,
+            total_refunded_set,
+            restock_location_id,
+            created_at,
+        })
+    }
+
+    /// Create an entity in its initial state by calculating the amounts to be refunded from the order.
+    ///
+    /// Each line item is refunded at its discounted unit price, up to the quantity not yet refunded by `refunds`,
+    /// the earlier refunds of the order. The shipping and total amounts are likewise capped by what is left to refund.
+    /// Amounts are calculated in the currency of the order.
+    pub fn calculate(
+        order: &Order,
+        refunds: &[Refund],
+        line_items: Vec<(LineItemId, u32)>,
+        shipping_amount: Option<Amount>,
+        restock_location_id: Option<LocationId>,
+        note: Option<String>,
+    ) -> Result<Self, DomainError> {
+        let shipping_amount = shipping_amount.unwrap_or(Amount::zero());
+        if line_items.is_empty() && *shipping_amount.value() == 0.0 {
+            log_error!("Refund must include line items or shipping", "order_id" => order.id());
+            return Err(DomainError::ValidationError);
+        }
+        if restock_location_id.is_some() && line_items.is_empty() {
+            log_error!("Restock requires line items to be refunded", "order_id" => order.id());
+            return Err(DomainError::ValidationError);
+        }
+
+        // Aggregate the requested quantities so that duplicate entries cannot bypass the quantity check.
+        let mut requested: Vec<(LineItemId, u32)> = Vec::new();
+        let mut indexes: HashMap<LineItemId, usize> = HashMap::new();
+        for (id, quantity) in line_items {
+            match indexes.get(&id) {
+                Some(index) => {
+                    let total = &mut requested[*index].1;
+                    *total = total.checked_add(quantity).ok_or_else(|| {
+                        log_error!("Requested quantity is too large.", "line_item_id" => id);
+                        DomainError::ValidationError
+                    })?;
+                }
+                None => {
+                    indexes.insert(id.clone(), requested.len());
+                    requested.push((id, quantity));
+                }
+            }
+        }
+
+        let mut refunded_quantities: HashMap<&LineItemId, u32> = HashMap::new();
+        for line_item in refunds.iter().flat_map(|refund| refund.line_items()) {
+            let refunded_quantity = refunded_quantities
+                .entry(line_item.line_item_id())
+                .or_default();
+            *refunded_quantity = refunded_quantity.saturating_add(*line_item.quantity());
+        }
+
+        let currency_code = order.total_price_set().currency_code().to_owned();
+
+        let refund_line_items = requested
+            .into_iter()
+            .map(|(id, quantity)| {
+                let line_item = order
+                    .line_items()
+                    .iter()
+                    .find(|l| l.id() == &id)
+                    .ok_or_else(|| {
+                        log_error!("Line item not found in the order.", "line_item_id" => id);
+                        DomainError::ValidationError
+                    })?;
+
+                if quantity == 0 {
+                    log_error!("Quantity must be greater than zero.", "line_item_id" => id);
+                    return Err(DomainError::ValidationError);
+                }
+
+                let refundable_quantity = line_item
+                    .quantity()
+                    .saturating_sub(refunded_quantities.get(&id).copied().unwrap_or(0));
+                if quantity > refundable_quantity {
+                    log_error!(
+                        "Quantity exceeds the refundable quantity.",
+                        "line_item_id" => id,
+                        "quantity" => quantity,
+                        "refundable_quantity" => refundable_quantity
+                    );
+                    return Err(DomainError::ValidationError);
+                }
+
+                let subtotal = Self::to_decimal(line_item.discounted_total_set().amount())?
+                    * Decimal::from(quantity)
+                    / Decimal::from(*line_item.quantity());
+
+                RefundLineItem::new(
+                    id,
+                    quantity,
+                    Money::new(currency_code.clone(), Self::to_amount(subtotal)?)?,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let refunded_shipping = Self::sum(refunds.iter().map(|r| r.shipping_set().amount()))?;
+        if Self::to_decimal(&shipping_amount)?
+            > Self::to_decimal(order.total_shipping_price_set().amount())? - refunded_shipping
+        {
+            log_error!(
+                "Shipping refund exceeds the shipping left to refund.",
+                "order_id" => order.id(),
+                "shipping_amount" => shipping_amount.value()
+            );
+            return Err(DomainError::ValidationError);
+        }
+
+        let total = Self::sum(refund_line_items.iter().map(|l| l.subtotal_set().amount()))?
+            + Self::to_decimal(&shipping_amount)?;
+        let refunded_total = Self::sum(refunds.iter().map(|r| r.total_refunded_set().amount()))?;
+        if total > Self::to_decimal(order.total_price_set().amount())? - refunded_total {
+            log_error!(
+                "Refund exceeds the amount left to refund on the order.",
+                "order_id" => order.id(),
+                "total" => total
+            );
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            id: String::new(),
+            order_id: order.id().to_owned(),
+            note,
+            line_items: refund_line_items,
+            shipping_set: Money::new(currency_code.clone(), shipping_amount)?,
+            total_refunded_set: Money::new(currency_code, Self::to_amount(total)?)?,
+            restock_location_id,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Amounts are added and compared as decimals rounded to cents, so that the caps cannot drift by a cent.
+    fn to_decimal(amount: &Amount) -> Result<Decimal, DomainError> {
+        Decimal::from_f64(*amount.value())
+            .map(|value| value.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero))
+            .ok_or_else(|| {
+                log_error!("Amount cannot be converted to a decimal.", "amount" => amount.value());
+                DomainError::ValidationError
+            })
+    }
+
+    fn to_amount(value: Decimal) -> Result<Amount, DomainError> {
+        let value = value.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        Amount::new(value.to_f64().ok_or(DomainError::ValidationError)?)
+    }
+
+    fn sum<'a>(amounts: impl Iterator<Item = &'a Amount>) -> Result<Decimal, DomainError> {
+        amounts.map(Self::to_decimal).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        line_item::line_item::LineItem,
+        money::money::CurrencyCode,
+        order::order::{FinancialStatus, FulfillmentStatus, OrderParams},
+    };
+
+    use super::*;
+
+    fn money(value: f64) -> Money {
+        Money::new(CurrencyCode::USD, Amount::new(value).unwrap()).unwrap()
+    }
+
+    fn mock_order() -> Order {
+        Order::new(OrderParams {
+            id: "1".to_string(),
+            name: "#1001".to_string(),
+            customer_id: None,
+            billing_address: None,
+            shipping_address: None,
+            note: None,
+            line_items: vec![
+                LineItem::new(
+                    "11".to_string(),
+                    false,
+                    Some("1"),
+                    3,
+                    None,
+                    money(90.0),
+                    money(100.0),
+                )
+                .unwrap(),
+                LineItem::new(
+                    "12".to_string(),
+                    false,
+                    Some("2"),
+                    1,
+                    None,
+                    money(20.0),
+                    money(20.0),
+                )
+                .unwrap(),
+            ],
+            financial_status: Some(FinancialStatus::Paid),
+            fulfillment_status: FulfillmentStatus::Fulfilled,
+            subtotal_price_set: money(110.0),
+            taxes_included: true,
+            total_tax_set: money(10.0),
+            total_discounts_set: money(10.0),
+            total_shipping_price_set: money(5.0),
+            total_price_set: money(115.0),
+            presentment_currency_code: CurrencyCode::USD,
+            owner_user_id: "Owner".to_string(),
+            cancel_reason: None,
+            processed_at: Utc::now(),
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .unwrap()
+    }
+
+    fn mock_refund(line_items: Vec<(&str, u32, f64)>, shipping: f64) -> Refund {
+        let line_items: Vec<RefundLineItem> = line_items
+            .into_iter()
+            .map(|(id, quantity, subtotal)| {
+                RefundLineItem::new(id, quantity, money(subtotal)).unwrap()
+            })
+            .collect();
+        let total = line_items
+            .iter()
+            .map(|l| l.subtotal_set().amount().value())
+            .sum::<f64>()
+            + shipping;
+
+        Refund::new(RefundParams {
+            id: "1".to_string(),
+            order_id: "1".to_string(),
+            note: None,
+            line_items,
+            shipping_set: money(shipping),
+            total_refunded_set: money(total),
+            restock_location_id: None,
+            created_at: Utc::now(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_calculate() {
+        let refund = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("11".to_string(), 2), ("12".to_string(), 1)],
+            Some(Amount::new(5.0).unwrap()),
+            Some("1".to_string()),
+            Some("Damaged items".to_string()),
+        )
+        .expect("Failed to calculate refund");
+
+        assert_eq!(refund.id(), "");
+        assert_eq!(refund.order_id(), "1");
+        assert_eq!(refund.line_items().len(), 2);
+        assert_eq!(refund.line_items()[0].subtotal_set(), &money(60.0));
+        assert_eq!(refund.line_items()[1].subtotal_set(), &money(20.0));
+        assert_eq!(refund.shipping_set(), &money(5.0));
+        assert_eq!(refund.total_refunded_set(), &money(85.0));
+        assert!(refund.restock_location_id().is_some());
+    }
+
+    #[test]
+    fn test_calculate_shipping_only() {
+        let refund = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![],
+            Some(Amount::new(5.0).unwrap()),
+            None,
+            None,
+        )
+        .expect("Failed to calculate refund");
+
+        assert!(refund.line_items().is_empty());
+        assert_eq!(refund.total_refunded_set(), &money(5.0));
+        assert!(refund.restock_location_id().is_none());
+    }
+
+    #[test]
+    fn test_calculate_rounds_amounts() {
+        let refund = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("11".to_string(), 1)],
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to calculate refund");
+
+        assert_eq!(refund.line_items()[0].subtotal_set(), &money(30.0));
+    }
+
+    #[test]
+    fn test_calculate_with_nothing_to_refund_should_fail() {
+        let result = Refund::calculate(&mock_order(), &[], vec![], None, None, None);
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_restock_without_line_items_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![],
+            Some(Amount::new(5.0).unwrap()),
+            Some("1".to_string()),
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_with_unknown_line_item_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("99".to_string(), 1)],
+            None,
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_with_duplicate_line_items_exceeding_quantity_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("11".to_string(), 2), ("11".to_string(), 2)],
+            None,
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_with_duplicate_line_items_overflowing_quantity_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("11".to_string(), u32::MAX), ("11".to_string(), 1)],
+            None,
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_with_zero_quantity_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![("11".to_string(), 0)],
+            Some(Amount::new(5.0).unwrap()),
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_after_earlier_refund() {
+        let earlier_refunds = vec![mock_refund(vec![("11", 2, 60.0)], 0.0)];
+
+        let refund = Refund::calculate(
+            &mock_order(),
+            &earlier_refunds,
+            vec![("11".to_string(), 1)],
+            Some(Amount::new(5.0).unwrap()),
+            None,
+            None,
+        )
+        .expect("Failed to calculate refund");
+
+        assert_eq!(refund.total_refunded_set(), &money(35.0));
+    }
+
+    #[test]
+    fn test_calculate_remaining_shipping_after_earlier_refunds() {
+        let earlier_refunds = vec![mock_refund(vec![], 0.1), mock_refund(vec![], 0.2)];
+
+        let refund = Refund::calculate(
+            &mock_order(),
+            &earlier_refunds,
+            vec![],
+            Some(Amount::new(4.7).unwrap()),
+            None,
+            None,
+        )
+        .expect("Failed to calculate refund");
+
+        assert_eq!(refund.total_refunded_set(), &money(4.7));
+    }
+
+    #[test]
+    fn test_calculate_exceeding_refundable_quantity_should_fail() {
+        let earlier_refunds = vec![mock_refund(vec![("11", 2, 60.0)], 0.0)];
+
+        let result = Refund::calculate(
+            &mock_order(),
+            &earlier_refunds,
+            vec![("11".to_string(), 2)],
+            None,
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_exceeding_refundable_shipping_should_fail() {
+        let earlier_refunds = vec![mock_refund(vec![], 3.0)];
+
+        let result = Refund::calculate(
+            &mock_order(),
+            &earlier_refunds,
+            vec![],
+            Some(Amount::new(3.0).unwrap()),
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+
+    #[test]
+    fn test_calculate_exceeding_shipping_should_fail() {
+        let result = Refund::calculate(
+            &mock_order(),
+            &[],
+            vec![],
+            Some(Amount::new(6.0).unwrap()),
+            None,
+            None,
+        );
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
@@ -0,0 +1 @@
+pub mod refund_line_item;
@@ -0,0 +1,52 @@
+use derive_getters::Getters;
+
+use crate::{
+    domain::{
+        error::error::DomainError, line_item::line_item::Id as LineItemId, money::money::Money,
+    },
+    log_error,
+};
+
+/// Represents an order line item included in a refund.
+///
+/// # Fields
+/// - `line_item_id` - The identifier of the refunded order line item.
+/// - `quantity` - The number of units refunded.
+/// - `subtotal_set` - The refunded amount for the units, including discounts and excluding taxes.
+#[derive(Debug, Getters, Clone)]
+pub struct RefundLineItem {
+    line_item_id: LineItemId,
+    quantity: u32,
+    subtotal_set: Money,
+}
+
+impl RefundLineItem {
+    pub fn new(
+        line_item_id: impl Into<LineItemId>,
+        quantity: u32,
+        subtotal_set: Money,
+    ) -> Result<Self, DomainError> {
+        if quantity == 0 {
+            log_error!("Refund quantity must be greater than zero");
+            return Err(DomainError::ValidationError);
+        }
+
+        Ok(Self {
+            line_item_id: line_item_id.into(),
+            quantity,
+            subtotal_set,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_zero_quantity_should_fail() {
+        let result = RefundLineItem::new("1", 0, Money::zero());
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
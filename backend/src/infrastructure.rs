@@ -1,8 +1,9 @@
 pub mod auth;
 pub mod config;
 pub mod db;
-mod ec;
+pub mod ec;
 mod error;
 pub mod module;
 pub mod router;
 pub mod secret;
+pub mod tenant;
@@ -1,6 +1,7 @@
 use std::{
     future::{ready, Ready},
     rc::Rc,
+    sync::Arc,
 };
 
 use actix_web::{
@@ -9,7 +10,9 @@ use actix_web::{
 };
 use futures_util::future::LocalBoxFuture;
 
-use crate::usecase::auth::authenticator_interface::Authenticator;
+use crate::{
+    infrastructure::tenant::tenant::Tenant, usecase::auth::authenticator_interface::Authenticator,
+};
 
 const ID_TOKEN_COOKIE_NAME: &str = "ID_TOKEN";
 const REFRESH_TOKEN_COOKIE_NAME: &str = "REFRESH_TOKEN";
@@ -84,6 +87,10 @@ where
             });
         }
 
+        if let Some(tenant) = req.extensions().get::<Arc<Tenant>>() {
+            authenticator.scope_to_tenant(*tenant.id());
+        }
+
         let id_token = req.cookie(ID_TOKEN_COOKIE_NAME);
         let refresh_token = req.cookie(REFRESH_TOKEN_COOKIE_NAME);
 
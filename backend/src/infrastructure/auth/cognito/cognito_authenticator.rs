@@ -262,4 +262,8 @@ impl Authenticator for CognitoAuthenticator {
 
         Ok(id_token)
     }
+
+    fn scope_to_tenant(&mut self, tenant_id: i32) {
+        self.authorizer.scope_to_tenant(tenant_id);
+    }
 }
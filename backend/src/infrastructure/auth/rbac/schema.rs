@@ -17,6 +17,10 @@ impl TryFrom<i32> for ResourceType {
             3 => Ok(ResourceType::Customer),
             4 => Ok(ResourceType::Inventory),
             5 => Ok(ResourceType::DraftOrder),
+            6 => Ok(ResourceType::Return),
+            7 => Ok(ResourceType::Location),
+            8 => Ok(ResourceType::Fulfillment),
+            9 => Ok(ResourceType::CustomerSegment),
             _ => Err(DomainError::ConversionError),
         }
     }
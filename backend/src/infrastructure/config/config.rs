@@ -51,10 +51,47 @@ impl fmt::Display for Env {
     }
 }
 
+/// EC platform that serves the catalog and inventory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ECPlatform {
+    Shopify,
+    Local,
+}
+
+impl FromStr for ECPlatform {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "shopify" => Ok(ECPlatform::Shopify),
+            "local" => Ok(ECPlatform::Local),
+            _ => {
+                eprintln!(
+                    "An invalid value has been set for EC_PLATFORM.
+                        Set one of shopify or local. EC_PLATFORM= {}",
+                    s
+                );
+                Err(DomainError::InitConfigError)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ECPlatform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ECPlatform::Shopify => write!(f, "shopify"),
+            ECPlatform::Local => write!(f, "local"),
+        }
+    }
+}
+
 #[derive(Getters, Clone)]
 pub struct ConfigProvider {
     app_config: AppConfig,
+    ec_config: ECConfig,
     shopify_config: ShopifyConfig,
+    tenant_config: TenantConfig,
     database_config: DatabaseConfig,
     cognito_config: CognitoConfig,
     aws_sdk_config: SdkConfig,
@@ -68,13 +105,22 @@ impl ConfigProvider {
         let secrets_client = SecretsManagerClient::new(&aws_sdk_config).await?;
 
         let app_config = AppConfig::new()?;
+        let ec_config = ECConfig::new()?;
         let shopify_config = ShopifyConfig::new()?;
+        let tenant_config = TenantConfig::new()?;
+        // The local store holds a single catalog, so it cannot be shared by several shops.
+        if *tenant_config.enabled() && *ec_config.platform() == ECPlatform::Local {
+            eprintln!("TENANT_ENABLED cannot be set when EC_PLATFORM is local");
+            return Err(DomainError::InitConfigError);
+        }
         let database_config = DatabaseConfig::new(&secrets_client, &env).await?;
         let cognito_config = CognitoConfig::new()?;
 
         Ok(ConfigProvider {
             app_config,
+            ec_config,
             shopify_config,
+            tenant_config,
             cognito_config,
             database_config,
             aws_sdk_config,
@@ -127,30 +173,250 @@ impl AppConfig {
     }
 }
 
+/// ECConfig manages the selection of the EC platform.
+#[derive(Getters, Clone)]
+pub struct ECConfig {
+    /// Set the platform that serves the catalog and inventory.
+    /// Orders, customers and the other domains are always served by Shopify.
+    platform: ECPlatform,
+    /// Set the path of the JSON file used as the store when the platform is `local`.
+    local_store_path: Option<String>,
+}
+
+impl ECConfig {
+    pub fn new() -> Result<Self, DomainError> {
+        let platform = ECPlatform::from_str(
+            &env::var("EC_PLATFORM").unwrap_or_else(|_| "shopify".to_string()),
+        )?;
+
+        let local_store_path = env::var("LOCAL_STORE_PATH").ok();
+        if platform == ECPlatform::Local && local_store_path.is_none() {
+            eprintln!("LOCAL_STORE_PATH is not set as an environment variable");
+            return Err(DomainError::InitConfigError);
+        }
+
+        Ok(ECConfig {
+            platform,
+            local_store_path,
+        })
+    }
+}
+
 /// ShopifyConfig manages Shopify settings.
 #[derive(Getters, Clone)]
 pub struct ShopifyConfig {
+    /// Set the endpoint of the Admin API, built from the shop domain and the API version.
     store_url: String,
+    /// Set the version of the Admin API that requests are pinned to.
+    api_version: String,
     access_token: String,
+    http_client_config: ShopifyHttpClientConfig,
 }
 
 impl ShopifyConfig {
+    /// Version of the Admin API used unless `SHOPIFY_API_VERSION` is set.
+    /// Shopify supports a stable version for about a year, so upgrade it before it is retired,
+    /// after checking the deprecations reported by Shopify.
+    pub const DEFAULT_API_VERSION: &'static str = "2026-07";
+
     pub fn new() -> Result<Self, DomainError> {
-        let store_url = env::var("STORE_URL").map_err(|_| {
-            eprintln!("STORE_URL is not set as an environment variable");
+        let shop_domain = env::var("SHOPIFY_SHOP_DOMAIN").map_err(|_| {
+            eprintln!("SHOPIFY_SHOP_DOMAIN is not set as an environment variable");
             DomainError::InitConfigError
         })?;
+        let api_version = env::var("SHOPIFY_API_VERSION")
+            .unwrap_or_else(|_| Self::DEFAULT_API_VERSION.to_string());
+        if !Self::is_valid_api_version(&api_version) {
+            eprintln!(
+                "An invalid value has been set for SHOPIFY_API_VERSION.
+                    Set a version such as 2026-07. SHOPIFY_API_VERSION= {}",
+                api_version
+            );
+            return Err(DomainError::InitConfigError);
+        }
         let access_token = env::var("ACCESS_TOKEN").map_err(|_| {
             eprintln!("ACCESS_TOKEN is not set as an environment variable");
             DomainError::InitConfigError
         })?;
-        Ok(ShopifyConfig {
+
+        Ok(Self::for_shop(
+            &shop_domain,
+            &api_version,
+            access_token,
+            ShopifyHttpClientConfig::new(),
+        ))
+    }
+
+    /// Build the settings for the Admin API of a shop, such as `acme.myshopify.com`.
+    /// A domain given with a scheme, such as `http://127.0.0.1:8090` for a local stub, is used as is.
+    pub fn for_shop(
+        shop_domain: &str,
+        api_version: &str,
+        access_token: String,
+        http_client_config: ShopifyHttpClientConfig,
+    ) -> Self {
+        let origin = if shop_domain.contains("://") {
+            shop_domain.trim_end_matches('/').to_string()
+        } else {
+            format!("https://{shop_domain}")
+        };
+
+        Self::from_values(
+            format!("{origin}/admin/api/{api_version}/graphql.json"),
+            api_version.to_string(),
+            access_token,
+            http_client_config,
+        )
+    }
+
+    /// Build the settings from already resolved values.
+    pub fn from_values(
+        store_url: String,
+        api_version: String,
+        access_token: String,
+        http_client_config: ShopifyHttpClientConfig,
+    ) -> Self {
+        ShopifyConfig {
             store_url,
+            api_version,
             access_token,
+            http_client_config,
+        }
+    }
+
+    /// Whether the value names a stable version such as `2026-07`, or `unstable`.
+    pub fn is_valid_api_version(api_version: &str) -> bool {
+        if api_version == "unstable" {
+            return true;
+        }
+
+        match api_version.split_once('-') {
+            Some((year, month)) => {
+                year.len() == 4
+                    && year.chars().all(|c| c.is_ascii_digit())
+                    && matches!(month, "01" | "04" | "07" | "10")
+            }
+            None => false,
+        }
+    }
+}
+
+/// TenantConfig manages the routing of requests to several Shopify shops.
+#[derive(Getters, Clone)]
+pub struct TenantConfig {
+    /// Set whether each request is routed to the shop of its tenant.
+    /// When disabled, every request is served by the shop of `SHOPIFY_SHOP_DOMAIN`.
+    enabled: bool,
+    /// Set the domain under which the subdomain names the shop, such as `example.com` for `acme.example.com`.
+    base_domain: Option<String>,
+    /// Set the key, encoded in base64, used to decrypt the access tokens of the tenants.
+    token_encryption_key: Option<String>,
+    /// Set the duration a resolved tenant is reused before it is read again, in seconds.
+    cache_ttl: u64,
+}
+
+impl TenantConfig {
+    pub fn new() -> Result<Self, DomainError> {
+        let enabled = env::var("TENANT_ENABLED")
+            .map(|s| s.parse::<bool>().unwrap_or(false))
+            .unwrap_or(false);
+        let base_domain = env::var("TENANT_BASE_DOMAIN").ok();
+        let token_encryption_key = env::var("TENANT_TOKEN_ENCRYPTION_KEY").ok();
+        if enabled && token_encryption_key.is_none() {
+            eprintln!("TENANT_TOKEN_ENCRYPTION_KEY is not set as an environment variable");
+            return Err(DomainError::InitConfigError);
+        }
+        let cache_ttl = env::var("TENANT_CACHE_TTL")
+            .map(|s| s.parse::<u64>().unwrap_or(60))
+            .unwrap_or(60);
+
+        Ok(TenantConfig {
+            enabled,
+            base_domain,
+            token_encryption_key,
+            cache_ttl,
         })
     }
 }
 
+/// ShopifyHttpClientConfig manages settings of the HTTP client shared by all requests to Shopify.
+#[derive(Getters, Clone)]
+pub struct ShopifyHttpClientConfig {
+    /// Set the maximum number of retries for a failed request.
+    max_retries: u32,
+    /// Set the base delay of the exponential backoff between retries, in milliseconds.
+    retry_base_delay: u64,
+    /// Set the upper limit of the delay between retries, in milliseconds.
+    retry_max_delay: u64,
+    /// Set the timeout duration of a whole request.
+    timeout: u64,
+    /// Set the timeout duration when establishing a connection.
+    connect_timeout: u64,
+    /// Set the maximum number of idle connections kept per host.
+    pool_max_idle_per_host: usize,
+    /// Set the idle duration before closing a pooled connection.
+    pool_idle_timeout: u64,
+    /// Set whether HTTP/2 may be negotiated. HTTP/1.1 is used when disabled.
+    http2: bool,
+}
+
+impl ShopifyHttpClientConfig {
+    pub fn new() -> Self {
+        let default = Self::default();
+
+        let max_retries = env::var("SHOPIFY_MAX_RETRIES")
+            .map(|s| s.parse::<u32>().unwrap_or(default.max_retries))
+            .unwrap_or(default.max_retries);
+        let retry_base_delay = env::var("SHOPIFY_RETRY_BASE_DELAY")
+            .map(|s| s.parse::<u64>().unwrap_or(default.retry_base_delay))
+            .unwrap_or(default.retry_base_delay);
+        let retry_max_delay = env::var("SHOPIFY_RETRY_MAX_DELAY")
+            .map(|s| s.parse::<u64>().unwrap_or(default.retry_max_delay))
+            .unwrap_or(default.retry_max_delay);
+        let timeout = env::var("SHOPIFY_TIMEOUT")
+            .map(|s| s.parse::<u64>().unwrap_or(default.timeout))
+            .unwrap_or(default.timeout);
+        let connect_timeout = env::var("SHOPIFY_CONNECT_TIMEOUT")
+            .map(|s| s.parse::<u64>().unwrap_or(default.connect_timeout))
+            .unwrap_or(default.connect_timeout);
+        let pool_max_idle_per_host = env::var("SHOPIFY_POOL_MAX_IDLE_PER_HOST")
+            .map(|s| s.parse::<usize>().unwrap_or(default.pool_max_idle_per_host))
+            .unwrap_or(default.pool_max_idle_per_host);
+        let pool_idle_timeout = env::var("SHOPIFY_POOL_IDLE_TIMEOUT")
+            .map(|s| s.parse::<u64>().unwrap_or(default.pool_idle_timeout))
+            .unwrap_or(default.pool_idle_timeout);
+        let http2 = env::var("SHOPIFY_HTTP2")
+            .map(|s| s.parse::<bool>().unwrap_or(default.http2))
+            .unwrap_or(default.http2);
+
+        ShopifyHttpClientConfig {
+            max_retries,
+            retry_base_delay,
+            retry_max_delay,
+            timeout,
+            connect_timeout,
+            pool_max_idle_per_host,
+            pool_idle_timeout,
+            http2,
+        }
+    }
+}
+
+impl Default for ShopifyHttpClientConfig {
+    fn default() -> Self {
+        ShopifyHttpClientConfig {
+            max_retries: 3,
+            retry_base_delay: 500,
+            retry_max_delay: 8000,
+            timeout: 30,
+            connect_timeout: 10,
+            pool_max_idle_per_host: 32,
+            pool_idle_timeout: 90,
+            http2: true,
+        }
+    }
+}
+
 /// CognitoConfig manages Cognito settings.
 #[derive(Getters, Clone)]
 pub struct CognitoConfig {
@@ -283,3 +549,52 @@ impl DatabaseConfig {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shopify_config_for_shop() {
+        let config = ShopifyConfig::for_shop(
+            "acme.myshopify.com",
+            "2025-01",
+            "token".to_string(),
+            ShopifyHttpClientConfig::default(),
+        );
+
+        assert_eq!(
+            config.store_url(),
+            "https://acme.myshopify.com/admin/api/2025-01/graphql.json"
+        );
+        assert_eq!(config.api_version(), "2025-01");
+    }
+
+    #[test]
+    fn test_shopify_config_for_shop_with_scheme() {
+        let config = ShopifyConfig::for_shop(
+            "http://127.0.0.1:8090/",
+            "2025-01",
+            "token".to_string(),
+            ShopifyHttpClientConfig::default(),
+        );
+
+        assert_eq!(
+            config.store_url(),
+            "http://127.0.0.1:8090/admin/api/2025-01/graphql.json"
+        );
+    }
+
+    #[test]
+    fn test_shopify_config_is_valid_api_version() {
+        assert!(ShopifyConfig::is_valid_api_version("2024-07"));
+        assert!(ShopifyConfig::is_valid_api_version("unstable"));
+        assert!(ShopifyConfig::is_valid_api_version(
+            ShopifyConfig::DEFAULT_API_VERSION
+        ));
+
+        assert!(!ShopifyConfig::is_valid_api_version("2024-08"));
+        assert!(!ShopifyConfig::is_valid_api_version("24-07"));
+        assert!(!ShopifyConfig::is_valid_api_version("latest"));
+    }
+}
@@ -1,3 +1,4 @@
 pub(super) mod model;
+pub mod repository;
 pub mod sea_orm;
 pub mod transaction_manager_interface;
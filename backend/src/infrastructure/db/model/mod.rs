@@ -2,11 +2,15 @@
 
 pub mod prelude;
 
+pub mod audit_log;
+pub mod customer_segment;
 pub mod permission;
 pub mod resource;
 pub mod role;
 pub mod role_resource_permission;
+pub mod tenant;
 pub mod user;
+pub mod user_customer;
 pub mod user_group;
 pub mod user_group_role;
 pub mod user_role;
@@ -0,0 +1,4 @@
+pub mod audit_log;
+pub mod customer_segment;
+pub mod user;
+pub mod user_customer;
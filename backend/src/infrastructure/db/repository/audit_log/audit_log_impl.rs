@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, DatabaseTransaction, NotSet, Set};
+
+use crate::{
+    domain::{audit_log::audit_log::AuditLog, error::error::DomainError},
+    infrastructure::{
+        db::{model::audit_log, transaction_manager_interface::TransactionManager},
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::audit_log_repository_interface::AuditLogRepository,
+};
+
+/// Repository for the audit trail stored in the database.
+pub struct AuditLogRepositoryImpl {
+    transaction_manager: Arc<dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>>,
+}
+
+impl AuditLogRepositoryImpl {
+    pub fn new(
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+    ) -> Self {
+        Self {
+            transaction_manager,
+        }
+    }
+
+    fn to_active_model(audit_log: &AuditLog) -> audit_log::ActiveModel {
+        audit_log::ActiveModel {
+            id: NotSet,
+            actor_user_id: Set(audit_log.actor_user_id().to_owned()),
+            action: Set(audit_log.action().to_string()),
+            resource_type: Set(audit_log.resource_type().to_string()),
+            resource_id: Set(audit_log.resource_id().to_owned()),
+            created_at: Set(audit_log.created_at().fixed_offset()),
+        }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for AuditLogRepositoryImpl {
+    async fn create(&self, audit_log: AuditLog) -> Result<AuditLog, DomainError> {
+        let active_model = Self::to_active_model(&audit_log);
+
+        let model = if self.transaction_manager.is_transaction_started().await {
+            active_model
+                .insert(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            active_model
+                .insert(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to create audit log.", "action" => audit_log.action(), "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        // The action and resource type are stored as display strings, so the saved values are used as they are.
+        AuditLog::new(
+            model.id.to_string(),
+            model.actor_user_id,
+            audit_log.action().to_owned(),
+            audit_log.resource_type().to_owned(),
+            model.resource_id,
+            model.created_at.with_timezone(&Utc),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::ActiveValue;
+
+    use crate::domain::{
+        audit_log::audit_log::AuditAction, authorized_resource::authorized_resource::ResourceType,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_to_active_model() {
+        let audit_log = AuditLog::create(
+            "user",
+            AuditAction::EraseCustomerData,
+            ResourceType::Customer,
+            "100",
+        )
+        .unwrap();
+
+        let active_model = AuditLogRepositoryImpl::to_active_model(&audit_log);
+
+        assert_eq!(active_model.id, ActiveValue::NotSet);
+        assert_eq!(
+            active_model.actor_user_id,
+            ActiveValue::Set("user".to_string())
+        );
+        assert_eq!(
+            active_model.action,
+            ActiveValue::Set("EraseCustomerData".to_string())
+        );
+        assert_eq!(
+            active_model.resource_type,
+            ActiveValue::Set("Customer".to_string())
+        );
+        assert_eq!(
+            active_model.resource_id,
+            ActiveValue::Set("100".to_string())
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, DatabaseConnection, DatabaseTransaction, EntityTrait, NotSet, QueryOrder, Set,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        customer::search_criteria::search_criteria::{
+            CustomerSearchCriteria, CustomerSearchCriteriaParams,
+        },
+        customer_segment::customer_segment::{CustomerSegment, Id as CustomerSegmentId},
+        email::email::Email,
+        error::error::DomainError,
+        money::amount::amount::Amount,
+        phone::phone::Phone,
+    },
+    infrastructure::{
+        db::{
+            model::{customer_segment, prelude::CustomerSegment as CustomerSegmentEntity},
+            transaction_manager_interface::TransactionManager,
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::customer_segment_repository_interface::CustomerSegmentRepository,
+};
+
+/// Representation of the search criteria stored in the JSON column.
+#[derive(Debug, Serialize, Deserialize)]
+struct CustomerSearchCriteriaRecord {
+    name: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    tags: Vec<String>,
+    created_at_min: Option<DateTime<Utc>>,
+    created_at_max: Option<DateTime<Utc>>,
+    orders_count_min: Option<u32>,
+    orders_count_max: Option<u32>,
+    amount_spent_min: Option<f64>,
+    amount_spent_max: Option<f64>,
+}
+
+impl From<&CustomerSearchCriteria> for CustomerSearchCriteriaRecord {
+    fn from(criteria: &CustomerSearchCriteria) -> Self {
+        Self {
+            name: criteria.name().to_owned(),
+            email: criteria.email().as_ref().map(|e| e.value().to_owned()),
+            phone: criteria.phone().as_ref().map(|p| p.value().to_owned()),
+            tags: criteria.tags().to_owned(),
+            created_at_min: criteria.created_at_min().to_owned(),
+            created_at_max: criteria.created_at_max().to_owned(),
+            orders_count_min: criteria.orders_count_min().to_owned(),
+            orders_count_max: criteria.orders_count_max().to_owned(),
+            amount_spent_min: criteria.amount_spent_min().as_ref().map(|a| *a.value()),
+            amount_spent_max: criteria.amount_spent_max().as_ref().map(|a| *a.value()),
+        }
+    }
+}
+
+impl CustomerSearchCriteriaRecord {
+    fn to_domain(self) -> Result<CustomerSearchCriteria, DomainError> {
+        CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: self.name,
+            email: self.email.map(Email::new).transpose()?,
+            phone: self.phone.map(Phone::new).transpose()?,
+            tags: self.tags,
+            created_at_min: self.created_at_min,
+            created_at_max: self.created_at_max,
+            orders_count_min: self.orders_count_min,
+            orders_count_max: self.orders_count_max,
+            amount_spent_min: self.amount_spent_min.map(Amount::new).transpose()?,
+            amount_spent_max: self.amount_spent_max.map(Amount::new).transpose()?,
+        })
+    }
+}
+
+/// Repository for customer segments stored in the database.
+pub struct CustomerSegmentRepositoryImpl {
+    transaction_manager: Arc<dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>>,
+}
+
+impl CustomerSegmentRepositoryImpl {
+    pub fn new(
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+    ) -> Self {
+        Self {
+            transaction_manager,
+        }
+    }
+
+    fn to_domain(model: customer_segment::Model) -> Result<CustomerSegment, DomainError> {
+        let criteria: CustomerSearchCriteriaRecord = serde_json::from_value(model.criteria)
+            .map_err(|e| {
+                log_error!("Failed to parse customer segment criteria.", "id" => model.id, "error" => e);
+                DomainError::ConversionError
+            })?;
+
+        CustomerSegment::new(
+            model.id.to_string(),
+            model.name,
+            criteria.to_domain()?,
+            model.created_at.with_timezone(&Utc),
+            model.updated_at.with_timezone(&Utc),
+        )
+    }
+}
+
+#[async_trait]
+impl CustomerSegmentRepository for CustomerSegmentRepositoryImpl {
+    async fn find_customer_segment_by_id(
+        &self,
+        id: &CustomerSegmentId,
+    ) -> Result<CustomerSegment, DomainError> {
+        let Ok(model_id) = id.parse::<i32>() else {
+            log_error!("Invalid customer segment id.", "id" => id);
+            return Err(DomainError::NotFound);
+        };
+
+        let query = CustomerSegmentEntity::find_by_id(model_id);
+        let model = if self.transaction_manager.is_transaction_started().await {
+            query
+                .one(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .one(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to get customer segment.", "id" => id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        match model {
+            Some(model) => Self::to_domain(model),
+            None => {
+                log_error!("Customer segment not found.", "id" => id);
+                Err(DomainError::NotFound)
+            }
+        }
+    }
+
+    async fn find_customer_segments(&self) -> Result<Vec<CustomerSegment>, DomainError> {
+        let query = CustomerSegmentEntity::find().order_by_asc(customer_segment::Column::Id);
+        let models = if self.transaction_manager.is_transaction_started().await {
+            query
+                .all(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .all(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to get customer segments.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        models.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn create(
+        &self,
+        customer_segment: CustomerSegment,
+    ) -> Result<CustomerSegment, DomainError> {
+        let criteria = serde_json::to_value(CustomerSearchCriteriaRecord::from(
+            customer_segment.criteria(),
+        ))
+        .map_err(|e| InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e)))?;
+
+        let active_model = customer_segment::ActiveModel {
+            id: NotSet,
+            name: Set(customer_segment.name().to_owned()),
+            criteria: Set(criteria),
+            created_at: Set(customer_segment.created_at().fixed_offset()),
+            updated_at: Set(customer_segment.updated_at().fixed_offset()),
+        };
+
+        let model = if self.transaction_manager.is_transaction_started().await {
+            active_model
+                .insert(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            active_model
+                .insert(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to create customer segment.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        Self::to_domain(model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use super::*;
+
+    fn mock_model(criteria: serde_json::Value) -> customer_segment::Model {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        customer_segment::Model {
+            id: 1,
+            name: "VIP".to_string(),
+            criteria,
+            created_at: now.fixed_offset(),
+            updated_at: now.fixed_offset(),
+        }
+    }
+
+    #[test]
+    fn test_criteria_round_trip() {
+        let criteria = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: Some("John".to_string()),
+            email: Some(Email::new("john@example.com").unwrap()),
+            tags: vec!["vip".to_string()],
+            created_at_min: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            orders_count_min: Some(3),
+            amount_spent_min: Some(Amount::new(500.0).unwrap()),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let value = serde_json::to_value(CustomerSearchCriteriaRecord::from(&criteria)).unwrap();
+        let segment = CustomerSegmentRepositoryImpl::to_domain(mock_model(value))
+            .expect("Failed to convert model");
+
+        assert_eq!(segment.id(), "1");
+        assert_eq!(segment.name(), "VIP");
+        assert_eq!(segment.criteria(), &criteria);
+    }
+
+    #[test]
+    fn test_to_domain_with_invalid_criteria() {
+        let result = CustomerSegmentRepositoryImpl::to_domain(mock_model(json!({"tags": "vip"})));
+
+        assert!(matches!(result, Err(DomainError::ConversionError)));
+    }
+
+    #[test]
+    fn test_to_domain_with_invalid_range() {
+        let value = json!({
+            "name": null,
+            "email": null,
+            "phone": null,
+            "tags": [],
+            "created_at_min": null,
+            "created_at_max": null,
+            "orders_count_min": 10,
+            "orders_count_max": 1,
+            "amount_spent_min": null,
+            "amount_spent_max": null,
+        });
+
+        let result = CustomerSegmentRepositoryImpl::to_domain(mock_model(value));
+
+        assert!(matches!(result, Err(DomainError::ValidationError)));
+    }
+}
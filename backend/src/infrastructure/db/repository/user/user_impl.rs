@@ -0,0 +1,296 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, QueryFilter, QueryOrder, Set,
+};
+
+use crate::{
+    domain::{error::error::DomainError, user::user::Id as UserId},
+    infrastructure::{
+        db::{
+            model::{
+                prelude::{User as UserEntity, UserUserGroup as UserUserGroupEntity},
+                user, user_user_group,
+            },
+            transaction_manager_interface::TransactionManager,
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::user_repository_interface::{UserRecord, UserRepository},
+};
+
+/// Placeholder that replaces the name of an anonymized user.
+const ANONYMIZED_USER_NAME: &str = "anonymized";
+
+/// Repository for users stored in the database for authorization.
+pub struct UserRepositoryImpl {
+    transaction_manager: Arc<dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>>,
+}
+
+impl UserRepositoryImpl {
+    pub fn new(
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+    ) -> Self {
+        Self {
+            transaction_manager,
+        }
+    }
+
+    async fn find_user<C: ConnectionTrait>(
+        db: &C,
+        id: &UserId,
+    ) -> Result<user::Model, DomainError> {
+        let user = UserEntity::find_by_id(id.to_owned())
+            .one(db)
+            .await
+            .map_err(|e| {
+                log_error!("Failed to get user.", "id" => id, "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+            })?;
+
+        user.ok_or_else(|| {
+            log_error!("User not found.", "id" => id);
+            DomainError::NotFound
+        })
+    }
+
+    async fn find_user_record<C: ConnectionTrait>(
+        db: &C,
+        id: &UserId,
+    ) -> Result<UserRecord, DomainError> {
+        let user = Self::find_user(db, id).await?;
+
+        let user_user_groups = UserUserGroupEntity::find()
+            .filter(user_user_group::Column::UserId.eq(id))
+            .order_by_asc(user_user_group::Column::UserGroupId)
+            .all(db)
+            .await
+            .map_err(|e| {
+                log_error!("Failed to get user groups of user.", "id" => id, "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+            })?;
+
+        Ok(UserRecord {
+            id: user.id,
+            name: user.name,
+            user_group_ids: user_user_groups
+                .into_iter()
+                .map(|user_user_group| user_user_group.user_group_id)
+                .collect(),
+        })
+    }
+
+    async fn anonymize_user<C: ConnectionTrait>(
+        db: &C,
+        id: &UserId,
+    ) -> Result<UserId, DomainError> {
+        let user = Self::find_user(db, id).await?;
+
+        UserUserGroupEntity::delete_many()
+            .filter(user_user_group::Column::UserId.eq(id))
+            .exec(db)
+            .await
+            .map_err(|e| {
+                log_error!("Failed to delete user groups of user.", "id" => id, "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+            })?;
+
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.name = Set(ANONYMIZED_USER_NAME.to_string());
+        let user = active_model.update(db).await.map_err(|e| {
+            log_error!("Failed to anonymize user.", "id" => id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        Ok(user.id)
+    }
+}
+
+#[async_trait]
+impl UserRepository for UserRepositoryImpl {
+    async fn find_user_by_id(&self, id: &UserId) -> Result<UserRecord, DomainError> {
+        if self.transaction_manager.is_transaction_started().await {
+            Self::find_user_record(
+                self.transaction_manager
+                    .get_transaction()
+                    .await?
+                    .as_ref()
+                    .ok_or(DomainError::SystemError)?,
+                id,
+            )
+            .await
+        } else {
+            Self::find_user_record(
+                self.transaction_manager.get_connection().await?.as_ref(),
+                id,
+            )
+            .await
+        }
+    }
+
+    async fn anonymize(&self, id: &UserId) -> Result<UserId, DomainError> {
+        if self.transaction_manager.is_transaction_started().await {
+            Self::anonymize_user(
+                self.transaction_manager
+                    .get_transaction()
+                    .await?
+                    .as_ref()
+                    .ok_or(DomainError::SystemError)?,
+                id,
+            )
+            .await
+        } else {
+            Self::anonymize_user(
+                self.transaction_manager.get_connection().await?.as_ref(),
+                id,
+            )
+            .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{
+        distributions::{Alphanumeric, DistString},
+        Rng,
+    };
+
+    use crate::infrastructure::{
+        config::config::{DatabaseConfig, Env},
+        db::{
+            model::user_group,
+            sea_orm::sea_orm_manager::{SeaOrmConnectionProvider, SeaOrmTransactionManager},
+        },
+        secret::secrets_manager::SecretsManagerClient,
+    };
+
+    use super::*;
+
+    async fn transaction_manager() -> SeaOrmTransactionManager {
+        let aws_sdk_config = aws_config::load_from_env().await;
+        let secrets_client = SecretsManagerClient::new(&aws_sdk_config).await.unwrap();
+
+        let connection_provider = SeaOrmConnectionProvider::new(
+            DatabaseConfig::new(&secrets_client, &Env::Local)
+                .await
+                .expect("Failed to get database config"),
+        )
+        .await
+        .expect("Failed to get connection provider");
+
+        let transaction_manager =
+            SeaOrmTransactionManager::new(Arc::clone(&connection_provider.get_connection()))
+                .await
+                .expect("Failed to get transaction manager");
+
+        transaction_manager
+            .begin()
+            .await
+            .expect("Failed to begin transaction");
+
+        transaction_manager
+    }
+
+    /// Insert a user belonging to a user group into the database.
+    async fn insert_user_with_group(
+        transaction: &DatabaseTransaction,
+    ) -> Result<(String, i32), Box<dyn std::error::Error>> {
+        let mut rng = rand::thread_rng();
+        let user_id = Alphanumeric.sample_string(&mut rng, 10);
+        let group_id = rng.gen_range(1000..10000);
+
+        let user = user::ActiveModel {
+            id: Set(user_id.to_string()),
+            name: Set("name".to_string()),
+        };
+        user.insert(transaction).await?;
+
+        let user_group = user_group::ActiveModel {
+            id: Set(group_id),
+            name: Set(format!("test_group_{}", group_id)),
+        };
+        user_group.insert(transaction).await?;
+
+        let user_user_group = user_user_group::ActiveModel {
+            id: Set(rng.gen_range(1000..10000)),
+            user_id: Set(user_id.to_string()),
+            user_group_id: Set(group_id),
+            tenant_id: Set(None),
+        };
+        user_user_group.insert(transaction).await?;
+
+        Ok((user_id, group_id))
+    }
+
+    #[tokio::test]
+    async fn test_find_user_by_id_success() {
+        let transaction_manager = transaction_manager().await;
+        let repository = UserRepositoryImpl::new(Arc::new(transaction_manager.clone()));
+
+        let (user_id, group_id) = insert_user_with_group(
+            transaction_manager
+                .get_transaction()
+                .await
+                .unwrap()
+                .as_ref()
+                .unwrap(),
+        )
+        .await
+        .expect("Failed to insert test data");
+
+        let user = repository
+            .find_user_by_id(&user_id)
+            .await
+            .expect("Failed to find user");
+
+        assert_eq!(user.id, user_id);
+        assert_eq!(user.name, "name");
+        assert_eq!(user.user_group_ids, vec![group_id]);
+    }
+
+    #[tokio::test]
+    async fn test_find_user_by_id_not_found() {
+        let transaction_manager = transaction_manager().await;
+        let repository = UserRepositoryImpl::new(Arc::new(transaction_manager));
+
+        let result = repository.find_user_by_id(&"unknown".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_anonymize_success() {
+        let transaction_manager = transaction_manager().await;
+        let repository = UserRepositoryImpl::new(Arc::new(transaction_manager.clone()));
+
+        let (user_id, _) = insert_user_with_group(
+            transaction_manager
+                .get_transaction()
+                .await
+                .unwrap()
+                .as_ref()
+                .unwrap(),
+        )
+        .await
+        .expect("Failed to insert test data");
+
+        let result = repository
+            .anonymize(&user_id)
+            .await
+            .expect("Failed to anonymize user");
+        assert_eq!(result, user_id);
+
+        let user = repository
+            .find_user_by_id(&user_id)
+            .await
+            .expect("Failed to find user");
+        assert_eq!(user.name, ANONYMIZED_USER_NAME);
+        assert!(user.user_group_ids.is_empty());
+    }
+}
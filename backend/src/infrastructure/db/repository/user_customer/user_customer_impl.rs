@@ -0,0 +1,430 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    sea_query::OnConflict, ColumnTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
+    QueryFilter, Set,
+};
+
+use crate::{
+    domain::{
+        customer::customer::Id as CustomerId, error::error::DomainError, user::user::Id as UserId,
+    },
+    infrastructure::{
+        db::{
+            model::{prelude::UserCustomer as UserCustomerEntity, user_customer},
+            transaction_manager_interface::TransactionManager,
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::user_customer_repository_interface::UserCustomerRepository,
+};
+
+/// Repository for the link between IdP users and customers stored in the database.
+///
+/// Customers belong to a shop, so the links are kept per tenant.
+pub struct UserCustomerRepositoryImpl {
+    transaction_manager: Arc<dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>>,
+    tenant_id: i32,
+}
+
+impl UserCustomerRepositoryImpl {
+    pub fn new(
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant_id: i32,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            tenant_id,
+        }
+    }
+
+    fn to_active_model(
+        tenant_id: i32,
+        user_id: &UserId,
+        customer_id: &CustomerId,
+    ) -> user_customer::ActiveModel {
+        let now = Utc::now().fixed_offset();
+        user_customer::ActiveModel {
+            tenant_id: Set(tenant_id),
+            user_id: Set(user_id.to_owned()),
+            customer_id: Set(customer_id.to_owned()),
+            created_at: Set(now),
+            updated_at: Set(now),
+        }
+    }
+}
+
+#[async_trait]
+impl UserCustomerRepository for UserCustomerRepositoryImpl {
+    async fn find_customer_id_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<CustomerId, DomainError> {
+        let query = UserCustomerEntity::find_by_id((self.tenant_id, user_id.to_owned()));
+        let model = if self.transaction_manager.is_transaction_started().await {
+            query
+                .one(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .one(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to get customer linked to user.", "user_id" => user_id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        match model {
+            Some(model) => Ok(model.customer_id),
+            None => Err(DomainError::NotFound),
+        }
+    }
+
+    async fn find_user_id_by_customer_id(
+        &self,
+        customer_id: &CustomerId,
+    ) -> Result<UserId, DomainError> {
+        let query = UserCustomerEntity::find()
+            .filter(user_customer::Column::TenantId.eq(self.tenant_id))
+            .filter(user_customer::Column::CustomerId.eq(customer_id));
+        let model = if self.transaction_manager.is_transaction_started().await {
+            query
+                .one(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .one(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to get user linked to customer.", "customer_id" => customer_id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        match model {
+            Some(model) => Ok(model.user_id),
+            None => Err(DomainError::NotFound),
+        }
+    }
+
+    async fn find_user_ids_by_customer_ids(
+        &self,
+        customer_ids: &[CustomerId],
+    ) -> Result<HashMap<CustomerId, UserId>, DomainError> {
+        if customer_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let query = UserCustomerEntity::find()
+            .filter(user_customer::Column::TenantId.eq(self.tenant_id))
+            .filter(user_customer::Column::CustomerId.is_in(customer_ids.to_vec()));
+        let models = if self.transaction_manager.is_transaction_started().await {
+            query
+                .all(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .all(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to get users linked to customers.", "customer_ids" => customer_ids, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        Ok(models
+            .into_iter()
+            .map(|model| (model.customer_id, model.user_id))
+            .collect())
+    }
+
+    async fn save(&self, user_id: &UserId, customer_id: &CustomerId) -> Result<(), DomainError> {
+        let query =
+            UserCustomerEntity::insert(Self::to_active_model(self.tenant_id, user_id, customer_id))
+                .on_conflict(
+                    OnConflict::columns([
+                        user_customer::Column::TenantId,
+                        user_customer::Column::UserId,
+                    ])
+                    .update_columns([
+                        user_customer::Column::CustomerId,
+                        user_customer::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+                );
+
+        if self.transaction_manager.is_transaction_started().await {
+            query
+                .exec(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .exec(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to link user to customer.", "user_id" => user_id, "customer_id" => customer_id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        Ok(())
+    }
+
+    async fn delete_by_customer_id(&self, customer_id: &CustomerId) -> Result<(), DomainError> {
+        let query = UserCustomerEntity::delete_many()
+            .filter(user_customer::Column::TenantId.eq(self.tenant_id))
+            .filter(user_customer::Column::CustomerId.eq(customer_id));
+
+        if self.transaction_manager.is_transaction_started().await {
+            query
+                .exec(
+                    self.transaction_manager
+                        .get_transaction()
+                        .await?
+                        .as_ref()
+                        .ok_or(DomainError::SystemError)?,
+                )
+                .await
+        } else {
+            query
+                .exec(self.transaction_manager.get_connection().await?.as_ref())
+                .await
+        }
+        .map_err(|e| {
+            log_error!("Failed to unlink users from customer.", "customer_id" => customer_id, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distributions::{Alphanumeric, DistString};
+    use sea_orm::ActiveValue;
+
+    use crate::infrastructure::{
+        config::config::{DatabaseConfig, Env},
+        db::sea_orm::sea_orm_manager::{SeaOrmConnectionProvider, SeaOrmTransactionManager},
+        secret::secrets_manager::SecretsManagerClient,
+        tenant::tenant::DEFAULT_TENANT_ID,
+    };
+
+    use super::*;
+
+    async fn transaction_manager() -> SeaOrmTransactionManager {
+        let aws_sdk_config = aws_config::load_from_env().await;
+        let secrets_client = SecretsManagerClient::new(&aws_sdk_config).await.unwrap();
+
+        let connection_provider = SeaOrmConnectionProvider::new(
+            DatabaseConfig::new(&secrets_client, &Env::Local)
+                .await
+                .expect("Failed to get database config"),
+        )
+        .await
+        .expect("Failed to get connection provider");
+
+        let transaction_manager =
+            SeaOrmTransactionManager::new(Arc::clone(&connection_provider.get_connection()))
+                .await
+                .expect("Failed to get transaction manager");
+
+        transaction_manager
+            .begin()
+            .await
+            .expect("Failed to begin transaction");
+
+        transaction_manager
+    }
+
+    fn random_id() -> String {
+        Alphanumeric.sample_string(&mut rand::thread_rng(), 10)
+    }
+
+    #[test]
+    fn test_to_active_model() {
+        let active_model = UserCustomerRepositoryImpl::to_active_model(
+            DEFAULT_TENANT_ID,
+            &"user".to_string(),
+            &"1".to_string(),
+        );
+
+        assert_eq!(active_model.tenant_id, ActiveValue::Set(DEFAULT_TENANT_ID));
+        assert_eq!(active_model.user_id, ActiveValue::Set("user".to_string()));
+        assert_eq!(active_model.customer_id, ActiveValue::Set("1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_customer_id_by_user_id() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+        let user_id = random_id();
+        let customer_id = random_id();
+
+        repository
+            .save(&user_id, &customer_id)
+            .await
+            .expect("Failed to save link");
+
+        let result = repository
+            .find_customer_id_by_user_id(&user_id)
+            .await
+            .expect("Failed to find link");
+        assert_eq!(result, customer_id);
+    }
+
+    #[tokio::test]
+    async fn test_save_replaces_existing_link() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+        let user_id = random_id();
+        let new_customer_id = random_id();
+
+        repository
+            .save(&user_id, &random_id())
+            .await
+            .expect("Failed to save link");
+        repository
+            .save(&user_id, &new_customer_id)
+            .await
+            .expect("Failed to replace link");
+
+        let result = repository
+            .find_customer_id_by_user_id(&user_id)
+            .await
+            .expect("Failed to find link");
+        assert_eq!(result, new_customer_id);
+    }
+
+    #[tokio::test]
+    async fn test_find_customer_id_by_user_id_in_other_tenant() {
+        let transaction_manager = Arc::new(transaction_manager().await);
+        let repository =
+            UserCustomerRepositoryImpl::new(transaction_manager.clone(), DEFAULT_TENANT_ID);
+        let user_id = random_id();
+
+        repository
+            .save(&user_id, &random_id())
+            .await
+            .expect("Failed to save link");
+
+        let other_tenant_repository =
+            UserCustomerRepositoryImpl::new(transaction_manager, DEFAULT_TENANT_ID + 1);
+        let result = other_tenant_repository
+            .find_customer_id_by_user_id(&user_id)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_user_id_by_customer_id() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+        let user_id = random_id();
+        let customer_id = random_id();
+
+        repository
+            .save(&user_id, &customer_id)
+            .await
+            .expect("Failed to save link");
+
+        let result = repository
+            .find_user_id_by_customer_id(&customer_id)
+            .await
+            .expect("Failed to find link");
+        assert_eq!(result, user_id);
+    }
+
+    #[tokio::test]
+    async fn test_find_user_ids_by_customer_ids() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+        let user_id = random_id();
+        let customer_id = random_id();
+
+        repository
+            .save(&user_id, &customer_id)
+            .await
+            .expect("Failed to save link");
+
+        let result = repository
+            .find_user_ids_by_customer_ids(&[customer_id.clone(), random_id()])
+            .await
+            .expect("Failed to find links");
+        assert_eq!(result, HashMap::from([(customer_id, user_id)]));
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_customer_id() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+        let user_id = random_id();
+        let customer_id = random_id();
+
+        repository
+            .save(&user_id, &customer_id)
+            .await
+            .expect("Failed to save link");
+        repository
+            .delete_by_customer_id(&customer_id)
+            .await
+            .expect("Failed to delete link");
+
+        let result = repository.find_customer_id_by_user_id(&user_id).await;
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_customer_id_by_user_id_not_found() {
+        let repository = UserCustomerRepositoryImpl::new(
+            Arc::new(transaction_manager().await),
+            DEFAULT_TENANT_ID,
+        );
+
+        let result = repository.find_customer_id_by_user_id(&random_id()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+}
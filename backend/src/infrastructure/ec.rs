@@ -1,2 +1,3 @@
 pub mod ec_client_interface;
+pub mod local;
 pub mod shopify;
@@ -7,17 +7,36 @@ use crate::domain::error::error::DomainError;
 
 pub trait ECClientResponse: Send + Sync {}
 
+/// GraphQL client used by the Shopify repositories.
+///
+/// This is not the boundary between platforms: a platform without a GraphQL API, such as the local store,
+/// implements the repository traits directly instead of this client.
 #[automock]
 #[async_trait]
 pub trait ECClient: Send + Sync {
-    /// Execute the query.
-    async fn query<T>(&self, query: &str) -> Result<T, DomainError>
+    /// Execute the query, passing values as variables rather than embedding them in the query.
+    async fn query_with_variables<T, U>(
+        &self,
+        query: &str,
+        variables: &T,
+    ) -> Result<U, DomainError>
     where
-        T: ECClientResponse + for<'de> Deserialize<'de> + Send + Sync + 'static;
+        T: Serialize + Send + Sync + fmt::Display + 'static,
+        U: ECClientResponse + for<'de> Deserialize<'de> + Send + Sync + 'static;
 
     /// Perform mutation.
     async fn mutation<T, U>(&self, query: &str, input: &T) -> Result<U, DomainError>
     where
         T: Serialize + Send + Sync + fmt::Display + 'static,
         U: ECClientResponse + for<'de> Deserialize<'de> + Send + Sync + 'static;
+
+    /// Perform mutation with arbitrary variables in addition to or instead of `input`.
+    async fn mutation_with_variables<T, U>(
+        &self,
+        query: &str,
+        variables: &T,
+    ) -> Result<U, DomainError>
+    where
+        T: Serialize + Send + Sync + fmt::Display + 'static,
+        U: ECClientResponse + for<'de> Deserialize<'de> + Send + Sync + 'static;
 }
@@ -0,0 +1,4 @@
+pub mod query_service;
+pub mod repository;
+mod schema;
+pub mod store;
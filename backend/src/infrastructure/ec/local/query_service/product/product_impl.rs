@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::error::error::DomainError,
+    infrastructure::ec::local::{schema::ProductStatusRecord, store::LocalStore},
+    usecase::query_service::{
+        dto::product::ProductDTO,
+        product_query_service_interface::{ProductQueryService, RelatedProductFilter},
+    },
+};
+
+/// Query service for products for the local store.
+pub struct ProductQueryServiceImpl {
+    store: LocalStore,
+}
+
+impl ProductQueryServiceImpl {
+    pub fn new(store: LocalStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ProductQueryService for ProductQueryServiceImpl {
+    async fn search_related_products(
+        &self,
+        filter: &RelatedProductFilter,
+    ) -> Result<Vec<ProductDTO>, DomainError> {
+        // Same conditions as on Shopify: other active products of the category that are in stock.
+        Ok(self
+            .store
+            .read(|data| {
+                data.products
+                    .iter()
+                    .filter(|product| {
+                        product.id != filter.id
+                            && product.category_id.as_ref() == Some(&filter.category_id)
+                            && product.status == ProductStatusRecord::Active
+                            && product.variants.iter().any(|variant| {
+                                data.available_quantity(&variant.inventory_item_id) > 0
+                            })
+                    })
+                    .map(|product| product.to_dto())
+                    .collect()
+            })
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    async fn query_service() -> ProductQueryServiceImpl {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/local/store.json");
+        ProductQueryServiceImpl::new(LocalStore::open(path).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_search_related_products_success() {
+        let query_service = query_service().await;
+
+        let products = query_service
+            .search_related_products(&RelatedProductFilter {
+                id: "1".to_string(),
+                category_id: "apparel".to_string(),
+            })
+            .await
+            .unwrap();
+
+        // Product 3 is excluded because it is a draft.
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].id, "2");
+        assert_eq!(products[0].handle, "hoodie");
+        assert_eq!(products[0].price, 5000.0);
+    }
+
+    #[tokio::test]
+    async fn test_search_related_products_without_match() {
+        let query_service = query_service().await;
+
+        let products = query_service
+            .search_related_products(&RelatedProductFilter {
+                id: "1".to_string(),
+                category_id: "food".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(products.is_empty());
+    }
+}
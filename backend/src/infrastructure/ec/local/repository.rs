@@ -0,0 +1,4 @@
+pub mod inventory_item;
+pub mod inventory_level;
+pub mod media;
+pub mod product;
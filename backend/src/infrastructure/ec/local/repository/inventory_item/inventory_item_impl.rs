@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError, inventory_item::inventory_item::InventoryItem,
+        product::product::Id as ProductId, product::variant::sku::sku::Sku,
+    },
+    infrastructure::ec::local::store::LocalStore,
+    log_error,
+    usecase::repository::inventory_item_repository_interface::InventoryItemRepository,
+};
+
+/// Repository for inventory items for the local store.
+pub struct InventoryItemRepositoryImpl {
+    store: LocalStore,
+}
+
+impl InventoryItemRepositoryImpl {
+    pub fn new(store: LocalStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl InventoryItemRepository for InventoryItemRepositoryImpl {
+    async fn find_inventory_items_by_product_id(
+        &self,
+        product_id: &ProductId,
+    ) -> Result<Vec<InventoryItem>, DomainError> {
+        self.store
+            .read(|data| {
+                data.products
+                    .iter()
+                    .filter(|product| &product.id == product_id)
+                    .flat_map(|product| product.variants.iter())
+                    .map(|variant| variant.to_inventory_item_domain())
+                    .collect()
+            })
+            .await
+    }
+
+    async fn find_inventory_item_by_sku(&self, sku: &Sku) -> Result<InventoryItem, DomainError> {
+        let sku = sku.value();
+
+        self.store
+            .read(|data| match data.find_variant_by_sku(sku) {
+                Some(variant) => variant.to_inventory_item_domain(),
+                None => {
+                    log_error!("No inventory item found for sku.", "sku" => sku);
+                    Err(DomainError::NotFound)
+                }
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    async fn repository() -> InventoryItemRepositoryImpl {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/local/store.json");
+        InventoryItemRepositoryImpl::new(LocalStore::open(path).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_items_by_product_id_success() {
+        let repo = repository().await;
+
+        let items = repo
+            .find_inventory_items_by_product_id(&"1".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id(), "111");
+        assert_eq!(items[0].variant_id(), "11");
+        assert_eq!(items[1].id(), "112");
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_items_by_product_id_without_product() {
+        let repo = repository().await;
+
+        let items = repo
+            .find_inventory_items_by_product_id(&"999".to_string())
+            .await
+            .unwrap();
+
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_item_by_sku_success() {
+        let repo = repository().await;
+
+        let item = repo
+            .find_inventory_item_by_sku(&Sku::new("HD-M").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(item.id(), "121");
+        assert_eq!(item.variant_id(), "21");
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_item_by_sku_not_found() {
+        let repo = repository().await;
+
+        let result = repo
+            .find_inventory_item_by_sku(&Sku::new("UNKNOWN").unwrap())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+}
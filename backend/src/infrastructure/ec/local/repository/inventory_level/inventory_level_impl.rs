@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        inventory_level::{
+            inventory_change::inventory_change::InventoryChange, inventory_level::InventoryLevel,
+        },
+        location::location::Id as LocationId,
+        product::variant::sku::sku::Sku,
+    },
+    infrastructure::ec::local::{
+        schema::{InventoryTypeRecord, QuantityRecord},
+        store::LocalStore,
+    },
+    log_error,
+    usecase::repository::inventory_level_repository_interface::InventoryLevelRepository,
+};
+
+/// Repository for inventory levels for the local store.
+pub struct InventoryLevelRepositoryImpl {
+    store: LocalStore,
+}
+
+impl InventoryLevelRepositoryImpl {
+    pub fn new(store: LocalStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl InventoryLevelRepository for InventoryLevelRepositoryImpl {
+    async fn find_inventory_level_by_sku_with_location_id(
+        &self,
+        sku: &Sku,
+        location_id: &LocationId,
+    ) -> Result<Option<InventoryLevel>, DomainError> {
+        let sku = sku.value();
+
+        self.store
+            .read(|data| {
+                let variant = match data.find_variant_by_sku(sku) {
+                    Some(variant) => variant,
+                    None => return Ok(None),
+                };
+
+                data.inventory_levels
+                    .iter()
+                    .find(|level| {
+                        level.inventory_item_id == variant.inventory_item_id
+                            && &level.location_id == location_id
+                    })
+                    .map(|level| level.to_domain())
+                    .transpose()
+            })
+            .await
+    }
+
+    async fn find_inventory_levels_by_sku(
+        &self,
+        sku: &Sku,
+    ) -> Result<Vec<InventoryLevel>, DomainError> {
+        let sku = sku.value();
+
+        self.store
+            .read(|data| {
+                let variant = match data.find_variant_by_sku(sku) {
+                    Some(variant) => variant,
+                    None => return Ok(Vec::new()),
+                };
+
+                data.inventory_levels
+                    .iter()
+                    .filter(|level| level.inventory_item_id == variant.inventory_item_id)
+                    .map(|level| level.to_domain())
+                    .collect()
+            })
+            .await
+    }
+
+    async fn update(
+        &self,
+        inventory_change: InventoryChange,
+    ) -> Result<InventoryLevel, DomainError> {
+        if inventory_change.changes().len() != 1 {
+            log_error!("Only one change is supported.", "changes" => inventory_change.changes());
+            return Err(DomainError::SystemError);
+        }
+        let name = InventoryTypeRecord::from(inventory_change.name());
+        let change = &inventory_change.changes()[0];
+
+        // The store keeps only the current quantities, so the reason and ledger document are not recorded.
+        self.store
+            .write(|data| {
+                let level = data
+                    .inventory_levels
+                    .iter_mut()
+                    .find(|level| {
+                        &level.inventory_item_id == change.inventory_item_id()
+                            && &level.location_id == change.location_id()
+                    })
+                    .ok_or_else(|| {
+                        log_error!(
+                            "No inventory level found.",
+                            "inventory_item_id" => change.inventory_item_id(),
+                            "location_id" => change.location_id()
+                        );
+                        DomainError::NotFound
+                    })?;
+
+                match level
+                    .quantities
+                    .iter_mut()
+                    .find(|quantity| quantity.name == name)
+                {
+                    Some(quantity) => quantity.quantity += change.delta(),
+                    None => level.quantities.push(QuantityRecord {
+                        name,
+                        quantity: *change.delta(),
+                    }),
+                }
+
+                level.to_domain()
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::domain::inventory_level::{
+        inventory_change::{change::change::Change, inventory_change::InventoryChangeReason},
+        quantity::quantity::{InventoryType, Quantity},
+    };
+
+    use super::*;
+
+    fn fixture_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/local/store.json")
+    }
+
+    async fn repository() -> InventoryLevelRepositoryImpl {
+        InventoryLevelRepositoryImpl::new(LocalStore::open(fixture_path()).await.unwrap())
+    }
+
+    /// Repository over a copy of the fixture, for tests that change the store.
+    async fn writable_repository() -> (InventoryLevelRepositoryImpl, PathBuf) {
+        let path = std::env::temp_dir().join(format!(
+            "local-store-{}-{}.json",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::copy(fixture_path(), &path).unwrap();
+
+        let repo = InventoryLevelRepositoryImpl::new(LocalStore::open(&path).await.unwrap());
+        (repo, path)
+    }
+
+    fn inventory_change(name: InventoryType, delta: i32, location_id: &str) -> InventoryChange {
+        InventoryChange::new(
+            name,
+            InventoryChangeReason::Correction,
+            vec![Change::new(delta, "111", None, location_id).unwrap()],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_level_by_sku_with_location_id_success() {
+        let repo = repository().await;
+
+        let level = repo
+            .find_inventory_level_by_sku_with_location_id(
+                &Sku::new("TS-S").unwrap(),
+                &"2".to_string(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(level.id(), "111-2");
+        assert_eq!(level.available_quantity(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_level_by_sku_with_location_id_not_stocked() {
+        let repo = repository().await;
+
+        let level = repo
+            .find_inventory_level_by_sku_with_location_id(
+                &Sku::new("HD-M").unwrap(),
+                &"2".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert!(level.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_levels_by_sku_success() {
+        let repo = repository().await;
+
+        let levels = repo
+            .find_inventory_levels_by_sku(&Sku::new("TS-S").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(levels.len(), 2);
+        assert_eq!(
+            levels[0].quantities(),
+            &vec![
+                Quantity::new(5, InventoryType::Available).unwrap(),
+                Quantity::new(1, InventoryType::Committed).unwrap(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_inventory_levels_by_sku_unknown_sku() {
+        let repo = repository().await;
+
+        let levels = repo
+            .find_inventory_levels_by_sku(&Sku::new("UNKNOWN").unwrap())
+            .await
+            .unwrap();
+
+        assert!(levels.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_success() {
+        let (repo, path) = writable_repository().await;
+
+        let level = repo
+            .update(inventory_change(InventoryType::Available, -2, "1"))
+            .await
+            .unwrap();
+
+        assert_eq!(level.available_quantity(), 3);
+
+        // The change is written to the file.
+        let reopened = InventoryLevelRepositoryImpl::new(LocalStore::open(&path).await.unwrap());
+        let level = reopened
+            .find_inventory_level_by_sku_with_location_id(
+                &Sku::new("TS-S").unwrap(),
+                &"1".to_string(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(level.available_quantity(), 3);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_without_inventory_level() {
+        let (repo, path) = writable_repository().await;
+
+        let result = repo
+            .update(inventory_change(InventoryType::Available, 1, "999"))
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_with_multiple_changes() {
+        let repo = repository().await;
+
+        let change = InventoryChange::new(
+            InventoryType::Available,
+            InventoryChangeReason::Correction,
+            vec![
+                Change::new(1, "111", None, "1").unwrap(),
+                Change::new(1, "111", None, "2").unwrap(),
+            ],
+        )
+        .unwrap();
+
+        let result = repo.update(change).await;
+
+        assert!(matches!(result, Err(DomainError::SystemError)));
+    }
+}
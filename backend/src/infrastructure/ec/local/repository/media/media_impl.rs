@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        media::{
+            media::{Id as MediaId, Media},
+            staged_upload::staged_upload::{StagedUpload, StagedUploadTarget},
+        },
+        product::product::Id as ProductId,
+    },
+    log_error,
+    usecase::repository::media_repository_interface::MediaRepository,
+};
+
+/// Repository for media for the local store.
+///
+/// The local store does not host files, so products have no media and media cannot be changed.
+pub struct MediaRepositoryImpl;
+
+impl MediaRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Refuse the request as invalid for this store, which is not a failure of the server.
+    fn unsupported<T>(operation: &str) -> Result<T, DomainError> {
+        log_error!("Media cannot be changed in the local store.", "operation" => operation);
+        Err(DomainError::InvalidRequest)
+    }
+}
+
+impl Default for MediaRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MediaRepository for MediaRepositoryImpl {
+    async fn find_media_by_product_id(
+        &self,
+        _product_id: &ProductId,
+    ) -> Result<Vec<Media>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn find_media_by_product_ids(
+        &self,
+        _product_ids: Vec<&ProductId>,
+    ) -> Result<Vec<Media>, DomainError> {
+        Ok(Vec::new())
+    }
+
+    async fn create_staged_upload(
+        &self,
+        _staged_upload: &StagedUpload,
+    ) -> Result<StagedUploadTarget, DomainError> {
+        Self::unsupported("create_staged_upload")
+    }
+
+    async fn create(&self, _product_id: &ProductId, _media: Media) -> Result<Media, DomainError> {
+        Self::unsupported("create")
+    }
+
+    async fn update(&self, _product_id: &ProductId, _media: Media) -> Result<Media, DomainError> {
+        Self::unsupported("update")
+    }
+
+    async fn reorder(
+        &self,
+        _product_id: &ProductId,
+        _media: Vec<&Media>,
+    ) -> Result<(), DomainError> {
+        Self::unsupported("reorder")
+    }
+
+    async fn delete(
+        &self,
+        _product_id: &ProductId,
+        _media: &Media,
+    ) -> Result<MediaId, DomainError> {
+        Self::unsupported("delete")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_find_media_by_product_id_returns_no_media() {
+        let repo = MediaRepositoryImpl::new();
+
+        let result = repo.find_media_by_product_id(&"1".to_string()).await;
+
+        assert!(result.expect("Failed to find media").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reorder_is_rejected_as_invalid_request() {
+        let repo = MediaRepositoryImpl::new();
+
+        let result = repo.reorder(&"1".to_string(), vec![]).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidRequest)));
+    }
+}
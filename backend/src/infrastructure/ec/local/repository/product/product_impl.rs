@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        product::product::{Id as ProductId, Product},
+    },
+    infrastructure::ec::local::store::LocalStore,
+    log_error,
+    usecase::repository::product_repository_interface::ProductRepository,
+};
+
+/// Repository for products for the local store.
+pub struct ProductRepositoryImpl {
+    store: LocalStore,
+}
+
+impl ProductRepositoryImpl {
+    pub fn new(store: LocalStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ProductRepository for ProductRepositoryImpl {
+    async fn find_product_by_id(&self, id: &ProductId) -> Result<Product, DomainError> {
+        self.store
+            .read(|data| {
+                let product = data
+                    .products
+                    .iter()
+                    .find(|product| &product.id == id)
+                    .ok_or_else(|| {
+                        log_error!("No product found for id.", "id" => id);
+                        DomainError::NotFound
+                    })?;
+
+                product.to_domain(data)
+            })
+            .await
+    }
+
+    async fn find_products(
+        &self,
+        limit: &Option<u32>,
+        offset: &Option<u32>,
+    ) -> Result<Vec<Product>, DomainError> {
+        let offset = offset.unwrap_or(0) as usize;
+        let limit = limit.map_or(usize::MAX, |limit| limit as usize);
+
+        self.store
+            .read(|data| {
+                data.products
+                    .iter()
+                    .skip(offset)
+                    .take(limit)
+                    .map(|product| product.to_domain(data))
+                    .collect()
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    async fn repository() -> ProductRepositoryImpl {
+        let path =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/local/store.json");
+        ProductRepositoryImpl::new(LocalStore::open(path).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_find_product_by_id_success() {
+        let repo = repository().await;
+
+        let product = repo.find_product_by_id(&"1".to_string()).await.unwrap();
+
+        assert_eq!(product.id(), "1");
+        assert_eq!(product.name(), "T-Shirt");
+        assert_eq!(product.category_id(), &Some("apparel".to_string()));
+        assert_eq!(product.variants().len(), 2);
+        assert_eq!(
+            product.variants()[0].sku().as_ref().unwrap().value(),
+            "TS-S"
+        );
+        assert_eq!(*product.variants()[0].list_order(), 1);
+        // Available quantities at all locations are added up.
+        assert_eq!(product.variants()[0].inventory_quantity(), &Some(8));
+        assert_eq!(product.variants()[1].inventory_quantity(), &Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_find_product_by_id_not_found() {
+        let repo = repository().await;
+
+        let result = repo.find_product_by_id(&"999".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_products_with_offset_and_limit() {
+        let repo = repository().await;
+
+        let products = repo.find_products(&Some(1), &Some(1)).await.unwrap();
+
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].id(), "2");
+    }
+}
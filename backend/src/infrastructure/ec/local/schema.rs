@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        inventory_item::inventory_item::InventoryItem,
+        inventory_level::{
+            inventory_level::InventoryLevel,
+            quantity::quantity::{InventoryType, Quantity},
+        },
+        money::amount::amount::Amount,
+        product::{
+            product::{Product, ProductStatus},
+            variant::{
+                barcode::barcode::Barcode,
+                sku::sku::Sku,
+                variant::{InventoryPolicy, Variant},
+            },
+        },
+    },
+    usecase::query_service::dto::product::ProductDTO,
+};
+
+/// Contents of the JSON file of the local store.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StoreData {
+    #[serde(default)]
+    pub products: Vec<ProductRecord>,
+    #[serde(default)]
+    pub inventory_levels: Vec<InventoryLevelRecord>,
+}
+
+impl StoreData {
+    /// Total available quantity of the inventory item across all locations.
+    pub fn available_quantity(&self, inventory_item_id: &str) -> i32 {
+        self.inventory_levels
+            .iter()
+            .filter(|level| level.inventory_item_id == inventory_item_id)
+            .flat_map(|level| level.quantities.iter())
+            .filter(|quantity| quantity.name == InventoryTypeRecord::Available)
+            .map(|quantity| quantity.quantity)
+            .sum()
+    }
+
+    /// Variant with the SKU. SKUs are assumed to be unique in the store.
+    pub fn find_variant_by_sku(&self, sku: &str) -> Option<&VariantRecord> {
+        self.products
+            .iter()
+            .flat_map(|product| product.variants.iter())
+            .find(|variant| variant.sku.as_deref() == Some(sku))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductRecord {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub handle: String,
+    #[serde(default)]
+    pub vendor: String,
+    #[serde(default)]
+    pub description: String,
+    pub status: ProductStatusRecord,
+    pub category_id: Option<String>,
+    #[serde(default)]
+    pub variants: Vec<VariantRecord>,
+}
+
+impl ProductRecord {
+    pub fn to_domain(&self, data: &StoreData) -> Result<Product, DomainError> {
+        let variants = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, variant)| variant.to_domain(i, data))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Product::new(
+            &self.id,
+            &self.name,
+            &self.description,
+            self.status.clone().into(),
+            variants,
+            self.category_id.clone(),
+        )
+    }
+
+    pub fn to_dto(&self) -> ProductDTO {
+        ProductDTO {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            handle: self.handle.clone(),
+            vendor: self.vendor.clone(),
+            price: self
+                .variants
+                .iter()
+                .map(|variant| variant.price)
+                .fold(0.0, f64::max),
+            featured_media_url: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductStatusRecord {
+    Active,
+    Inactive,
+    Draft,
+}
+
+impl From<ProductStatusRecord> for ProductStatus {
+    fn from(status: ProductStatusRecord) -> Self {
+        match status {
+            ProductStatusRecord::Active => ProductStatus::Active,
+            ProductStatusRecord::Inactive => ProductStatus::Inactive,
+            ProductStatusRecord::Draft => ProductStatus::Draft,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantRecord {
+    pub id: String,
+    pub name: Option<String>,
+    pub sku: Option<String>,
+    pub barcode: Option<String>,
+    #[serde(default = "default_true")]
+    pub available_for_sale: bool,
+    pub inventory_item_id: String,
+    pub inventory_policy: InventoryPolicyRecord,
+    pub price: f64,
+    #[serde(default = "default_true")]
+    pub taxable: bool,
+    pub tax_code: Option<String>,
+    #[serde(default = "default_true")]
+    pub requires_shipping: bool,
+    #[serde(default = "default_true")]
+    pub tracked: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl VariantRecord {
+    /// Convert into a variant, ordered by its index in the product and counting the stock in the store.
+    pub fn to_domain(&self, index: usize, data: &StoreData) -> Result<Variant, DomainError> {
+        let inventory_quantity = data.available_quantity(&self.inventory_item_id).max(0) as u32;
+
+        Variant::new(
+            &self.id,
+            self.name.clone(),
+            self.sku.as_ref().map(Sku::new).transpose()?,
+            self.barcode.as_ref().map(Barcode::new).transpose()?,
+            self.available_for_sale,
+            (index + 1).min(u8::MAX as usize) as u8,
+            &self.inventory_item_id,
+            self.inventory_policy.clone().into(),
+            Some(inventory_quantity),
+            Amount::new(self.price)?,
+            self.taxable,
+            self.tax_code.clone(),
+            self.created_at,
+            self.updated_at,
+        )
+    }
+
+    pub fn to_inventory_item_domain(&self) -> Result<InventoryItem, DomainError> {
+        InventoryItem::new(
+            &self.inventory_item_id,
+            &self.id,
+            self.requires_shipping,
+            self.tracked,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryPolicyRecord {
+    Deny,
+    Continue,
+}
+
+impl From<InventoryPolicyRecord> for InventoryPolicy {
+    fn from(policy: InventoryPolicyRecord) -> Self {
+        match policy {
+            InventoryPolicyRecord::Deny => InventoryPolicy::Deny,
+            InventoryPolicyRecord::Continue => InventoryPolicy::Continue,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryLevelRecord {
+    pub id: String,
+    pub inventory_item_id: String,
+    pub location_id: String,
+    #[serde(default)]
+    pub quantities: Vec<QuantityRecord>,
+}
+
+impl InventoryLevelRecord {
+    pub fn to_domain(&self) -> Result<InventoryLevel, DomainError> {
+        InventoryLevel::new(
+            &self.id,
+            &self.inventory_item_id,
+            &self.location_id,
+            self.quantities
+                .iter()
+                .map(|quantity| Quantity::new(quantity.quantity, quantity.name.clone().into()))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantityRecord {
+    pub name: InventoryTypeRecord,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryTypeRecord {
+    Available,
+    Committed,
+    Incoming,
+    Reserved,
+    SafetyStock,
+    Damaged,
+}
+
+impl From<InventoryTypeRecord> for InventoryType {
+    fn from(name: InventoryTypeRecord) -> Self {
+        match name {
+            InventoryTypeRecord::Available => InventoryType::Available,
+            InventoryTypeRecord::Committed => InventoryType::Committed,
+            InventoryTypeRecord::Incoming => InventoryType::Incoming,
+            InventoryTypeRecord::Reserved => InventoryType::Reserved,
+            InventoryTypeRecord::SafetyStock => InventoryType::SafetyStock,
+            InventoryTypeRecord::Damaged => InventoryType::Damaged,
+        }
+    }
+}
+
+impl From<&InventoryType> for InventoryTypeRecord {
+    fn from(name: &InventoryType) -> Self {
+        match name {
+            InventoryType::Available => InventoryTypeRecord::Available,
+            InventoryType::Committed => InventoryTypeRecord::Committed,
+            InventoryType::Incoming => InventoryTypeRecord::Incoming,
+            InventoryType::Reserved => InventoryTypeRecord::Reserved,
+            InventoryType::SafetyStock => InventoryTypeRecord::SafetyStock,
+            InventoryType::Damaged => InventoryTypeRecord::Damaged,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
@@ -0,0 +1,159 @@
+use std::{path::PathBuf, sync::Arc};
+
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::error::error::DomainError,
+    infrastructure::error::{InfrastructureError, InfrastructureErrorMapper},
+    log_error,
+};
+
+use super::schema::StoreData;
+
+/// Catalog and inventory kept in a local JSON file, serving as an EC platform without any external service.
+///
+/// The whole file is loaded when opened and rewritten on every change,
+/// so it suits development, demos and small catalogs rather than production traffic.
+/// Clone it for each repository so that all of them share the same data.
+#[derive(Clone)]
+pub struct LocalStore {
+    path: PathBuf,
+    data: Arc<RwLock<StoreData>>,
+}
+
+impl LocalStore {
+    /// Load the store from the JSON file at the path.
+    pub async fn open(path: impl Into<PathBuf>) -> Result<Self, DomainError> {
+        let path = path.into();
+
+        let contents = tokio::fs::read(&path).await.map_err(|e| {
+            log_error!("Failed to read the local store.", "path" => path, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::IoError(e))
+        })?;
+        let data: StoreData = serde_json::from_slice(&contents).map_err(|e| {
+            log_error!("Failed to parse the local store.", "path" => path, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        Ok(Self {
+            path,
+            data: Arc::new(RwLock::new(data)),
+        })
+    }
+
+    /// Read the data.
+    pub(super) async fn read<T>(&self, f: impl FnOnce(&StoreData) -> T) -> T {
+        f(&*self.data.read().await)
+    }
+
+    /// Change the data and write it to the file.
+    ///
+    /// The change is applied to a copy, so the data is left as it was if either the change or the write fails.
+    pub(super) async fn write<T>(
+        &self,
+        f: impl FnOnce(&mut StoreData) -> Result<T, DomainError>,
+    ) -> Result<T, DomainError> {
+        let mut data = self.data.write().await;
+
+        let mut changed = data.clone();
+        let result = f(&mut changed)?;
+        self.persist(&changed).await?;
+
+        *data = changed;
+        Ok(result)
+    }
+
+    async fn persist(&self, data: &StoreData) -> Result<(), DomainError> {
+        let contents = serde_json::to_vec_pretty(data).map_err(|e| {
+            log_error!("Failed to serialize the local store.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        // Replace the file only after it is fully written, so that a failure never leaves a truncated store.
+        let temp_path = self.path.with_extension("json.tmp");
+        tokio::fs::write(&temp_path, contents).await.map_err(|e| {
+            log_error!("Failed to write the local store.", "path" => temp_path, "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::IoError(e))
+        })?;
+        tokio::fs::rename(&temp_path, &self.path)
+            .await
+            .map_err(|e| {
+                log_error!("Failed to replace the local store.", "path" => self.path, "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::IoError(e))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_path(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/local")
+            .join(name)
+    }
+
+    fn temp_copy(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "local-store-{}-{}.json",
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        std::fs::copy(fixture_path(name), &path).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_open_success() {
+        let store = LocalStore::open(fixture_path("store.json")).await.unwrap();
+
+        let product_count = store.read(|data| data.products.len()).await;
+
+        assert_eq!(product_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_open_missing_file() {
+        let result = LocalStore::open(fixture_path("missing.json")).await;
+
+        assert!(matches!(result, Err(DomainError::SystemError)));
+    }
+
+    #[tokio::test]
+    async fn test_write_persists_change() {
+        let path = temp_copy("store.json");
+        let store = LocalStore::open(&path).await.unwrap();
+
+        store
+            .write(|data| {
+                data.products.truncate(1);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let reopened = LocalStore::open(&path).await.unwrap();
+        assert_eq!(reopened.read(|data| data.products.len()).await, 1);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_write_keeps_data_on_error() {
+        let path = temp_copy("store.json");
+        let store = LocalStore::open(&path).await.unwrap();
+
+        let result: Result<(), DomainError> = store
+            .write(|data| {
+                data.products.clear();
+                Err(DomainError::NotFound)
+            })
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+        assert_eq!(store.read(|data| data.products.len()).await, 3);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,5 +1,9 @@
 pub mod client_impl;
+mod cost_throttle;
+mod error;
 mod gql_helper;
+mod query_builder;
 pub mod query_service;
 pub mod repository;
+mod retry_policy;
 mod schema;
@@ -1,12 +1,17 @@
 use async_trait::async_trait;
+use opentelemetry::{global, metrics::Counter, KeyValue};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
     Client,
 };
 use serde::Serialize;
-use serde_json::json;
-use std::{fmt, sync::Arc};
-use tokio::sync::Mutex;
+use serde_json::{json, Value};
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{Arc, LazyLock, Mutex},
+    time::Duration,
+};
 
 use crate::{
     domain::error::error::DomainError,
@@ -15,23 +20,63 @@ use crate::{
         ec::ec_client_interface::{ECClient, ECClientResponse},
         error::{InfrastructureError, InfrastructureErrorMapper},
     },
-    log_debug, log_error,
+    log_debug, log_error, log_warn,
+};
+
+use super::{
+    cost_throttle::CostThrottle, retry_policy::RetryPolicy, schema::GraphQLResponseExtensions,
 };
 
+/// Number of requests for which Shopify reported the use of deprecated fields.
+static DEPRECATED_REQUESTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter("shopify")
+        .u64_counter("shopify.api.deprecated_requests")
+        .with_description("Requests to the Shopify Admin API that used deprecated fields")
+        .build()
+});
+
 /// A client that interacts with GraphQL for Shopify.
+///
+/// Create it once per process and clone it for each repository,
+/// so that connections are pooled and the cost limit is tracked across all requests.
+#[derive(Clone)]
 pub struct ShopifyGQLClient {
-    client: Arc<Mutex<Client>>,
+    client: Client,
     config: ShopifyConfig,
+    retry_policy: RetryPolicy,
+    cost_throttle: Arc<CostThrottle>,
+    reported_deprecations: Arc<Mutex<HashSet<String>>>,
 }
 
 impl ShopifyGQLClient {
     const SHOPIFY_ACCESS_TOKEN_HEADER: &'static str = "X-Shopify-Access-Token";
+    const SHOPIFY_THROTTLED_ERROR_CODE: &'static str = "THROTTLED";
+    const SHOPIFY_DEPRECATED_REASON_HEADER: &'static str = "X-Shopify-API-Deprecated-Reason";
+
+    pub fn new(config: ShopifyConfig) -> Result<Self, DomainError> {
+        let http_client_config = config.http_client_config();
 
-    pub fn new(config: ShopifyConfig) -> Self {
-        Self {
-            client: Arc::new(Mutex::new(Client::new())),
-            config: config,
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(*http_client_config.timeout()))
+            .connect_timeout(Duration::from_secs(*http_client_config.connect_timeout()))
+            .pool_max_idle_per_host(*http_client_config.pool_max_idle_per_host())
+            .pool_idle_timeout(Duration::from_secs(*http_client_config.pool_idle_timeout()));
+        if !http_client_config.http2() {
+            builder = builder.http1_only();
         }
+
+        let client = builder.build().map_err(|e| {
+            log_error!("Failed to build the HTTP client for Shopify.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::NetworkError(e))
+        })?;
+
+        Ok(Self {
+            client,
+            retry_policy: RetryPolicy::from(http_client_config),
+            cost_throttle: Arc::new(CostThrottle::default()),
+            reported_deprecations: Arc::new(Mutex::new(HashSet::new())),
+            config,
+        })
     }
 
     /// Generate headers to be used in GraphQL requests for Shopify.
@@ -43,38 +88,167 @@ impl ShopifyGQLClient {
         );
         headers
     }
-}
 
-#[async_trait]
-impl ECClient for ShopifyGQLClient {
-    async fn query<T>(&self, query: &str) -> Result<T, DomainError>
+    /// Send the request body, retrying according to the retry policy.
+    ///
+    /// Only idempotent requests are retried once Shopify may have received them.
+    async fn execute<T>(&self, body: &Value, idempotent: bool) -> Result<T, DomainError>
     where
-        T: ECClientResponse + for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+        T: for<'de> serde::Deserialize<'de>,
     {
-        log_debug!("Query", "query" => query);
+        let mut retries = 0;
 
-        // Lock the mutex to get the client
-        let client = self.client.lock().await;
+        loop {
+            self.cost_throttle.acquire().await;
 
-        let response = client
-            .post(self.config.store_url())
-            .headers(self.build_headers())
-            .json(&json!({
-                "query": query,
-            }))
-            .send()
-            .await
-            .map_err(|e| {
-                log_error!("Error returned by GraphQL run.", "error" => e);
+            let result = self
+                .client
+                .post(self.config.store_url())
+                .headers(self.build_headers())
+                .json(body)
+                .send()
+                .await;
+
+            let response = match result {
+                Ok(response) => response,
+                Err(e)
+                    if self.retry_policy.is_retryable_error(&e, idempotent)
+                        && self.retry_policy.can_retry(retries) =>
+                {
+                    log_warn!("Retrying GraphQL request after a network error.", "error" => e, "retries" => retries);
+                    tokio::time::sleep(self.retry_policy.backoff(retries)).await;
+                    retries += 1;
+                    continue;
+                }
+                Err(e) => {
+                    log_error!("Error returned by GraphQL run.", "error" => e);
+                    return Err(InfrastructureErrorMapper::to_domain(
+                        InfrastructureError::NetworkError(e),
+                    ));
+                }
+            };
+
+            let status = response.status();
+            if self.retry_policy.is_retryable_status(status, idempotent) {
+                if self.retry_policy.can_retry(retries) {
+                    log_warn!("Retrying GraphQL request after an error status.", "status" => status, "retries" => retries);
+                    tokio::time::sleep(self.retry_policy.backoff(retries)).await;
+                    retries += 1;
+                    continue;
+                }
+
+                log_error!("GraphQL request failed after retries.", "status" => status);
+                return Err(DomainError::SystemError);
+            }
+
+            if let Some(reason) = Self::deprecation_reason(response.headers()) {
+                self.report_deprecation(body["query"].as_str().unwrap_or_default(), &reason);
+            }
+
+            let graphql_response = response.json::<Value>().await.map_err(|e| {
+                log_error!("Failed to parse GraphQL query response.", "error" => e);
                 InfrastructureErrorMapper::to_domain(InfrastructureError::NetworkError(e))
             })?;
 
-        let graphql_response = response.json::<T>().await.map_err(|e| {
-            log_error!("Failed to parse GraphQL query response.", "error" => e);
-            InfrastructureErrorMapper::to_domain(InfrastructureError::NetworkError(e))
-        })?;
+            self.update_cost_throttle(&graphql_response);
+
+            if idempotent
+                && Self::is_throttled(&graphql_response)
+                && self.retry_policy.can_retry(retries)
+            {
+                log_warn!("Retrying throttled GraphQL request.", "retries" => retries);
+                tokio::time::sleep(self.retry_policy.backoff(retries)).await;
+                retries += 1;
+                continue;
+            }
+
+            return serde_json::from_value::<T>(graphql_response).map_err(|e| {
+                log_error!("Failed to parse GraphQL query response.", "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            });
+        }
+    }
+
+    /// Pace subsequent requests with the cost reported in the response extensions.
+    fn update_cost_throttle(&self, response: &Value) {
+        let extensions = match response.get("extensions") {
+            Some(extensions) => extensions,
+            None => return,
+        };
+
+        match serde_json::from_value::<GraphQLResponseExtensions>(extensions.clone()) {
+            Ok(GraphQLResponseExtensions { cost: Some(cost) }) => self.cost_throttle.update(&cost),
+            Ok(_) => {}
+            Err(e) => {
+                log_warn!("Failed to parse GraphQL response extensions.", "error" => e);
+            }
+        }
+    }
 
-        Ok(graphql_response)
+    /// Get the reason Shopify gives when the request used fields deprecated in the pinned API version.
+    fn deprecation_reason(headers: &HeaderMap) -> Option<String> {
+        headers
+            .get(Self::SHOPIFY_DEPRECATED_REASON_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+
+    /// Count the use of deprecated fields, and log it the first time for each query.
+    /// Returns whether it was logged.
+    fn report_deprecation(&self, query: &str, reason: &str) -> bool {
+        DEPRECATED_REQUESTS.add(
+            1,
+            &[KeyValue::new(
+                "api_version",
+                self.config.api_version().to_string(),
+            )],
+        );
+
+        let mut reported = self
+            .reported_deprecations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if !reported.insert(query.to_string()) {
+            return false;
+        }
+
+        log_warn!(
+            "Deprecated fields of the Shopify Admin API were used.",
+            "api_version" => self.config.api_version(),
+            "reason" => reason,
+            "query" => query
+        );
+        true
+    }
+
+    /// Whether Shopify rejected the request because the cost limit was exceeded.
+    fn is_throttled(response: &Value) -> bool {
+        response["errors"].as_array().is_some_and(|errors| {
+            errors
+                .iter()
+                .any(|error| error["extensions"]["code"] == Self::SHOPIFY_THROTTLED_ERROR_CODE)
+        })
+    }
+}
+
+#[async_trait]
+impl ECClient for ShopifyGQLClient {
+    async fn query_with_variables<T, U>(&self, query: &str, variables: &T) -> Result<U, DomainError>
+    where
+        T: Serialize + ?Sized + Send + Sync + fmt::Display + 'static,
+        U: ECClientResponse + for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+    {
+        log_debug!("Query", "query" => query);
+        log_debug!("Variables", "variables" => variables.to_string());
+
+        self.execute(
+            &json!({
+                "query": query,
+                "variables": variables,
+            }),
+            true,
+        )
+        .await
     }
 
     async fn mutation<T, U>(&self, query: &str, input: &T) -> Result<U, DomainError>
@@ -85,30 +259,380 @@ impl ECClient for ShopifyGQLClient {
         log_debug!("Query", "query" => query);
         log_debug!("Input", "input" => input.to_string());
 
-        // Lock the mutex to get the client
-        let client = self.client.lock().await;
-
-        let response = client
-            .post(self.config.store_url())
-            .headers(self.build_headers())
-            .json(&json!({
+        // Mutations are not idempotent, so they are retried only when the request was never sent.
+        self.execute(
+            &json!({
                 "query": query,
                 "variables": {
                     "input": input
                 },
-            }))
-            .send()
-            .await
-            .map_err(|e| {
-                log_error!("Error returned by GraphQL run.", "error" => e);
-                InfrastructureErrorMapper::to_domain(InfrastructureError::NetworkError(e))
-            })?;
+            }),
+            false,
+        )
+        .await
+    }
 
-        let graphql_response = response.json::<U>().await.map_err(|e| {
-            log_error!("Failed to parse GraphQL query response.", "error" => e);
-            InfrastructureErrorMapper::to_domain(InfrastructureError::NetworkError(e))
-        })?;
+    async fn mutation_with_variables<T, U>(
+        &self,
+        query: &str,
+        variables: &T,
+    ) -> Result<U, DomainError>
+    where
+        T: Serialize + ?Sized + Send + Sync + fmt::Display + 'static,
+        U: ECClientResponse + for<'de> serde::Deserialize<'de> + Send + Sync + 'static,
+    {
+        log_debug!("Query", "query" => query);
+        log_debug!("Variables", "variables" => variables.to_string());
+
+        // Mutations are not idempotent, so they are retried only when the request was never sent.
+        self.execute(
+            &json!({
+                "query": query,
+                "variables": variables,
+            }),
+            false,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Instant,
+    };
+
+    use serde_json::Value;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::infrastructure::{
+        config::config::ShopifyHttpClientConfig, ec::shopify::schema::GraphQLResponse,
+    };
+
+    use super::*;
+
+    const OK_BODY: &str = r#"{"data":{"shop":{"name":"stub"}}}"#;
+    const THROTTLED_BODY: &str = r#"{
+        "errors": [{"message": "Throttled", "extensions": {"code": "THROTTLED"}}],
+        "extensions": {
+            "cost": {
+                "requestedQueryCost": 10,
+                "throttleStatus": {"maximumAvailable": 1000, "currentlyAvailable": 990, "restoreRate": 1000}
+            }
+        }
+    }"#;
+
+    /// Start a stub HTTP server that answers each connection with the next canned response.
+    async fn spawn_stub_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql.json", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&hits);
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                read_request(&mut socket).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (url, hits)
+    }
+
+    /// Read one HTTP request from the socket, returning false once the peer has closed it.
+    async fn read_request(socket: &mut TcpStream) -> bool {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 1024];
+
+        loop {
+            let n = match socket.read(&mut chunk).await {
+                Ok(0) | Err(_) => return false,
+                Ok(n) => n,
+            };
+            buf.extend_from_slice(&chunk[..n]);
+
+            let text = String::from_utf8_lossy(&buf);
+            if let Some(header_end) = text.find("\r\n\r\n") {
+                let content_length = text[..header_end]
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        if name.eq_ignore_ascii_case("content-length") {
+                            value.trim().parse::<usize>().ok()
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(0);
+                if buf.len() >= header_end + 4 + content_length {
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Start a stub HTTP server that keeps connections alive and answers every request after a delay.
+    async fn spawn_delayed_stub_server(delay: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql.json", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    while read_request(&mut socket).await {
+                        tokio::time::sleep(delay).await;
+
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{OK_BODY}",
+                            OK_BODY.len()
+                        );
+                        if socket.write_all(response.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        url
+    }
+
+    fn client(url: String) -> ShopifyGQLClient {
+        let config = ShopifyConfig::from_values(
+            url,
+            ShopifyConfig::DEFAULT_API_VERSION.to_string(),
+            "token".to_string(),
+            ShopifyHttpClientConfig::default(),
+        );
 
-        Ok(graphql_response)
+        let mut client = ShopifyGQLClient::new(config).unwrap();
+        client.retry_policy =
+            RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(10));
+        client
+    }
+
+    async fn run_concurrent_queries(client: &ShopifyGQLClient, count: usize) -> Duration {
+        let started = Instant::now();
+
+        let handles: Vec<_> = (0..count)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    client
+                        .query_with_variables::<Value, GraphQLResponse<Value>>(
+                            "query { shop { name } }",
+                            &json!({}),
+                        )
+                        .await
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
+        }
+
+        started.elapsed()
+    }
+
+    #[tokio::test]
+    async fn test_query_success() {
+        let (url, hits) = spawn_stub_server(vec![(200, OK_BODY)]).await;
+
+        let result = client(url)
+            .query_with_variables::<Value, GraphQLResponse<Value>>(
+                "query { shop { name } }",
+                &json!({}),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().data.unwrap()["shop"]["name"], "stub");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_query_retries_server_error() {
+        let (url, hits) = spawn_stub_server(vec![(503, "{}"), (200, OK_BODY)]).await;
+
+        let result = client(url)
+            .query_with_variables::<Value, GraphQLResponse<Value>>(
+                "query { shop { name } }",
+                &json!({}),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().data.is_some());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_query_fails_after_max_retries() {
+        let (url, hits) = spawn_stub_server(vec![(503, "{}"), (502, "{}"), (429, "{}")]).await;
+
+        let result = client(url)
+            .query_with_variables::<Value, GraphQLResponse<Value>>(
+                "query { shop { name } }",
+                &json!({}),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::SystemError)));
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_query_retries_throttled() {
+        let (url, hits) = spawn_stub_server(vec![(200, THROTTLED_BODY), (200, OK_BODY)]).await;
+        let client = client(url);
+
+        let result = client
+            .query_with_variables::<Value, GraphQLResponse<Value>>(
+                "query { shop { name } }",
+                &json!({}),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().errors.is_none());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert_eq!(client.cost_throttle.reserve(), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_mutation_is_not_retried_on_server_error() {
+        let (url, hits) = spawn_stub_server(vec![
+            (503, r#"{"errors":[{"message":"Service unavailable"}]}"#),
+            (200, OK_BODY),
+        ])
+        .await;
+
+        let result = client(url)
+            .mutation::<Value, GraphQLResponse<Value>>(
+                "mutation { shopUpdate { id } }",
+                &serde_json::to_value("").unwrap(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().errors.is_some());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mutation_is_not_retried_when_throttled() {
+        let (url, hits) = spawn_stub_server(vec![(200, THROTTLED_BODY), (200, OK_BODY)]).await;
+
+        let result = client(url)
+            .mutation::<Value, GraphQLResponse<Value>>(
+                "mutation { shopUpdate { id } }",
+                &serde_json::to_value("").unwrap(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().errors.is_some());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mutation_fails_on_connection_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql.json", listener.local_addr().unwrap());
+        drop(listener);
+
+        let result = client(url)
+            .mutation::<Value, GraphQLResponse<Value>>(
+                "mutation { shopUpdate { id } }",
+                &serde_json::to_value("").unwrap(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::SystemError)));
+    }
+
+    #[tokio::test]
+    async fn test_queries_run_concurrently() {
+        let url = spawn_delayed_stub_server(Duration::from_millis(200)).await;
+        let client = client(url);
+
+        let elapsed = run_concurrent_queries(&client, 10).await;
+
+        // Serialised requests would take at least 10 * 200ms.
+        assert!(elapsed < Duration::from_millis(1000), "took {elapsed:?}");
+    }
+
+    #[test]
+    fn test_deprecation_reason() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ShopifyGQLClient::deprecation_reason(&headers), None);
+
+        headers.insert(
+            "x-shopify-api-deprecated-reason",
+            HeaderValue::from_static(
+                "https://shopify.dev/api/usage/versioning#deprecation-practices",
+            ),
+        );
+        assert_eq!(
+            ShopifyGQLClient::deprecation_reason(&headers),
+            Some("https://shopify.dev/api/usage/versioning#deprecation-practices".to_string())
+        );
+    }
+
+    #[test]
+    fn test_report_deprecation_logs_once_per_query() {
+        let client = client("http://127.0.0.1:0/graphql.json".to_string());
+
+        assert!(client.report_deprecation("query { shop { name } }", "reason"));
+        assert!(!client.report_deprecation("query { shop { name } }", "reason"));
+        assert!(client.report_deprecation("query { products { id } }", "reason"));
+
+        // Clones share what was already reported.
+        assert!(!client
+            .clone()
+            .report_deprecation("query { shop { name } }", "reason"));
+    }
+
+    /// Throughput benchmark of concurrent queries through one shared client.
+    ///
+    /// Each stub response takes 50ms, so a client that keeps every request in flight
+    /// reaches 20 requests per second per concurrent caller. At least a quarter of that is required,
+    /// leaving room for new connections beyond the idle pool, while serialised requests fall far short.
+    /// Run with `cargo test bench_concurrent_query_throughput -- --ignored --nocapture`.
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore]
+    async fn bench_concurrent_query_throughput() {
+        let delay = Duration::from_millis(50);
+        let url = spawn_delayed_stub_server(delay).await;
+        let client = client(url);
+
+        for concurrency in [1, 10, 50, 100] {
+            let requests = concurrency * 10;
+            let started = Instant::now();
+            for _ in 0..10 {
+                run_concurrent_queries(&client, concurrency).await;
+            }
+            let throughput = requests as f64 / started.elapsed().as_secs_f64();
+            let expected = concurrency as f64 / delay.as_secs_f64() / 4.0;
+
+            println!("concurrency={concurrency} throughput={throughput:.1} req/s");
+            assert!(
+                throughput >= expected,
+                "concurrency={concurrency} throughput={throughput:.1} req/s, expected at least {expected:.1} req/s"
+            );
+        }
     }
 }
@@ -0,0 +1,140 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use super::schema::QueryCost;
+
+/// Client-side leaky bucket that mirrors Shopify's calculated query cost limit.
+///
+/// The bucket is refreshed from `extensions.cost.throttleStatus` of each response,
+/// and requests wait until the bucket has restored enough points for the last requested cost.
+#[derive(Default)]
+pub struct CostThrottle {
+    bucket: Mutex<Option<Bucket>>,
+}
+
+struct Bucket {
+    maximum_available: f64,
+    currently_available: f64,
+    restore_rate: f64,
+    requested_cost: f64,
+    observed_at: Instant,
+}
+
+impl Bucket {
+    fn available_at(&self, now: Instant) -> f64 {
+        let elapsed = now
+            .saturating_duration_since(self.observed_at)
+            .as_secs_f64();
+        (self.currently_available + self.restore_rate * elapsed).min(self.maximum_available)
+    }
+}
+
+impl CostThrottle {
+    /// Reserve capacity for the next request and return how long to wait before sending it.
+    pub fn reserve(&self) -> Duration {
+        self.reserve_at(Instant::now())
+    }
+
+    /// Wait until the bucket is expected to afford the next request.
+    pub async fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Synchronise the bucket with the cost reported by Shopify.
+    pub fn update(&self, cost: &QueryCost) {
+        let mut bucket = self.bucket.lock().unwrap();
+        *bucket = Some(Bucket {
+            maximum_available: cost.throttle_status.maximum_available,
+            currently_available: cost.throttle_status.currently_available,
+            restore_rate: cost.throttle_status.restore_rate,
+            requested_cost: cost.requested_query_cost,
+            observed_at: Instant::now(),
+        });
+    }
+
+    fn reserve_at(&self, now: Instant) -> Duration {
+        let mut guard = self.bucket.lock().unwrap();
+        let bucket = match guard.as_mut() {
+            Some(bucket) => bucket,
+            None => return Duration::ZERO,
+        };
+
+        let available = bucket.available_at(now);
+        let cost = bucket.requested_cost.min(bucket.maximum_available);
+
+        let wait = if available >= cost || bucket.restore_rate <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((cost - available) / bucket.restore_rate)
+        };
+
+        // Deduct the reservation so that concurrent callers queue up behind each other.
+        bucket.currently_available = bucket.available_at(now + wait) - cost;
+        bucket.observed_at = now + wait;
+
+        wait
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::ec::shopify::schema::ThrottleStatus;
+
+    use super::*;
+
+    fn cost(requested: f64, currently_available: f64) -> QueryCost {
+        QueryCost {
+            requested_query_cost: requested,
+            throttle_status: ThrottleStatus {
+                maximum_available: 1000.0,
+                currently_available,
+                restore_rate: 50.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_reserve_without_status() {
+        let throttle = CostThrottle::default();
+
+        assert_eq!(throttle.reserve(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_reserve_with_enough_capacity() {
+        let throttle = CostThrottle::default();
+        throttle.update(&cost(100.0, 500.0));
+
+        assert_eq!(throttle.reserve(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_reserve_waits_for_restore() {
+        let throttle = CostThrottle::default();
+        throttle.update(&cost(100.0, 50.0));
+
+        let wait = throttle.reserve_at(Instant::now());
+
+        assert!(wait > Duration::from_millis(900));
+        assert!(wait <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_reserve_queues_concurrent_requests() {
+        let throttle = CostThrottle::default();
+        throttle.update(&cost(100.0, 100.0));
+
+        let now = Instant::now();
+        let first = throttle.reserve_at(now);
+        let second = throttle.reserve_at(now);
+
+        assert_eq!(first, Duration::ZERO);
+        assert!(second > Duration::from_millis(1900));
+        assert!(second <= Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    domain::error::error::{DomainError, FieldError},
+    infrastructure::ec::shopify::schema::{GraphQLError, UserError},
+};
+
+/// Error reported by Shopify in the `errors` or `userErrors` of a GraphQL response.
+#[derive(Debug, PartialEq)]
+pub enum ShopifyError {
+    /// The calculated query cost exceeded the available capacity.
+    Throttled,
+    /// The access token is not permitted to access the resource.
+    AccessDenied,
+    /// The requested resource does not exist.
+    NotFound,
+    /// The input was rejected by a mutation.
+    UserErrors(Vec<FieldError>),
+    /// Any other error, whose meaning depends on the operation.
+    Unknown,
+}
+
+impl ShopifyError {
+    const THROTTLED: &'static str = "THROTTLED";
+    const ACCESS_DENIED: &'static str = "ACCESS_DENIED";
+    const NOT_FOUND: &'static str = "NOT_FOUND";
+
+    /// Classify the top-level `errors` by the first error code that is known.
+    pub fn from_graphql_errors(errors: &[GraphQLError]) -> Self {
+        errors
+            .iter()
+            .filter_map(|error| error.extensions.as_ref()?.code.as_deref())
+            .find_map(|code| match code {
+                Self::THROTTLED => Some(Self::Throttled),
+                Self::ACCESS_DENIED => Some(Self::AccessDenied),
+                Self::NOT_FOUND => Some(Self::NotFound),
+                _ => None,
+            })
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// Collect `userErrors` of a mutation as field-level errors, with fields named as in the request.
+    pub fn from_user_errors<E: ShopifyUserError>(errors: &[E]) -> Self {
+        Self::UserErrors(
+            errors
+                .iter()
+                .map(|error| {
+                    FieldError::new(
+                        error.field().and_then(Self::to_request_field),
+                        error.code().map(str::to_string),
+                        error.message(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Convert the input path of a user error, such as `input.lineItems.0.quantity`, into the request field,
+    /// such as `line_items.0.quantity`.
+    ///
+    /// The first segment names the mutation argument, which the request does not have, unless it is the only one.
+    fn to_request_field(path: &[String]) -> Option<String> {
+        let segments = match path {
+            [] => return None,
+            [argument] => std::slice::from_ref(argument),
+            [_, rest @ ..] => rest,
+        };
+
+        Some(
+            segments
+                .iter()
+                .map(|segment| Self::to_snake_case(segment))
+                .collect::<Vec<_>>()
+                .join("."),
+        )
+    }
+
+    fn to_snake_case(segment: &str) -> String {
+        let mut snake_case = String::with_capacity(segment.len());
+        for c in segment.chars() {
+            if c.is_ascii_uppercase() {
+                snake_case.push('_');
+                snake_case.push(c.to_ascii_lowercase());
+            } else {
+                snake_case.push(c);
+            }
+        }
+        snake_case
+    }
+
+    /// Convert into a domain error, using `fallback` for errors that have no specific meaning.
+    pub fn into_domain_error(self, fallback: DomainError) -> DomainError {
+        match self {
+            Self::Throttled => DomainError::Throttled,
+            Self::AccessDenied => DomainError::SystemError,
+            Self::NotFound => DomainError::NotFound,
+            Self::UserErrors(errors) => DomainError::InvalidInput { errors },
+            Self::Unknown => fallback,
+        }
+    }
+}
+
+/// Common accessors of the `userErrors` types returned by Shopify mutations.
+pub trait ShopifyUserError {
+    fn field(&self) -> Option<&[String]>;
+    fn code(&self) -> Option<&str>;
+    fn message(&self) -> &str;
+}
+
+impl ShopifyUserError for UserError {
+    fn field(&self) -> Option<&[String]> {
+        Some(&self.field)
+    }
+
+    fn code(&self) -> Option<&str> {
+        None
+    }
+
+    fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::ec::shopify::schema::GraphQLErrorExtensions;
+
+    use super::*;
+
+    fn graphql_error(code: Option<&str>) -> GraphQLError {
+        GraphQLError {
+            message: "Some GraphQL error".to_string(),
+            extensions: Some(GraphQLErrorExtensions {
+                code: code.map(str::to_string),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_from_graphql_errors_with_known_codes() {
+        assert_eq!(
+            ShopifyError::from_graphql_errors(&[graphql_error(Some("THROTTLED"))]),
+            ShopifyError::Throttled
+        );
+        assert_eq!(
+            ShopifyError::from_graphql_errors(&[graphql_error(Some("ACCESS_DENIED"))]),
+            ShopifyError::AccessDenied
+        );
+        assert_eq!(
+            ShopifyError::from_graphql_errors(&[
+                graphql_error(None),
+                graphql_error(Some("NOT_FOUND"))
+            ]),
+            ShopifyError::NotFound
+        );
+    }
+
+    #[test]
+    fn test_from_graphql_errors_with_unknown_code() {
+        assert_eq!(
+            ShopifyError::from_graphql_errors(&[graphql_error(Some("INTERNAL_SERVER_ERROR"))]),
+            ShopifyError::Unknown
+        );
+        assert_eq!(
+            ShopifyError::from_graphql_errors(&[GraphQLError {
+                message: "Some GraphQL error".to_string(),
+                extensions: None,
+            }]),
+            ShopifyError::Unknown
+        );
+    }
+
+    #[test]
+    fn test_from_user_errors() {
+        let errors = vec![
+            UserError {
+                field: vec!["input".to_string(), "email".to_string()],
+                message: "Email has already been taken".to_string(),
+            },
+            UserError {
+                field: vec![],
+                message: "Something went wrong".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            ShopifyError::from_user_errors(&errors),
+            ShopifyError::UserErrors(vec![
+                FieldError::new(
+                    Some("email".to_string()),
+                    None,
+                    "Email has already been taken"
+                ),
+                FieldError::new(None, None, "Something went wrong"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_user_errors_with_nested_field() {
+        let errors = vec![
+            UserError {
+                field: vec![
+                    "input".to_string(),
+                    "lineItems".to_string(),
+                    "0".to_string(),
+                    "quantity".to_string(),
+                ],
+                message: "Quantity must be greater than 0".to_string(),
+            },
+            UserError {
+                field: vec!["locationId".to_string()],
+                message: "Location does not exist".to_string(),
+            },
+        ];
+
+        assert_eq!(
+            ShopifyError::from_user_errors(&errors),
+            ShopifyError::UserErrors(vec![
+                FieldError::new(
+                    Some("line_items.0.quantity".to_string()),
+                    None,
+                    "Quantity must be greater than 0"
+                ),
+                FieldError::new(
+                    Some("location_id".to_string()),
+                    None,
+                    "Location does not exist"
+                ),
+            ])
+        );
+    }
+
+    struct CodedUserError;
+
+    impl ShopifyUserError for CodedUserError {
+        fn field(&self) -> Option<&[String]> {
+            None
+        }
+
+        fn code(&self) -> Option<&str> {
+            Some("TAKEN")
+        }
+
+        fn message(&self) -> &str {
+            "Name has already been taken"
+        }
+    }
+
+    #[test]
+    fn test_from_user_errors_with_code() {
+        let errors = vec![CodedUserError];
+
+        assert_eq!(
+            ShopifyError::from_user_errors(&errors),
+            ShopifyError::UserErrors(vec![FieldError::new(
+                None,
+                Some("TAKEN".to_string()),
+                "Name has already been taken"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_into_domain_error() {
+        assert_eq!(
+            ShopifyError::Throttled.into_domain_error(DomainError::QueryError),
+            DomainError::Throttled
+        );
+        assert_eq!(
+            ShopifyError::AccessDenied.into_domain_error(DomainError::QueryError),
+            DomainError::SystemError
+        );
+        assert_eq!(
+            ShopifyError::NotFound.into_domain_error(DomainError::SaveError),
+            DomainError::NotFound
+        );
+        assert_eq!(
+            ShopifyError::Unknown.into_domain_error(DomainError::SaveError),
+            DomainError::SaveError
+        );
+        assert_eq!(
+            ShopifyError::UserErrors(vec![]).into_domain_error(DomainError::SaveError),
+            DomainError::InvalidInput { errors: vec![] }
+        );
+    }
+}
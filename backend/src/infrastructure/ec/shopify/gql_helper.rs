@@ -3,70 +3,45 @@ pub struct ShopifyGQLHelper {}
 impl ShopifyGQLHelper {
     pub const SHOPIFY_QUERY_LIMIT: usize = 250;
 
+    pub const SHOPIFY_PRODUCT_GID_PREFIX: &'static str = "gid://shopify/Product/";
+    pub const SHOPIFY_MEDIA_IMAGE_GID_PREFIX: &'static str = "gid://shopify/MediaImage/";
+    pub const SHOPIFY_VIDEO_GID_PREFIX: &'static str = "gid://shopify/Video/";
+    pub const SHOPIFY_MODEL3D_GID_PREFIX: &'static str = "gid://shopify/Model3d/";
     pub const SHOPIFY_PRODUCT_VARIANT_GID_PREFIX: &'static str = "gid://shopify/ProductVariant/";
     pub const SHOPIFY_INVENTORY_ITEM_GID_PREFIX: &'static str = "gid://shopify/InventoryItem/";
     pub const SHOPIFY_LOCATION_GID_PREFIX: &'static str = "gid://shopify/Location/";
     pub const SHOPIFY_DRAFT_ORDER_GID_PREFIX: &'static str = "gid://shopify/DraftOrder/";
     pub const SHOPIFY_CUSTOMER_GID_PREFIX: &'static str = "gid://shopify/Customer/";
+    pub const SHOPIFY_ORDER_GID_PREFIX: &'static str = "gid://shopify/Order/";
+    pub const SHOPIFY_FULFILLMENT_GID_PREFIX: &'static str = "gid://shopify/Fulfillment/";
+    pub const SHOPIFY_FULFILLMENT_ORDER_GID_PREFIX: &'static str =
+        "gid://shopify/FulfillmentOrder/";
+    pub const SHOPIFY_FULFILLMENT_ORDER_LINE_ITEM_GID_PREFIX: &'static str =
+        "gid://shopify/FulfillmentOrderLineItem/";
+    pub const SHOPIFY_FULFILLMENT_LINE_ITEM_GID_PREFIX: &'static str =
+        "gid://shopify/FulfillmentLineItem/";
+    pub const SHOPIFY_LINE_ITEM_GID_PREFIX: &'static str = "gid://shopify/LineItem/";
+    pub const SHOPIFY_RETURN_GID_PREFIX: &'static str = "gid://shopify/Return/";
+    pub const SHOPIFY_MAILING_ADDRESS_GID_PREFIX: &'static str = "gid://shopify/MailingAddress/";
+    pub const SHOPIFY_CUSTOMER_ADDRESS_GID_SUFFIX: &'static str = "?model_name=CustomerAddress";
 
-    /// Return first query with max limit.
-    pub fn first_query() -> String {
-        format!("first: {}", Self::SHOPIFY_QUERY_LIMIT)
-    }
-
-    /// Return pageInfo query.
-    pub fn page_info() -> String {
-        "pageInfo {
-            hasPreviousPage
-            hasNextPage
-            startCursor
-            endCursor
-        }"
-        .to_string()
-    }
-
-    /// Return userErrors query.
-    pub fn user_errors() -> String {
-        "userErrors {
-            field
-            message
-        }"
-        .to_string()
-    }
-
-    /// Return address fields.
-    pub fn address_fields() -> String {
-        "address1
-        address2
-        city
-        coordinatesValidated
-        country
-        firstName
-        id
-        lastName
-        name
-        phone
-        province
-        zip"
-        .to_string()
-    }
-
-    /// Return money bag fields.
-    pub fn money_bag_fields() -> String {
-        "shopMoney {
-            amount
-            currencyCode
-        }"
-        .to_string()
-    }
-
-    pub fn metafield_query(key: &str, namespace: &str) -> String {
-        format!(
-            "metafield(namespace: \"{}\", key: \"{}\") {{
-            value
-        }}",
-            namespace, key
-        )
+    /// Escape a value for Shopify's search syntax.
+    ///
+    /// The search string is expected to be passed as a GraphQL variable, so no further escaping is needed.
+    pub fn escape_search_value(value: &str) -> String {
+        let mut escaped = String::new();
+        for c in value.chars() {
+            if matches!(c, '\\' | '\'' | '"' | ':' | '(' | ')' | '*') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// Return the search string that matches the given SKU exactly.
+    pub fn sku_search_query(sku: &str) -> String {
+        format!("sku:'{}'", Self::escape_search_value(sku))
     }
 
     /// Add Shopify gid prefix for InventoryItem.
@@ -101,6 +76,111 @@ impl ShopifyGQLHelper {
         format!("{}{}", Self::SHOPIFY_CUSTOMER_GID_PREFIX, id)
     }
 
+    /// Add Shopify gid prefix for Order.
+    pub fn add_order_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_ORDER_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_ORDER_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for Fulfillment.
+    pub fn add_fulfillment_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_FULFILLMENT_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_FULFILLMENT_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for FulfillmentOrder.
+    pub fn add_fulfillment_order_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_FULFILLMENT_ORDER_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_FULFILLMENT_ORDER_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for FulfillmentOrderLineItem.
+    pub fn add_fulfillment_order_line_item_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_FULFILLMENT_ORDER_LINE_ITEM_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!(
+            "{}{}",
+            Self::SHOPIFY_FULFILLMENT_ORDER_LINE_ITEM_GID_PREFIX,
+            id
+        )
+    }
+
+    /// Add Shopify gid prefix for FulfillmentLineItem.
+    pub fn add_fulfillment_line_item_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_FULFILLMENT_LINE_ITEM_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_FULFILLMENT_LINE_ITEM_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for LineItem.
+    pub fn add_line_item_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_LINE_ITEM_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_LINE_ITEM_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for Return.
+    pub fn add_return_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_RETURN_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_RETURN_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for an address in the address book of a customer.
+    pub fn add_customer_address_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_MAILING_ADDRESS_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!(
+            "{}{}{}",
+            Self::SHOPIFY_MAILING_ADDRESS_GID_PREFIX,
+            id,
+            Self::SHOPIFY_CUSTOMER_ADDRESS_GID_SUFFIX
+        )
+    }
+
+    /// Add Shopify gid prefix for Product.
+    pub fn add_product_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_PRODUCT_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_PRODUCT_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for MediaImage.
+    pub fn add_media_image_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_MEDIA_IMAGE_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_MEDIA_IMAGE_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for Video.
+    pub fn add_video_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_VIDEO_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_VIDEO_GID_PREFIX, id)
+    }
+
+    /// Add Shopify gid prefix for Model3d.
+    pub fn add_model3d_gid_prefix(id: &str) -> String {
+        if id.contains(Self::SHOPIFY_MODEL3D_GID_PREFIX) {
+            return id.to_string();
+        }
+        format!("{}{}", Self::SHOPIFY_MODEL3D_GID_PREFIX, id)
+    }
+
     /// Add Shopify gid prefix for Product variant.
     pub fn add_product_variant_gid_prefix(id: &str) -> String {
         if id.contains(Self::SHOPIFY_PRODUCT_VARIANT_GID_PREFIX) {
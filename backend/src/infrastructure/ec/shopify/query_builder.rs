@@ -0,0 +1,522 @@
+#[cfg(test)]
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Formatter};
+
+use super::gql_helper::ShopifyGQLHelper;
+
+/// Response struct that knows the fields to select for it.
+///
+/// The selection is declared next to the struct, so the query sent to Shopify and the fields read from the response are kept together.
+pub trait Selectable {
+    fn selection() -> Selection;
+}
+
+/// Value passed to an argument of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    /// Variable of the operation, written as `$name`.
+    Variable(String),
+    Int(i64),
+    Boolean(bool),
+    /// String literal, which is quoted and escaped.
+    String(String),
+    /// Enum value, written as is.
+    Enum(String),
+    List(Vec<Argument>),
+}
+
+impl Display for Argument {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Argument::Variable(name) => write!(f, "${name}"),
+            Argument::Int(value) => write!(f, "{value}"),
+            Argument::Boolean(value) => write!(f, "{value}"),
+            Argument::String(value) => {
+                write!(f, "{}", serde_json::Value::String(value.to_owned()))
+            }
+            Argument::Enum(value) => write!(f, "{value}"),
+            Argument::List(values) => {
+                let values = values
+                    .iter()
+                    .map(|value| value.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "[{values}]")
+            }
+        }
+    }
+}
+
+/// Field of a selection set, with its alias, arguments and sub-selection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    name: String,
+    alias: Option<String>,
+    arguments: Vec<(String, Argument)>,
+    selection: Selection,
+}
+
+impl Field {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            alias: None,
+            arguments: Vec::new(),
+            selection: Selection::new(),
+        }
+    }
+
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    pub fn arg(mut self, name: impl Into<String>, value: Argument) -> Self {
+        self.arguments.push((name.into(), value));
+        self
+    }
+
+    /// Pass the variable of the same name to the argument, as in `location(id: $id)`.
+    pub fn var(self, name: &str) -> Self {
+        self.arg(name, Argument::Variable(name.to_string()))
+    }
+
+    /// Metafield of the owner object, as in `metafield(namespace: "custom", key: "user_id") { value }`.
+    pub fn metafield(namespace: &str, key: &str) -> Self {
+        Self::new("metafield")
+            .arg("namespace", Argument::String(namespace.to_string()))
+            .arg("key", Argument::String(key.to_string()))
+            .select(Selection::new().field("value"))
+    }
+
+    /// Request the max number of nodes of a connection in one page.
+    pub fn first(self) -> Self {
+        self.arg(
+            "first",
+            Argument::Int(ShopifyGQLHelper::SHOPIFY_QUERY_LIMIT as i64),
+        )
+    }
+
+    pub fn select(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Select the fields of a response struct.
+    pub fn select_type<T: Selectable>(self) -> Self {
+        self.select(T::selection())
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(alias) = &self.alias {
+            write!(f, "{alias}: ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        if !self.arguments.is_empty() {
+            let arguments = self
+                .arguments
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "({arguments})")?;
+        }
+
+        if !self.selection.is_empty() {
+            write!(f, " {}", self.selection)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selected {
+    Field(Field),
+    InlineFragment(String, Selection),
+}
+
+/// Selection set of an object, written as `{ ... }`.
+///
+/// A selection on its own is also an anonymous query, as used by bulk operations.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Selection {
+    items: Vec<Selected>,
+}
+
+impl Selection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Select a scalar field.
+    pub fn field(self, name: &str) -> Self {
+        self.with(Field::new(name))
+    }
+
+    /// Select scalar fields.
+    pub fn fields(self, names: &[&str]) -> Self {
+        names
+            .iter()
+            .fold(self, |selection, name| selection.field(name))
+    }
+
+    /// Select the nodes of a connection without its page info, which bulk operations do not accept.
+    pub fn edges(node: Selection) -> Self {
+        Self::new()
+            .with(Field::new("edges").select(Self::new().with(Field::new("node").select(node))))
+    }
+
+    /// Select an object field with the fields of its response struct.
+    pub fn object<T: Selectable>(self, name: &str) -> Self {
+        self.with(Field::new(name).select_type::<T>())
+    }
+
+    pub fn with(mut self, field: Field) -> Self {
+        self.items.push(Selected::Field(field));
+        self
+    }
+
+    /// Select fields only when the object is of the type, as in `... on Video { ... }`.
+    pub fn on(mut self, type_condition: &str, selection: Selection) -> Self {
+        self.items.push(Selected::InlineFragment(
+            type_condition.to_string(),
+            selection,
+        ));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Keys that the selection returns in the response, including those of inline fragments.
+    #[cfg(test)]
+    pub fn response_keys(&self) -> BTreeSet<&str> {
+        self.items
+            .iter()
+            .flat_map(|item| match item {
+                Selected::Field(field) => {
+                    BTreeSet::from([field.alias.as_deref().unwrap_or(&field.name)])
+                }
+                Selected::InlineFragment(_, selection) => selection.response_keys(),
+            })
+            .collect()
+    }
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for item in &self.items {
+            match item {
+                Selected::Field(field) => write!(f, " {field}")?,
+                Selected::InlineFragment(type_condition, selection) => {
+                    write!(f, " ... on {type_condition} {selection}")?
+                }
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+/// Named query or mutation with its variables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    kind: &'static str,
+    name: String,
+    variables: Vec<(String, String)>,
+    selection: Selection,
+}
+
+impl Operation {
+    pub fn query(name: impl Into<String>) -> Self {
+        Self::new("query", name)
+    }
+
+    pub fn mutation(name: impl Into<String>) -> Self {
+        Self::new("mutation", name)
+    }
+
+    fn new(kind: &'static str, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            variables: Vec::new(),
+            selection: Selection::new(),
+        }
+    }
+
+    /// Declare a variable with its GraphQL type, such as `ID!`.
+    pub fn variable(mut self, name: &str, type_name: &str) -> Self {
+        self.variables
+            .push((name.to_string(), type_name.to_string()));
+        self
+    }
+
+    /// Select a root field.
+    pub fn with(mut self, field: Field) -> Self {
+        self.selection = self.selection.with(field);
+        self
+    }
+
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.name)?;
+
+        if !self.variables.is_empty() {
+            let variables = self
+                .variables
+                .iter()
+                .map(|(name, type_name)| format!("${name}: {type_name}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "({variables})")?;
+        }
+
+        write!(f, " {}", self.selection)
+    }
+}
+
+/// Assert that a response struct reads exactly the fields that its selection requests.
+///
+/// A field that is read but not selected would fail or be silently empty at runtime,
+/// and a field that is selected but not read only adds to the query cost.
+#[cfg(test)]
+pub fn assert_selection_matches<T>()
+where
+    T: Selectable + serde::de::DeserializeOwned,
+{
+    assert_partial_selection_matches::<T>(&T::selection(), &[]);
+}
+
+/// Assert that a selection requests the fields that a response struct reads, except the given optional ones.
+///
+/// Use this for structs shared by queries that add different fields to a common selection.
+#[cfg(test)]
+pub fn assert_partial_selection_matches<T>(selection: &Selection, unselected: &[&str])
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut selected = selection.response_keys();
+    selected.extend(unselected);
+    let read: BTreeSet<&str> = test_helper::struct_fields::<T>().iter().copied().collect();
+
+    assert_eq!(
+        selected,
+        read,
+        "selection of {} does not match its fields",
+        std::any::type_name::<T>()
+    );
+}
+
+#[cfg(test)]
+mod test_helper {
+    use serde::{
+        de::{self, value::Error, DeserializeOwned, Visitor},
+        forward_to_deserialize_any, Deserializer,
+    };
+
+    /// Deserializer that only records the field names of the struct deserialized from it.
+    struct FieldRecorder<'a> {
+        fields: &'a mut Option<&'static [&'static str]>,
+    }
+
+    impl<'de, 'a> Deserializer<'de> for FieldRecorder<'a> {
+        type Error = Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+            Err(de::Error::custom("only structs can be recorded"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Error> {
+            *self.fields = Some(fields);
+            Err(de::Error::custom("fields recorded"))
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    /// Names of the fields that a struct reads, as they appear in the response.
+    pub fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+        let mut fields = None;
+        let _ = T::deserialize(FieldRecorder {
+            fields: &mut fields,
+        });
+        fields.unwrap_or_else(|| panic!("{} is not a struct", std::any::type_name::<T>()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ImageNode {
+        alt_text: Option<String>,
+        url: String,
+    }
+
+    impl Selectable for ImageNode {
+        fn selection() -> Selection {
+            Selection::new().fields(&["altText", "url"])
+        }
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct MediaNode {
+        id: String,
+        image: Option<ImageNode>,
+        duration: Option<u32>,
+        video_sources: Option<Vec<String>>,
+    }
+
+    impl Selectable for MediaNode {
+        fn selection() -> Selection {
+            Selection::new()
+                .field("id")
+                .object::<ImageNode>("image")
+                .on(
+                    "Video",
+                    Selection::new()
+                        .field("duration")
+                        .with(Field::new("sources").alias("videoSources")),
+                )
+        }
+    }
+
+    #[test]
+    fn test_selection_to_string() {
+        assert_eq!(
+            MediaNode::selection().to_string(),
+            "{ id image { altText url } ... on Video { duration videoSources: sources } }"
+        );
+    }
+
+    #[test]
+    fn test_field_with_arguments() {
+        let field = Field::metafield("custom", "say \"hi\"").alias("color");
+
+        assert_eq!(
+            field.to_string(),
+            "color: metafield(namespace: \"custom\", key: \"say \\\"hi\\\"\") { value }"
+        );
+    }
+
+    #[test]
+    fn test_operation_to_string() {
+        let query = Operation::query("products")
+            .variable("after", "String")
+            .variable("query", "String!")
+            .with(
+                Field::new("products")
+                    .first()
+                    .var("after")
+                    .var("query")
+                    .arg("sortKey", Argument::Enum("UPDATED_AT".to_string()))
+                    .arg("reverse", Argument::Boolean(true))
+                    .select(Selection::new().field("id")),
+            )
+            .build();
+
+        assert_eq!(
+            query,
+            "query products($after: String, $query: String!) { products(first: 250, after: $after, query: $query, sortKey: UPDATED_AT, reverse: true) { id } }"
+        );
+    }
+
+    #[test]
+    fn test_list_argument() {
+        let field = Field::new("quantities").arg(
+            "names",
+            Argument::List(vec![
+                Argument::String("available".to_string()),
+                Argument::String("committed".to_string()),
+            ]),
+        );
+
+        assert_eq!(
+            field.to_string(),
+            "quantities(names: [\"available\", \"committed\"])"
+        );
+    }
+
+    #[test]
+    fn test_edges() {
+        assert_eq!(
+            Selection::edges(Selection::new().field("id")).to_string(),
+            "{ edges { node { id } } }"
+        );
+    }
+
+    #[test]
+    fn test_mutation_without_variables() {
+        let mutation = Operation::mutation("ping").with(Field::new("ping")).build();
+
+        assert_eq!(mutation, "mutation ping { ping }");
+    }
+
+    #[test]
+    fn test_response_keys() {
+        assert_eq!(
+            MediaNode::selection().response_keys(),
+            BTreeSet::from(["id", "image", "duration", "videoSources"])
+        );
+    }
+
+    #[test]
+    fn test_assert_selection_matches() {
+        assert_selection_matches::<ImageNode>();
+        assert_selection_matches::<MediaNode>();
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct DriftedNode {
+        id: String,
+        created_at: String,
+    }
+
+    impl Selectable for DriftedNode {
+        fn selection() -> Selection {
+            Selection::new().fields(&["id", "createAt"])
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match its fields")]
+    fn test_assert_selection_matches_with_typo() {
+        assert_selection_matches::<DriftedNode>();
+    }
+
+    #[test]
+    fn test_assert_partial_selection_matches() {
+        assert_partial_selection_matches::<MediaNode>(
+            &Selection::new().fields(&["id", "duration"]),
+            &["image", "videoSources"],
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::{
     domain::error::error::DomainError,
     infrastructure::ec::{
         ec_client_interface::ECClient,
         shopify::{
-            gql_helper::ShopifyGQLHelper, query_service::schema::product::RelatedProductsData,
-            schema::GraphQLResponse,
+            error::ShopifyError,
+            gql_helper::ShopifyGQLHelper,
+            query_builder::{Argument, Field, Operation},
+            query_service::schema::product::{ProductNode, RelatedProductsData},
+            schema::{Edges, GraphQLResponse},
         },
     },
     log_error,
@@ -33,52 +37,34 @@ impl<C: ECClient + Send + Sync> ProductQueryService for ProductQueryServiceImpl<
         &self,
         filter: &RelatedProductFilter,
     ) -> Result<Vec<ProductDTO>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
         let id = &filter.id;
         let category_id = &filter.category_id;
 
-        let query = format!(
-            "query {{
-                    products(
-                        {first_query},
-                        sortKey: UPDATED_AT,
-                        query: \"
-                            (NOT id:{id}) AND
-                            category_id:{category_id} AND
-                            inventory_total:>0 AND
-                            product_publication_status:published AND
-                            gift_card:false\"
-                    ) {{
-                        edges {{
-                            node {{
-                                id
-                                title
-                                handle
-                                vendor
-                                priceRangeV2 {{
-                                    maxVariantPrice {{
-                                        amount
-                                    }}
-                                }}
-                                featuredMedia {{
-                                    preview {{
-                                        image {{
-                                            url
-                                        }}
-                                    }}
-                                }}
-                            }}
-                        }}
-                        {page_info}
-                    }}
-                }}"
+        let search_query = format!(
+            "(NOT id:{}) AND category_id:{} AND inventory_total:>0 AND product_publication_status:published AND gift_card:false",
+            ShopifyGQLHelper::escape_search_value(id),
+            ShopifyGQLHelper::escape_search_value(category_id)
         );
 
-        let response: GraphQLResponse<RelatedProductsData> = self.client.query(&query).await?;
+        let query = Operation::query("products")
+            .variable("query", "String!")
+            .with(
+                Field::new("products")
+                    .first()
+                    .arg("sortKey", Argument::Enum("UPDATED_AT".to_string()))
+                    .var("query")
+                    .select_type::<Edges<ProductNode>>(),
+            )
+            .build();
+
+        let response: GraphQLResponse<RelatedProductsData> = self
+            .client
+            .query_with_variables(&query, &json!({ "query": search_query }))
+            .await?;
         if let Some(errors) = response.errors {
             log_error!("Error returned in Products response.", "response" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         Ok(response
@@ -94,14 +80,16 @@ impl<C: ECClient + Send + Sync> ProductQueryService for ProductQueryServiceImpl<
 
 #[cfg(test)]
 mod tests {
+    use serde_json::Value;
+
     use crate::infrastructure::ec::{
         ec_client_interface::MockECClient,
         shopify::{
+            query_builder::assert_selection_matches,
             query_service::schema::product::{
                 ImageNode, MediaNode, MediaPreviewImageNode, MoneyV2Node, PriceRangeV2Node,
-                ProductNode,
             },
-            schema::{Edges, GraphQLError, Node, PageInfo},
+            schema::{GraphQLError, Node, PageInfo},
         },
     };
 
@@ -179,9 +167,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<RelatedProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<RelatedProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_related_products_response(PageOption {
                     start: 0,
                     end: 250,
@@ -223,14 +211,48 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_search_related_products_passes_filter_as_variable() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<RelatedProductsData>>()
+            .withf(|query, variables| {
+                !query.contains("gift_card:true")
+                    && variables["query"]
+                        .as_str()
+                        .unwrap()
+                        .starts_with("(NOT id:1000\\) OR gift_card\\:true)")
+            })
+            .times(1)
+            .return_once(|_, _| {
+                Ok(mock_related_products_response(PageOption {
+                    start: 0,
+                    end: 1,
+                    has_next_page: false,
+                }))
+            });
+
+        let repo = ProductQueryServiceImpl::new(client);
+
+        let result = repo
+            .search_related_products(&RelatedProductFilter {
+                id: "1000) OR gift_card:true".to_string(),
+                category_id: "0".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_search_related_productsd_with_graphql_error() {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<RelatedProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<RelatedProductsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = ProductQueryServiceImpl::new(client);
 
@@ -254,9 +276,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<RelatedProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<RelatedProductsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = ProductQueryServiceImpl::new(client);
 
@@ -274,4 +296,14 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<ProductNode>();
+        assert_selection_matches::<PriceRangeV2Node>();
+        assert_selection_matches::<MoneyV2Node>();
+        assert_selection_matches::<MediaNode>();
+        assert_selection_matches::<MediaPreviewImageNode>();
+        assert_selection_matches::<ImageNode>();
+    }
 }
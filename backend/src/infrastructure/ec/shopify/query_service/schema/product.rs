@@ -1,7 +1,11 @@
 use serde::Deserialize;
 
 use crate::{
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::Edges},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::Edges,
+    },
     usecase::query_service::dto::product::ProductDTO,
 };
 
@@ -27,6 +31,45 @@ impl From<ProductNode> for ProductDTO {
     }
 }
 
+impl Selectable for ProductNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "title", "handle", "vendor"])
+            .object::<PriceRangeV2Node>("priceRangeV2")
+            .object::<MediaNode>("featuredMedia")
+    }
+}
+
+impl Selectable for PriceRangeV2Node {
+    fn selection() -> Selection {
+        Selection::new().object::<MoneyV2Node>("maxVariantPrice")
+    }
+}
+
+impl Selectable for MoneyV2Node {
+    fn selection() -> Selection {
+        Selection::new().field("amount")
+    }
+}
+
+impl Selectable for MediaNode {
+    fn selection() -> Selection {
+        Selection::new().object::<MediaPreviewImageNode>("preview")
+    }
+}
+
+impl Selectable for MediaPreviewImageNode {
+    fn selection() -> Selection {
+        Selection::new().object::<ImageNode>("image")
+    }
+}
+
+impl Selectable for ImageNode {
+    fn selection() -> Selection {
+        Selection::new().field("url")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RelatedProductsData {
     pub products: Edges<ProductNode>,
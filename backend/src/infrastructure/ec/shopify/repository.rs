@@ -1,8 +1,13 @@
 pub mod customer;
 pub mod draft_order;
+pub mod fulfillment;
+pub mod fulfillment_order;
 pub mod inventory_item;
 pub mod inventory_level;
 pub mod location;
 pub mod media;
+pub mod order;
+pub mod order_return;
 pub mod product;
+pub mod refund;
 mod schema;
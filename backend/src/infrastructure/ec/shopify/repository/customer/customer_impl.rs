@@ -1,17 +1,44 @@
 use async_trait::async_trait;
+use chrono::SecondsFormat;
+use serde_json::json;
 
 use crate::{
-    domain::{customer::customer::Customer, email::email::Email, error::error::DomainError},
-    infrastructure::ec::{
-        ec_client_interface::ECClient,
-        shopify::{
-            gql_helper::ShopifyGQLHelper,
-            repository::schema::customer::{CustomerNode, CustomersData},
-            schema::GraphQLResponse,
+    domain::{
+        address::address::{Address, Id as AddressId},
+        customer::{
+            customer::{Customer, Id as CustomerId},
+            search_criteria::search_criteria::{CustomerSearchCriteria, CustomerSortKey},
         },
+        email::email::Email,
+        error::error::DomainError,
+    },
+    infrastructure::{
+        ec::{
+            ec_client_interface::ECClient,
+            shopify::{
+                error::ShopifyError,
+                gql_helper::ShopifyGQLHelper,
+                query_builder::{Argument, Field, Operation},
+                repository::schema::{
+                    address_input::AddressInput,
+                    customer::{CustomerData, CustomerNode, CustomersData},
+                    customer_input::{
+                        CustomerAddressCreateData, CustomerAddressDelete,
+                        CustomerAddressDeleteData, CustomerAddressMutation,
+                        CustomerAddressUpdateData, CustomerCreateData,
+                        CustomerEmailMarketingConsentUpdateData,
+                        CustomerEmailMarketingConsentUpdateInput, CustomerInput, CustomerMutation,
+                        CustomerRequestDataErasure, CustomerRequestDataErasureData,
+                        CustomerUpdateData, CustomerUpdateDefaultAddressData,
+                    },
+                },
+                schema::{Edges, GraphQLResponse},
+            },
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
     },
     log_error,
-    usecase::repository::customer_repository_interface::CustomerRepository,
+    usecase::repository::customer_repository_interface::{CustomerPage, CustomerRepository},
 };
 
 /// Repository for Customers for Shopify.
@@ -23,60 +50,82 @@ impl<C: ECClient> CustomerRepositoryImpl<C> {
     pub fn new(client: C) -> Self {
         Self { client }
     }
-}
 
-#[async_trait]
-impl<C: ECClient + Send + Sync> CustomerRepository for CustomerRepositoryImpl<C> {
-    async fn find_customer_by_email(&self, email: &Email) -> Result<Customer, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let address_fields = ShopifyGQLHelper::address_fields();
-        let email = email.value();
-        let user_id_query = ShopifyGQLHelper::metafield_query("user_id", "custom");
-
-        let query = format!(
-            "query {{
-                customers({first_query}, query: \"email:{email}\") {{
-                    edges {{
-                        node {{
-                            canDelete
-                            createdAt
-                            displayName
-                            email
-                            firstName
-                            id
-                            lastName
-                            note
-                            phone
-                            state
-                            updatedAt
-                            validEmailAddress
-                            verifiedEmail
-                            addresses({first_query}) {{
-                                {address_fields}
-                            }}
-                            image {{
-                                altText
-                                height
-                                id
-                                url
-                                width
-                            }}
-                            defaultAddress {{
-                                {address_fields}
-                            }}
-                            {user_id_query}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
+    /// Translate the criteria into Shopify's customer search syntax.
+    fn to_search_query(criteria: &CustomerSearchCriteria) -> String {
+        let mut terms = Vec::new();
+
+        if let Some(name) = criteria.name() {
+            for word in name.split_whitespace() {
+                let word = ShopifyGQLHelper::escape_search_value(word);
+                terms.push(format!("(first_name:{word}* OR last_name:{word}*)"));
+            }
+        }
+        if let Some(email) = criteria.email() {
+            terms.push(format!(
+                "email:'{}'",
+                ShopifyGQLHelper::escape_search_value(email.value())
+            ));
+        }
+        if let Some(phone) = criteria.phone() {
+            terms.push(format!(
+                "phone:'{}'",
+                ShopifyGQLHelper::escape_search_value(phone.value())
+            ));
+        }
+        for tag in criteria.tags() {
+            terms.push(format!(
+                "tag:'{}'",
+                ShopifyGQLHelper::escape_search_value(tag)
+            ));
+        }
+        if let Some(min) = criteria.created_at_min() {
+            terms.push(format!(
+                "created_at:>='{}'",
+                min.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        if let Some(max) = criteria.created_at_max() {
+            terms.push(format!(
+                "created_at:<='{}'",
+                max.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        }
+        if let Some(min) = criteria.orders_count_min() {
+            terms.push(format!("orders_count:>={min}"));
+        }
+        if let Some(max) = criteria.orders_count_max() {
+            terms.push(format!("orders_count:<={max}"));
+        }
+        if let Some(min) = criteria.amount_spent_min() {
+            terms.push(format!("total_spent:>={}", min.value()));
+        }
+        if let Some(max) = criteria.amount_spent_max() {
+            terms.push(format!("total_spent:<={}", max.value()));
+        }
+
+        terms.join(" AND ")
+    }
+
+    async fn find_customer_by_query(&self, search_query: &str) -> Result<Customer, DomainError> {
+        let query = Operation::query("customers")
+            .variable("query", "String!")
+            .with(
+                Field::new("customers")
+                    .first()
+                    .var("query")
+                    .select_type::<Edges<CustomerNode>>(),
+            )
+            .build();
 
-        let graphql_response: GraphQLResponse<CustomersData> = self.client.query(&query).await?;
+        let graphql_response: GraphQLResponse<CustomersData> = self
+            .client
+            .query_with_variables(&query, &json!({ "query": search_query }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response. Response.", "errors" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let nodes: Vec<CustomerNode> = graphql_response
@@ -91,34 +140,507 @@ impl<C: ECClient + Send + Sync> CustomerRepository for CustomerRepositoryImpl<C>
         let domains = CustomerNode::to_domains(nodes)?;
 
         if domains.is_empty() {
-            log_error!("No customer found for email.", "email" => email.clone());
+            log_error!("No customer found for query.", "query" => search_query);
             return Err(DomainError::NotFound);
         }
 
         Ok(domains.into_iter().next().unwrap())
     }
+
+    fn to_saved_customer(
+        graphql_response: GraphQLResponse<CustomerMutation>,
+    ) -> Result<Customer, DomainError> {
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response.data.ok_or(DomainError::SaveError)?;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.customer {
+            Some(customer) => customer.to_domain(),
+            None => {
+                log_error!("No customer returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    fn to_saved_address(
+        graphql_response: GraphQLResponse<CustomerAddressMutation>,
+    ) -> Result<Address, DomainError> {
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response.data.ok_or(DomainError::SaveError)?;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.address {
+            Some(address) => address.to_domain(),
+            None => {
+                log_error!("No address returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> CustomerRepository for CustomerRepositoryImpl<C> {
+    async fn find_customer_by_id(&self, id: &CustomerId) -> Result<Customer, DomainError> {
+        let id = ShopifyGQLHelper::add_customer_gid_prefix(id);
+        let query = Operation::query("customer")
+            .variable("id", "ID!")
+            .with(
+                Field::new("customer")
+                    .var("id")
+                    .select_type::<CustomerNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        CustomerNode::to_domain(
+            graphql_response
+                .data
+                .ok_or(DomainError::NotFound)?
+                .customer
+                .ok_or(DomainError::NotFound)?,
+        )
+    }
+
+    async fn find_customers_by_criteria(
+        &self,
+        criteria: &CustomerSearchCriteria,
+        sort_key: &CustomerSortKey,
+        reverse: bool,
+        first: u32,
+        after: Option<String>,
+    ) -> Result<CustomerPage, DomainError> {
+        let search_query = Self::to_search_query(criteria);
+        let sort_key = String::from(sort_key.clone());
+        let query = Operation::query("customers")
+            .variable("first", "Int!")
+            .variable("after", "String")
+            .variable("query", "String!")
+            .variable("sortKey", "CustomerSortKeys!")
+            .variable("reverse", "Boolean!")
+            .with(
+                Field::new("customers")
+                    .var("first")
+                    .var("after")
+                    .var("query")
+                    .var("sortKey")
+                    .var("reverse")
+                    .select_type::<Edges<CustomerNode>>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomersData> = self
+            .client
+            .query_with_variables(
+                &query,
+                &json!({
+                    "first": first,
+                    "after": after,
+                    "query": search_query,
+                    "sortKey": sort_key,
+                    "reverse": reverse,
+                }),
+            )
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        let customers = graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .customers;
+
+        Ok(CustomerPage {
+            customers: CustomerNode::to_domains(
+                customers.edges.into_iter().map(|node| node.node).collect(),
+            )?,
+            has_next_page: customers.page_info.has_next_page,
+            end_cursor: customers.page_info.end_cursor,
+        })
+    }
+
+    async fn find_customer_by_email(&self, email: &Email) -> Result<Customer, DomainError> {
+        self.find_customer_by_query(&format!(
+            "email:{}",
+            ShopifyGQLHelper::escape_search_value(email.value())
+        ))
+        .await
+    }
+
+    async fn create(&self, customer: Customer) -> Result<Customer, DomainError> {
+        let input = serde_json::to_value(CustomerInput::from(&customer)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let query = Operation::mutation("customerCreate")
+            .variable("input", "CustomerInput!")
+            .with(
+                Field::new("customerCreate")
+                    .var("input")
+                    .select_type::<CustomerMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerCreateData> =
+            self.client.mutation(&query, &input).await?;
+
+        Self::to_saved_customer(GraphQLResponse {
+            data: graphql_response.data.map(|d| d.customer_create),
+            errors: graphql_response.errors,
+        })
+    }
+
+    async fn update(&self, customer: Customer) -> Result<Customer, DomainError> {
+        let input = serde_json::to_value(CustomerInput::from(&customer)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let query = Operation::mutation("customerUpdate")
+            .variable("input", "CustomerInput!")
+            .with(
+                Field::new("customerUpdate")
+                    .var("input")
+                    .select_type::<CustomerMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerUpdateData> =
+            self.client.mutation(&query, &input).await?;
+
+        let updated = Self::to_saved_customer(GraphQLResponse {
+            data: graphql_response.data.map(|d| d.customer_update),
+            errors: graphql_response.errors,
+        })?;
+
+        // Shopify does not accept marketing consent in the update input, so it is updated separately when it changed.
+        if customer.email().is_none()
+            || updated.email_marketing_consent() == customer.email_marketing_consent()
+        {
+            return Ok(updated);
+        }
+
+        let input = serde_json::to_value(CustomerEmailMarketingConsentUpdateInput::from(&customer))
+            .map_err(|e| {
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
+
+        let query = Operation::mutation("customerEmailMarketingConsentUpdate")
+            .variable("input", "CustomerEmailMarketingConsentUpdateInput!")
+            .with(
+                Field::new("customerEmailMarketingConsentUpdate")
+                    .var("input")
+                    .select_type::<CustomerMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerEmailMarketingConsentUpdateData> =
+            self.client.mutation(&query, &input).await?;
+
+        Self::to_saved_customer(GraphQLResponse {
+            data: graphql_response
+                .data
+                .map(|d| d.customer_email_marketing_consent_update),
+            errors: graphql_response.errors,
+        })
+    }
+
+    async fn create_address(
+        &self,
+        customer_id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Address, DomainError> {
+        let input = serde_json::to_value(AddressInput::from(address)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let customer_id = ShopifyGQLHelper::add_customer_gid_prefix(customer_id);
+        let query = Operation::mutation("customerAddressCreate")
+            .variable("customerId", "ID!")
+            .variable("input", "MailingAddressInput!")
+            .variable("setAsDefault", "Boolean")
+            .with(
+                Field::new("customerAddressCreate")
+                    .var("customerId")
+                    .arg("address", Argument::Variable("input".to_string()))
+                    .var("setAsDefault")
+                    .select_type::<CustomerAddressMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerAddressCreateData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({
+                    "customerId": customer_id,
+                    "input": input,
+                    "setAsDefault": set_default,
+                }),
+            )
+            .await?;
+
+        Self::to_saved_address(GraphQLResponse {
+            data: graphql_response.data.map(|d| d.customer_address_create),
+            errors: graphql_response.errors,
+        })
+    }
+
+    async fn update_address(
+        &self,
+        customer_id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Address, DomainError> {
+        let address_id = match address.id() {
+            Some(id) => ShopifyGQLHelper::add_customer_address_gid_prefix(id),
+            None => {
+                log_error!("Address to update has no id.", "customer_id" => customer_id);
+                return Err(DomainError::SaveError);
+            }
+        };
+        let input = serde_json::to_value(AddressInput::from(address)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let customer_id = ShopifyGQLHelper::add_customer_gid_prefix(customer_id);
+        let query = Operation::mutation("customerAddressUpdate")
+            .variable("customerId", "ID!")
+            .variable("addressId", "ID!")
+            .variable("input", "MailingAddressInput!")
+            .variable("setAsDefault", "Boolean")
+            .with(
+                Field::new("customerAddressUpdate")
+                    .var("customerId")
+                    .var("addressId")
+                    .arg("address", Argument::Variable("input".to_string()))
+                    .var("setAsDefault")
+                    .select_type::<CustomerAddressMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerAddressUpdateData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({
+                    "customerId": customer_id,
+                    "addressId": address_id,
+                    "input": input,
+                    "setAsDefault": set_default,
+                }),
+            )
+            .await?;
+
+        Self::to_saved_address(GraphQLResponse {
+            data: graphql_response.data.map(|d| d.customer_address_update),
+            errors: graphql_response.errors,
+        })
+    }
+
+    async fn delete_address(
+        &self,
+        customer_id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<AddressId, DomainError> {
+        let customer_id = ShopifyGQLHelper::add_customer_gid_prefix(customer_id);
+        let address_id = ShopifyGQLHelper::add_customer_address_gid_prefix(address_id);
+        let query = Operation::mutation("customerAddressDelete")
+            .variable("customerId", "ID!")
+            .variable("addressId", "ID!")
+            .with(
+                Field::new("customerAddressDelete")
+                    .var("customerId")
+                    .var("addressId")
+                    .select_type::<CustomerAddressDelete>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerAddressDeleteData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({ "customerId": customer_id, "addressId": address_id }),
+            )
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .customer_address_delete;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.deleted_address_id {
+            Some(id) => Ok(ShopifyGQLHelper::remove_gid_prefix(&id)),
+            None => {
+                log_error!("No deleted address id returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    async fn update_default_address(
+        &self,
+        customer_id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError> {
+        let customer_id = ShopifyGQLHelper::add_customer_gid_prefix(customer_id);
+        let address_id = ShopifyGQLHelper::add_customer_address_gid_prefix(address_id);
+        let query = Operation::mutation("customerUpdateDefaultAddress")
+            .variable("customerId", "ID!")
+            .variable("addressId", "ID!")
+            .with(
+                Field::new("customerUpdateDefaultAddress")
+                    .var("customerId")
+                    .var("addressId")
+                    .select_type::<CustomerMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerUpdateDefaultAddressData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({ "customerId": customer_id, "addressId": address_id }),
+            )
+            .await?;
+
+        Self::to_saved_customer(GraphQLResponse {
+            data: graphql_response
+                .data
+                .map(|d| d.customer_update_default_address),
+            errors: graphql_response.errors,
+        })
+    }
+
+    async fn request_data_erasure(&self, id: &CustomerId) -> Result<CustomerId, DomainError> {
+        let customer_id = ShopifyGQLHelper::add_customer_gid_prefix(id);
+        let query = Operation::mutation("customerRequestDataErasure")
+            .variable("customerId", "ID!")
+            .with(
+                Field::new("customerRequestDataErasure")
+                    .var("customerId")
+                    .select_type::<CustomerRequestDataErasure>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<CustomerRequestDataErasureData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "customerId": customer_id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .customer_request_data_erasure;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.customer_id {
+            Some(id) => Ok(ShopifyGQLHelper::remove_gid_prefix(&id)),
+            None => {
+                log_error!("No customer id returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
+    use serde_json::Value;
 
     use crate::{
         domain::{
-            customer::customer::CustomerStatus, email::email::Email, error::error::DomainError,
+            customer::{
+                customer::{Customer, CustomerStatus},
+                search_criteria::search_criteria::{
+                    CustomerSearchCriteria, CustomerSearchCriteriaParams, CustomerSortKey,
+                },
+            },
+            email::email::Email,
+            error::error::{DomainError, FieldError},
+            money::amount::amount::Amount,
         },
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::assert_selection_matches,
                 repository::{
                     customer::customer_impl::CustomerRepositoryImpl,
                     schema::{
                         address::AddressNode,
-                        customer::{CustomerNode, CustomersData},
+                        customer::{
+                            CustomerData, CustomerNode, CustomersData, EmailMarketingConsentNode,
+                        },
+                        customer_input::{
+                            CustomerAddressCreateData, CustomerAddressDelete,
+                            CustomerAddressDeleteData, CustomerAddressMutation,
+                            CustomerAddressUpdateData, CustomerCreateData,
+                            CustomerEmailMarketingConsentUpdateData, CustomerMutation,
+                            CustomerRequestDataErasure, CustomerRequestDataErasureData,
+                            CustomerUpdateData, CustomerUpdateDefaultAddressData,
+                        },
                         media::ImageNode,
                     },
                 },
-                schema::{Edges, GraphQLError, GraphQLResponse, Metafield, Node, PageInfo},
+                schema::{
+                    Edges, GraphQLError, GraphQLErrorExtensions, GraphQLResponse, Metafield, Node,
+                    PageInfo, UserError,
+                },
             },
         },
         usecase::repository::customer_repository_interface::CustomerRepository,
@@ -141,6 +663,10 @@ mod tests {
             note: Some("Test note".to_string()),
             state: "ENABLED".to_string(),
             verified_email: true,
+            email_marketing_consent: Some(EmailMarketingConsentNode {
+                marketing_state: "NOT_SUBSCRIBED".to_string(),
+            }),
+            deactivated: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -149,14 +675,17 @@ mod tests {
     fn mock_address(address1: Option<impl Into<String>>) -> AddressNode {
         let address1 = address1.map(|a| a.into());
         AddressNode {
+            id: Some("gid://shopify/MailingAddress/1?model_name=CustomerAddress".to_string()),
             address1: address1,
             address2: Some("Apt 123".to_string()),
             city: Some("Test City".to_string()),
             coordinates_validated: true,
-            country: Some("Test Country".to_string()),
+            country: Some("Japan".to_string()),
+            country_code_v2: Some("JP".to_string()),
             first_name: Some("John".to_string()),
             last_name: Some("Doe".to_string()),
-            province: Some("Test Province".to_string()),
+            province: Some("Tokyo".to_string()),
+            province_code: Some("13".to_string()),
             zip: Some("12345".to_string()),
             phone: Some("+1234567890".to_string()),
         }
@@ -195,6 +724,29 @@ mod tests {
         }
     }
 
+    fn mock_customer_response(node: Option<CustomerNode>) -> GraphQLResponse<CustomerData> {
+        GraphQLResponse {
+            data: Some(CustomerData { customer: node }),
+            errors: None,
+        }
+    }
+
+    fn mock_customer_mutation(
+        node: Option<CustomerNode>,
+        user_errors: Vec<UserError>,
+    ) -> CustomerMutation {
+        CustomerMutation {
+            customer: node,
+            user_errors,
+        }
+    }
+
+    fn mock_customer_domain(id: u32) -> Customer {
+        mock_customer(id)
+            .to_domain()
+            .expect("Failed to convert mock customer")
+    }
+
     fn mock_with_error<T>() -> GraphQLResponse<T> {
         GraphQLResponse {
             data: None,
@@ -217,9 +769,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<CustomersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_customers_response(1)));
+            .return_once(|_, _| Ok(mock_customers_response(1)));
 
         let repo = CustomerRepositoryImpl::new(client);
 
@@ -254,9 +806,9 @@ mod tests {
             .state = "INVALID_STATE".to_string();
 
         client
-            .expect_query::<GraphQLResponse<CustomersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = CustomerRepositoryImpl::new(client);
 
@@ -277,9 +829,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<CustomersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = CustomerRepositoryImpl::new(client);
 
@@ -295,14 +847,42 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_find_customer_by_email_with_throttled_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Throttled".to_string(),
+                        extensions: Some(GraphQLErrorExtensions {
+                            code: Some("THROTTLED".to_string()),
+                        }),
+                    }]),
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .find_customer_by_email(&Email::new("test@example.com".to_string()).unwrap())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Throttled)));
+    }
+
     #[tokio::test]
     async fn test_find_customer_by_email_with_missing_data() {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<CustomersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = CustomerRepositoryImpl::new(client);
 
@@ -317,4 +897,612 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    #[test]
+    fn test_to_search_query() {
+        let criteria = CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: Some("Jo Do".to_string()),
+            email: Some(Email::new("test@example.com").unwrap()),
+            tags: vec!["vip".to_string(), "O'Neil's".to_string()],
+            created_at_min: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+            created_at_max: Some(Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap()),
+            orders_count_min: Some(1),
+            orders_count_max: Some(10),
+            amount_spent_min: Some(Amount::new(100.0).unwrap()),
+            amount_spent_max: Some(Amount::new(1000.5).unwrap()),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let query = CustomerRepositoryImpl::<MockECClient>::to_search_query(&criteria);
+
+        assert_eq!(
+            query,
+            "(first_name:Jo* OR last_name:Jo*) AND (first_name:Do* OR last_name:Do*) \
+            AND email:'test@example.com' AND tag:'vip' AND tag:'O\\'Neil\\'s' \
+            AND created_at:>='2024-01-01T00:00:00Z' AND created_at:<='2024-12-31T23:59:59Z' \
+            AND orders_count:>=1 AND orders_count:<=10 \
+            AND total_spent:>=100 AND total_spent:<=1000.5"
+        );
+    }
+
+    #[test]
+    fn test_to_search_query_with_empty_criteria() {
+        let query = CustomerRepositoryImpl::<MockECClient>::to_search_query(
+            &CustomerSearchCriteria::default(),
+        );
+
+        assert_eq!(query, "");
+    }
+
+    #[tokio::test]
+    async fn test_find_customers_by_criteria_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                let mut response = mock_customers_response(3);
+                let page_info = &mut response.data.as_mut().unwrap().customers.page_info;
+                page_info.has_next_page = true;
+                page_info.end_cursor = Some("cursor".to_string());
+                Ok(response)
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .find_customers_by_criteria(
+                &CustomerSearchCriteria::default(),
+                &CustomerSortKey::CreatedAt,
+                true,
+                3,
+                None,
+            )
+            .await;
+
+        let page = result.expect("Failed to find customers");
+        assert_eq!(page.customers.len(), 3);
+        assert!(page.has_next_page);
+        assert_eq!(page.end_cursor, Some("cursor".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_find_customers_by_criteria_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomersData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .find_customers_by_criteria(
+                &CustomerSearchCriteria::default(),
+                &CustomerSortKey::Relevance,
+                false,
+                50,
+                Some("cursor".to_string()),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_customer_by_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomerData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_customer_response(Some(mock_customer(1)))));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.find_customer_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let customer = result.unwrap();
+        assert_eq!(customer.id(), "1");
+        assert_eq!(customer.user_id(), "user_1");
+        assert!(!customer.email_marketing_consent());
+    }
+
+    #[tokio::test]
+    async fn test_find_customer_by_id_deactivated() {
+        let mut client = MockECClient::new();
+
+        let mut node = mock_customer(1);
+        node.deactivated = Some(Metafield {
+            value: "true".to_string(),
+        });
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomerData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_customer_response(Some(node))));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let customer = repo
+            .find_customer_by_id(&"1".to_string())
+            .await
+            .expect("Failed to find customer");
+
+        assert_eq!(*customer.status(), CustomerStatus::Inactive);
+    }
+
+    #[tokio::test]
+    async fn test_find_customer_by_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<CustomerData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_customer_response(None)));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.find_customer_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerCreateData {
+                        customer_create: mock_customer_mutation(Some(mock_customer(1)), vec![]),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let customer = Customer::create(
+            "user_1",
+            Some(Email::new("test@example.com").unwrap()),
+            Some("Test".to_string()),
+            Some("Customer".to_string()),
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        let result = repo.create(customer).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().id(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerCreateData {
+                        customer_create: mock_customer_mutation(
+                            None,
+                            vec![UserError {
+                                field: vec!["email".to_string()],
+                                message: "Email has already been taken".to_string(),
+                            }],
+                        ),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let customer = Customer::create(
+            "user_1",
+            Some(Email::new("test@example.com").unwrap()),
+            None,
+            None,
+            None,
+            None,
+            false,
+        )
+        .unwrap();
+        let result = repo.create(customer).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("email".to_string()),
+                    None,
+                    "Email has already been taken"
+                )]
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerUpdateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerUpdateData {
+                        customer_update: mock_customer_mutation(Some(mock_customer(1)), vec![]),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.update(mock_customer_domain(1)).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().id(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_update_with_marketing_consent_change() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerUpdateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerUpdateData {
+                        customer_update: mock_customer_mutation(Some(mock_customer(1)), vec![]),
+                    }),
+                    errors: None,
+                })
+            });
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerEmailMarketingConsentUpdateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                let mut node = mock_customer(1);
+                node.email_marketing_consent = Some(EmailMarketingConsentNode {
+                    marketing_state: "SUBSCRIBED".to_string(),
+                });
+                Ok(GraphQLResponse {
+                    data: Some(CustomerEmailMarketingConsentUpdateData {
+                        customer_email_marketing_consent_update: mock_customer_mutation(
+                            Some(node),
+                            vec![],
+                        ),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let mut customer = mock_customer_domain(1);
+        customer
+            .update_profile(
+                Some("Test".to_string()),
+                Some("Customer".to_string()),
+                None,
+                None,
+                true,
+            )
+            .unwrap();
+        let result = repo.update(customer).await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().email_marketing_consent());
+    }
+
+    #[tokio::test]
+    async fn test_update_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<CustomerUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.update(mock_customer_domain(1)).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_create_address_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerAddressCreateData {
+                        customer_address_create: CustomerAddressMutation {
+                            address: Some(mock_address(Some("123"))),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let address = mock_address(Some("123")).to_domain().unwrap();
+        let result = repo.create_address(&"1".to_string(), address, true).await;
+
+        let address = result.expect("Failed to create address");
+        assert_eq!(address.id(), &Some("1".to_string()));
+        assert_eq!(address.country_code(), &Some("JP".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_address_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerAddressCreateData {
+                        customer_address_create: CustomerAddressMutation {
+                            address: None,
+                            user_errors: vec![UserError {
+                                field: vec!["address".to_string(), "zip".to_string()],
+                                message: "Zip is invalid".to_string(),
+                            }],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let address = mock_address(Some("123")).to_domain().unwrap();
+        let result = repo.create_address(&"1".to_string(), address, false).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_update_address_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressUpdateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerAddressUpdateData {
+                        customer_address_update: CustomerAddressMutation {
+                            address: Some(mock_address(Some("456"))),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let address = mock_address(Some("456")).to_domain().unwrap();
+        let result = repo.update_address(&"1".to_string(), address, false).await;
+
+        let address = result.expect("Failed to update address");
+        assert_eq!(address.address1(), &Some("456".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_update_address_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let address = mock_address(Some("456")).to_domain().unwrap();
+        let result = repo.update_address(&"1".to_string(), address, false).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_delete_address_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressDeleteData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerAddressDeleteData {
+                        customer_address_delete: CustomerAddressDelete {
+                            deleted_address_id: Some(
+                                "gid://shopify/MailingAddress/1?model_name=CustomerAddress"
+                                    .to_string(),
+                            ),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .delete_address(&"1".to_string(), &"1".to_string())
+            .await;
+
+        assert_eq!(result.expect("Failed to delete address"), "1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_address_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerAddressDeleteData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerAddressDeleteData {
+                        customer_address_delete: CustomerAddressDelete {
+                            deleted_address_id: None,
+                            user_errors: vec![UserError {
+                                field: vec!["addressId".to_string()],
+                                message: "Address does not exist".to_string(),
+                            }],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .delete_address(&"1".to_string(), &"1".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_update_default_address_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerUpdateDefaultAddressData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerUpdateDefaultAddressData {
+                        customer_update_default_address: mock_customer_mutation(
+                            Some(mock_customer(1)),
+                            vec![],
+                        ),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .update_default_address(&"1".to_string(), &"1".to_string())
+            .await;
+
+        let customer = result.expect("Failed to update default address");
+        assert!(customer.default_address().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_update_default_address_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerUpdateDefaultAddressData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo
+            .update_default_address(&"1".to_string(), &"1".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_request_data_erasure_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerRequestDataErasureData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerRequestDataErasureData {
+                        customer_request_data_erasure: CustomerRequestDataErasure {
+                            customer_id: Some("gid://shopify/Customer/1".to_string()),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.request_data_erasure(&"1".to_string()).await;
+
+        assert_eq!(result.expect("Failed to request data erasure"), "1");
+    }
+
+    #[tokio::test]
+    async fn test_request_data_erasure_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<CustomerRequestDataErasureData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(CustomerRequestDataErasureData {
+                        customer_request_data_erasure: CustomerRequestDataErasure {
+                            customer_id: None,
+                            user_errors: vec![UserError {
+                                field: vec!["customerId".to_string()],
+                                message: "Customer does not exist".to_string(),
+                            }],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = CustomerRepositoryImpl::new(client);
+
+        let result = repo.request_data_erasure(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<CustomerNode>();
+        assert_selection_matches::<EmailMarketingConsentNode>();
+        assert_selection_matches::<AddressNode>();
+        assert_selection_matches::<ImageNode>();
+        assert_selection_matches::<CustomerMutation>();
+        assert_selection_matches::<CustomerAddressMutation>();
+        assert_selection_matches::<CustomerAddressDelete>();
+        assert_selection_matches::<CustomerRequestDataErasure>();
+    }
 }
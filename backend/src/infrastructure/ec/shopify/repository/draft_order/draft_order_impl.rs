@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use serde_json::json;
 
 use crate::{
     domain::{
@@ -11,15 +11,19 @@ use crate::{
         ec::{
             ec_client_interface::ECClient,
             shopify::{
+                error::ShopifyError,
                 gql_helper::ShopifyGQLHelper,
+                query_builder::{Field, Operation},
                 repository::schema::{
                     draft_order::{DraftOrderData, DraftOrderNode, DraftOrdersData},
                     draft_order_input::{
-                        DraftOrderCompleteData, DraftOrderCreateData, DraftOrderDeleteData,
-                        DraftOrderDeleteInput, DraftOrderInput, DraftOrderUpdateData,
+                        DraftOrderComplete, DraftOrderCompleteData, DraftOrderCreate,
+                        DraftOrderCreateData, DraftOrderDelete, DraftOrderDeleteData,
+                        DraftOrderDeleteInput, DraftOrderInput, DraftOrderUpdate,
+                        DraftOrderUpdateData,
                     },
                 },
-                schema::GraphQLResponse,
+                schema::{Edges, GraphQLResponse},
             },
         },
         error::{InfrastructureError, InfrastructureErrorMapper},
@@ -37,113 +41,29 @@ impl<C: ECClient> DraftOrderRepositoryImpl<C> {
     pub fn new(client: C) -> Self {
         Self { client }
     }
-
-    fn draft_order_fields() -> String {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let address_fields = ShopifyGQLHelper::address_fields();
-        let money_bag_fields = ShopifyGQLHelper::money_bag_fields();
-        let owner_user_id_query = ShopifyGQLHelper::metafield_query("owner_user_id", "custom");
-
-        format!(
-            "id
-            name
-            status
-            customer {{
-                id
-            }}
-            billingAddress {{
-                {address_fields}
-            }}
-            shippingAddress {{
-                {address_fields}
-            }}
-            note2
-            lineItems({first_query}) {{
-                edges {{
-                    node {{
-                        id
-                        custom
-                        variant {{
-                            id
-                        }}
-                        quantity
-                        appliedDiscount {{
-                            title
-                            description
-                            value
-                            valueType
-                            amountSet {{
-                                {money_bag_fields}
-                            }}
-                        }}
-                        discountedTotalSet {{
-                            {money_bag_fields}
-                        }}
-                        originalTotalSet {{
-                            {money_bag_fields}
-                        }}
-                    }}
-                }}
-                {page_info}
-            }}
-            reserveInventoryUntil
-            appliedDiscount {{
-                title
-                description
-                value
-                valueType
-                amountSet {{
-                    {money_bag_fields}
-                }}
-            }}
-            subtotalPriceSet {{
-                {money_bag_fields}
-            }}
-            taxesIncluded
-            taxExempt
-            totalTaxSet {{
-                {money_bag_fields}
-            }}
-            totalDiscountsSet {{
-                {money_bag_fields}
-            }}
-            totalShippingPriceSet {{
-                {money_bag_fields}
-            }}
-            totalPriceSet {{
-                {money_bag_fields}
-            }}
-            presentmentCurrencyCode
-            order {{
-                id
-            }}
-            {owner_user_id_query}
-            completedAt
-            createdAt
-            updatedAt"
-        )
-    }
 }
 
 #[async_trait]
 impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImpl<C> {
     async fn find_draft_order_by_id(&self, id: &DraftOrderId) -> Result<DraftOrder, DomainError> {
         let id = ShopifyGQLHelper::add_draft_order_gid_prefix(id);
-        let draft_order_fields = Self::draft_order_fields();
-
-        let query = format!(
-            "query {{
-                draftOrder(id: \"{id}\") {{
-                    {draft_order_fields}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<DraftOrderData> = self.client.query(&query).await?;
+        let query = Operation::query("draftOrder")
+            .variable("id", "ID!")
+            .with(
+                Field::new("draftOrder")
+                    .var("id")
+                    .select_type::<DraftOrderNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<DraftOrderData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         DraftOrderNode::to_domain(
@@ -158,29 +78,31 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
         &self,
         customer_id: &CustomerId,
     ) -> Result<Vec<DraftOrder>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let draft_order_fields = Self::draft_order_fields();
-
         // TODO: Handling draft orders exceeding 250 for a customer.
         // The lineItem in the draft order shall not exceed 250.
-        let query = format!(
-            "query {{
-                draftOrders({first_query}, query: \"customer_id:{customer_id}\") {{
-                    edges {{
-                        node {{
-                            {draft_order_fields}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
+        let query = Operation::query("draftOrders")
+            .variable("query", "String!")
+            .with(
+                Field::new("draftOrders")
+                    .first()
+                    .var("query")
+                    .select_type::<Edges<DraftOrderNode>>(),
+            )
+            .build();
+
+        let search_query = format!(
+            "customer_id:{}",
+            ShopifyGQLHelper::escape_search_value(customer_id)
         );
 
-        let graphql_response: GraphQLResponse<DraftOrdersData> = self.client.query(&query).await?;
+        let graphql_response: GraphQLResponse<DraftOrdersData> = self
+            .client
+            .query_with_variables(&query, &json!({ "query": search_query }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let nodes: Vec<DraftOrderNode> = graphql_response
@@ -202,25 +124,21 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
             InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
         })?;
 
-        let draft_order_fields = Self::draft_order_fields();
-        let user_errors = ShopifyGQLHelper::user_errors();
-
-        let query = format!(
-            "mutation draftOrderCreate($input: DraftOrderInput!) {{
-                draftOrderCreate(input: $input) {{
-                    draftOrder {{
-                        {draft_order_fields}
-                    }}
-                    {user_errors}
-                }}
-            }}",
-        );
+        let query = Operation::mutation("draftOrderCreate")
+            .variable("input", "DraftOrderInput!")
+            .with(
+                Field::new("draftOrderCreate")
+                    .var("input")
+                    .select_type::<DraftOrderCreate>(),
+            )
+            .build();
 
         let graphql_response: GraphQLResponse<DraftOrderCreateData> =
             self.client.mutation(&query, &input).await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::SaveError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
         }
 
         let data = graphql_response
@@ -229,8 +147,9 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
             .draft_order_create;
 
         if !data.user_errors.is_empty() {
-            log_error!("UserErrors returned.", "userErrors" => user_errors);
-            return Err(DomainError::SaveError);
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
         }
 
         match data.draft_order {
@@ -243,97 +162,87 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
     }
 
     async fn update(&self, draft_order: DraftOrder) -> Result<DraftOrder, DomainError> {
-        let completed_at = draft_order.completed_at().clone();
-
-        match completed_at {
-            Some(completed_at) if completed_at == DateTime::<Utc>::default() => {
-                let id = ShopifyGQLHelper::add_draft_order_gid_prefix(draft_order.id());
-
-                let draft_order_fields = Self::draft_order_fields();
-                let user_errors = ShopifyGQLHelper::user_errors();
-
-                let query = format!(
-                    "mutation draftOrderComplete {{
-                        draftOrderComplete(id: \"{id}\") {{
-                            draftOrder {{
-                                {draft_order_fields}
-                            }}
-                            {user_errors}
-                        }}
-                    }}",
-                );
-
-                let graphql_response: GraphQLResponse<DraftOrderCompleteData> = self
-                    .client
-                    .mutation(&query, &serde_json::to_value("").unwrap())
-                    .await?;
-                if let Some(errors) = graphql_response.errors {
-                    log_error!("Error returned in GraphQL response.", "Response" => errors);
-                    return Err(DomainError::SaveError);
-                }
+        // Completion converts the draft order into an order on Shopify,
+        // so it must go through the dedicated mutation instead of a plain update.
+        if draft_order.is_pending_completion() {
+            let id = ShopifyGQLHelper::add_draft_order_gid_prefix(draft_order.id());
+
+            let query = Operation::mutation("draftOrderComplete")
+                .variable("id", "ID!")
+                .with(
+                    Field::new("draftOrderComplete")
+                        .var("id")
+                        .select_type::<DraftOrderComplete>(),
+                )
+                .build();
+
+            let graphql_response: GraphQLResponse<DraftOrderCompleteData> = self
+                .client
+                .mutation_with_variables(&query, &json!({ "id": id }))
+                .await?;
+            if let Some(errors) = graphql_response.errors {
+                log_error!("Error returned in GraphQL response.", "Response" => errors);
+                return Err(ShopifyError::from_graphql_errors(&errors)
+                    .into_domain_error(DomainError::SaveError));
+            }
 
-                let data = graphql_response
-                    .data
-                    .ok_or(DomainError::SaveError)?
-                    .draft_order_complete;
+            let data = graphql_response
+                .data
+                .ok_or(DomainError::SaveError)?
+                .draft_order_complete;
 
-                if !data.user_errors.is_empty() {
-                    log_error!("UserErrors returned.", "userErrors" => user_errors);
-                    return Err(DomainError::SaveError);
-                }
+            if !data.user_errors.is_empty() {
+                log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+                return Err(ShopifyError::from_user_errors(&data.user_errors)
+                    .into_domain_error(DomainError::SaveError));
+            }
 
-                match data.draft_order {
-                    Some(draft_order) => draft_order.to_domain(),
-                    None => {
-                        log_error!("No draft order returned.");
-                        Err(DomainError::SaveError)
-                    }
+            match data.draft_order {
+                Some(draft_order) => draft_order.to_domain(),
+                None => {
+                    log_error!("No draft order returned.");
+                    Err(DomainError::SaveError)
                 }
             }
-            _ => {
-                let input =
-                    serde_json::to_value(DraftOrderInput::from(draft_order)).map_err(|e| {
-                        log_error!("Failed to parse the request structure.", "error" => e);
-                        InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
-                    })?;
-
-                let draft_order_fields = Self::draft_order_fields();
-                let user_errors = ShopifyGQLHelper::user_errors();
-
-                let query = format!(
-                    "mutation draftOrderUpdate($input: DraftOrderInput!) {{
-                        draftOrderUpdate(input: $input) {{
-                            draftOrder {{
-                                {draft_order_fields}
-                            }}
-                            {user_errors}
-                        }}
-                    }}",
-                );
-
-                let graphql_response: GraphQLResponse<DraftOrderUpdateData> =
-                    self.client.mutation(&query, &input).await?;
-                if let Some(errors) = graphql_response.errors {
-                    log_error!("Error returned in GraphQL response.", "Response" => errors);
-                    return Err(DomainError::SaveError);
-                }
+        } else {
+            let input = serde_json::to_value(DraftOrderInput::from(draft_order)).map_err(|e| {
+                log_error!("Failed to parse the request structure.", "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
 
-                let data = graphql_response
-                    .data
-                    .ok_or(DomainError::SaveError)?
-                    .draft_order_update;
+            let query = Operation::mutation("draftOrderUpdate")
+                .variable("input", "DraftOrderInput!")
+                .with(
+                    Field::new("draftOrderUpdate")
+                        .var("input")
+                        .select_type::<DraftOrderUpdate>(),
+                )
+                .build();
+
+            let graphql_response: GraphQLResponse<DraftOrderUpdateData> =
+                self.client.mutation(&query, &input).await?;
+            if let Some(errors) = graphql_response.errors {
+                log_error!("Error returned in GraphQL response.", "Response" => errors);
+                return Err(ShopifyError::from_graphql_errors(&errors)
+                    .into_domain_error(DomainError::SaveError));
+            }
 
-                if !data.user_errors.is_empty() {
-                    log_error!("UserErrors returned.", "userErrors" => user_errors);
-                    return Err(DomainError::SaveError);
-                }
+            let data = graphql_response
+                .data
+                .ok_or(DomainError::SaveError)?
+                .draft_order_update;
+
+            if !data.user_errors.is_empty() {
+                log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+                return Err(ShopifyError::from_user_errors(&data.user_errors)
+                    .into_domain_error(DomainError::SaveError));
+            }
 
-                match data.draft_order {
-                    Some(draft_order) => draft_order.to_domain(),
-                    None => {
-                        log_error!("No draft order returned.");
-                        Err(DomainError::SaveError)
-                    }
+            match data.draft_order {
+                Some(draft_order) => draft_order.to_domain(),
+                None => {
+                    log_error!("No draft order returned.");
+                    Err(DomainError::SaveError)
                 }
             }
         }
@@ -346,22 +255,21 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
                 InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
             })?;
 
-        let user_errors = ShopifyGQLHelper::user_errors();
-
-        let query = format!(
-            "mutation draftOrderDelete($input: DraftOrderDeleteInput!) {{
-                draftOrderDelete(input: $input) {{
-                    deletedId
-                    {user_errors}
-                }}
-            }}",
-        );
+        let query = Operation::mutation("draftOrderDelete")
+            .variable("input", "DraftOrderDeleteInput!")
+            .with(
+                Field::new("draftOrderDelete")
+                    .var("input")
+                    .select_type::<DraftOrderDelete>(),
+            )
+            .build();
 
         let graphql_response: GraphQLResponse<DraftOrderDeleteData> =
             self.client.mutation(&query, &input).await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::DeleteError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::DeleteError));
         }
 
         let data = graphql_response
@@ -370,8 +278,9 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
             .draft_order_delete;
 
         if !data.user_errors.is_empty() {
-            log_error!("UserErrors returned.", "userErrors" => user_errors);
-            return Err(DomainError::DeleteError);
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::DeleteError));
         }
 
         match data.deleted_id {
@@ -386,13 +295,13 @@ impl<C: ECClient + Send + Sync> DraftOrderRepository for DraftOrderRepositoryImp
 
 #[cfg(test)]
 mod tests {
-    use chrono::{DateTime, Utc};
+    use chrono::Utc;
     use serde_json::Value;
 
     use crate::{
         domain::{
-            draft_order::draft_order::{DraftOrder, DraftOrderStatus},
-            error::error::DomainError,
+            draft_order::draft_order::{DraftOrder, DraftOrderParams, DraftOrderStatus},
+            error::error::{DomainError, FieldError},
             money::{
                 amount::amount::Amount,
                 money::{CurrencyCode, Money},
@@ -401,6 +310,7 @@ mod tests {
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::assert_selection_matches,
                 repository::{
                     draft_order::draft_order_impl::DraftOrderRepositoryImpl,
                     schema::{
@@ -464,6 +374,8 @@ mod tests {
             owner_user_id: Metafield {
                 value: "Owner".to_string(),
             },
+            cancel_reason: None,
+            canceled_at: None,
             completed_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -506,14 +418,17 @@ mod tests {
     fn mock_address_node(address1: Option<impl Into<String>>) -> Option<AddressNode> {
         let address1 = address1.map(|a| a.into());
         Some(AddressNode {
+            id: Some("gid://shopify/MailingAddress/1?model_name=CustomerAddress".to_string()),
             address1: address1,
             address2: Some("Apt 123".to_string()),
             city: Some("Test City".to_string()),
             coordinates_validated: true,
-            country: Some("Test Country".to_string()),
+            country: Some("Japan".to_string()),
+            country_code_v2: Some("JP".to_string()),
             first_name: Some("John".to_string()),
             last_name: Some("Doe".to_string()),
-            province: Some("Test Province".to_string()),
+            province: Some("Tokyo".to_string()),
+            province_code: Some("13".to_string()),
             zip: Some("12345".to_string()),
             phone: Some("+1234567890".to_string()),
         })
@@ -525,37 +440,41 @@ mod tests {
     }
 
     fn mock_draft_order_domain(completed: bool) -> DraftOrder {
-        let mut completed_at = None;
+        let mut draft_order = DraftOrder::new(DraftOrderParams {
+            id: "0".to_string(),
+            name: "Test Order".to_string(),
+            status: DraftOrderStatus::Open,
+            customer_id: None,
+            billing_address: None,
+            shipping_address: None,
+            note: None,
+            line_items: vec![],
+            reserve_inventory_until: None,
+            discount: None,
+            subtotal_price_set: mock_money_domain(),
+            taxes_included: true,
+            tax_exempt: false,
+            total_tax_set: mock_money_domain(),
+            total_discounts_set: mock_money_domain(),
+            total_shipping_price_set: mock_money_domain(),
+            total_price_set: mock_money_domain(),
+            presentment_currency_code: CurrencyCode::JPY,
+            order_id: None,
+            owner_user_id: "Owner".to_string(),
+            cancel_reason: None,
+            completed_at: None,
+            canceled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        })
+        .expect("Failed to create mock draft order domain");
+
         if completed {
-            completed_at = Some(DateTime::<Utc>::default());
+            draft_order
+                .complete()
+                .expect("Failed to complete mock draft order domain");
         }
-
-        DraftOrder::new(
-            "0",
-            "Test Order",
-            DraftOrderStatus::Open,
-            None,
-            None,
-            None,
-            None,
-            vec![],
-            None,
-            None,
-            mock_money_domain(),
-            true,
-            false,
-            mock_money_domain(),
-            mock_money_domain(),
-            mock_money_domain(),
-            mock_money_domain(),
-            CurrencyCode::JPY,
-            None,
-            "Owner".to_string(),
-            completed_at,
-            Utc::now(),
-            Utc::now(),
-        )
-        .expect("Failed to create mock draft order domain")
+        draft_order
     }
 
     fn mock_draft_order_response() -> GraphQLResponse<DraftOrderData> {
@@ -660,9 +579,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrderData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrderData>>()
             .times(1)
-            .return_once(|_| Ok(mock_draft_order_response()));
+            .return_once(|_, _| Ok(mock_draft_order_response()));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -690,14 +609,45 @@ mod tests {
         assert_eq!(draft_order.completed_at(), &None);
     }
 
+    #[tokio::test]
+    async fn test_find_draft_order_by_id_with_cancel_reason() {
+        let mut client = MockECClient::new();
+
+        let canceled_at = Utc::now();
+        let mut response = mock_draft_order_response();
+        let node = &mut response.data.as_mut().unwrap().draft_order;
+        node.cancel_reason = Some(Metafield {
+            value: "Customer request".to_string(),
+        });
+        node.canceled_at = Some(Metafield { value: canceled_at });
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(response));
+
+        let repo = DraftOrderRepositoryImpl::new(client);
+
+        let result = repo.find_draft_order_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let draft_order = result.unwrap();
+        assert_eq!(draft_order.status(), &DraftOrderStatus::Canceled);
+        assert_eq!(
+            draft_order.cancel_reason(),
+            &Some("Customer request".to_string())
+        );
+        assert_eq!(draft_order.canceled_at(), &Some(canceled_at));
+    }
+
     #[tokio::test]
     async fn test_find_draft_order_by_id_with_graphql_error() {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrderData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrderData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -716,9 +666,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrderData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrderData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -737,9 +687,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrdersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrdersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_draft_orders_response(1)));
+            .return_once(|_, _| Ok(mock_draft_orders_response(1)));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -779,9 +729,9 @@ mod tests {
             .status = "INVALID_STATUS".to_string();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrdersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrdersData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -802,9 +752,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrdersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrdersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -825,9 +775,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<DraftOrdersData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<DraftOrdersData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = DraftOrderRepositoryImpl::new(client);
 
@@ -883,12 +833,16 @@ mod tests {
 
         let result = repo.create(mock_draft_order_domain(false)).await;
 
-        assert!(result.is_err());
-        if let Err(DomainError::SaveError) = result {
-            // Test passed
-        } else {
-            panic!("Expected DomainError::SaveError, but got something else");
-        }
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("quantity".to_string()),
+                    None,
+                    "Quantity must be positive"
+                )]
+            }
+        );
     }
 
     #[tokio::test]
@@ -953,7 +907,7 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_mutation::<Value, GraphQLResponse<DraftOrderCompleteData>>()
+            .expect_mutation_with_variables::<Value, GraphQLResponse<DraftOrderCompleteData>>()
             .times(1)
             .return_once(|_, _| Ok(mock_draft_order_complete_response()));
 
@@ -964,6 +918,27 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_update_for_cancel_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<DraftOrderUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_draft_order_update_response()));
+
+        let repo = DraftOrderRepositoryImpl::new(client);
+
+        let mut draft_order = mock_draft_order_domain(false);
+        draft_order
+            .cancel("Customer request")
+            .expect("Failed to cancel mock draft order domain");
+
+        let result = repo.update(draft_order).await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_update_with_user_errors() {
         let mut client = MockECClient::new();
@@ -988,12 +963,16 @@ mod tests {
 
         let result = repo.update(mock_draft_order_domain(false)).await;
 
-        assert!(result.is_err());
-        if let Err(DomainError::SaveError) = result {
-            // Test passed
-        } else {
-            panic!("Expected DomainError::SaveError, but got something else");
-        }
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("quantity".to_string()),
+                    None,
+                    "Quantity must be positive"
+                )]
+            }
+        );
     }
 
     #[tokio::test]
@@ -1078,12 +1057,16 @@ mod tests {
 
         let result = repo.delete(mock_draft_order_domain(false)).await;
 
-        assert!(result.is_err());
-        if let Err(DomainError::DeleteError) = result {
-            // Test passed
-        } else {
-            panic!("Expected DomainError::DeleteError, but got something else");
-        }
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("quantity".to_string()),
+                    None,
+                    "Quantity must be positive"
+                )]
+            }
+        );
     }
 
     #[tokio::test]
@@ -1126,4 +1109,21 @@ mod tests {
             panic!("Expected DomainError::DeleteError, but got something else");
         }
     }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<DraftOrderNode>();
+        assert_selection_matches::<CustomerIdNode>();
+        assert_selection_matches::<OrderIdNode>();
+        assert_selection_matches::<AddressNode>();
+        assert_selection_matches::<LineItemNode>();
+        assert_selection_matches::<VariantIdNode>();
+        assert_selection_matches::<DiscountNode>();
+        assert_selection_matches::<MoneyBagNode>();
+        assert_selection_matches::<MoneyNode>();
+        assert_selection_matches::<DraftOrderCreate>();
+        assert_selection_matches::<DraftOrderUpdate>();
+        assert_selection_matches::<DraftOrderComplete>();
+        assert_selection_matches::<DraftOrderDelete>();
+    }
 }
@@ -0,0 +1,554 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment::fulfillment::{Fulfillment, Id as FulfillmentId},
+    },
+    infrastructure::{
+        ec::{
+            ec_client_interface::ECClient,
+            shopify::{
+                error::ShopifyError,
+                gql_helper::ShopifyGQLHelper,
+                query_builder::{Argument, Field, Operation},
+                repository::schema::{
+                    fulfillment::{FulfillmentData, FulfillmentNode},
+                    fulfillment_input::{
+                        FulfillmentCreate, FulfillmentCreateData, FulfillmentInput,
+                        FulfillmentTrackingInfoUpdate, FulfillmentTrackingInfoUpdateData,
+                        FulfillmentTrackingInput,
+                    },
+                },
+                schema::GraphQLResponse,
+            },
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::fulfillment_repository_interface::FulfillmentRepository,
+};
+
+/// Repository for Fulfillments for Shopify.
+pub struct FulfillmentRepositoryImpl<C: ECClient> {
+    client: C,
+}
+
+impl<C: ECClient> FulfillmentRepositoryImpl<C> {
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> FulfillmentRepository for FulfillmentRepositoryImpl<C> {
+    async fn find_fulfillment_by_id(&self, id: &FulfillmentId) -> Result<Fulfillment, DomainError> {
+        let id = ShopifyGQLHelper::add_fulfillment_gid_prefix(id);
+        let query = Operation::query("fulfillment")
+            .variable("id", "ID!")
+            .with(
+                Field::new("fulfillment")
+                    .var("id")
+                    .select_type::<FulfillmentNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<FulfillmentData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .fulfillment
+            .ok_or(DomainError::NotFound)?
+            .to_domain()
+    }
+
+    async fn create(&self, fulfillment: Fulfillment) -> Result<Fulfillment, DomainError> {
+        let input = serde_json::to_value(FulfillmentInput::from(fulfillment)).map_err(|e| {
+            log_error!("Failed to parse the request structure.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let query = Operation::mutation("fulfillmentCreate")
+            .variable("input", "FulfillmentInput!")
+            .with(
+                Field::new("fulfillmentCreate")
+                    .arg("fulfillment", Argument::Variable("input".to_string()))
+                    .select_type::<FulfillmentCreate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<FulfillmentCreateData> =
+            self.client.mutation(&query, &input).await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .fulfillment_create;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.fulfillment {
+            Some(fulfillment) => fulfillment.to_domain(),
+            None => {
+                log_error!("No fulfillment returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    async fn update(&self, fulfillment: Fulfillment) -> Result<Fulfillment, DomainError> {
+        // Only the tracking information of a fulfillment can be updated on Shopify.
+        let tracking_info = match fulfillment.tracking_info().first() {
+            Some(tracking_info) => tracking_info.to_owned(),
+            None => {
+                log_error!("No tracking info to update.", "id" => fulfillment.id());
+                return Err(DomainError::SaveError);
+            }
+        };
+        let input =
+            serde_json::to_value(FulfillmentTrackingInput::from(tracking_info)).map_err(|e| {
+                log_error!("Failed to parse the request structure.", "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
+
+        let id = ShopifyGQLHelper::add_fulfillment_gid_prefix(fulfillment.id());
+        let query = Operation::mutation("fulfillmentTrackingInfoUpdate")
+            .variable("fulfillmentId", "ID!")
+            .variable("input", "FulfillmentTrackingInput!")
+            .with(
+                Field::new("fulfillmentTrackingInfoUpdate")
+                    .var("fulfillmentId")
+                    .arg("trackingInfoInput", Argument::Variable("input".to_string()))
+                    .select_type::<FulfillmentTrackingInfoUpdate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<FulfillmentTrackingInfoUpdateData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "fulfillmentId": id, "input": input }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .fulfillment_tracking_info_update;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.fulfillment {
+            Some(fulfillment) => fulfillment.to_domain(),
+            None => {
+                log_error!("No fulfillment returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            error::error::DomainError,
+            fulfillment::{
+                fulfillment::{Fulfillment, FulfillmentStatus},
+                fulfillment_line_item::fulfillment_line_item::FulfillmentLineItem,
+                tracking_info::tracking_info::TrackingInfo,
+            },
+        },
+        infrastructure::ec::{
+            ec_client_interface::MockECClient,
+            shopify::{
+                query_builder::assert_selection_matches,
+                repository::{
+                    fulfillment::fulfillment_impl::FulfillmentRepositoryImpl,
+                    schema::{
+                        draft_order::OrderIdNode,
+                        fulfillment::{
+                            FulfillmentData, FulfillmentLineItemNode, FulfillmentNode,
+                            TrackingInfoNode,
+                        },
+                        fulfillment_input::{
+                            FulfillmentCreate, FulfillmentCreateData,
+                            FulfillmentTrackingInfoUpdate, FulfillmentTrackingInfoUpdateData,
+                        },
+                        fulfillment_order::LineItemIdNode,
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo, UserError},
+            },
+        },
+        usecase::repository::fulfillment_repository_interface::FulfillmentRepository,
+    };
+
+    fn mock_fulfillment_node(status: &str) -> FulfillmentNode {
+        FulfillmentNode {
+            id: "gid://shopify/Fulfillment/1".to_string(),
+            status: status.to_string(),
+            order: OrderIdNode {
+                id: "gid://shopify/Order/1".to_string(),
+            },
+            tracking_info: vec![
+                TrackingInfoNode {
+                    company: Some("UPS".to_string()),
+                    number: Some("123".to_string()),
+                    url: Some("https://example.com/123".to_string()),
+                },
+                TrackingInfoNode {
+                    company: None,
+                    number: None,
+                    url: None,
+                },
+            ],
+            fulfillment_line_items: Edges {
+                edges: vec![Node {
+                    node: FulfillmentLineItemNode {
+                        id: "gid://shopify/FulfillmentLineItem/1".to_string(),
+                        line_item: LineItemIdNode {
+                            id: "gid://shopify/LineItem/1".to_string(),
+                        },
+                        quantity: 2,
+                    },
+                }],
+                page_info: PageInfo {
+                    has_previous_page: false,
+                    has_next_page: false,
+                    start_cursor: None,
+                    end_cursor: None,
+                },
+            },
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn mock_fulfillment_domain() -> Fulfillment {
+        Fulfillment::new(
+            "1",
+            "1",
+            FulfillmentStatus::Success,
+            vec![FulfillmentLineItem::new(
+                "1",
+                "1",
+                Some("1".to_string()),
+                Some("1".to_string()),
+                2,
+            )
+            .unwrap()],
+            vec![TrackingInfo::new(Some("UPS"), "123", Some("https://example.com/123")).unwrap()],
+            Utc::now(),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    fn mock_create_response() -> GraphQLResponse<FulfillmentCreateData> {
+        GraphQLResponse {
+            data: Some(FulfillmentCreateData {
+                fulfillment_create: FulfillmentCreate {
+                    fulfillment: Some(mock_fulfillment_node("SUCCESS")),
+                    user_errors: vec![],
+                },
+            }),
+            errors: None,
+        }
+    }
+
+    fn mock_tracking_info_update_response() -> GraphQLResponse<FulfillmentTrackingInfoUpdateData> {
+        GraphQLResponse {
+            data: Some(FulfillmentTrackingInfoUpdateData {
+                fulfillment_tracking_info_update: FulfillmentTrackingInfoUpdate {
+                    fulfillment: Some(mock_fulfillment_node("SUCCESS")),
+                    user_errors: vec![],
+                },
+            }),
+            errors: None,
+        }
+    }
+
+    fn mock_graphql_error<T>() -> GraphQLResponse<T> {
+        GraphQLResponse {
+            data: None,
+            errors: Some(vec![GraphQLError {
+                message: "Some GraphQL error".to_string(),
+                extensions: None,
+            }]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_by_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<FulfillmentData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(FulfillmentData {
+                        fulfillment: Some(mock_fulfillment_node("SUCCESS")),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.find_fulfillment_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let fulfillment = result.unwrap();
+        assert_eq!(fulfillment.id(), "1");
+        assert_eq!(fulfillment.order_id(), "1");
+        assert_eq!(fulfillment.status(), &FulfillmentStatus::Success);
+        assert_eq!(fulfillment.line_items().len(), 1);
+        assert_eq!(fulfillment.line_items()[0].id(), "1");
+        assert_eq!(fulfillment.line_items()[0].line_item_id(), "1");
+        assert_eq!(fulfillment.line_items()[0].quantity(), &2);
+        // Tracking entries without a number are dropped.
+        assert_eq!(fulfillment.tracking_info().len(), 1);
+        assert_eq!(fulfillment.tracking_info()[0].number(), "123");
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_by_id_with_invalid_status() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<FulfillmentData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(FulfillmentData {
+                        fulfillment: Some(mock_fulfillment_node("INVALID")),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.find_fulfillment_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::ConversionError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_by_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<FulfillmentData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(FulfillmentData { fulfillment: None }),
+                    errors: None,
+                })
+            });
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.find_fulfillment_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_by_id_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<FulfillmentData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_graphql_error()));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.find_fulfillment_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<FulfillmentCreateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_create_response()));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.create(mock_fulfillment_domain()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().id(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        let mut response = mock_create_response();
+        response
+            .data
+            .as_mut()
+            .unwrap()
+            .fulfillment_create
+            .user_errors = vec![UserError {
+            field: vec!["quantity".to_string()],
+            message: "Invalid quantity".to_string(),
+        }];
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<FulfillmentCreateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(response));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.create(mock_fulfillment_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<FulfillmentCreateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_graphql_error()));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.create(mock_fulfillment_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_update_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<FulfillmentTrackingInfoUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_tracking_info_update_response()));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.update(mock_fulfillment_domain()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_without_tracking_info() {
+        let client = MockECClient::new();
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let fulfillment = Fulfillment::new(
+            "1",
+            "1",
+            FulfillmentStatus::Success,
+            vec![],
+            vec![],
+            Utc::now(),
+            Utc::now(),
+        )
+        .unwrap();
+        let result = repo.update(fulfillment).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_update_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        let mut response = mock_tracking_info_update_response();
+        response
+            .data
+            .as_mut()
+            .unwrap()
+            .fulfillment_tracking_info_update
+            .user_errors = vec![UserError {
+            field: vec!["trackingInfoInput".to_string()],
+            message: "Invalid tracking info".to_string(),
+        }];
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<FulfillmentTrackingInfoUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(response));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.update(mock_fulfillment_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_update_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<FulfillmentTrackingInfoUpdateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_graphql_error()));
+
+        let repo = FulfillmentRepositoryImpl::new(client);
+
+        let result = repo.update(mock_fulfillment_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<FulfillmentNode>();
+        assert_selection_matches::<FulfillmentLineItemNode>();
+        assert_selection_matches::<TrackingInfoNode>();
+        assert_selection_matches::<OrderIdNode>();
+        assert_selection_matches::<LineItemIdNode>();
+        assert_selection_matches::<FulfillmentCreate>();
+        assert_selection_matches::<FulfillmentTrackingInfoUpdate>();
+    }
+}
@@ -0,0 +1,272 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::{
+    domain::{
+        error::error::DomainError, fulfillment_order::fulfillment_order::FulfillmentOrder,
+        order::order::Id as OrderId,
+    },
+    infrastructure::ec::{
+        ec_client_interface::ECClient,
+        shopify::{
+            error::ShopifyError,
+            gql_helper::ShopifyGQLHelper,
+            query_builder::{Field, Operation},
+            repository::schema::fulfillment_order::{
+                FulfillmentOrderNode, OrderFulfillmentOrdersData, OrderFulfillmentOrdersNode,
+            },
+            schema::GraphQLResponse,
+        },
+    },
+    log_error,
+    usecase::repository::fulfillment_order_repository_interface::FulfillmentOrderRepository,
+};
+
+/// Repository for FulfillmentOrders for Shopify.
+pub struct FulfillmentOrderRepositoryImpl<C: ECClient> {
+    client: C,
+}
+
+impl<C: ECClient> FulfillmentOrderRepositoryImpl<C> {
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> FulfillmentOrderRepository for FulfillmentOrderRepositoryImpl<C> {
+    async fn find_fulfillment_orders_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<FulfillmentOrder>, DomainError> {
+        let id = ShopifyGQLHelper::add_order_gid_prefix(order_id);
+        let query = Operation::query("orderFulfillmentOrders")
+            .variable("id", "ID!")
+            .with(
+                Field::new("order")
+                    .var("id")
+                    .select_type::<OrderFulfillmentOrdersNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderFulfillmentOrdersData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        let nodes: Vec<FulfillmentOrderNode> = graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .order
+            .ok_or(DomainError::NotFound)?
+            .fulfillment_orders
+            .edges
+            .into_iter()
+            .map(|node| node.node)
+            .collect();
+
+        FulfillmentOrderNode::to_domains(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            error::error::DomainError, fulfillment_order::fulfillment_order::FulfillmentOrderStatus,
+        },
+        infrastructure::ec::{
+            ec_client_interface::MockECClient,
+            shopify::{
+                query_builder::assert_selection_matches,
+                repository::{
+                    fulfillment_order::fulfillment_order_impl::FulfillmentOrderRepositoryImpl,
+                    schema::fulfillment_order::{
+                        AssignedLocationNode, FulfillmentOrderLineItemNode, FulfillmentOrderNode,
+                        LineItemIdNode, LocationIdNode, OrderFulfillmentOrdersData,
+                        OrderFulfillmentOrdersNode,
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo},
+            },
+        },
+        usecase::repository::fulfillment_order_repository_interface::FulfillmentOrderRepository,
+    };
+
+    fn mock_page_info() -> PageInfo {
+        PageInfo {
+            has_previous_page: false,
+            has_next_page: false,
+            start_cursor: None,
+            end_cursor: None,
+        }
+    }
+
+    fn mock_fulfillment_order_node(id: u32, status: &str) -> FulfillmentOrderNode {
+        FulfillmentOrderNode {
+            id: format!("gid://shopify/FulfillmentOrder/{id}"),
+            order_id: "gid://shopify/Order/1".to_string(),
+            status: status.to_string(),
+            assigned_location: AssignedLocationNode {
+                location: Some(LocationIdNode {
+                    id: "gid://shopify/Location/1".to_string(),
+                }),
+            },
+            line_items: Edges {
+                edges: vec![Node {
+                    node: FulfillmentOrderLineItemNode {
+                        id: format!("gid://shopify/FulfillmentOrderLineItem/{id}"),
+                        line_item: LineItemIdNode {
+                            id: format!("gid://shopify/LineItem/{id}"),
+                        },
+                        total_quantity: 3,
+                        remaining_quantity: 2,
+                    },
+                }],
+                page_info: mock_page_info(),
+            },
+        }
+    }
+
+    fn mock_response(
+        nodes: Option<Vec<FulfillmentOrderNode>>,
+    ) -> GraphQLResponse<OrderFulfillmentOrdersData> {
+        GraphQLResponse {
+            data: Some(OrderFulfillmentOrdersData {
+                order: nodes.map(|nodes| OrderFulfillmentOrdersNode {
+                    fulfillment_orders: Edges {
+                        edges: nodes.into_iter().map(|node| Node { node }).collect(),
+                        page_info: mock_page_info(),
+                    },
+                }),
+            }),
+            errors: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_orders_by_order_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderFulfillmentOrdersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(mock_response(Some(vec![
+                    mock_fulfillment_order_node(1, "OPEN"),
+                    mock_fulfillment_order_node(2, "CLOSED"),
+                ])))
+            });
+
+        let repo = FulfillmentOrderRepositoryImpl::new(client);
+
+        let result = repo
+            .find_fulfillment_orders_by_order_id(&"1".to_string())
+            .await;
+
+        assert!(result.is_ok());
+        let fulfillment_orders = result.unwrap();
+        assert_eq!(fulfillment_orders.len(), 2);
+        assert_eq!(fulfillment_orders[0].id(), "1");
+        assert_eq!(fulfillment_orders[0].order_id(), "1");
+        assert_eq!(
+            fulfillment_orders[0].assigned_location_id(),
+            &Some("1".to_string())
+        );
+        assert_eq!(
+            fulfillment_orders[0].status(),
+            &FulfillmentOrderStatus::Open
+        );
+        assert_eq!(
+            fulfillment_orders[0].line_items()[0].remaining_quantity(),
+            &2
+        );
+        assert_eq!(
+            fulfillment_orders[1].status(),
+            &FulfillmentOrderStatus::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_orders_by_order_id_with_invalid_status() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderFulfillmentOrdersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(mock_response(Some(vec![mock_fulfillment_order_node(
+                    1, "INVALID",
+                )])))
+            });
+
+        let repo = FulfillmentOrderRepositoryImpl::new(client);
+
+        let result = repo
+            .find_fulfillment_orders_by_order_id(&"1".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::ConversionError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_orders_by_order_id_order_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderFulfillmentOrdersData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_response(None)));
+
+        let repo = FulfillmentOrderRepositoryImpl::new(client);
+
+        let result = repo
+            .find_fulfillment_orders_by_order_id(&"1".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_fulfillment_orders_by_order_id_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderFulfillmentOrdersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Some GraphQL error".to_string(),
+                        extensions: None,
+                    }]),
+                })
+            });
+
+        let repo = FulfillmentOrderRepositoryImpl::new(client);
+
+        let result = repo
+            .find_fulfillment_orders_by_order_id(&"1".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<OrderFulfillmentOrdersNode>();
+        assert_selection_matches::<FulfillmentOrderNode>();
+        assert_selection_matches::<AssignedLocationNode>();
+        assert_selection_matches::<LocationIdNode>();
+        assert_selection_matches::<FulfillmentOrderLineItemNode>();
+        assert_selection_matches::<LineItemIdNode>();
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::{
     domain::{
@@ -9,11 +10,14 @@ use crate::{
     infrastructure::ec::{
         ec_client_interface::ECClient,
         shopify::{
+            error::ShopifyError,
             gql_helper::ShopifyGQLHelper,
+            query_builder::{Field, Operation},
             repository::schema::inventory_item::{
-                InventoryItemNode, InventoryItemsData, VariantsDataForInventory,
+                InventoryItemNode, InventoryItemsData, VariantNodeForInventory,
+                VariantsDataForInventory,
             },
-            schema::GraphQLResponse,
+            schema::{Edges, GraphQLResponse},
         },
     },
     log_error,
@@ -37,37 +41,29 @@ impl<C: ECClient + Send + Sync> InventoryItemRepository for InventoryItemReposit
         &self,
         product_id: &ProductId,
     ) -> Result<Vec<InventoryItem>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-
-        let query = format!(
-            "query {{
-                productVariants({first_query}, query: \"product_id:'{product_id}'\") {{
-                    edges {{
-                        node {{
-                            id
-                            inventoryItem {{
-                                id
-                                variant {{
-                                    id
-                                }}
-                                requiresShipping
-                                tracked
-                                createdAt
-                                updatedAt
-                            }}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
+        let query = Operation::query("productVariants")
+            .variable("query", "String!")
+            .with(
+                Field::new("productVariants")
+                    .first()
+                    .var("query")
+                    .select_type::<Edges<VariantNodeForInventory>>(),
+            )
+            .build();
+
+        let search_query = format!(
+            "product_id:'{}'",
+            ShopifyGQLHelper::escape_search_value(product_id)
         );
 
-        let graphql_response: GraphQLResponse<VariantsDataForInventory> =
-            self.client.query(&query).await?;
+        let graphql_response: GraphQLResponse<VariantsDataForInventory> = self
+            .client
+            .query_with_variables(&query, &json!({ "query": search_query }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response. Response.", "errors" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         graphql_response
@@ -81,35 +77,29 @@ impl<C: ECClient + Send + Sync> InventoryItemRepository for InventoryItemReposit
     }
 
     async fn find_inventory_item_by_sku(&self, sku: &Sku) -> Result<InventoryItem, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
         let sku = sku.value();
 
-        let query = format!(
-            "query {{
-                inventoryItems({first_query}, query: \"sku:{sku}\") {{
-                    edges {{
-                        node {{
-                            id
-                            variant {{
-                                id
-                            }}
-                            requiresShipping
-                            tracked
-                            createdAt
-                            updatedAt
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<InventoryItemsData> =
-            self.client.query(&query).await?;
+        let query = Operation::query("inventoryItems")
+            .variable("query", "String!")
+            .with(
+                Field::new("inventoryItems")
+                    .first()
+                    .var("query")
+                    .select_type::<Edges<InventoryItemNode>>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<InventoryItemsData> = self
+            .client
+            .query_with_variables(
+                &query,
+                &json!({ "query": ShopifyGQLHelper::sku_search_query(sku) }),
+            )
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response. Response.", "errors" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let nodes: Vec<InventoryItemNode> = graphql_response
@@ -136,12 +126,16 @@ mod tests {
     use std::vec;
 
     use chrono::Utc;
+    use serde_json::Value;
 
     use crate::{
         domain::{error::error::DomainError, product::variant::sku::sku::Sku},
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::{
+                    assert_partial_selection_matches, assert_selection_matches, Selectable,
+                },
                 repository::{
                     inventory_item::inventory_item_impl::InventoryItemRepositoryImpl,
                     schema::{
@@ -279,9 +273,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsDataForInventory>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsDataForInventory>>()
             .times(1)
-            .return_once(|_| Ok(mock_inventories_response_for_variant_data(10)));
+            .return_once(|_, _| Ok(mock_inventories_response_for_variant_data(10)));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -315,9 +309,9 @@ mod tests {
             .id = "".to_string();
 
         client
-            .expect_query::<GraphQLResponse<VariantsDataForInventory>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsDataForInventory>>()
             .times(1)
-            .return_once(|_| Ok(invalid_variant));
+            .return_once(|_, _| Ok(invalid_variant));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -338,9 +332,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsDataForInventory>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsDataForInventory>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -361,9 +355,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsDataForInventory>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsDataForInventory>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -384,9 +378,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_inventories_response_for_inventory_items_data(1)));
+            .return_once(|_, _| Ok(mock_inventories_response_for_inventory_items_data(1)));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -405,9 +399,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -428,9 +422,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = InventoryItemRepositoryImpl::new(client);
 
@@ -445,4 +439,14 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<VariantNodeForInventory>();
+        assert_selection_matches::<VariantIdNode>();
+        assert_partial_selection_matches::<InventoryItemNode>(
+            &InventoryItemNode::selection(),
+            &["inventoryLevel", "inventoryLevels"],
+        );
+    }
 }
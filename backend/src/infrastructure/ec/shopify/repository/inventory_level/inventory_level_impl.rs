@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::{
     domain::{
@@ -13,15 +14,18 @@ use crate::{
         ec::{
             ec_client_interface::ECClient,
             shopify::{
+                error::ShopifyError,
                 gql_helper::ShopifyGQLHelper,
+                query_builder::{Argument, Field, Operation, Selection},
                 repository::schema::{
                     inventory_change::{
-                        InventoryAdjustQuantitiesData, InventoryAdjustQuantitiesInput,
+                        InventoryAdjustQuantities, InventoryAdjustQuantitiesData,
+                        InventoryAdjustQuantitiesInput,
                     },
-                    inventory_item::InventoryItemsData,
+                    inventory_item::{InventoryItemNode, InventoryItemsData},
                     inventory_level::InventoryLevelNode,
                 },
-                schema::GraphQLResponse,
+                schema::{GraphQLResponse, PageInfo},
             },
         },
         error::{InfrastructureError, InfrastructureErrorMapper},
@@ -36,29 +40,16 @@ pub struct InventoryLevelRepositoryImpl<C: ECClient> {
 }
 
 impl<C: ECClient> InventoryLevelRepositoryImpl<C> {
-    const SHOPIFY_ALL_INVENTORY_NAMES_FOR_QUERY: &'static str =
-        "[\"incoming\",\"available\",\"committed\",\"reserved\",\"damaged\",\"safety_stock\"]";
-
     pub fn new(client: C) -> Self {
         Self { client }
     }
 
-    fn inventory_level_fields() -> String {
-        let inventory_names = Self::SHOPIFY_ALL_INVENTORY_NAMES_FOR_QUERY;
-
-        format!(
-            "id
-            item {{
-                id
-            }}
-            location {{
-                id
-            }}
-            quantities(names: {inventory_names}) {{
-                name
-                quantity
-            }}"
-        )
+    /// Select the only InventoryItem of a SKU with the given fields.
+    fn inventory_item_by_sku(item_selection: Selection) -> Field {
+        Field::new("inventoryItems")
+            .arg("first", Argument::Int(1))
+            .var("query")
+            .select(Selection::edges(item_selection).object::<PageInfo>("pageInfo"))
     }
 }
 
@@ -69,40 +60,32 @@ impl<C: ECClient + Send + Sync> InventoryLevelRepository for InventoryLevelRepos
         sku: &Sku,
         location_id: &LocationId,
     ) -> Result<Option<InventoryLevel>, DomainError> {
-        let page_info = ShopifyGQLHelper::page_info();
-        let inventory_level_fields = Self::inventory_level_fields();
         let sku = sku.value();
         let location_id = ShopifyGQLHelper::add_location_gid_prefix(location_id);
 
         // Only one InventoryItem per SKU.
-        let query = format!(
-            "query {{
-                inventoryItems(first: 1, query: \"sku:{sku}\") {{
-                    edges {{
-                        node {{
-                            id
-                            variant {{
-                                id
-                            }}
-                            requiresShipping
-                            tracked
-                            createdAt
-                            updatedAt
-                            inventoryLevel(locationId: \"{location_id}\") {{
-                                {inventory_level_fields}
-                            }}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<InventoryItemsData> =
-            self.client.query(&query).await?;
+        let query = Operation::query("inventoryItems")
+            .variable("query", "String!")
+            .variable("locationId", "ID!")
+            .with(Self::inventory_item_by_sku(
+                InventoryItemNode::selection_with_level(),
+            ))
+            .build();
+
+        let graphql_response: GraphQLResponse<InventoryItemsData> = self
+            .client
+            .query_with_variables(
+                &query,
+                &json!({
+                    "query": ShopifyGQLHelper::sku_search_query(sku),
+                    "locationId": location_id,
+                }),
+            )
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let nodes: Vec<InventoryLevelNode> = graphql_response
@@ -130,50 +113,32 @@ impl<C: ECClient + Send + Sync> InventoryLevelRepository for InventoryLevelRepos
         let mut cursor = None;
         let mut all_nodes: Vec<InventoryLevelNode> = Vec::new();
 
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let inventory_level_fields = Self::inventory_level_fields();
         let sku = sku.value();
 
-        loop {
-            let after_query = cursor
-                .as_deref()
-                .map_or(String::new(), |a| format!("after: \"{}\"", a));
+        // Only one InventoryItem per SKU.
+        let query = Operation::query("inventoryItems")
+            .variable("query", "String!")
+            .variable("after", "String")
+            .with(Self::inventory_item_by_sku(
+                InventoryItemNode::selection_with_levels(),
+            ))
+            .build();
 
-            // Only one InventoryItem per SKU.
-            let query = format!(
-                "query {{
-                    inventoryItems(first: 1, query: \"sku:{sku}\") {{
-                        edges {{
-                            node {{
-                                id
-                                variant {{
-                                    id
-                                }}
-                                requiresShipping
-                                tracked
-                                createdAt
-                                updatedAt
-                                inventoryLevels({first_query} {after_query}) {{
-                                    edges {{
-                                        node {{
-                                            {inventory_level_fields}
-                                        }}
-                                    }}
-                                    {page_info}
-                                }}
-                            }}
-                        }}
-                        {page_info}
-                    }}
-                }}"
-            );
-
-            let graphql_response: GraphQLResponse<InventoryItemsData> =
-                self.client.query(&query).await?;
+        loop {
+            let graphql_response: GraphQLResponse<InventoryItemsData> = self
+                .client
+                .query_with_variables(
+                    &query,
+                    &json!({
+                        "query": ShopifyGQLHelper::sku_search_query(sku),
+                        "after": cursor,
+                    }),
+                )
+                .await?;
             if let Some(errors) = graphql_response.errors {
                 log_error!("Error returned in GraphQL response.", "Response" => errors);
-                return Err(DomainError::QueryError);
+                return Err(ShopifyError::from_graphql_errors(&errors)
+                    .into_domain_error(DomainError::QueryError));
             }
 
             let mut item_data = graphql_response
@@ -230,40 +195,32 @@ impl<C: ECClient + Send + Sync> InventoryLevelRepository for InventoryLevelRepos
             InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
         })?;
 
-        let user_errors = ShopifyGQLHelper::user_errors();
-        let inventory_level_fields = Self::inventory_level_fields();
-
-        // NOTE: By specifying quantityNames, only the results of the specified name will be responded to, so that the results acquired in the inventoryLevels field will not be duplicated.
-        let query = format!(
-            "mutation inventoryAdjustQuantities($input: InventoryAdjustQuantitiesInput!) {{
-                inventoryAdjustQuantities(input: $input) {{
-                    inventoryAdjustmentGroup {{
-                        changes(quantityNames: \"{quantity_name}\") {{
-                            item {{
-                                id
-                                variant {{
-                                    id
-                                }}
-                                requiresShipping
-                                tracked
-                                createdAt
-                                updatedAt
-                                inventoryLevel(locationId: \"{location_id}\") {{
-                                    {inventory_level_fields}
-                                }}
-                            }}
-                        }}
-                    }}
-                    {user_errors}
-                }}
-            }}",
-        );
-
-        let graphql_response: GraphQLResponse<InventoryAdjustQuantitiesData> =
-            self.client.mutation(&query, &input).await?;
+        let query = Operation::mutation("inventoryAdjustQuantities")
+            .variable("input", "InventoryAdjustQuantitiesInput!")
+            .variable("quantityNames", "[String!]")
+            .variable("locationId", "ID!")
+            .with(
+                Field::new("inventoryAdjustQuantities")
+                    .var("input")
+                    .select_type::<InventoryAdjustQuantities>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<InventoryAdjustQuantitiesData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({
+                    "input": input,
+                    "quantityNames": [quantity_name],
+                    "locationId": location_id,
+                }),
+            )
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::SaveError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
         }
 
         let data = graphql_response
@@ -273,7 +230,8 @@ impl<C: ECClient + Send + Sync> InventoryLevelRepository for InventoryLevelRepos
 
         if !data.user_errors.is_empty() {
             log_error!("UserErrors returned.", "userErrors" => data.user_errors);
-            return Err(DomainError::SaveError);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
         }
 
         match data.inventory_adjustment_group {
@@ -295,7 +253,7 @@ mod tests {
 
     use crate::{
         domain::{
-            error::error::DomainError,
+            error::error::{DomainError, FieldError},
             inventory_level::{
                 inventory_change::{
                     change::{
@@ -310,6 +268,7 @@ mod tests {
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::{assert_partial_selection_matches, assert_selection_matches},
                 repository::{
                     inventory_level::inventory_level_impl::InventoryLevelRepositoryImpl,
                     schema::{
@@ -480,9 +439,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_inventory_items_response(
                     1,
                     PageOption {
@@ -547,9 +506,9 @@ mod tests {
             .name = "invalid".to_string();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = InventoryLevelRepositoryImpl::new(client);
 
@@ -573,9 +532,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = InventoryLevelRepositoryImpl::new(client);
 
@@ -599,9 +558,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = InventoryLevelRepositoryImpl::new(client);
 
@@ -625,9 +584,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_inventory_items_response(
                     1,
                     PageOption {
@@ -684,9 +643,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_inventory_items_response(
                     1,
                     PageOption {
@@ -697,9 +656,9 @@ mod tests {
                 ))
             });
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_inventory_items_response(
                     1,
                     PageOption {
@@ -730,9 +689,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_inventory_items_response(
                     1,
                     PageOption {
@@ -759,9 +718,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = InventoryLevelRepositoryImpl::new(client);
 
@@ -782,9 +741,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<InventoryItemsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<InventoryItemsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = InventoryLevelRepositoryImpl::new(client);
 
@@ -805,7 +764,7 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_mutation::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
+            .expect_mutation_with_variables::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
             .times(1)
             .return_once(|_, _| Ok(mock_inventory_adjust_quantities_response()));
 
@@ -832,7 +791,7 @@ mod tests {
         }];
 
         client
-            .expect_mutation::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
+            .expect_mutation_with_variables::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
             .times(1)
             .return_once(|_, _| Ok(response));
 
@@ -840,12 +799,16 @@ mod tests {
 
         let result = repo.update(mock_inventory_change_domain()).await;
 
-        assert!(result.is_err());
-        if let Err(DomainError::SaveError) = result {
-            // Test passed
-        } else {
-            panic!("Expected DomainError::SaveError, but got something else");
-        }
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("quantity".to_string()),
+                    None,
+                    "Quantity must be positive"
+                )]
+            }
+        );
     }
 
     #[tokio::test]
@@ -853,7 +816,7 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_mutation::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
+            .expect_mutation_with_variables::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
             .times(1)
             .return_once(|_, _| Ok(mock_with_error()));
 
@@ -874,7 +837,7 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_mutation::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
+            .expect_mutation_with_variables::<Value, GraphQLResponse<InventoryAdjustQuantitiesData>>()
             .times(1)
             .return_once(|_, _| Ok(mock_with_no_data()));
 
@@ -889,4 +852,23 @@ mod tests {
             panic!("Expected DomainError::SaveError, but got something else");
         }
     }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<InventoryLevelNode>();
+        assert_selection_matches::<QuantityNode>();
+        assert_selection_matches::<LocationIdNode>();
+        assert_selection_matches::<InventoryItemIdNode>();
+        assert_selection_matches::<InventoryAdjustQuantities>();
+        assert_selection_matches::<InventoryAdjustmentGroupNode>();
+        assert_selection_matches::<InventoryChangeNode>();
+        assert_partial_selection_matches::<InventoryItemNode>(
+            &InventoryItemNode::selection_with_level(),
+            &["inventoryLevels"],
+        );
+        assert_partial_selection_matches::<InventoryItemNode>(
+            &InventoryItemNode::selection_with_levels(),
+            &["inventoryLevel"],
+        );
+    }
 }
@@ -1,17 +1,30 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::{
     domain::{
-        error::error::DomainError,
+        error::error::{DomainError, FieldError},
         location::location::{Id as LocationId, Location},
     },
-    infrastructure::ec::{
-        ec_client_interface::ECClient,
-        shopify::{
-            gql_helper::ShopifyGQLHelper,
-            repository::schema::location::{LocationNode, LocationsData},
-            schema::GraphQLResponse,
+    infrastructure::{
+        ec::{
+            ec_client_interface::ECClient,
+            shopify::{
+                error::ShopifyError,
+                gql_helper::ShopifyGQLHelper,
+                query_builder::{Field, Operation},
+                repository::schema::{
+                    location::{LocationData, LocationNode, LocationsData},
+                    location_input::{
+                        LocationActivate, LocationActivateData, LocationAddData, LocationAddInput,
+                        LocationDeactivate, LocationDeactivateData, LocationEditData,
+                        LocationEditInput, LocationMutation, LocationUserError,
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse},
+            },
         },
+        error::{InfrastructureError, InfrastructureErrorMapper},
     },
     log_debug, log_error,
     usecase::repository::location_repository_interface::LocationRepository,
@@ -23,56 +36,69 @@ pub struct LocationRepositoryImpl<C: ECClient> {
 }
 
 impl<C: ECClient> LocationRepositoryImpl<C> {
+    /// Error code returned by Shopify when a location to be deactivated still stocks inventory.
+    const HAS_ACTIVE_INVENTORY_ERROR: &'static str = "HAS_ACTIVE_INVENTORY_ERROR";
+
     pub fn new(client: C) -> Self {
         Self { client }
     }
 
-    fn location_address_fields() -> String {
-        "address1
-        address2
-        city
-        country
-        province
-        zip"
-        .to_string()
+    fn to_saved_location(
+        errors: Option<Vec<GraphQLError>>,
+        location: Option<LocationNode>,
+        user_errors: Vec<LocationUserError>,
+    ) -> Result<Location, DomainError> {
+        if let Some(errors) = errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        if !user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => user_errors);
+            return Err(ShopifyError::from_user_errors(&user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match location {
+            Some(location) => location.to_domain(),
+            None => {
+                log_error!("No location returned.");
+                Err(DomainError::SaveError)
+            }
+        }
     }
 }
 
 #[async_trait]
 impl<C: ECClient + Send + Sync> LocationRepository for LocationRepositoryImpl<C> {
-    async fn find_all_location_ids(&self) -> Result<Vec<LocationId>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-
-        let query = format!(
-            "query {{
-                locations({first_query}) {{
-                    edges {{
-                        node {{
-                            id
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<LocationsData> = self.client.query(&query).await?;
+    async fn find_location_by_id(&self, id: &LocationId) -> Result<Location, DomainError> {
+        let id = ShopifyGQLHelper::add_location_gid_prefix(id);
+        let query = Operation::query("location")
+            .variable("id", "ID!")
+            .with(
+                Field::new("location")
+                    .var("id")
+                    .select_type::<LocationNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<LocationData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response.", "Response" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
-        let ids: Vec<LocationId> = graphql_response
+        graphql_response
             .data
-            .ok_or(DomainError::QueryError)?
-            .locations
-            .edges
-            .into_iter()
-            .map(|node| node.node.id)
-            .collect();
-
-        Ok(ids)
+            .ok_or(DomainError::NotFound)?
+            .location
+            .ok_or(DomainError::NotFound)?
+            .to_domain()
     }
 
     async fn find_locations(
@@ -88,42 +114,25 @@ impl<C: ECClient + Send + Sync> LocationRepository for LocationRepositoryImpl<C>
         let mut cursor = None;
         let mut all_nodes: Vec<LocationNode> = Vec::new();
 
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let location_address_fields = Self::location_address_fields();
+        let query = Operation::query("locations")
+            .variable("after", "String")
+            .with(
+                Field::new("locations")
+                    .first()
+                    .var("after")
+                    .select_type::<Edges<LocationNode>>(),
+            )
+            .build();
 
         for i in 0..((limit + offset) / query_limit).max(1) {
-            let after_query = cursor
-                .as_deref()
-                .map_or(String::new(), |a| format!("after: \"{}\"", a));
-
-            let query = format!(
-                "query {{
-                    locations({first_query}, {after_query}) {{
-                        edges {{
-                            node {{
-                                id
-                                name
-                                isActive
-                                fulfillsOnlineOrders
-                                address {{
-                                    {location_address_fields}
-                                }}
-                                suggestedAddresses {{
-                                    {location_address_fields}
-                                }}
-                            }}
-                        }}
-                        {page_info}
-                    }}
-                }}"
-            );
-
-            let graphql_response: GraphQLResponse<LocationsData> =
-                self.client.query(&query).await?;
+            let graphql_response: GraphQLResponse<LocationsData> = self
+                .client
+                .query_with_variables(&query, &json!({ "after": cursor }))
+                .await?;
             if let Some(errors) = graphql_response.errors {
                 log_error!("Error returned in GraphQL response.", "Response" => errors);
-                return Err(DomainError::QueryError);
+                return Err(ShopifyError::from_graphql_errors(&errors)
+                    .into_domain_error(DomainError::QueryError));
             }
 
             let data = graphql_response
@@ -174,18 +183,183 @@ impl<C: ECClient + Send + Sync> LocationRepository for LocationRepositoryImpl<C>
             .take(end - start)
             .collect::<Vec<_>>())
     }
+
+    async fn create(&self, location: Location) -> Result<Location, DomainError> {
+        let input = serde_json::to_value(LocationAddInput::from(&location)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let query = Operation::mutation("locationAdd")
+            .variable("input", "LocationAddInput!")
+            .with(
+                Field::new("locationAdd")
+                    .var("input")
+                    .select_type::<LocationMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<LocationAddData> =
+            self.client.mutation(&query, &input).await?;
+
+        let data = graphql_response.data.map(|d| d.location_add);
+        match data {
+            Some(data) => {
+                Self::to_saved_location(graphql_response.errors, data.location, data.user_errors)
+            }
+            None => Self::to_saved_location(graphql_response.errors, None, vec![]),
+        }
+    }
+
+    async fn update(&self, location: Location) -> Result<Location, DomainError> {
+        let id = ShopifyGQLHelper::add_location_gid_prefix(location.id());
+        let input = serde_json::to_value(LocationEditInput::from(&location)).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let query = Operation::mutation("locationEdit")
+            .variable("id", "ID!")
+            .variable("input", "LocationEditInput!")
+            .with(
+                Field::new("locationEdit")
+                    .var("id")
+                    .var("input")
+                    .select_type::<LocationMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<LocationEditData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "id": id, "input": input }))
+            .await?;
+
+        let data = graphql_response.data.map(|d| d.location_edit);
+        match data {
+            Some(data) => {
+                Self::to_saved_location(graphql_response.errors, data.location, data.user_errors)
+            }
+            None => Self::to_saved_location(graphql_response.errors, None, vec![]),
+        }
+    }
+
+    async fn deactivate(
+        &self,
+        id: &LocationId,
+        destination_location_id: &Option<LocationId>,
+    ) -> Result<Location, DomainError> {
+        let location_id = ShopifyGQLHelper::add_location_gid_prefix(id);
+        let destination_location_id = destination_location_id
+            .as_deref()
+            .map(ShopifyGQLHelper::add_location_gid_prefix);
+
+        let query = Operation::mutation("locationDeactivate")
+            .variable("locationId", "ID!")
+            .variable("destinationLocationId", "ID")
+            .with(
+                Field::new("locationDeactivate")
+                    .var("locationId")
+                    .var("destinationLocationId")
+                    .select_type::<LocationDeactivate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<LocationDeactivateData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({
+                    "locationId": location_id,
+                    "destinationLocationId": destination_location_id,
+                }),
+            )
+            .await?;
+
+        let data = graphql_response.data.map(|d| d.location_deactivate);
+        match data {
+            Some(data) => {
+                if data
+                    .location_deactivate_user_errors
+                    .iter()
+                    .any(|e| e.code.as_deref() == Some(Self::HAS_ACTIVE_INVENTORY_ERROR))
+                {
+                    log_error!(
+                        "Location still has inventory.",
+                        "id" => id,
+                        "userErrors" => data.location_deactivate_user_errors
+                    );
+                    return Err(DomainError::InvalidInput {
+                        errors: vec![FieldError::new(
+                            Some("destination_location_id".to_string()),
+                            Some(Self::HAS_ACTIVE_INVENTORY_ERROR.to_string()),
+                            "The location still has inventory. Specify a destination location to transfer it to.",
+                        )],
+                    });
+                }
+
+                Self::to_saved_location(
+                    graphql_response.errors,
+                    data.location,
+                    data.location_deactivate_user_errors,
+                )
+            }
+            None => Self::to_saved_location(graphql_response.errors, None, vec![]),
+        }
+    }
+
+    async fn activate(&self, id: &LocationId) -> Result<Location, DomainError> {
+        let location_id = ShopifyGQLHelper::add_location_gid_prefix(id);
+
+        let query = Operation::mutation("locationActivate")
+            .variable("locationId", "ID!")
+            .with(
+                Field::new("locationActivate")
+                    .var("locationId")
+                    .select_type::<LocationActivate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<LocationActivateData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "locationId": location_id }))
+            .await?;
+
+        let data = graphql_response.data.map(|d| d.location_activate);
+        match data {
+            Some(data) => Self::to_saved_location(
+                graphql_response.errors,
+                data.location,
+                data.location_activate_user_errors,
+            ),
+            None => Self::to_saved_location(graphql_response.errors, None, vec![]),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::Value;
+
     use crate::{
-        domain::error::error::DomainError,
+        domain::{
+            address::address::{Address, AddressParams},
+            error::error::{DomainError, FieldError},
+            location::location::Location,
+        },
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::assert_selection_matches,
                 repository::{
                     location::location_impl::LocationRepositoryImpl,
-                    schema::location::{LocationAddressNode, LocationNode, LocationsData},
+                    schema::{
+                        location::{
+                            LocationAddressNode, LocationData, LocationNode, LocationsData,
+                        },
+                        location_input::{
+                            LocationActivate, LocationActivateData, LocationAddData,
+                            LocationDeactivate, LocationDeactivateData, LocationEditData,
+                            LocationMutation, LocationUserError,
+                        },
+                    },
                 },
                 schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo},
             },
@@ -210,12 +384,40 @@ mod tests {
             address1: address1,
             address2: Some("Apt 123".to_string()),
             city: Some("Test City".to_string()),
-            country: Some("Test Country".to_string()),
-            province: Some("Test Province".to_string()),
+            country: Some("Japan".to_string()),
+            country_code: Some("JP".to_string()),
+            province: Some("Tokyo".to_string()),
+            province_code: Some("13".to_string()),
             zip: Some("12345".to_string()),
+            latitude: Some(35.6812),
+            longitude: Some(139.7671),
+        }
+    }
+
+    fn mock_location_user_error(code: &str) -> LocationUserError {
+        LocationUserError {
+            code: Some(code.to_string()),
+            field: Some(vec!["locationId".to_string()]),
+            message: "Some user error".to_string(),
         }
     }
 
+    fn mock_location() -> Location {
+        let address = Address::new(AddressParams {
+            address1: Some("123 Main St".to_string()),
+            city: Some("Test City".to_string()),
+            coordinates_validated: true,
+            country: Some("Japan".to_string()),
+            country_code: Some("JP".to_string()),
+            province: Some("Tokyo".to_string()),
+            province_code: Some("13".to_string()),
+            zip: Some("12345".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+        Location::create("Some location", address, true).unwrap()
+    }
+
     struct PageOption {
         start: usize,
         end: usize,
@@ -271,9 +473,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_locations_response(PageOption {
                     start: 0,
                     end: 250,
@@ -315,9 +517,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_locations_response(PageOption {
                     start: 0,
                     end: 250,
@@ -359,9 +561,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_locations_response(PageOption {
                     start: 0,
                     end: 250,
@@ -369,9 +571,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_locations_response(PageOption {
                     start: 250,
                     end: 500,
@@ -397,9 +599,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_locations_response(PageOption {
                     start: 0,
                     end: 0,
@@ -421,9 +623,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = LocationRepositoryImpl::new(client);
 
@@ -442,9 +644,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<LocationsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = LocationRepositoryImpl::new(client);
 
@@ -457,4 +659,319 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    #[tokio::test]
+    async fn test_find_location_by_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationData {
+                        location: Some(mock_location_node(1)),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let location = repo
+            .find_location_by_id(&"1".to_string())
+            .await
+            .expect("Failed to find location");
+
+        assert_eq!(location.id(), "1");
+        assert_eq!(location.name(), "Some location");
+        assert_eq!(
+            location
+                .address()
+                .coordinates()
+                .as_ref()
+                .unwrap()
+                .latitude(),
+            &35.6812
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_location_by_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<LocationData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationData { location: None }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.find_location_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<LocationAddData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationAddData {
+                        location_add: LocationMutation {
+                            location: Some(mock_location_node(1)),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.create(mock_location()).await;
+
+        assert_eq!(result.expect("Failed to create location").id(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<LocationAddData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationAddData {
+                        location_add: LocationMutation {
+                            location: None,
+                            user_errors: vec![mock_location_user_error("TAKEN")],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.create(mock_location()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("location_id".to_string()),
+                    Some("TAKEN".to_string()),
+                    "Some user error"
+                )]
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationEditData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationEditData {
+                        location_edit: LocationMutation {
+                            location: Some(mock_location_node(1)),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo
+            .update(mock_location_node(1).to_domain().unwrap())
+            .await;
+
+        assert_eq!(result.expect("Failed to update location").id(), "1");
+    }
+
+    #[tokio::test]
+    async fn test_update_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationEditData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo
+            .update(mock_location_node(1).to_domain().unwrap())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationDeactivateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                let mut node = mock_location_node(1);
+                node.is_active = false;
+                Ok(GraphQLResponse {
+                    data: Some(LocationDeactivateData {
+                        location_deactivate: LocationDeactivate {
+                            location: Some(node),
+                            location_deactivate_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo
+            .deactivate(&"1".to_string(), &Some("2".to_string()))
+            .await;
+
+        assert!(!result.expect("Failed to deactivate location").is_active());
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_with_remaining_inventory() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationDeactivateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationDeactivateData {
+                        location_deactivate: LocationDeactivate {
+                            location: None,
+                            location_deactivate_user_errors: vec![mock_location_user_error(
+                                "HAS_ACTIVE_INVENTORY_ERROR",
+                            )],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.deactivate(&"1".to_string(), &None).await;
+
+        match result {
+            Err(DomainError::InvalidInput { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(
+                    errors[0].field(),
+                    &Some("destination_location_id".to_string())
+                );
+                assert_eq!(
+                    errors[0].code(),
+                    &Some("HAS_ACTIVE_INVENTORY_ERROR".to_string())
+                );
+            }
+            _ => panic!("Expected DomainError::InvalidInput"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deactivate_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationDeactivateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationDeactivateData {
+                        location_deactivate: LocationDeactivate {
+                            location: None,
+                            location_deactivate_user_errors: vec![mock_location_user_error(
+                                "LOCATION_NOT_FOUND",
+                            )],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.deactivate(&"1".to_string(), &None).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_activate_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationActivateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(LocationActivateData {
+                        location_activate: LocationActivate {
+                            location: Some(mock_location_node(1)),
+                            location_activate_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.activate(&"1".to_string()).await;
+
+        assert!(result.expect("Failed to activate location").is_active());
+    }
+
+    #[tokio::test]
+    async fn test_activate_with_missing_data() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<LocationActivateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_no_data()));
+
+        let repo = LocationRepositoryImpl::new(client);
+
+        let result = repo.activate(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<LocationNode>();
+        assert_selection_matches::<LocationAddressNode>();
+        assert_selection_matches::<LocationMutation>();
+        assert_selection_matches::<LocationDeactivate>();
+        assert_selection_matches::<LocationActivate>();
+        assert_selection_matches::<LocationUserError>();
+    }
 }
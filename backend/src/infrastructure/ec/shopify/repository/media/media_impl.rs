@@ -1,19 +1,34 @@
 use async_trait::async_trait;
-use serde_json::Value;
+use serde_json::{json, Value};
 
 use crate::{
     domain::{
         error::error::DomainError,
-        media::{associated_id::associated_id::AssociatedId, media::Media},
+        media::{
+            associated_id::associated_id::AssociatedId,
+            media::{Id as MediaId, Media},
+            staged_upload::staged_upload::{StagedUpload, StagedUploadTarget},
+        },
         product::product::Id as ProductId,
     },
     infrastructure::{
         ec::{
             ec_client_interface::ECClient,
             shopify::{
+                error::ShopifyError,
                 gql_helper::ShopifyGQLHelper,
-                repository::schema::media::{MediaData, MediaNode},
-                schema::GraphQLResponse,
+                query_builder::{Argument, Field, Operation},
+                repository::schema::{
+                    media::{MediaNode, ProductMediaData, ProductMediaNode},
+                    media_input::{
+                        CreateMediaInput, MediaUserError, MoveInput, ProductCreateMediaData,
+                        ProductDeleteMedia, ProductDeleteMediaData, ProductMediaMutation,
+                        ProductReorderMedia, ProductReorderMediaData, ProductUpdateMediaData,
+                        StagedUploadInput, StagedUploadsCreate, StagedUploadsCreateData,
+                        UpdateMediaInput,
+                    },
+                },
+                schema::{GraphQLError, GraphQLResponse},
             },
         },
         error::{InfrastructureError, InfrastructureErrorMapper},
@@ -32,64 +47,71 @@ impl<C: ECClient> MediaRepositoryImpl<C> {
         Self { client }
     }
 
-    pub fn image_fields() -> String {
-        "altText
-        height
-        id
-        url
-        width"
-            .to_string()
-    }
+    fn verify_saved(
+        errors: Option<Vec<GraphQLError>>,
+        user_errors: &[MediaUserError],
+    ) -> Result<(), DomainError> {
+        if let Some(errors) = errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
 
-    fn media_fields() -> String {
-        let image_fields = Self::image_fields();
+        if !user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => user_errors);
+            return Err(ShopifyError::from_user_errors(&user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+        Ok(())
+    }
 
-        format!(
-            "id
-            fileStatus
-            alt
-            preview {{
-                image {{
-                    {image_fields}
-                }}
-            }}
-            createdAt
-            updatedAt"
-        )
+    fn to_saved_media(
+        errors: Option<Vec<GraphQLError>>,
+        product_id: &ProductId,
+        media: Option<Vec<MediaNode>>,
+        user_errors: Vec<MediaUserError>,
+    ) -> Result<Media, DomainError> {
+        Self::verify_saved(errors, &user_errors)?;
+
+        match media.and_then(|media| media.into_iter().next()) {
+            Some(media) => media.to_domain(Some(AssociatedId::Product(product_id.to_string()))),
+            None => {
+                log_error!("No media returned.");
+                Err(DomainError::SaveError)
+            }
+        }
     }
 }
 
 #[async_trait]
 impl<C: ECClient + Send + Sync> MediaRepository for MediaRepositoryImpl<C> {
     async fn find_media_by_product_id(&self, id: &ProductId) -> Result<Vec<Media>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let media_fields = Self::media_fields();
-
-        // The number of media associated with a single product shall not exceed 250.
-        let query = format!(
-            "query {{
-                files({first_query}, query: \"product_id:'{id}'\") {{
-                    edges {{
-                        node {{
-                            {media_fields}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<MediaData> = self.client.query(&query).await?;
+        let gid = ShopifyGQLHelper::add_product_gid_prefix(id);
+        let query = Operation::query("productMedia")
+            .variable("id", "ID!")
+            .with(
+                Field::new("product")
+                    .var("id")
+                    .select_type::<ProductMediaNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<ProductMediaData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": gid }))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response. Response.", "errors" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let media_domains: Result<Vec<Media>, DomainError> = graphql_response
             .data
             .ok_or(DomainError::QueryError)?
-            .files
+            .product
+            .ok_or(DomainError::NotFound)?
+            .media
             .edges
             .into_iter()
             .map(|node| {
@@ -105,32 +127,32 @@ impl<C: ECClient + Send + Sync> MediaRepository for MediaRepositoryImpl<C> {
         &self,
         product_ids: Vec<&ProductId>,
     ) -> Result<Vec<Media>, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let media_fields = Self::media_fields();
-
-        let mut query = String::from("query { ");
+        let mut operation = Operation::query("productsMedia");
+        let mut variables = serde_json::Map::new();
         for (i, id) in product_ids.iter().enumerate() {
             let alias = format!("i{}", i);
-            let query_part = format!(
-                "{}: files({}, query: \"product_id:'{}'\") {{
-                    edges {{
-                        node {{
-                            {media_fields}
-                        }}
-                    }}
-                }}",
-                alias,
-                first_query,
-                ShopifyGQLHelper::remove_gid_prefix(id)
+            variables.insert(
+                alias.clone(),
+                Value::String(ShopifyGQLHelper::add_product_gid_prefix(id)),
+            );
+
+            operation = operation.variable(&alias, "ID!").with(
+                Field::new("product")
+                    .alias(&alias)
+                    .arg("id", Argument::Variable(alias.clone()))
+                    .select_type::<ProductMediaNode>(),
             );
-            query.push_str(&query_part);
         }
-        query.push_str(" }");
+        let query = operation.build();
 
-        let graphql_response: GraphQLResponse<Value> = self.client.query(&query).await?;
+        let graphql_response: GraphQLResponse<Value> = self
+            .client
+            .query_with_variables(&query, &Value::Object(variables))
+            .await?;
         if let Some(errors) = graphql_response.errors {
             log_error!("Error returned in GraphQL response. Response.", "errors" => errors);
-            return Err(DomainError::QueryError);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
         }
 
         let data = graphql_response.data.ok_or(DomainError::QueryError)?;
@@ -141,17 +163,21 @@ impl<C: ECClient + Send + Sync> MediaRepository for MediaRepositoryImpl<C> {
         }
 
         let mut media_nodes = Vec::new();
-        for (i, _) in product_ids.iter().enumerate() {
+        for (i, id) in product_ids.iter().enumerate() {
             let alias = format!("i{}", i);
 
-            if let Some(file_data) = data.get(&alias).and_then(|d| d.as_object()) {
-                if let Some(files) = file_data.get("edges").and_then(|f| f.as_array()) {
-                    for edge in files {
+            if let Some(product_data) = data.get(&alias).and_then(|d| d.as_object()) {
+                if let Some(edges) = product_data
+                    .get("media")
+                    .and_then(|m| m.get("edges"))
+                    .and_then(|e| e.as_array())
+                {
+                    for edge in edges {
                         let node = &edge["node"];
                         let v: MediaNode = serde_json::from_value(node.clone()).map_err(|e| {
                             InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
                         })?;
-                        media_nodes.push(v);
+                        media_nodes.push((v, Some(AssociatedId::Product(id.to_string()))));
                     }
                 }
             } else {
@@ -160,15 +186,209 @@ impl<C: ECClient + Send + Sync> MediaRepository for MediaRepositoryImpl<C> {
             }
         }
 
-        let media_domains: Result<Vec<Media>, DomainError> = MediaNode::to_domains(
-            media_nodes,
-            product_ids
-                .into_iter()
-                .map(|id| Some(AssociatedId::Product(id.to_string())))
-                .collect(),
-        );
+        MediaNode::to_domains(media_nodes)
+    }
 
-        media_domains
+    async fn create_staged_upload(
+        &self,
+        staged_upload: &StagedUpload,
+    ) -> Result<StagedUploadTarget, DomainError> {
+        let input =
+            serde_json::to_value(vec![StagedUploadInput::from(staged_upload)]).map_err(|e| {
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
+
+        let query = Operation::mutation("stagedUploadsCreate")
+            .variable("input", "[StagedUploadInput!]!")
+            .with(
+                Field::new("stagedUploadsCreate")
+                    .var("input")
+                    .select_type::<StagedUploadsCreate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<StagedUploadsCreateData> =
+            self.client.mutation(&query, &input).await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .staged_uploads_create;
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data
+            .staged_targets
+            .and_then(|targets| targets.into_iter().next())
+        {
+            Some(target) => target.to_domain(),
+            None => {
+                log_error!("No staged target returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    async fn create(&self, product_id: &ProductId, media: Media) -> Result<Media, DomainError> {
+        let input =
+            serde_json::to_value(vec![CreateMediaInput::from_domain(&media)?]).map_err(|e| {
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
+
+        let gid = ShopifyGQLHelper::add_product_gid_prefix(product_id);
+        let query = Operation::mutation("productCreateMedia")
+            .variable("productId", "ID!")
+            .variable("input", "[CreateMediaInput!]!")
+            .with(
+                Field::new("productCreateMedia")
+                    .var("productId")
+                    .arg("media", Argument::Variable("input".to_string()))
+                    .select_type::<ProductMediaMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<ProductCreateMediaData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "productId": gid, "input": input }))
+            .await?;
+
+        let data = graphql_response.data.map(|d| d.product_create_media);
+        match data {
+            Some(data) => Self::to_saved_media(
+                graphql_response.errors,
+                product_id,
+                data.media,
+                data.media_user_errors,
+            ),
+            None => Self::to_saved_media(graphql_response.errors, product_id, None, vec![]),
+        }
+    }
+
+    async fn update(&self, product_id: &ProductId, media: Media) -> Result<Media, DomainError> {
+        let input = serde_json::to_value(vec![UpdateMediaInput::from(&media)]).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let gid = ShopifyGQLHelper::add_product_gid_prefix(product_id);
+        let query = Operation::mutation("productUpdateMedia")
+            .variable("productId", "ID!")
+            .variable("input", "[UpdateMediaInput!]!")
+            .with(
+                Field::new("productUpdateMedia")
+                    .var("productId")
+                    .arg("media", Argument::Variable("input".to_string()))
+                    .select_type::<ProductMediaMutation>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<ProductUpdateMediaData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "productId": gid, "input": input }))
+            .await?;
+
+        let data = graphql_response.data.map(|d| d.product_update_media);
+        match data {
+            Some(data) => Self::to_saved_media(
+                graphql_response.errors,
+                product_id,
+                data.media,
+                data.media_user_errors,
+            ),
+            None => Self::to_saved_media(graphql_response.errors, product_id, None, vec![]),
+        }
+    }
+
+    async fn reorder(&self, product_id: &ProductId, media: Vec<&Media>) -> Result<(), DomainError> {
+        // Moves are applied in order, so moving each media to its index places them at the front.
+        let moves: Vec<MoveInput> = media
+            .into_iter()
+            .enumerate()
+            .map(|(i, media)| MoveInput {
+                id: MediaNode::to_gid(media),
+                new_position: i.to_string(),
+            })
+            .collect();
+        let input = serde_json::to_value(moves).map_err(|e| {
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })?;
+
+        let gid = ShopifyGQLHelper::add_product_gid_prefix(product_id);
+        let query = Operation::mutation("productReorderMedia")
+            .variable("id", "ID!")
+            .variable("input", "[MoveInput!]!")
+            .with(
+                Field::new("productReorderMedia")
+                    .var("id")
+                    .arg("moves", Argument::Variable("input".to_string()))
+                    .select_type::<ProductReorderMedia>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<ProductReorderMediaData> = self
+            .client
+            .mutation_with_variables(&query, &json!({ "id": gid, "input": input }))
+            .await?;
+
+        match graphql_response.data.map(|d| d.product_reorder_media) {
+            Some(data) => Self::verify_saved(graphql_response.errors, &data.media_user_errors),
+            None => {
+                Self::verify_saved(graphql_response.errors, &[])?;
+                log_error!("No data returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    async fn delete(&self, product_id: &ProductId, media: &Media) -> Result<MediaId, DomainError> {
+        let gid = ShopifyGQLHelper::add_product_gid_prefix(product_id);
+        let media_gid = MediaNode::to_gid(media);
+        let query = Operation::mutation("productDeleteMedia")
+            .variable("productId", "ID!")
+            .variable("mediaIds", "[ID!]!")
+            .with(
+                Field::new("productDeleteMedia")
+                    .var("productId")
+                    .var("mediaIds")
+                    .select_type::<ProductDeleteMedia>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<ProductDeleteMediaData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({ "productId": gid, "mediaIds": [media_gid] }),
+            )
+            .await?;
+
+        let data = match graphql_response.data.map(|d| d.product_delete_media) {
+            Some(data) => data,
+            None => {
+                Self::verify_saved(graphql_response.errors, &[])?;
+                log_error!("No data returned.");
+                return Err(DomainError::SaveError);
+            }
+        };
+        Self::verify_saved(graphql_response.errors, &data.media_user_errors)?;
+
+        match data
+            .deleted_media_ids
+            .and_then(|ids| ids.into_iter().next())
+        {
+            Some(deleted_id) => Ok(ShopifyGQLHelper::remove_gid_prefix(&deleted_id)),
+            None => {
+                log_error!("No deleted media id returned.", "id" => media.id());
+                Err(DomainError::SaveError)
+            }
+        }
     }
 }
 
@@ -181,29 +401,47 @@ mod tests {
         domain::{
             error::error::DomainError,
             media::{
-                associated_id::associated_id::AssociatedId, media::MediaStatus,
-                media_content::media_content::MediaContent,
+                associated_id::associated_id::AssociatedId,
+                media::{Media, MediaStatus},
+                media_content::{image::image::Image, media_content::MediaContent},
+                src::src::Src,
+                staged_upload::staged_upload::StagedUpload,
             },
         },
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::assert_selection_matches,
                 repository::{
                     media::media_impl::MediaRepositoryImpl,
-                    schema::media::{ImageNode, MediaData, MediaNode, MediaPreviewImageNode},
+                    schema::{
+                        media::{
+                            ImageNode, MediaNode, MediaPreviewImageNode, Model3dBoundingBoxNode,
+                            Model3dSourceNode, ProductMediaData, ProductMediaNode, Vector3Node,
+                            VideoSourceNode,
+                        },
+                        media_input::{
+                            MediaUserError, ProductCreateMediaData, ProductDeleteMedia,
+                            ProductDeleteMediaData, ProductMediaMutation, ProductReorderMedia,
+                            ProductReorderMediaData, ProductUpdateMediaData,
+                            StagedMediaUploadTargetNode, StagedUploadParameterNode,
+                            StagedUploadsCreate, StagedUploadsCreateData,
+                        },
+                    },
                 },
-                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo},
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo, UserError},
             },
         },
         usecase::repository::media_repository_interface::MediaRepository,
     };
 
-    fn mock_media_response(count: usize) -> GraphQLResponse<MediaData> {
+    fn mock_media_response(count: usize) -> GraphQLResponse<ProductMediaData> {
         let nodes: Vec<Node<MediaNode>> = (0..count)
             .map(|i| Node {
                 node: MediaNode {
                     id: format!("gid://shopify/MediaImage/{i}"),
-                    file_status: "UPLOADED".to_string(),
+                    media_content_type: "IMAGE".to_string(),
+                    status: "READY".to_string(),
                     alt: Some(format!("Alt text for media {i}")),
                     preview: Some(MediaPreviewImageNode {
                         image: Some(ImageNode {
@@ -214,23 +452,29 @@ mod tests {
                             width: Some(500),
                         }),
                     }),
-                    created_at: Utc::now(),
-                    updated_at: Utc::now(),
+                    created_at: Some(Utc::now()),
+                    updated_at: Some(Utc::now()),
+                    duration: None,
+                    video_sources: None,
+                    model3d_sources: None,
+                    bounding_box: None,
                 },
             })
             .collect();
 
         GraphQLResponse {
-            data: Some(MediaData {
-                files: Edges {
-                    edges: nodes,
-                    page_info: PageInfo {
-                        has_previous_page: false,
-                        has_next_page: false,
-                        start_cursor: None,
-                        end_cursor: None,
+            data: Some(ProductMediaData {
+                product: Some(ProductMediaNode {
+                    media: Edges {
+                        edges: nodes,
+                        page_info: PageInfo {
+                            has_previous_page: false,
+                            has_next_page: false,
+                            start_cursor: None,
+                            end_cursor: None,
+                        },
                     },
-                },
+                }),
             }),
             errors: None,
         }
@@ -240,42 +484,48 @@ mod tests {
         let mock_graphql_response = json!({
             "data": {
                 "i0": {
-                    "edges": [
-                        {
-                            "node": {
-                                "alt": "Alt text for media 0",
-                                "createdAt": "2024-07-30T15:37:45Z",
-                                "fileStatus": "READY",
-                                "id": "gid://shopify/MediaImage/0",
-                                "updatedAt": "2024-07-30T15:37:45Z",
-                                "preview": {
-                                    "image": {
-                                        "id": "gid://shopify/MediaImage/0",
-                                        "url": "https://example.com/image0.jpg",
+                    "media": {
+                        "edges": [
+                            {
+                                "node": {
+                                    "alt": "Alt text for media 0",
+                                    "createdAt": "2024-07-30T15:37:45Z",
+                                    "mediaContentType": "IMAGE",
+                                    "status": "READY",
+                                    "id": "gid://shopify/MediaImage/0",
+                                    "updatedAt": "2024-07-30T15:37:45Z",
+                                    "preview": {
+                                        "image": {
+                                            "id": "gid://shopify/MediaImage/0",
+                                            "url": "https://example.com/image0.jpg",
+                                        }
                                     }
                                 }
                             }
-                        }
-                    ]
+                        ]
+                    }
                 },
                 "i1": {
-                    "edges": [
-                        {
-                            "node": {
-                                "alt": "Alt text for media 1",
-                                "createdAt": "2024-07-30T15:37:45Z",
-                                "fileStatus": "READY",
-                                "id": "gid://shopify/MediaImage/1",
-                                "updatedAt": "2024-07-30T15:37:45Z",
-                                "preview": {
-                                    "image": {
-                                        "id": "gid://shopify/MediaImage/1",
-                                        "url": "https://example.com/image1.jpg",
+                    "media": {
+                        "edges": [
+                            {
+                                "node": {
+                                    "alt": "Alt text for media 1",
+                                    "createdAt": "2024-07-30T15:37:45Z",
+                                    "mediaContentType": "IMAGE",
+                                    "status": "READY",
+                                    "id": "gid://shopify/MediaImage/1",
+                                    "updatedAt": "2024-07-30T15:37:45Z",
+                                    "preview": {
+                                        "image": {
+                                            "id": "gid://shopify/MediaImage/1",
+                                            "url": "https://example.com/image1.jpg",
+                                        }
                                     }
                                 }
                             }
-                        }
-                    ]
+                        ]
+                    }
                 },
             }
         });
@@ -305,9 +555,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<MediaData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductMediaData>>()
             .times(1)
-            .return_once(|_| Ok(mock_media_response(10)));
+            .return_once(|_, _| Ok(mock_media_response(10)));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -348,19 +598,108 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_find_media_by_product_id_with_video_and_model3d() {
+        let mut client = MockECClient::new();
+
+        let mut response = mock_media_response(2);
+        let edges = &mut response
+            .data
+            .as_mut()
+            .unwrap()
+            .product
+            .as_mut()
+            .unwrap()
+            .media
+            .edges;
+
+        let video = &mut edges[0].node;
+        video.id = "gid://shopify/Video/0".to_string();
+        video.media_content_type = "VIDEO".to_string();
+        video.duration = Some(15000);
+        video.video_sources = Some(vec![VideoSourceNode {
+            url: "https://example.com/video.mp4".to_string(),
+            mime_type: "video/mp4".to_string(),
+            width: 1920,
+            height: 1080,
+        }]);
+
+        let model3d = &mut edges[1].node;
+        model3d.id = "gid://shopify/Model3d/1".to_string();
+        model3d.media_content_type = "MODEL_3D".to_string();
+        model3d.created_at = None;
+        model3d.updated_at = None;
+        model3d.model3d_sources = Some(vec![Model3dSourceNode {
+            url: "https://example.com/model.glb".to_string(),
+            mime_type: "model/gltf-binary".to_string(),
+            format: "glb".to_string(),
+            filesize: Some(1024),
+        }]);
+        model3d.bounding_box = Some(Model3dBoundingBoxNode {
+            size: Vector3Node {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+        });
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductMediaData>>()
+            .times(1)
+            .return_once(|_, _| Ok(response));
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let result = repo.find_media_by_product_id(&"123456".to_string()).await;
+
+        assert!(result.is_ok());
+        let media = result.unwrap();
+        assert_eq!(media.len(), 2);
+
+        assert_eq!(media[0].id(), "0");
+        let video = match media[0].content() {
+            Some(MediaContent::Video(video)) => video,
+            _ => panic!("Expected MediaContent::Video"),
+        };
+        assert_eq!(*video.duration_ms(), Some(15000));
+        assert_eq!(video.sources().len(), 1);
+        assert_eq!(video.sources()[0].mime_type(), "video/mp4");
+        assert_eq!(*video.sources()[0].width(), 1920);
+        assert_eq!(*video.sources()[0].height(), 1080);
+
+        assert_eq!(media[1].id(), "1");
+        assert!(media[1].created_at().is_none());
+        let model3d = match media[1].content() {
+            Some(MediaContent::Model3d(model3d)) => model3d,
+            _ => panic!("Expected MediaContent::Model3d"),
+        };
+        assert_eq!(model3d.sources()[0].format(), "glb");
+        assert_eq!(*model3d.sources()[0].file_size(), Some(1024));
+        let bounding_box = model3d.bounding_box().as_ref().unwrap();
+        assert_eq!(*bounding_box.z(), 3.0);
+    }
+
     #[tokio::test]
     async fn test_find_media_by_product_id_with_invalid_file_status() {
         let mut client = MockECClient::new();
 
         let mut invalid_response = mock_media_response(1);
-        invalid_response.data.as_mut().unwrap().files.edges[0]
+        invalid_response
+            .data
+            .as_mut()
+            .unwrap()
+            .product
+            .as_mut()
+            .unwrap()
+            .media
+            .edges[0]
             .node
-            .file_status = "INVALID_STATUS".to_string();
+            .status = "INVALID_STATUS".to_string();
 
         client
-            .expect_query::<GraphQLResponse<MediaData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductMediaData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -381,9 +720,9 @@ mod tests {
         let graphql_response_with_error = mock_with_error();
 
         client
-            .expect_query::<GraphQLResponse<MediaData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductMediaData>>()
             .times(1)
-            .return_once(|_| Ok(graphql_response_with_error));
+            .return_once(|_, _| Ok(graphql_response_with_error));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -404,9 +743,9 @@ mod tests {
         let graphql_response_with_no_data = mock_with_no_data();
 
         client
-            .expect_query::<GraphQLResponse<MediaData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductMediaData>>()
             .times(1)
-            .return_once(|_| Ok(graphql_response_with_no_data));
+            .return_once(|_, _| Ok(graphql_response_with_no_data));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -425,9 +764,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<Value>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<Value>>()
             .times(1)
-            .return_once(|_| Ok(mock_media_response_by_alias()));
+            .return_once(|_, _| Ok(mock_media_response_by_alias()));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -447,9 +786,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<Value>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<Value>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -470,9 +809,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<Value>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<Value>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = MediaRepositoryImpl::new(client);
 
@@ -487,4 +826,360 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    fn mock_media_node(id: u32, status: &str) -> MediaNode {
+        MediaNode {
+            id: format!("gid://shopify/MediaImage/{id}"),
+            media_content_type: "IMAGE".to_string(),
+            status: status.to_string(),
+            alt: Some("alt".to_string()),
+            preview: Some(MediaPreviewImageNode {
+                image: Some(ImageNode {
+                    id: Some(format!("gid://shopify/MediaImage/{id}")),
+                    alt_text: Some("alt".to_string()),
+                    url: format!("https://example.com/MediaImage/{id}.jpg"),
+                    height: Some(600),
+                    width: Some(500),
+                }),
+            }),
+            created_at: Some(Utc::now()),
+            updated_at: Some(Utc::now()),
+            duration: None,
+            video_sources: None,
+            model3d_sources: None,
+            bounding_box: None,
+        }
+    }
+
+    fn mock_media_user_error() -> MediaUserError {
+        MediaUserError {
+            code: Some("INVALID".to_string()),
+            field: Some(vec!["media".to_string()]),
+            message: "Invalid media".to_string(),
+        }
+    }
+
+    fn mock_new_media() -> Media {
+        Media::create(MediaContent::Image(
+            Image::create(
+                Some(AssociatedId::Product("1".to_string())),
+                Some("alt"),
+                Src::new("https://example.com/resource").unwrap(),
+            )
+            .unwrap(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_create_staged_upload_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<StagedUploadsCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(StagedUploadsCreateData {
+                        staged_uploads_create: StagedUploadsCreate {
+                            staged_targets: Some(vec![StagedMediaUploadTargetNode {
+                                url: Some("https://example.com/upload".to_string()),
+                                resource_url: Some("https://example.com/resource".to_string()),
+                                parameters: vec![StagedUploadParameterNode {
+                                    name: "key".to_string(),
+                                    value: "value".to_string(),
+                                }],
+                            }]),
+                            user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let target = repo
+            .create_staged_upload(&StagedUpload::new("image.png", "image/png", 1024).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(target.url().value(), "https://example.com/upload");
+        assert_eq!(
+            target.resource_url().value(),
+            "https://example.com/resource"
+        );
+        assert_eq!(target.parameters().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_staged_upload_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<StagedUploadsCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(StagedUploadsCreateData {
+                        staged_uploads_create: StagedUploadsCreate {
+                            staged_targets: None,
+                            user_errors: vec![UserError {
+                                field: vec!["input".to_string()],
+                                message: "Invalid input".to_string(),
+                            }],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let result = repo
+            .create_staged_upload(&StagedUpload::new("image.png", "image/png", 1024).unwrap())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductCreateMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductCreateMediaData {
+                        product_create_media: ProductMediaMutation {
+                            media: Some(vec![mock_media_node(1, "UPLOADED")]),
+                            media_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let media = repo
+            .create(&"1".to_string(), mock_new_media())
+            .await
+            .unwrap();
+
+        assert_eq!(media.id(), "1");
+        assert!(media.is_in_preparation());
+        let image = match media.content() {
+            Some(MediaContent::Image(image)) => image,
+            _ => panic!("Expected MediaContent::Image"),
+        };
+        assert_eq!(
+            image.associated_id(),
+            &Some(AssociatedId::Product("1".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductCreateMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductCreateMediaData {
+                        product_create_media: ProductMediaMutation {
+                            media: None,
+                            media_user_errors: vec![mock_media_user_error()],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let result = repo.create(&"1".to_string(), mock_new_media()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_update_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductUpdateMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductUpdateMediaData {
+                        product_update_media: ProductMediaMutation {
+                            media: Some(vec![mock_media_node(1, "READY")]),
+                            media_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+        let media = mock_media_node(1, "READY").to_domain(None).unwrap();
+
+        let media = repo.update(&"1".to_string(), media).await.unwrap();
+
+        assert_eq!(media.id(), "1");
+        assert_eq!(*media.status(), MediaStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductUpdateMediaData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_with_error()));
+
+        let repo = MediaRepositoryImpl::new(client);
+        let media = mock_media_node(1, "READY").to_domain(None).unwrap();
+
+        let result = repo.update(&"1".to_string(), media).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_reorder_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductReorderMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductReorderMediaData {
+                        product_reorder_media: ProductReorderMedia {
+                            media_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let media = [
+            mock_media_node(2, "READY").to_domain(None).unwrap(),
+            mock_media_node(1, "READY").to_domain(None).unwrap(),
+        ];
+
+        let result = repo.reorder(&"1".to_string(), media.iter().collect()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reorder_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductReorderMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductReorderMediaData {
+                        product_reorder_media: ProductReorderMedia {
+                            media_user_errors: vec![mock_media_user_error()],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let media = mock_media_node(2, "READY").to_domain(None).unwrap();
+
+        let result = repo.reorder(&"1".to_string(), vec![&media]).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_delete_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductDeleteMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductDeleteMediaData {
+                        product_delete_media: ProductDeleteMedia {
+                            deleted_media_ids: Some(vec!["gid://shopify/MediaImage/2".to_string()]),
+                            media_user_errors: vec![],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let media = mock_media_node(2, "READY").to_domain(None).unwrap();
+
+        let result = repo.delete(&"1".to_string(), &media).await;
+
+        assert_eq!(result.unwrap(), "2");
+    }
+
+    #[tokio::test]
+    async fn test_delete_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<ProductDeleteMediaData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ProductDeleteMediaData {
+                        product_delete_media: ProductDeleteMedia {
+                            deleted_media_ids: None,
+                            media_user_errors: vec![mock_media_user_error()],
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = MediaRepositoryImpl::new(client);
+
+        let media = mock_media_node(2, "READY").to_domain(None).unwrap();
+
+        let result = repo.delete(&"1".to_string(), &media).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<ProductMediaNode>();
+        assert_selection_matches::<MediaNode>();
+        assert_selection_matches::<MediaPreviewImageNode>();
+        assert_selection_matches::<ImageNode>();
+        assert_selection_matches::<VideoSourceNode>();
+        assert_selection_matches::<Model3dSourceNode>();
+        assert_selection_matches::<Model3dBoundingBoxNode>();
+        assert_selection_matches::<Vector3Node>();
+        assert_selection_matches::<StagedUploadsCreate>();
+        assert_selection_matches::<StagedMediaUploadTargetNode>();
+        assert_selection_matches::<StagedUploadParameterNode>();
+        assert_selection_matches::<ProductMediaMutation>();
+        assert_selection_matches::<ProductReorderMedia>();
+        assert_selection_matches::<ProductDeleteMedia>();
+        assert_selection_matches::<MediaUserError>();
+    }
 }
@@ -0,0 +1,831 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::{
+    domain::{
+        customer::customer::Id as CustomerId,
+        error::error::DomainError,
+        order::order::{Id as OrderId, Order},
+    },
+    infrastructure::ec::{
+        ec_client_interface::ECClient,
+        shopify::{
+            error::ShopifyError,
+            gql_helper::ShopifyGQLHelper,
+            query_builder::{Argument, Field, Operation},
+            repository::schema::{
+                order::{OrderData, OrderNode, OrdersData},
+                order_input::{OrderCancel, OrderCancelData},
+            },
+            schema::{Edges, GraphQLResponse},
+        },
+    },
+    log_error,
+    usecase::repository::{
+        order_repository_interface::{OrderPage, OrderRepository},
+        user_customer_repository_interface::UserCustomerRepository,
+    },
+};
+
+/// Repository for Orders for Shopify.
+///
+/// Orders are owned by the users linked to their customers, which are not known to Shopify.
+pub struct OrderRepositoryImpl<C: ECClient> {
+    client: C,
+    user_customer_repository: Box<dyn UserCustomerRepository>,
+}
+
+impl<C: ECClient> OrderRepositoryImpl<C> {
+    pub fn new(client: C, user_customer_repository: Box<dyn UserCustomerRepository>) -> Self {
+        Self {
+            client,
+            user_customer_repository,
+        }
+    }
+
+    /// Convert to the domain with the owners of the orders.
+    async fn to_domains(&self, nodes: Vec<OrderNode>) -> Result<Vec<Order>, DomainError> {
+        let mut customer_ids: Vec<CustomerId> =
+            nodes.iter().filter_map(|node| node.customer_id()).collect();
+        customer_ids.sort();
+        customer_ids.dedup();
+
+        let owners = self
+            .user_customer_repository
+            .find_user_ids_by_customer_ids(&customer_ids)
+            .await?;
+
+        OrderNode::to_domains(nodes, &owners)
+    }
+
+    async fn find_orders_by_query(
+        &self,
+        search_query: &str,
+        first: u32,
+        after: Option<String>,
+    ) -> Result<OrderPage, DomainError> {
+        let query = Operation::query("orders")
+            .variable("first", "Int!")
+            .variable("after", "String")
+            .variable("query", "String!")
+            .with(
+                Field::new("orders")
+                    .var("first")
+                    .var("after")
+                    .arg("reverse", Argument::Boolean(true))
+                    .var("query")
+                    .select_type::<Edges<OrderNode>>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrdersData> = self
+            .client
+            .query_with_variables(
+                &query,
+                &json!({
+                    "first": first,
+                    "after": after,
+                    "query": search_query,
+                }),
+            )
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        let orders = graphql_response.data.ok_or(DomainError::QueryError)?.orders;
+
+        Ok(OrderPage {
+            orders: self
+                .to_domains(orders.edges.into_iter().map(|node| node.node).collect())
+                .await?,
+            has_next_page: orders.page_info.has_next_page,
+            end_cursor: orders.page_info.end_cursor,
+        })
+    }
+
+    fn customer_search_query(customer_id: &CustomerId) -> String {
+        format!(
+            "customer_id:{}",
+            ShopifyGQLHelper::escape_search_value(customer_id)
+        )
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> OrderRepository for OrderRepositoryImpl<C> {
+    async fn find_order_by_id(&self, id: &OrderId) -> Result<Order, DomainError> {
+        let id = ShopifyGQLHelper::add_order_gid_prefix(id);
+        let query = Operation::query("order")
+            .variable("id", "ID!")
+            .with(Field::new("order").var("id").select_type::<OrderNode>())
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        let node = graphql_response
+            .data
+            .ok_or(DomainError::NotFound)?
+            .order
+            .ok_or(DomainError::NotFound)?;
+
+        self.to_domains(vec![node])
+            .await?
+            .pop()
+            .ok_or(DomainError::NotFound)
+    }
+
+    async fn find_orders_by_customer_id(
+        &self,
+        customer_id: &CustomerId,
+    ) -> Result<Vec<Order>, DomainError> {
+        let search_query = Self::customer_search_query(customer_id);
+        let mut orders = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let page = self
+                .find_orders_by_query(
+                    &search_query,
+                    ShopifyGQLHelper::SHOPIFY_QUERY_LIMIT as u32,
+                    cursor,
+                )
+                .await?;
+            orders.extend(page.orders);
+
+            cursor = page.end_cursor;
+            if !page.has_next_page || cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(orders)
+    }
+
+    async fn find_orders(
+        &self,
+        customer_id: Option<&CustomerId>,
+        first: u32,
+        after: Option<String>,
+    ) -> Result<OrderPage, DomainError> {
+        let search_query = customer_id
+            .map(Self::customer_search_query)
+            .unwrap_or_default();
+
+        self.find_orders_by_query(&search_query, first, after).await
+    }
+
+    async fn cancel(
+        &self,
+        order: Order,
+        refund: bool,
+        restock: bool,
+        notify_customer: bool,
+    ) -> Result<Order, DomainError> {
+        let reason: String = match order.cancel_reason() {
+            Some(reason) => reason.to_owned().into(),
+            None => {
+                log_error!("Order has not been canceled.", "id" => order.id());
+                return Err(DomainError::SaveError);
+            }
+        };
+        let id = ShopifyGQLHelper::add_order_gid_prefix(order.id());
+
+        let query = Operation::mutation("orderCancel")
+            .variable("orderId", "ID!")
+            .variable("reason", "OrderCancelReason!")
+            .variable("refund", "Boolean!")
+            .variable("restock", "Boolean!")
+            .variable("notifyCustomer", "Boolean")
+            .with(
+                Field::new("orderCancel")
+                    .var("orderId")
+                    .var("reason")
+                    .var("refund")
+                    .var("restock")
+                    .var("notifyCustomer")
+                    .select_type::<OrderCancel>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderCancelData> = self
+            .client
+            .mutation_with_variables(
+                &query,
+                &json!({
+                    "orderId": id,
+                    "reason": reason,
+                    "refund": refund,
+                    "restock": restock,
+                    "notifyCustomer": notify_customer,
+                }),
+            )
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .order_cancel;
+
+        if !data.order_cancel_user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.order_cancel_user_errors);
+            return Err(
+                ShopifyError::from_user_errors(&data.order_cancel_user_errors)
+                    .into_domain_error(DomainError::SaveError),
+            );
+        }
+        // Shopify completes the cancellation asynchronously, so the order canceled in the domain is returned.
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            error::error::DomainError,
+            order::order::{FinancialStatus, FulfillmentStatus, Order, OrderCancelReason},
+        },
+        infrastructure::ec::{
+            ec_client_interface::MockECClient,
+            shopify::{
+                query_builder::assert_selection_matches,
+                repository::{
+                    order::order_impl::OrderRepositoryImpl,
+                    schema::{
+                        draft_order::CustomerIdNode,
+                        line_item::VariantIdNode,
+                        money::{CurrencyCodeNode, MoneyBagNode, MoneyNode},
+                        order::{OrderData, OrderLineItemNode, OrderNode, OrdersData},
+                        order_input::{OrderCancel, OrderCancelData},
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo, UserError},
+            },
+        },
+        usecase::repository::{
+            order_repository_interface::OrderRepository,
+            user_customer_repository_interface::MockUserCustomerRepository,
+        },
+    };
+
+    fn mock_order_node(id: u32) -> OrderNode {
+        OrderNode {
+            id: format!("gid://shopify/Order/{id}"),
+            name: format!("#100{id}"),
+            customer: Some(CustomerIdNode {
+                id: format!("gid://shopify/Customer/{id}"),
+            }),
+            billing_address: None,
+            shipping_address: None,
+            note: Some("Test note".to_string()),
+            line_items: Edges {
+                edges: vec![Node {
+                    node: mock_line_item_node(id),
+                }],
+                page_info: mock_page_info(),
+            },
+            display_financial_status: Some("PAID".to_string()),
+            display_fulfillment_status: "UNFULFILLED".to_string(),
+            subtotal_price_set: mock_money_node("100.00", "USD"),
+            taxes_included: true,
+            total_tax_set: mock_money_node("5.00", "USD"),
+            total_discounts_set: mock_money_node("10.00", "USD"),
+            total_shipping_price_set: mock_money_node("15.00", "USD"),
+            total_price_set: mock_money_node("110.00", "USD"),
+            presentment_currency_code: CurrencyCodeNode("USD".to_string()),
+            cancel_reason: None,
+            processed_at: Utc::now(),
+            cancelled_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn mock_user_customer_repository() -> Box<MockUserCustomerRepository> {
+        let mut repository = MockUserCustomerRepository::new();
+        repository
+            .expect_find_user_ids_by_customer_ids()
+            .returning(|customer_ids| {
+                Ok(customer_ids
+                    .iter()
+                    .map(|id| (id.clone(), format!("user_{id}")))
+                    .collect())
+            });
+        Box::new(repository)
+    }
+
+    fn mock_line_item_node(id: u32) -> OrderLineItemNode {
+        OrderLineItemNode {
+            id: format!("gid://shopify/LineItem/{id}"),
+            variant: Some(VariantIdNode {
+                id: format!("gid://shopify/ProductVariant/{id}"),
+            }),
+            quantity: 2,
+            discounted_total_set: mock_money_node("90.00", "USD"),
+            original_total_set: mock_money_node("100.00", "USD"),
+        }
+    }
+
+    fn mock_money_node(amount: &str, currency: &str) -> MoneyBagNode {
+        MoneyBagNode {
+            shop_money: MoneyNode {
+                amount: amount.to_string(),
+                currency_code: CurrencyCodeNode(currency.to_string()),
+            },
+        }
+    }
+
+    fn mock_page_info() -> PageInfo {
+        PageInfo {
+            has_previous_page: false,
+            has_next_page: false,
+            start_cursor: None,
+            end_cursor: None,
+        }
+    }
+
+    fn mock_order_response(node: Option<OrderNode>) -> GraphQLResponse<OrderData> {
+        GraphQLResponse {
+            data: Some(OrderData { order: node }),
+            errors: None,
+        }
+    }
+
+    fn mock_order_cancel_response(user_errors: Vec<UserError>) -> GraphQLResponse<OrderCancelData> {
+        GraphQLResponse {
+            data: Some(OrderCancelData {
+                order_cancel: OrderCancel {
+                    order_cancel_user_errors: user_errors,
+                },
+            }),
+            errors: None,
+        }
+    }
+
+    fn mock_canceled_order() -> Order {
+        let mut order = mock_order_node(1)
+            .to_domain(&HashMap::new())
+            .expect("Failed to convert mock order node");
+        order
+            .cancel(OrderCancelReason::Customer)
+            .expect("Failed to cancel mock order");
+        order
+    }
+
+    fn mock_orders_response(count: usize) -> GraphQLResponse<OrdersData> {
+        let nodes: Vec<Node<OrderNode>> = (0..count)
+            .map(|i| Node {
+                node: mock_order_node(i as u32),
+            })
+            .collect();
+
+        GraphQLResponse {
+            data: Some(OrdersData {
+                orders: Edges {
+                    edges: nodes,
+                    page_info: mock_page_info(),
+                },
+            }),
+            errors: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(mock_order_node(1)))));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let order = result.unwrap();
+        assert_eq!(order.id(), "1");
+        assert_eq!(order.name(), "#1001");
+        assert_eq!(order.customer_id(), &Some("1".to_string()));
+        assert_eq!(order.line_items().len(), 1);
+        assert_eq!(order.line_items()[0].is_custom(), &false);
+        assert_eq!(order.financial_status(), &Some(FinancialStatus::Paid));
+        assert_eq!(order.fulfillment_status(), &FulfillmentStatus::Unfulfilled);
+        assert_eq!(order.total_price_set().amount().value(), &110.0);
+        assert_eq!(order.owner_user_id(), "user_1");
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_passes_id_as_variable() {
+        let mut client = MockECClient::new();
+        let hostile_id = "1\") { id } } mutation { orderCancel(orderId: \"1";
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .withf(move |query, variables| {
+                !query.contains("orderCancel")
+                    && variables["id"] == format!("gid://shopify/Order/{hostile_id}")
+            })
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(mock_order_node(1)))));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&hostile_id.to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_without_linked_user() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(mock_order_node(1)))));
+
+        let mut user_customer_repository = MockUserCustomerRepository::new();
+        user_customer_repository
+            .expect_find_user_ids_by_customer_ids()
+            .withf(|customer_ids| customer_ids == ["1".to_string()])
+            .times(1)
+            .return_once(|_| Ok(HashMap::new()));
+
+        let repo = OrderRepositoryImpl::new(client, Box::new(user_customer_repository));
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().owner_user_id(), "");
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_without_customer() {
+        let mut client = MockECClient::new();
+
+        let mut node = mock_order_node(1);
+        node.customer = None;
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(node))));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let order = result.unwrap();
+        assert_eq!(order.customer_id(), &None);
+        assert_eq!(order.owner_user_id(), "");
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_canceled() {
+        let mut client = MockECClient::new();
+
+        let canceled_at = Utc::now();
+        let mut node = mock_order_node(1);
+        node.cancel_reason = Some("FRAUD".to_string());
+        node.cancelled_at = Some(canceled_at);
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(node))));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let order = repo
+            .find_order_by_id(&"1".to_string())
+            .await
+            .expect("Failed to find order");
+
+        assert!(order.is_canceled());
+        assert_eq!(order.cancel_reason(), &Some(OrderCancelReason::Fraud));
+        assert_eq!(order.canceled_at(), &Some(canceled_at));
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(None)));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_with_invalid_status() {
+        let mut client = MockECClient::new();
+
+        let mut node = mock_order_node(1);
+        node.display_fulfillment_status = "INVALID".to_string();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_response(Some(node))));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::ConversionError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_id_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Some GraphQL error".to_string(),
+                        extensions: None,
+                    }]),
+                })
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_order_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_by_customer_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_orders_response(10)));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_orders_by_customer_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let orders = result.unwrap();
+        assert_eq!(orders.len(), 10);
+        assert_eq!(orders[0].id(), "0");
+        assert_eq!(orders[9].id(), "9");
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_by_customer_id_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Some GraphQL error".to_string(),
+                        extensions: None,
+                    }]),
+                })
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_orders_by_customer_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_by_customer_id_multiple_retrievals_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .withf(|_, variables| variables["after"].is_null())
+            .times(1)
+            .return_once(|_, _| {
+                let mut response = mock_orders_response(2);
+                let page_info = &mut response.data.as_mut().unwrap().orders.page_info;
+                page_info.has_next_page = true;
+                page_info.end_cursor = Some("cursor".to_string());
+                Ok(response)
+            });
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .withf(|_, variables| variables["after"] == "cursor")
+            .times(1)
+            .return_once(|_, _| Ok(mock_orders_response(1)));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_orders_by_customer_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .withf(|_, variables| {
+                variables["first"] == 3 && variables["after"].is_null() && variables["query"] == ""
+            })
+            .times(1)
+            .return_once(|_, _| Ok(mock_orders_response(3)));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_orders(None, 3, None).await;
+
+        assert!(result.is_ok());
+        let page = result.unwrap();
+        assert_eq!(page.orders.len(), 3);
+        assert_eq!(page.orders[0].owner_user_id(), "user_0");
+        assert!(!page.has_next_page);
+        assert_eq!(page.end_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_of_customer_with_cursor() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .withf(|_, variables| {
+                variables["first"] == 10
+                    && variables["after"] == "cursor"
+                    && variables["query"] == "customer_id:1"
+            })
+            .times(1)
+            .return_once(|_, _| {
+                let mut response = mock_orders_response(10);
+                let page_info = &mut response.data.as_mut().unwrap().orders.page_info;
+                page_info.has_next_page = true;
+                page_info.end_cursor = Some("next".to_string());
+                Ok(response)
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo
+            .find_orders(Some(&"1".to_string()), 10, Some("cursor".to_string()))
+            .await;
+
+        assert!(result.is_ok());
+        let page = result.unwrap();
+        assert_eq!(page.orders.len(), 10);
+        assert!(page.has_next_page);
+        assert_eq!(page.end_cursor, Some("next".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_find_orders_with_missing_data() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrdersData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: None,
+                })
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.find_orders(None, 50, None).await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<OrderCancelData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_order_cancel_response(vec![])));
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.cancel(mock_canceled_order(), true, true, false).await;
+
+        assert!(result.is_ok());
+        let order = result.unwrap();
+        assert_eq!(order.id(), "1");
+        assert!(order.is_canceled());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_not_canceled_order_should_fail() {
+        let client = MockECClient::new();
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let order = mock_order_node(1)
+            .to_domain(&HashMap::new())
+            .expect("Failed to convert mock order node");
+        let result = repo.cancel(order, true, true, false).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<OrderCancelData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(mock_order_cancel_response(vec![UserError {
+                    field: vec!["orderId".to_string()],
+                    message: "Cannot cancel a fulfilled order".to_string(),
+                }]))
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.cancel(mock_canceled_order(), true, true, false).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation_with_variables::<Value, GraphQLResponse<OrderCancelData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Some GraphQL error".to_string(),
+                        extensions: None,
+                    }]),
+                })
+            });
+
+        let repo = OrderRepositoryImpl::new(client, mock_user_customer_repository());
+
+        let result = repo.cancel(mock_canceled_order(), true, true, false).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<OrderNode>();
+        assert_selection_matches::<OrderLineItemNode>();
+        assert_selection_matches::<CustomerIdNode>();
+        assert_selection_matches::<VariantIdNode>();
+        assert_selection_matches::<MoneyBagNode>();
+        assert_selection_matches::<MoneyNode>();
+        assert_selection_matches::<OrderCancel>();
+    }
+}
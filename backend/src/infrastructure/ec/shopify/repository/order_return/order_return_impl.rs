@@ -0,0 +1,689 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        order::order::Id as OrderId,
+        order_return::order_return::{Id as ReturnId, OrderReturn, ReturnStatus},
+    },
+    infrastructure::{
+        ec::{
+            ec_client_interface::ECClient,
+            shopify::{
+                error::ShopifyError,
+                gql_helper::ShopifyGQLHelper,
+                query_builder::{Field, Operation},
+                repository::schema::{
+                    order_return::{OrderReturnsData, OrderReturnsNode, ReturnData, ReturnNode},
+                    order_return_input::{
+                        ReturnApproveRequestData, ReturnApproveRequestInput,
+                        ReturnDeclineRequestData, ReturnDeclineRequestInput, ReturnMutation,
+                        ReturnRequestData, ReturnRequestInput,
+                    },
+                },
+                schema::GraphQLResponse,
+            },
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::order_return_repository_interface::OrderReturnRepository,
+};
+
+/// Repository for Returns for Shopify.
+pub struct OrderReturnRepositoryImpl<C: ECClient> {
+    client: C,
+}
+
+impl<C: ECClient> OrderReturnRepositoryImpl<C> {
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    /// Build a mutation that takes the input as `$input` and returns the return.
+    fn return_mutation(name: &str, input_type: &str) -> String {
+        Operation::mutation(name)
+            .variable("input", input_type)
+            .with(
+                Field::new(name)
+                    .var("input")
+                    .select_type::<ReturnMutation>(),
+            )
+            .build()
+    }
+
+    fn to_domain_from_mutation(data: ReturnMutation) -> Result<OrderReturn, DomainError> {
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.order_return {
+            Some(order_return) => order_return.to_domain(),
+            None => {
+                log_error!("No return returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+
+    fn to_input_value<T: serde::Serialize>(input: T) -> Result<serde_json::Value, DomainError> {
+        serde_json::to_value(input).map_err(|e| {
+            log_error!("Failed to parse the request structure.", "error" => e);
+            InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+        })
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> OrderReturnRepository for OrderReturnRepositoryImpl<C> {
+    async fn find_return_by_id(&self, id: &ReturnId) -> Result<OrderReturn, DomainError> {
+        let id = ShopifyGQLHelper::add_return_gid_prefix(id);
+        let query = Operation::query("return")
+            .variable("id", "ID!")
+            .with(Field::new("return").var("id").select_type::<ReturnNode>())
+            .build();
+
+        let graphql_response: GraphQLResponse<ReturnData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .order_return
+            .ok_or(DomainError::NotFound)?
+            .to_domain()
+    }
+
+    async fn find_returns_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<OrderReturn>, DomainError> {
+        let id = ShopifyGQLHelper::add_order_gid_prefix(order_id);
+        let query = Operation::query("order")
+            .variable("id", "ID!")
+            .with(
+                Field::new("order")
+                    .var("id")
+                    .select_type::<OrderReturnsNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderReturnsData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        let nodes: Vec<ReturnNode> = graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .order
+            .ok_or(DomainError::NotFound)?
+            .returns
+            .edges
+            .into_iter()
+            .map(|node| node.node)
+            .collect();
+
+        ReturnNode::to_domains(nodes)
+    }
+
+    async fn create(&self, order_return: OrderReturn) -> Result<OrderReturn, DomainError> {
+        let input = Self::to_input_value(ReturnRequestInput::from(order_return))?;
+
+        let query = Self::return_mutation("returnRequest", "ReturnRequestInput!");
+
+        let graphql_response: GraphQLResponse<ReturnRequestData> =
+            self.client.mutation(&query, &input).await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        Self::to_domain_from_mutation(
+            graphql_response
+                .data
+                .ok_or(DomainError::SaveError)?
+                .return_request,
+        )
+    }
+
+    async fn update(&self, order_return: OrderReturn) -> Result<OrderReturn, DomainError> {
+        let id = ShopifyGQLHelper::add_return_gid_prefix(order_return.id());
+
+        // Shopify only allows the status of a return to be changed through dedicated mutations.
+        let data = match order_return.status() {
+            ReturnStatus::Open => {
+                let input = Self::to_input_value(ReturnApproveRequestInput { id })?;
+                let query =
+                    Self::return_mutation("returnApproveRequest", "ReturnApproveRequestInput!");
+
+                let graphql_response: GraphQLResponse<ReturnApproveRequestData> =
+                    self.client.mutation(&query, &input).await?;
+                if let Some(errors) = graphql_response.errors {
+                    log_error!("Error returned in GraphQL response.", "Response" => errors);
+                    return Err(ShopifyError::from_graphql_errors(&errors)
+                        .into_domain_error(DomainError::SaveError));
+                }
+
+                graphql_response
+                    .data
+                    .ok_or(DomainError::SaveError)?
+                    .return_approve_request
+            }
+            ReturnStatus::Declined => {
+                let decline_reason = match order_return.decline_reason() {
+                    Some(reason) => reason.to_owned().into(),
+                    None => {
+                        log_error!("No decline reason to update.", "id" => order_return.id());
+                        return Err(DomainError::SaveError);
+                    }
+                };
+                let input = Self::to_input_value(ReturnDeclineRequestInput {
+                    id,
+                    decline_reason,
+                    decline_note: order_return.decline_note().to_owned(),
+                })?;
+                let query =
+                    Self::return_mutation("returnDeclineRequest", "ReturnDeclineRequestInput!");
+
+                let graphql_response: GraphQLResponse<ReturnDeclineRequestData> =
+                    self.client.mutation(&query, &input).await?;
+                if let Some(errors) = graphql_response.errors {
+                    log_error!("Error returned in GraphQL response.", "Response" => errors);
+                    return Err(ShopifyError::from_graphql_errors(&errors)
+                        .into_domain_error(DomainError::SaveError));
+                }
+
+                graphql_response
+                    .data
+                    .ok_or(DomainError::SaveError)?
+                    .return_decline_request
+            }
+            status => {
+                log_error!(
+                    "Unsupported return status to update.",
+                    "id" => order_return.id(),
+                    "status" => status
+                );
+                return Err(DomainError::SaveError);
+            }
+        };
+
+        Self::to_domain_from_mutation(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            error::error::DomainError,
+            order_return::{
+                order_return::{OrderReturn, ReturnDeclineReason, ReturnStatus},
+                return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+            },
+        },
+        infrastructure::ec::{
+            ec_client_interface::MockECClient,
+            shopify::{
+                query_builder::assert_selection_matches,
+                repository::{
+                    order_return::order_return_impl::OrderReturnRepositoryImpl,
+                    schema::{
+                        draft_order::OrderIdNode,
+                        order_return::{
+                            FulfillmentLineItemIdNode, OrderReturnsData, OrderReturnsNode,
+                            ReturnData, ReturnDeclineNode, ReturnDeclineReasonNode,
+                            ReturnLineItemNode, ReturnNode,
+                        },
+                        order_return_input::{
+                            ReturnApproveRequestData, ReturnDeclineRequestData, ReturnMutation,
+                            ReturnRequestData,
+                        },
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo, UserError},
+            },
+        },
+        usecase::repository::order_return_repository_interface::OrderReturnRepository,
+    };
+
+    fn mock_page_info() -> PageInfo {
+        PageInfo {
+            has_previous_page: false,
+            has_next_page: false,
+            start_cursor: None,
+            end_cursor: None,
+        }
+    }
+
+    fn mock_return_node(id: u32, status: &str) -> ReturnNode {
+        ReturnNode {
+            id: format!("gid://shopify/Return/{id}"),
+            status: status.to_string(),
+            order: OrderIdNode {
+                id: "gid://shopify/Order/1".to_string(),
+            },
+            decline: if status == "DECLINED" {
+                Some(ReturnDeclineNode {
+                    reason: ReturnDeclineReasonNode("FINAL_SALE".to_string()),
+                    note: Some("Final sale item".to_string()),
+                })
+            } else {
+                None
+            },
+            return_line_items: Edges {
+                edges: vec![
+                    Node {
+                        node: ReturnLineItemNode {
+                            id: format!("gid://shopify/ReturnLineItem/{id}"),
+                            fulfillment_line_item: Some(FulfillmentLineItemIdNode {
+                                id: "gid://shopify/FulfillmentLineItem/1".to_string(),
+                            }),
+                            quantity: 1,
+                            return_reason: "DEFECTIVE".to_string(),
+                            customer_note: Some("Broken on arrival".to_string()),
+                        },
+                    },
+                    Node {
+                        node: ReturnLineItemNode {
+                            id: "gid://shopify/UnverifiedReturnLineItem/1".to_string(),
+                            fulfillment_line_item: None,
+                            quantity: 1,
+                            return_reason: "OTHER".to_string(),
+                            customer_note: None,
+                        },
+                    },
+                ],
+                page_info: mock_page_info(),
+            },
+        }
+    }
+
+    fn mock_return_domain(status: ReturnStatus) -> OrderReturn {
+        OrderReturn::new(
+            "1",
+            "1",
+            status.clone(),
+            vec![ReturnLineItem::new("1", "1", 1, ReturnReason::Defective, None).unwrap()],
+            if status == ReturnStatus::Declined {
+                Some(ReturnDeclineReason::FinalSale)
+            } else {
+                None
+            },
+            None,
+        )
+        .unwrap()
+    }
+
+    fn mock_mutation(status: &str) -> ReturnMutation {
+        ReturnMutation {
+            order_return: Some(mock_return_node(1, status)),
+            user_errors: vec![],
+        }
+    }
+
+    fn mock_user_errors() -> Vec<UserError> {
+        vec![UserError {
+            field: vec!["returnLineItems".to_string()],
+            message: "Invalid quantity".to_string(),
+        }]
+    }
+
+    fn mock_graphql_error<T>() -> GraphQLResponse<T> {
+        GraphQLResponse {
+            data: None,
+            errors: Some(vec![GraphQLError {
+                message: "Some GraphQL error".to_string(),
+                extensions: None,
+            }]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_return_by_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<ReturnData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnData {
+                        order_return: Some(mock_return_node(1, "DECLINED")),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_return_by_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let order_return = result.unwrap();
+        assert_eq!(order_return.id(), "1");
+        assert_eq!(order_return.order_id(), "1");
+        assert_eq!(order_return.status(), &ReturnStatus::Declined);
+        assert_eq!(
+            order_return.decline_reason(),
+            &Some(ReturnDeclineReason::FinalSale)
+        );
+        // Line items without a fulfillment are dropped.
+        assert_eq!(order_return.line_items().len(), 1);
+        assert_eq!(order_return.line_items()[0].fulfillment_line_item_id(), "1");
+        assert_eq!(
+            order_return.line_items()[0].reason(),
+            &ReturnReason::Defective
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_return_by_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<ReturnData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnData { order_return: None }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_return_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_return_by_id_with_invalid_status() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<ReturnData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnData {
+                        order_return: Some(mock_return_node(1, "INVALID")),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_return_by_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::ConversionError)));
+    }
+
+    #[tokio::test]
+    async fn test_find_returns_by_order_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderReturnsData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(OrderReturnsData {
+                        order: Some(OrderReturnsNode {
+                            returns: Edges {
+                                edges: vec![
+                                    Node {
+                                        node: mock_return_node(1, "REQUESTED"),
+                                    },
+                                    Node {
+                                        node: mock_return_node(2, "OPEN"),
+                                    },
+                                ],
+                                page_info: mock_page_info(),
+                            },
+                        }),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_returns_by_order_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns.len(), 2);
+        assert_eq!(returns[0].status(), &ReturnStatus::Requested);
+        assert_eq!(returns[1].id(), "2");
+        assert_eq!(returns[1].status(), &ReturnStatus::Open);
+    }
+
+    #[tokio::test]
+    async fn test_find_returns_by_order_id_order_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderReturnsData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(OrderReturnsData { order: None }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_returns_by_order_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_find_returns_by_order_id_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderReturnsData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_graphql_error()));
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.find_returns_by_order_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::QueryError)));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnRequestData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnRequestData {
+                        return_request: mock_mutation("REQUESTED"),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo
+            .create(mock_return_domain(ReturnStatus::Requested))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status(), &ReturnStatus::Requested);
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnRequestData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnRequestData {
+                        return_request: ReturnMutation {
+                            order_return: None,
+                            user_errors: mock_user_errors(),
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo
+            .create(mock_return_domain(ReturnStatus::Requested))
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnRequestData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_graphql_error()));
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo
+            .create(mock_return_domain(ReturnStatus::Requested))
+            .await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_update_to_open_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnApproveRequestData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnApproveRequestData {
+                        return_approve_request: mock_mutation("OPEN"),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.update(mock_return_domain(ReturnStatus::Open)).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status(), &ReturnStatus::Open);
+    }
+
+    #[tokio::test]
+    async fn test_update_to_declined_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnDeclineRequestData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnDeclineRequestData {
+                        return_decline_request: mock_mutation("DECLINED"),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo
+            .update(mock_return_domain(ReturnStatus::Declined))
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status(), &ReturnStatus::Declined);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_unsupported_status() {
+        let client = MockECClient::new();
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.update(mock_return_domain(ReturnStatus::Closed)).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_update_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_mutation::<Value, GraphQLResponse<ReturnApproveRequestData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(ReturnApproveRequestData {
+                        return_approve_request: ReturnMutation {
+                            order_return: None,
+                            user_errors: mock_user_errors(),
+                        },
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = OrderReturnRepositoryImpl::new(client);
+
+        let result = repo.update(mock_return_domain(ReturnStatus::Open)).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<OrderReturnsNode>();
+        assert_selection_matches::<ReturnNode>();
+        assert_selection_matches::<ReturnLineItemNode>();
+        assert_selection_matches::<FulfillmentLineItemIdNode>();
+        assert_selection_matches::<ReturnDeclineNode>();
+        assert_selection_matches::<OrderIdNode>();
+        assert_selection_matches::<ReturnMutation>();
+    }
+}
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::{
     domain::{
@@ -8,9 +9,11 @@ use crate::{
     infrastructure::ec::{
         ec_client_interface::ECClient,
         shopify::{
+            error::ShopifyError,
             gql_helper::ShopifyGQLHelper,
-            repository::schema::product::{ProductsData, VariantNode, VariantsData},
-            schema::GraphQLResponse,
+            query_builder::{Field, Operation},
+            repository::schema::product::{ProductNode, ProductsData, VariantNode, VariantsData},
+            schema::{Edges, GraphQLResponse},
         },
     },
     log_debug, log_error,
@@ -26,75 +29,32 @@ impl<C: ECClient> ProductRepositoryImpl<C> {
     pub fn new(client: C) -> Self {
         Self { client }
     }
-
-    fn product_fields() -> String {
-        let description_length = Product::MAX_DESCRIPTION_LENGTH;
-
-        format!(
-            "id
-            title
-            handle
-            description(truncateAt: {description_length})
-            status
-            category {{
-                id
-                name
-            }}"
-        )
-    }
-
-    fn variant_fields() -> String {
-        let product_fields = Self::product_fields();
-
-        format!(
-            "id
-            title
-            sku
-            barcode
-            availableForSale
-            position
-            inventoryItem {{
-                id
-            }}
-            inventoryQuantity
-            inventoryPolicy
-            price
-            taxable
-            taxCode
-            createdAt
-            updatedAt
-            product {{
-                {product_fields}
-            }}"
-        )
-    }
 }
 
 #[async_trait]
 impl<C: ECClient + Send + Sync> ProductRepository for ProductRepositoryImpl<C> {
     async fn find_product_by_id(&self, id: &ProductId) -> Result<Product, DomainError> {
-        let first_query = ShopifyGQLHelper::first_query();
-        let page_info = ShopifyGQLHelper::page_info();
-        let variant_fields = Self::variant_fields();
-
-        let query = format!(
-            "query {{
-                productVariants({first_query}, query: \"product_id:'{id}'\") {{
-                    edges {{
-                        node {{
-                            {variant_fields}
-                        }}
-                    }}
-                    {page_info}
-                }}
-            }}"
-        );
-
-        let graphql_response: GraphQLResponse<VariantsData> = self.client.query(&query).await?;
+        let query = Operation::query("productVariants")
+            .variable("query", "String!")
+            .with(
+                Field::new("productVariants")
+                    .first()
+                    .var("query")
+                    .select_type::<Edges<VariantNode>>(),
+            )
+            .build();
+
+        let search_query = format!("product_id:'{}'", ShopifyGQLHelper::escape_search_value(id));
+
+        let graphql_response: GraphQLResponse<VariantsData> = self
+            .client
+            .query_with_variables(&query, &json!({ "query": search_query }))
+            .await?;
         match graphql_response.errors {
             Some(errors) => {
                 log_error!("Error returned in GraphQL response.", "Response" => errors);
-                Err(DomainError::QueryError)
+                Err(ShopifyError::from_graphql_errors(&errors)
+                    .into_domain_error(DomainError::QueryError))
             }
             None => {
                 let variant_nodes: Vec<VariantNode> = graphql_response
@@ -130,34 +90,37 @@ impl<C: ECClient + Send + Sync> ProductRepository for ProductRepositoryImpl<C> {
         let mut products_cursor = None;
         let mut all_variants: Vec<VariantNode> = Vec::new();
 
-        let first_query = ShopifyGQLHelper::first_query();
-        let product_fields = Self::product_fields();
-        let page_info = ShopifyGQLHelper::page_info();
+        let products_query = Operation::query("products")
+            .variable("after", "String")
+            .with(
+                Field::new("products")
+                    .first()
+                    .var("after")
+                    .select_type::<Edges<ProductNode>>(),
+            )
+            .build();
+        let variants_query = Operation::query("productVariants")
+            .variable("after", "String")
+            .variable("query", "String!")
+            .with(
+                Field::new("productVariants")
+                    .first()
+                    .var("after")
+                    .var("query")
+                    .select_type::<Edges<VariantNode>>(),
+            )
+            .build();
 
         for i in 0..((limit + offset) / query_limit).max(1) {
-            let products_after_query = products_cursor
-                .as_deref()
-                .map_or(String::new(), |a| format!("after: \"{}\"", a));
-
-            let products_query = format!(
-                "query {{
-                    products({first_query}, {products_after_query}) {{
-                        edges {{
-                            node {{
-                                {product_fields}
-                            }}
-                        }}
-                        {page_info}
-                    }}
-                }}"
-            );
-
-            let products_response: GraphQLResponse<ProductsData> =
-                self.client.query(&products_query).await?;
+            let products_response: GraphQLResponse<ProductsData> = self
+                .client
+                .query_with_variables(&products_query, &json!({ "after": products_cursor }))
+                .await?;
             match products_response.errors {
                 Some(errors) => {
                     log_error!("Error returned in Products response.", "Response" => errors);
-                    return Err(DomainError::QueryError);
+                    return Err(ShopifyError::from_graphql_errors(&errors)
+                        .into_domain_error(DomainError::QueryError));
                 }
                 None => {
                     let products_data = products_response
@@ -194,31 +157,23 @@ impl<C: ECClient + Send + Sync> ProductRepository for ProductRepositoryImpl<C> {
                     log_debug!("product_ids", "product_ids" => product_ids);
 
                     let mut variants_cursor = None;
-                    let variant_fields = Self::variant_fields();
+                    let variants_search_query = format!("product_ids:'{product_ids}'");
                     loop {
-                        let variants_after_query = variants_cursor
-                            .as_deref()
-                            .map_or(String::new(), |a| format!("after: \"{}\"", a));
-
-                        let variants_query = format!(
-                            "query {{
-                                productVariants({first_query}, {variants_after_query}, query: \"product_ids:'{product_ids}'\") {{
-                                    edges {{
-                                        node {{
-                                            {variant_fields}
-                                        }}
-                                    }}
-                                    {page_info}
-                                }}
-                            }}"
-                        );
-
-                        let variants_response: GraphQLResponse<VariantsData> =
-                            self.client.query(&variants_query).await?;
+                        let variants_response: GraphQLResponse<VariantsData> = self
+                            .client
+                            .query_with_variables(
+                                &variants_query,
+                                &json!({
+                                    "after": variants_cursor,
+                                    "query": variants_search_query,
+                                }),
+                            )
+                            .await?;
                         match variants_response.errors {
                             Some(errors) => {
                                 log_error!("Error returned in Variants response.", "Response" => errors);
-                                return Err(DomainError::QueryError);
+                                return Err(ShopifyError::from_graphql_errors(&errors)
+                                    .into_domain_error(DomainError::QueryError));
                             }
                             None => {
                                 let variants_data = variants_response
@@ -269,12 +224,14 @@ impl<C: ECClient + Send + Sync> ProductRepository for ProductRepositoryImpl<C> {
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
+    use serde_json::Value;
 
     use crate::{
         domain::{error::error::DomainError, product::product::ProductStatus},
         infrastructure::ec::{
             ec_client_interface::MockECClient,
             shopify::{
+                query_builder::assert_selection_matches,
                 repository::schema::product::{
                     InventoryItemIdNode, ProductNode, TaxonomyCategoryNode, VariantNode,
                 },
@@ -401,9 +358,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_variants_response(PageOption {
                     start: 0,
                     end: 1,
@@ -427,9 +384,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 let mut mock = mock_variants_response(PageOption {
                     start: 0,
                     end: 2,
@@ -478,9 +435,9 @@ mod tests {
             .title = "".to_string();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -514,9 +471,9 @@ mod tests {
             .status = "INVALID_STATUS".to_string();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -549,9 +506,9 @@ mod tests {
             .inventory_policy = "INVALID_POLICY".to_string();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| Ok(invalid_response));
+            .return_once(|_, _| Ok(invalid_response));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -570,9 +527,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -591,9 +548,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -612,9 +569,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_products_response(PageOption {
                     start: 0,
                     end: 250,
@@ -622,9 +579,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_variants_response(PageOption {
                     start: 0,
                     end: 250,
@@ -654,9 +611,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_products_response(PageOption {
                     start: 0,
                     end: 250,
@@ -664,9 +621,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_variants_response(PageOption {
                     start: 0,
                     end: 250,
@@ -696,9 +653,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_products_response(PageOption {
                     start: 0,
                     end: 250,
@@ -706,9 +663,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_variants_response(PageOption {
                     start: 0,
                     end: 250,
@@ -716,9 +673,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_products_response(PageOption {
                     start: 250,
                     end: 500,
@@ -726,9 +683,9 @@ mod tests {
                 }))
             });
         client
-            .expect_query::<GraphQLResponse<VariantsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<VariantsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_variants_response(PageOption {
                     start: 250,
                     end: 500,
@@ -758,9 +715,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| {
+            .return_once(|_, _| {
                 Ok(mock_products_response(PageOption {
                     start: 0,
                     end: 0,
@@ -784,9 +741,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_error()));
+            .return_once(|_, _| Ok(mock_with_error()));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -805,9 +762,9 @@ mod tests {
         let mut client = MockECClient::new();
 
         client
-            .expect_query::<GraphQLResponse<ProductsData>>()
+            .expect_query_with_variables::<Value, GraphQLResponse<ProductsData>>()
             .times(1)
-            .return_once(|_| Ok(mock_with_no_data()));
+            .return_once(|_, _| Ok(mock_with_no_data()));
 
         let repo = ProductRepositoryImpl::new(client);
 
@@ -820,4 +777,12 @@ mod tests {
             panic!("Expected DomainError::QueryError, but got something else");
         }
     }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<VariantNode>();
+        assert_selection_matches::<ProductNode>();
+        assert_selection_matches::<TaxonomyCategoryNode>();
+        assert_selection_matches::<InventoryItemIdNode>();
+    }
 }
@@ -0,0 +1,482 @@
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::{
+    domain::{error::error::DomainError, order::order::Id as OrderId, refund::refund::Refund},
+    infrastructure::{
+        ec::{
+            ec_client_interface::ECClient,
+            shopify::{
+                error::ShopifyError,
+                gql_helper::ShopifyGQLHelper,
+                query_builder::{Field, Operation},
+                repository::schema::{
+                    refund::{
+                        OrderRefundsData, OrderRefundsNode, OrderTransactionsData,
+                        OrderTransactionsNode,
+                    },
+                    refund_input::{
+                        ParentTransaction, RefundCreate, RefundCreateData, RefundInput,
+                    },
+                },
+                schema::GraphQLResponse,
+            },
+        },
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+    usecase::repository::refund_repository_interface::RefundRepository,
+};
+
+/// Repository for Refunds for Shopify.
+pub struct RefundRepositoryImpl<C: ECClient> {
+    client: C,
+}
+
+impl<C: ECClient> RefundRepositoryImpl<C> {
+    pub fn new(client: C) -> Self {
+        Self { client }
+    }
+
+    /// Find the captured payment of the order that the refund is returned through.
+    async fn find_parent_transaction(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<ParentTransaction, DomainError> {
+        let id = ShopifyGQLHelper::add_order_gid_prefix(order_id);
+        let query = Operation::query("order")
+            .variable("id", "ID!")
+            .with(
+                Field::new("order")
+                    .var("id")
+                    .select_type::<OrderTransactionsNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderTransactionsData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .order
+            .ok_or(DomainError::NotFound)?
+            .transactions
+            .into_iter()
+            .find(|t| t.status == "SUCCESS" && (t.kind == "SALE" || t.kind == "CAPTURE"))
+            .and_then(|t| {
+                t.gateway
+                    .map(|gateway| ParentTransaction { id: t.id, gateway })
+            })
+            .ok_or_else(|| {
+                log_error!("No captured payment to refund.", "order_id" => order_id);
+                DomainError::SaveError
+            })
+    }
+}
+
+#[async_trait]
+impl<C: ECClient + Send + Sync> RefundRepository for RefundRepositoryImpl<C> {
+    async fn find_refunds_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<Refund>, DomainError> {
+        let id = ShopifyGQLHelper::add_order_gid_prefix(order_id);
+        let query = Operation::query("order")
+            .variable("id", "ID!")
+            .with(
+                Field::new("order")
+                    .var("id")
+                    .select_type::<OrderRefundsNode>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<OrderRefundsData> = self
+            .client
+            .query_with_variables(&query, &json!({ "id": id }))
+            .await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::QueryError));
+        }
+
+        graphql_response
+            .data
+            .ok_or(DomainError::QueryError)?
+            .order
+            .ok_or(DomainError::NotFound)?
+            .refunds
+            .into_iter()
+            .map(|refund| refund.to_domain())
+            .collect()
+    }
+
+    async fn create(&self, refund: Refund) -> Result<Refund, DomainError> {
+        let parent_transaction = self.find_parent_transaction(refund.order_id()).await?;
+
+        let input = serde_json::to_value(RefundInput::from_domain(refund, parent_transaction))
+            .map_err(|e| {
+                log_error!("Failed to parse the request structure.", "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::ParseError(e))
+            })?;
+
+        let query = Operation::mutation("refundCreate")
+            .variable("input", "RefundInput!")
+            .with(
+                Field::new("refundCreate")
+                    .var("input")
+                    .select_type::<RefundCreate>(),
+            )
+            .build();
+
+        let graphql_response: GraphQLResponse<RefundCreateData> =
+            self.client.mutation(&query, &input).await?;
+        if let Some(errors) = graphql_response.errors {
+            log_error!("Error returned in GraphQL response.", "Response" => errors);
+            return Err(ShopifyError::from_graphql_errors(&errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        let data = graphql_response
+            .data
+            .ok_or(DomainError::SaveError)?
+            .refund_create;
+
+        if !data.user_errors.is_empty() {
+            log_error!("UserErrors returned.", "userErrors" => data.user_errors);
+            return Err(ShopifyError::from_user_errors(&data.user_errors)
+                .into_domain_error(DomainError::SaveError));
+        }
+
+        match data.refund {
+            Some(refund) => refund.to_domain(),
+            None => {
+                log_error!("No refund returned.");
+                Err(DomainError::SaveError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::Value;
+
+    use crate::{
+        domain::{
+            error::error::DomainError,
+            money::{
+                amount::amount::Amount,
+                money::{CurrencyCode, Money},
+            },
+            refund::{
+                refund::{Refund, RefundParams},
+                refund_line_item::refund_line_item::RefundLineItem,
+            },
+        },
+        infrastructure::ec::{
+            ec_client_interface::MockECClient,
+            shopify::{
+                query_builder::assert_selection_matches,
+                repository::{
+                    refund::refund_impl::RefundRepositoryImpl,
+                    schema::{
+                        draft_order::OrderIdNode,
+                        fulfillment_order::{LineItemIdNode, LocationIdNode},
+                        money::{CurrencyCodeNode, MoneyBagNode, MoneyNode},
+                        refund::{
+                            OrderRefundsData, OrderRefundsNode, OrderTransactionsData,
+                            OrderTransactionsNode, RefundLineItemNode, RefundNode,
+                            RefundShippingLineNode, TransactionNode,
+                        },
+                        refund_input::{RefundCreate, RefundCreateData},
+                    },
+                },
+                schema::{Edges, GraphQLError, GraphQLResponse, Node, PageInfo, UserError},
+            },
+        },
+        usecase::repository::refund_repository_interface::RefundRepository,
+    };
+
+    fn mock_page_info() -> PageInfo {
+        PageInfo {
+            has_previous_page: false,
+            has_next_page: false,
+            start_cursor: None,
+            end_cursor: None,
+        }
+    }
+
+    fn mock_money_bag(amount: &str) -> MoneyBagNode {
+        MoneyBagNode {
+            shop_money: MoneyNode {
+                amount: amount.to_string(),
+                currency_code: CurrencyCodeNode("USD".to_string()),
+            },
+        }
+    }
+
+    fn mock_money(value: f64) -> Money {
+        Money::new(CurrencyCode::USD, Amount::new(value).unwrap()).unwrap()
+    }
+
+    fn mock_refund_node() -> RefundNode {
+        RefundNode {
+            id: "gid://shopify/Refund/1".to_string(),
+            note: Some("Damaged items".to_string()),
+            order: OrderIdNode {
+                id: "gid://shopify/Order/1".to_string(),
+            },
+            total_refunded_set: mock_money_bag("65.0"),
+            refund_line_items: Edges {
+                edges: vec![Node {
+                    node: RefundLineItemNode {
+                        line_item: LineItemIdNode {
+                            id: "gid://shopify/LineItem/1".to_string(),
+                        },
+                        quantity: 2,
+                        subtotal_set: mock_money_bag("60.0"),
+                        restock_type: "RETURN".to_string(),
+                        location: Some(LocationIdNode {
+                            id: "gid://shopify/Location/1".to_string(),
+                        }),
+                    },
+                }],
+                page_info: mock_page_info(),
+            },
+            refund_shipping_lines: Edges {
+                edges: vec![Node {
+                    node: RefundShippingLineNode {
+                        subtotal_amount_set: mock_money_bag("5.0"),
+                    },
+                }],
+                page_info: mock_page_info(),
+            },
+            created_at: Some(Utc::now()),
+        }
+    }
+
+    fn mock_refund_domain() -> Refund {
+        Refund::new(RefundParams {
+            id: "1".to_string(),
+            order_id: "1".to_string(),
+            note: None,
+            line_items: vec![RefundLineItem::new("1", 2, mock_money(60.0)).unwrap()],
+            shipping_set: mock_money(5.0),
+            total_refunded_set: mock_money(65.0),
+            restock_location_id: Some("1".to_string()),
+            created_at: Utc::now(),
+        })
+        .unwrap()
+    }
+
+    fn mock_transactions_response(
+        transactions: Vec<TransactionNode>,
+    ) -> GraphQLResponse<OrderTransactionsData> {
+        GraphQLResponse {
+            data: Some(OrderTransactionsData {
+                order: Some(OrderTransactionsNode { transactions }),
+            }),
+            errors: None,
+        }
+    }
+
+    fn mock_sale_transaction() -> TransactionNode {
+        TransactionNode {
+            id: "gid://shopify/OrderTransaction/1".to_string(),
+            gateway: Some("shopify_payments".to_string()),
+            kind: "SALE".to_string(),
+            status: "SUCCESS".to_string(),
+        }
+    }
+
+    fn mock_create_response() -> GraphQLResponse<RefundCreateData> {
+        GraphQLResponse {
+            data: Some(RefundCreateData {
+                refund_create: RefundCreate {
+                    refund: Some(mock_refund_node()),
+                    user_errors: vec![],
+                },
+            }),
+            errors: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_refunds_by_order_id_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderRefundsData>>()
+            .withf(|_, variables| variables["id"] == "gid://shopify/Order/1")
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(OrderRefundsData {
+                        order: Some(OrderRefundsNode {
+                            refunds: vec![mock_refund_node()],
+                        }),
+                    }),
+                    errors: None,
+                })
+            });
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.find_refunds_by_order_id(&"1".to_string()).await;
+
+        assert!(result.is_ok());
+        let refunds = result.unwrap();
+        assert_eq!(refunds.len(), 1);
+        assert_eq!(refunds[0].line_items()[0].quantity(), &2);
+        assert_eq!(refunds[0].shipping_set(), &mock_money(5.0));
+    }
+
+    #[tokio::test]
+    async fn test_find_refunds_by_order_id_not_found() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderRefundsData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: Some(OrderRefundsData { order: None }),
+                    errors: None,
+                })
+            });
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.find_refunds_by_order_id(&"1".to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_create_success() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderTransactionsData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_transactions_response(vec![mock_sale_transaction()])));
+        client
+            .expect_mutation::<Value, GraphQLResponse<RefundCreateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_create_response()));
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.create(mock_refund_domain()).await;
+
+        assert!(result.is_ok());
+        let refund = result.unwrap();
+        assert_eq!(refund.id(), "1");
+        assert_eq!(refund.order_id(), "1");
+        assert_eq!(refund.line_items().len(), 1);
+        assert_eq!(refund.line_items()[0].subtotal_set(), &mock_money(60.0));
+        assert_eq!(refund.shipping_set(), &mock_money(5.0));
+        assert_eq!(refund.total_refunded_set(), &mock_money(65.0));
+        assert_eq!(refund.restock_location_id(), &Some("1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_without_captured_payment() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderTransactionsData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(mock_transactions_response(vec![TransactionNode {
+                    kind: "AUTHORIZATION".to_string(),
+                    ..mock_sale_transaction()
+                }]))
+            });
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.create(mock_refund_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_user_errors() {
+        let mut client = MockECClient::new();
+
+        let mut response = mock_create_response();
+        response.data.as_mut().unwrap().refund_create.user_errors = vec![UserError {
+            field: vec!["refundLineItems".to_string()],
+            message: "Invalid quantity".to_string(),
+        }];
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderTransactionsData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_transactions_response(vec![mock_sale_transaction()])));
+        client
+            .expect_mutation::<Value, GraphQLResponse<RefundCreateData>>()
+            .times(1)
+            .return_once(|_, _| Ok(response));
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.create(mock_refund_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_graphql_error() {
+        let mut client = MockECClient::new();
+
+        client
+            .expect_query_with_variables::<Value, GraphQLResponse<OrderTransactionsData>>()
+            .times(1)
+            .return_once(|_, _| Ok(mock_transactions_response(vec![mock_sale_transaction()])));
+        client
+            .expect_mutation::<Value, GraphQLResponse<RefundCreateData>>()
+            .times(1)
+            .return_once(|_, _| {
+                Ok(GraphQLResponse {
+                    data: None,
+                    errors: Some(vec![GraphQLError {
+                        message: "Some GraphQL error".to_string(),
+                        extensions: None,
+                    }]),
+                })
+            });
+
+        let repo = RefundRepositoryImpl::new(client);
+
+        let result = repo.create(mock_refund_domain()).await;
+
+        assert!(matches!(result, Err(DomainError::SaveError)));
+    }
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<RefundNode>();
+        assert_selection_matches::<RefundLineItemNode>();
+        assert_selection_matches::<RefundShippingLineNode>();
+        assert_selection_matches::<OrderRefundsNode>();
+        assert_selection_matches::<OrderTransactionsNode>();
+        assert_selection_matches::<TransactionNode>();
+        assert_selection_matches::<OrderIdNode>();
+        assert_selection_matches::<LineItemIdNode>();
+        assert_selection_matches::<LocationIdNode>();
+        assert_selection_matches::<MoneyBagNode>();
+        assert_selection_matches::<RefundCreate>();
+    }
+}
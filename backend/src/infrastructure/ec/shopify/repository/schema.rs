@@ -1,15 +1,27 @@
 pub mod address;
 pub mod address_input;
 pub mod customer;
+pub mod customer_input;
 pub mod draft_order;
 pub mod draft_order_input;
+pub mod fulfillment;
+pub mod fulfillment_input;
+pub mod fulfillment_order;
 pub mod inventory_change;
 pub mod inventory_item;
 pub mod inventory_level;
 pub mod line_item;
 pub mod line_item_input;
 pub mod location;
+pub mod location_input;
 pub mod media;
+pub mod media_input;
 pub mod money;
 pub mod money_input;
+pub mod order;
+pub mod order_input;
+pub mod order_return;
+pub mod order_return_input;
 pub mod product;
+pub mod refund;
+pub mod refund_input;
@@ -1,35 +1,70 @@
 use serde::Deserialize;
 
-use crate::domain::{address::address::Address, error::error::DomainError};
+use crate::{
+    domain::{
+        address::address::{Address, AddressParams},
+        error::error::DomainError,
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+    },
+};
 
 impl AddressNode {
     pub fn to_domain(self) -> Result<Address, DomainError> {
-        Address::new(
-            self.address1,
-            self.address2,
-            self.city,
-            self.coordinates_validated,
-            self.country,
-            self.first_name,
-            self.last_name,
-            self.province,
-            self.zip,
-            self.phone,
-        )
+        Address::new(AddressParams {
+            id: self.id.map(|id| ShopifyGQLHelper::remove_gid_prefix(&id)),
+            address1: self.address1,
+            address2: self.address2,
+            city: self.city,
+            coordinates_validated: self.coordinates_validated,
+            country: self.country,
+            country_code: self.country_code_v2,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            province: self.province,
+            province_code: self.province_code,
+            zip: self.zip,
+            phone: self.phone,
+        })
+    }
+}
+
+impl Selectable for AddressNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&[
+            "address1",
+            "address2",
+            "city",
+            "coordinatesValidated",
+            "country",
+            "countryCodeV2",
+            "firstName",
+            "id",
+            "lastName",
+            "phone",
+            "province",
+            "provinceCode",
+            "zip",
+        ])
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddressNode {
+    pub id: Option<String>,
     pub address1: Option<String>,
     pub address2: Option<String>,
     pub city: Option<String>,
     pub coordinates_validated: bool,
     pub country: Option<String>,
+    pub country_code_v2: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub province: Option<String>,
+    pub province_code: Option<String>,
     pub zip: Option<String>,
     pub phone: Option<String>,
 }
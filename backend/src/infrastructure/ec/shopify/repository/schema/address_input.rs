@@ -9,9 +9,11 @@ impl From<Address> for AddressInput {
             address2: address.address2().to_owned(),
             city: address.city().to_owned(),
             country: address.country().to_owned(),
+            country_code: address.country_code().to_owned(),
             first_name: address.first_name().to_owned(),
             last_name: address.last_name().to_owned(),
             province: address.province().to_owned(),
+            province_code: address.province_code().to_owned(),
             zip: address.zip().to_owned(),
             phone: address.phone().to_owned(),
         }
@@ -25,9 +27,11 @@ pub struct AddressInput {
     pub address2: Option<String>,
     pub city: Option<String>,
     pub country: Option<String>,
+    pub country_code: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub province: Option<String>,
+    pub province_code: Option<String>,
     pub zip: Option<String>,
     pub phone: Option<String>,
 }
@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 use crate::{
     domain::{
-        customer::customer::{Customer, CustomerStatus},
+        customer::customer::{Customer, CustomerParams, CustomerStatus},
         email::email::Email,
         error::error::DomainError,
         media::associated_id::associated_id::AssociatedId,
@@ -11,6 +11,7 @@ use crate::{
     },
     infrastructure::ec::shopify::{
         gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
         schema::{Edges, Metafield},
     },
 };
@@ -25,6 +26,17 @@ impl CustomerNode {
             "DISABLED" => Ok(CustomerStatus::Inactive),
             _ => Err(DomainError::ConversionError),
         }?;
+        // Shopify has no API to disable a customer account, so the status managed by the application is kept in a metafield.
+        // Customers created outside the application do not have it and fall back to the account state.
+        let status = match self.deactivated.map(|m| m.value) {
+            Some(deactivated) if deactivated == "true" => CustomerStatus::Inactive,
+            Some(_) => CustomerStatus::Active,
+            None => status,
+        };
+        let email_marketing_consent = self
+            .email_marketing_consent
+            .map(|c| c.marketing_state == "SUBSCRIBED")
+            .unwrap_or(false);
         let image = match self.image.id.clone() {
             Some(id) => Some(
                 self.image
@@ -33,28 +45,31 @@ impl CustomerNode {
             None => None,
         };
 
-        Customer::new(
-            id.clone(),
-            self.user_id.value,
-            self.addresses
+        Customer::new(CustomerParams {
+            id: id.clone(),
+            user_id: self.user_id.value,
+            addresses: self
+                .addresses
                 .into_iter()
                 .map(|address| address.to_domain())
                 .collect::<Result<Vec<_>, _>>()?,
-            self.default_address
+            default_address: self
+                .default_address
                 .map(|address| address.to_domain())
                 .transpose()?,
-            self.display_name,
-            self.email.map(|email| Email::new(email)).transpose()?,
-            self.first_name,
-            self.last_name,
+            display_name: self.display_name,
+            email: self.email.map(|email| Email::new(email)).transpose()?,
+            first_name: self.first_name,
+            last_name: self.last_name,
             image,
-            self.phone.map(|phone| Phone::new(phone)).transpose()?,
-            self.note,
+            phone: self.phone.map(|phone| Phone::new(phone)).transpose()?,
+            note: self.note,
             status,
-            self.verified_email,
-            self.created_at,
-            self.updated_at,
-        )
+            verified_email: self.verified_email,
+            email_marketing_consent,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
     }
 
     pub fn to_domains(schemas: Vec<Self>) -> Result<Vec<Customer>, DomainError> {
@@ -65,6 +80,42 @@ impl CustomerNode {
     }
 }
 
+impl Selectable for CustomerNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&[
+                "createdAt",
+                "displayName",
+                "email",
+                "firstName",
+                "id",
+                "lastName",
+                "note",
+                "phone",
+                "state",
+                "updatedAt",
+                "verifiedEmail",
+            ])
+            .object::<EmailMarketingConsentNode>("emailMarketingConsent")
+            .with(Field::new("addresses").first().select_type::<AddressNode>())
+            .object::<ImageNode>("image")
+            .object::<AddressNode>("defaultAddress")
+            .with(Field::metafield("custom", "user_id"))
+            .with(Field::metafield("custom", "deactivated").alias("deactivated"))
+    }
+}
+
+impl Selectable for EmailMarketingConsentNode {
+    fn selection() -> Selection {
+        Selection::new().field("marketingState")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomerData {
+    pub customer: Option<CustomerNode>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CustomersData {
     pub customers: Edges<CustomerNode>,
@@ -87,6 +138,14 @@ pub struct CustomerNode {
     pub note: Option<String>,
     pub state: String,
     pub verified_email: bool,
+    pub email_marketing_consent: Option<EmailMarketingConsentNode>,
+    pub deactivated: Option<Metafield<String>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailMarketingConsentNode {
+    pub marketing_state: String,
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::customer::{
+        customer::{Customer, CustomerStatus},
+        search_criteria::search_criteria::CustomerSortKey,
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::{MetafieldInput, UserError},
+    },
+};
+
+use super::{address::AddressNode, customer::CustomerNode};
+
+impl From<CustomerSortKey> for String {
+    fn from(sort_key: CustomerSortKey) -> Self {
+        match sort_key {
+            CustomerSortKey::CreatedAt => "CREATED_AT".to_string(),
+            CustomerSortKey::Id => "ID".to_string(),
+            CustomerSortKey::Name => "NAME".to_string(),
+            CustomerSortKey::Relevance => "RELEVANCE".to_string(),
+            CustomerSortKey::UpdatedAt => "UPDATED_AT".to_string(),
+        }
+    }
+}
+
+impl From<&Customer> for CustomerInput {
+    fn from(customer: &Customer) -> Self {
+        let id = if customer.id().is_empty() {
+            None
+        } else {
+            Some(ShopifyGQLHelper::add_customer_gid_prefix(customer.id()))
+        };
+        // Marketing consent can only be given with the input on creation,
+        // later changes go through the dedicated mutation.
+        let email_marketing_consent = match (&id, customer.email()) {
+            (None, Some(_)) => Some(EmailMarketingConsentInput::from(customer)),
+            _ => None,
+        };
+
+        Self {
+            id,
+            email: customer.email().as_ref().map(|e| e.value().to_owned()),
+            first_name: customer.first_name().to_owned(),
+            last_name: customer.last_name().to_owned(),
+            phone: customer.phone().as_ref().map(|p| p.value().to_owned()),
+            note: customer.note().to_owned(),
+            email_marketing_consent,
+            metafields: Self::metafields(customer),
+        }
+    }
+}
+
+impl CustomerInput {
+    fn metafields(customer: &Customer) -> Vec<MetafieldInput<String>> {
+        let mut metafields = vec![MetafieldInput {
+            key: "deactivated".to_string(),
+            namespace: "custom".to_string(),
+            value: (customer.status() == &CustomerStatus::Inactive).to_string(),
+        }];
+        // Shopify rejects blank metafield values, so an unlinked customer has no owner metafield.
+        if !customer.user_id().is_empty() {
+            metafields.push(MetafieldInput {
+                key: "user_id".to_string(),
+                namespace: "custom".to_string(),
+                value: customer.user_id().to_owned(),
+            });
+        }
+        metafields
+    }
+}
+
+impl From<&Customer> for EmailMarketingConsentInput {
+    fn from(customer: &Customer) -> Self {
+        let marketing_state = if *customer.email_marketing_consent() {
+            "SUBSCRIBED"
+        } else {
+            "NOT_SUBSCRIBED"
+        };
+        Self {
+            marketing_state: marketing_state.to_string(),
+        }
+    }
+}
+
+impl From<&Customer> for CustomerEmailMarketingConsentUpdateInput {
+    fn from(customer: &Customer) -> Self {
+        Self {
+            customer_id: ShopifyGQLHelper::add_customer_gid_prefix(customer.id()),
+            email_marketing_consent: customer.into(),
+        }
+    }
+}
+
+impl Selectable for CustomerMutation {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<CustomerNode>("customer")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for CustomerAddressMutation {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<AddressNode>("address")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for CustomerAddressDelete {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("deletedAddressId")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for CustomerRequestDataErasure {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("customerId")
+            .object::<UserError>("userErrors")
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerInput {
+    pub id: Option<String>,
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone: Option<String>,
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_marketing_consent: Option<EmailMarketingConsentInput>,
+    pub metafields: Vec<MetafieldInput<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailMarketingConsentInput {
+    pub marketing_state: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerEmailMarketingConsentUpdateInput {
+    pub customer_id: String,
+    pub email_marketing_consent: EmailMarketingConsentInput,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerCreateData {
+    pub customer_create: CustomerMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerUpdateData {
+    pub customer_update: CustomerMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerEmailMarketingConsentUpdateData {
+    pub customer_email_marketing_consent_update: CustomerMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerMutation {
+    pub customer: Option<CustomerNode>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerAddressCreateData {
+    pub customer_address_create: CustomerAddressMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerAddressUpdateData {
+    pub customer_address_update: CustomerAddressMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerAddressMutation {
+    pub address: Option<AddressNode>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerAddressDeleteData {
+    pub customer_address_delete: CustomerAddressDelete,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerAddressDelete {
+    pub deleted_address_id: Option<String>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerRequestDataErasureData {
+    pub customer_request_data_erasure: CustomerRequestDataErasure,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerRequestDataErasure {
+    pub customer_id: Option<String>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerUpdateDefaultAddressData {
+    pub customer_update_default_address: CustomerMutation,
+}
@@ -2,9 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::{
-    domain::{draft_order::draft_order::DraftOrder, error::error::DomainError},
+    domain::{
+        draft_order::draft_order::{DraftOrder, DraftOrderParams, DraftOrderStatus},
+        error::error::DomainError,
+    },
     infrastructure::ec::shopify::{
         gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
         schema::{Edges, Metafield},
     },
 };
@@ -17,44 +21,53 @@ use super::{
 
 impl DraftOrderNode {
     pub fn to_domain(self) -> Result<DraftOrder, DomainError> {
+        let cancel_reason = self.cancel_reason.map(|m| m.value);
         let status = match self.status.as_str() {
-            "OPEN" => Ok(crate::domain::draft_order::draft_order::DraftOrderStatus::Open),
-            "COMPLETED" => Ok(crate::domain::draft_order::draft_order::DraftOrderStatus::Completed),
-            "CANCELED" => Ok(crate::domain::draft_order::draft_order::DraftOrderStatus::Canceled),
+            // Shopify does not support canceling draft orders,
+            // so an open draft order with a cancel reason is treated as canceled.
+            "OPEN" if cancel_reason.is_some() => Ok(DraftOrderStatus::Canceled),
+            "OPEN" => Ok(DraftOrderStatus::Open),
+            "COMPLETED" => Ok(DraftOrderStatus::Completed),
+            "CANCELED" => Ok(DraftOrderStatus::Canceled),
             _ => Err(DomainError::ConversionError),
         }?;
 
-        DraftOrder::new(
-            ShopifyGQLHelper::remove_gid_prefix(&self.id),
-            self.name,
+        DraftOrder::new(DraftOrderParams {
+            id: ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            name: self.name,
             status,
-            self.customer
+            customer_id: self
+                .customer
                 .map(|c| ShopifyGQLHelper::remove_gid_prefix(&c.id)),
-            self.billing_address.map(|a| a.to_domain()).transpose()?,
-            self.shipping_address.map(|a| a.to_domain()).transpose()?,
-            self.note2,
-            self.line_items
+            billing_address: self.billing_address.map(|a| a.to_domain()).transpose()?,
+            shipping_address: self.shipping_address.map(|a| a.to_domain()).transpose()?,
+            note: self.note2,
+            line_items: self
+                .line_items
                 .edges
                 .into_iter()
                 .map(|node| node.node.to_domain())
                 .collect::<Result<Vec<_>, _>>()?,
-            self.reserve_inventory_until,
-            self.applied_discount.map(|d| d.to_domain()).transpose()?,
-            self.subtotal_price_set.to_domain()?,
-            self.taxes_included,
-            self.tax_exempt,
-            self.total_tax_set.to_domain()?,
-            self.total_discounts_set.to_domain()?,
-            self.total_shipping_price_set.to_domain()?,
-            self.total_price_set.to_domain()?,
-            self.presentment_currency_code.to_domain()?,
-            self.order
+            reserve_inventory_until: self.reserve_inventory_until,
+            discount: self.applied_discount.map(|d| d.to_domain()).transpose()?,
+            subtotal_price_set: self.subtotal_price_set.to_domain()?,
+            taxes_included: self.taxes_included,
+            tax_exempt: self.tax_exempt,
+            total_tax_set: self.total_tax_set.to_domain()?,
+            total_discounts_set: self.total_discounts_set.to_domain()?,
+            total_shipping_price_set: self.total_shipping_price_set.to_domain()?,
+            total_price_set: self.total_price_set.to_domain()?,
+            presentment_currency_code: self.presentment_currency_code.to_domain()?,
+            order_id: self
+                .order
                 .map(|o| ShopifyGQLHelper::remove_gid_prefix(&o.id)),
-            self.owner_user_id.value,
-            self.completed_at,
-            self.created_at,
-            self.updated_at,
-        )
+            owner_user_id: self.owner_user_id.value,
+            cancel_reason,
+            completed_at: self.completed_at,
+            canceled_at: self.canceled_at.map(|m| m.value),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
     }
 
     pub fn to_domains(schemas: Vec<Self>) -> Result<Vec<DraftOrder>, DomainError> {
@@ -65,6 +78,48 @@ impl DraftOrderNode {
     }
 }
 
+impl Selectable for DraftOrderNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "name", "status"])
+            .object::<CustomerIdNode>("customer")
+            .object::<AddressNode>("billingAddress")
+            .object::<AddressNode>("shippingAddress")
+            .field("note2")
+            .with(
+                Field::new("lineItems")
+                    .first()
+                    .select_type::<Edges<LineItemNode>>(),
+            )
+            .field("reserveInventoryUntil")
+            .object::<DiscountNode>("appliedDiscount")
+            .object::<MoneyBagNode>("subtotalPriceSet")
+            .fields(&["taxesIncluded", "taxExempt"])
+            .object::<MoneyBagNode>("totalTaxSet")
+            .object::<MoneyBagNode>("totalDiscountsSet")
+            .object::<MoneyBagNode>("totalShippingPriceSet")
+            .object::<MoneyBagNode>("totalPriceSet")
+            .field("presentmentCurrencyCode")
+            .object::<OrderIdNode>("order")
+            .with(Field::metafield("custom", "owner_user_id"))
+            .with(Field::metafield("custom", "cancel_reason").alias("cancelReason"))
+            .with(Field::metafield("custom", "canceled_at").alias("canceledAt"))
+            .fields(&["completedAt", "createdAt", "updatedAt"])
+    }
+}
+
+impl Selectable for CustomerIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for OrderIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DraftOrderData {
@@ -107,6 +162,8 @@ pub struct DraftOrderNode {
 
     #[serde(rename = "metafield")]
     pub owner_user_id: Metafield<String>,
+    pub cancel_reason: Option<Metafield<String>>,
+    pub canceled_at: Option<Metafield<DateTime<Utc>>>,
 
     pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
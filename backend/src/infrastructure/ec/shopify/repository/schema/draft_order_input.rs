@@ -5,6 +5,7 @@ use crate::{
     domain::{customer::customer::Id as CustomerId, draft_order::draft_order::DraftOrder},
     infrastructure::ec::shopify::{
         gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
         schema::{MetafieldInput, UserError},
     },
 };
@@ -26,17 +27,36 @@ impl From<DraftOrder> for DraftOrderInput {
             reserve_inventory_until: draft_order.reserve_inventory_until().to_owned(),
             applied_discount: draft_order.discount().to_owned().map(|d| d.into()),
             tax_exempt: Some(*draft_order.tax_exempt()),
-            metafields: vec![{
-                MetafieldInput {
-                    key: "owner_user_id".to_string(),
-                    namespace: "custom".to_string(),
-                    value: draft_order.owner_user_id().to_owned(),
-                }
-            }],
+            metafields: Self::metafields(&draft_order),
         }
     }
 }
 
+impl DraftOrderInput {
+    fn metafields(draft_order: &DraftOrder) -> Vec<MetafieldInput<String>> {
+        let mut metafields = vec![MetafieldInput {
+            key: "owner_user_id".to_string(),
+            namespace: "custom".to_string(),
+            value: draft_order.owner_user_id().to_owned(),
+        }];
+        if let Some(cancel_reason) = draft_order.cancel_reason() {
+            metafields.push(MetafieldInput {
+                key: "cancel_reason".to_string(),
+                namespace: "custom".to_string(),
+                value: cancel_reason.to_owned(),
+            });
+        }
+        if let Some(canceled_at) = draft_order.canceled_at() {
+            metafields.push(MetafieldInput {
+                key: "canceled_at".to_string(),
+                namespace: "custom".to_string(),
+                value: canceled_at.to_rfc3339(),
+            });
+        }
+        metafields
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DraftOrderInput {
@@ -83,6 +103,38 @@ pub struct DraftOrderDeleteInput {
     pub id: String,
 }
 
+impl Selectable for DraftOrderCreate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<DraftOrderNode>("draftOrder")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for DraftOrderUpdate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<DraftOrderNode>("draftOrder")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for DraftOrderComplete {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<DraftOrderNode>("draftOrder")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for DraftOrderDelete {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("deletedId")
+            .object::<UserError>("userErrors")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DraftOrderCreateData {
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment::{
+            fulfillment::{Fulfillment, FulfillmentStatus},
+            fulfillment_line_item::fulfillment_line_item::FulfillmentLineItem,
+            tracking_info::tracking_info::TrackingInfo,
+        },
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
+};
+
+use super::{draft_order::OrderIdNode, fulfillment_order::LineItemIdNode};
+
+impl FulfillmentNode {
+    pub fn to_domain(self) -> Result<Fulfillment, DomainError> {
+        let status = match self.status.as_str() {
+            "PENDING" => Ok(FulfillmentStatus::Pending),
+            "OPEN" => Ok(FulfillmentStatus::Open),
+            "SUCCESS" => Ok(FulfillmentStatus::Success),
+            "CANCELLED" => Ok(FulfillmentStatus::Cancelled),
+            "ERROR" => Ok(FulfillmentStatus::Error),
+            "FAILURE" => Ok(FulfillmentStatus::Failure),
+            _ => Err(DomainError::ConversionError),
+        }?;
+
+        Fulfillment::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            ShopifyGQLHelper::remove_gid_prefix(&self.order.id),
+            status,
+            self.fulfillment_line_items
+                .edges
+                .into_iter()
+                .map(|node| node.node.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+            // Tracking entries without a number carry no information for customers.
+            self.tracking_info
+                .into_iter()
+                .filter(|t| t.number.is_some())
+                .map(|t| t.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+impl FulfillmentLineItemNode {
+    pub fn to_domain(self) -> Result<FulfillmentLineItem, DomainError> {
+        FulfillmentLineItem::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            ShopifyGQLHelper::remove_gid_prefix(&self.line_item.id),
+            None,
+            None,
+            self.quantity as u32,
+        )
+    }
+}
+
+impl TrackingInfoNode {
+    pub fn to_domain(self) -> Result<TrackingInfo, DomainError> {
+        TrackingInfo::new(self.company, self.number.unwrap_or_default(), self.url)
+    }
+}
+
+impl Selectable for FulfillmentNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "status"])
+            .object::<OrderIdNode>("order")
+            .object::<TrackingInfoNode>("trackingInfo")
+            .with(
+                Field::new("fulfillmentLineItems")
+                    .first()
+                    .select_type::<Edges<FulfillmentLineItemNode>>(),
+            )
+            .fields(&["createdAt", "updatedAt"])
+    }
+}
+
+impl Selectable for FulfillmentLineItemNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("id")
+            .object::<LineItemIdNode>("lineItem")
+            .field("quantity")
+    }
+}
+
+impl Selectable for TrackingInfoNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["company", "number", "url"])
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentData {
+    pub fulfillment: Option<FulfillmentNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentNode {
+    pub id: String,
+    pub status: String,
+    pub order: OrderIdNode,
+    pub tracking_info: Vec<TrackingInfoNode>,
+    pub fulfillment_line_items: Edges<FulfillmentLineItemNode>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentLineItemNode {
+    pub id: String,
+    pub line_item: LineItemIdNode,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrackingInfoNode {
+    pub company: Option<String>,
+    pub number: Option<String>,
+    pub url: Option<String>,
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::fulfillment::{fulfillment::Fulfillment, tracking_info::tracking_info::TrackingInfo},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::UserError,
+    },
+};
+
+use super::fulfillment::FulfillmentNode;
+
+impl From<Fulfillment> for FulfillmentInput {
+    fn from(fulfillment: Fulfillment) -> Self {
+        // Shopify expects the line items to be grouped by fulfillment order.
+        let mut line_items_by_fulfillment_order: Vec<FulfillmentOrderLineItemsInput> = Vec::new();
+        for line_item in fulfillment.line_items() {
+            let (Some(fulfillment_order_id), Some(fulfillment_order_line_item_id)) = (
+                line_item.fulfillment_order_id(),
+                line_item.fulfillment_order_line_item_id(),
+            ) else {
+                continue;
+            };
+
+            let fulfillment_order_id =
+                ShopifyGQLHelper::add_fulfillment_order_gid_prefix(fulfillment_order_id);
+            let input = FulfillmentOrderLineItemInput {
+                id: ShopifyGQLHelper::add_fulfillment_order_line_item_gid_prefix(
+                    fulfillment_order_line_item_id,
+                ),
+                quantity: *line_item.quantity() as i32,
+            };
+
+            match line_items_by_fulfillment_order
+                .iter_mut()
+                .find(|l| l.fulfillment_order_id == fulfillment_order_id)
+            {
+                Some(group) => group.fulfillment_order_line_items.push(input),
+                None => line_items_by_fulfillment_order.push(FulfillmentOrderLineItemsInput {
+                    fulfillment_order_id,
+                    fulfillment_order_line_items: vec![input],
+                }),
+            }
+        }
+
+        Self {
+            line_items_by_fulfillment_order,
+            tracking_info: fulfillment
+                .tracking_info()
+                .first()
+                .map(|t| t.to_owned().into()),
+        }
+    }
+}
+
+impl From<TrackingInfo> for FulfillmentTrackingInput {
+    fn from(tracking_info: TrackingInfo) -> Self {
+        Self {
+            company: tracking_info.company().to_owned(),
+            number: Some(tracking_info.number().to_owned()),
+            url: tracking_info.url().to_owned(),
+        }
+    }
+}
+
+impl Selectable for FulfillmentCreate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<FulfillmentNode>("fulfillment")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for FulfillmentTrackingInfoUpdate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<FulfillmentNode>("fulfillment")
+            .object::<UserError>("userErrors")
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentInput {
+    pub line_items_by_fulfillment_order: Vec<FulfillmentOrderLineItemsInput>,
+    pub tracking_info: Option<FulfillmentTrackingInput>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentOrderLineItemsInput {
+    pub fulfillment_order_id: String,
+    pub fulfillment_order_line_items: Vec<FulfillmentOrderLineItemInput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FulfillmentOrderLineItemInput {
+    pub id: String,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FulfillmentTrackingInput {
+    pub company: Option<String>,
+    pub number: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentCreateData {
+    pub fulfillment_create: FulfillmentCreate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentCreate {
+    pub fulfillment: Option<FulfillmentNode>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentTrackingInfoUpdateData {
+    pub fulfillment_tracking_info_update: FulfillmentTrackingInfoUpdate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentTrackingInfoUpdate {
+    pub fulfillment: Option<FulfillmentNode>,
+    pub user_errors: Vec<UserError>,
+}
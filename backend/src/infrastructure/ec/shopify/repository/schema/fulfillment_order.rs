@@ -0,0 +1,159 @@
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        fulfillment_order::{
+            fulfillment_order::{FulfillmentOrder, FulfillmentOrderStatus},
+            fulfillment_order_line_item::fulfillment_order_line_item::FulfillmentOrderLineItem,
+        },
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
+};
+
+impl FulfillmentOrderNode {
+    pub fn to_domain(self) -> Result<FulfillmentOrder, DomainError> {
+        let status = match self.status.as_str() {
+            "OPEN" => Ok(FulfillmentOrderStatus::Open),
+            "IN_PROGRESS" => Ok(FulfillmentOrderStatus::InProgress),
+            "SCHEDULED" => Ok(FulfillmentOrderStatus::Scheduled),
+            "ON_HOLD" => Ok(FulfillmentOrderStatus::OnHold),
+            "INCOMPLETE" => Ok(FulfillmentOrderStatus::Incomplete),
+            "CLOSED" => Ok(FulfillmentOrderStatus::Closed),
+            "CANCELLED" => Ok(FulfillmentOrderStatus::Cancelled),
+            _ => Err(DomainError::ConversionError),
+        }?;
+
+        FulfillmentOrder::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            ShopifyGQLHelper::remove_gid_prefix(&self.order_id),
+            self.assigned_location
+                .location
+                .map(|l| ShopifyGQLHelper::remove_gid_prefix(&l.id)),
+            status,
+            self.line_items
+                .edges
+                .into_iter()
+                .map(|node| node.node.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    }
+
+    pub fn to_domains(schemas: Vec<Self>) -> Result<Vec<FulfillmentOrder>, DomainError> {
+        schemas
+            .into_iter()
+            .map(|schema| schema.to_domain())
+            .collect()
+    }
+}
+
+impl FulfillmentOrderLineItemNode {
+    pub fn to_domain(self) -> Result<FulfillmentOrderLineItem, DomainError> {
+        FulfillmentOrderLineItem::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            ShopifyGQLHelper::remove_gid_prefix(&self.line_item.id),
+            self.total_quantity as u32,
+            self.remaining_quantity as u32,
+        )
+    }
+}
+
+impl Selectable for OrderFulfillmentOrdersNode {
+    fn selection() -> Selection {
+        Selection::new().with(
+            Field::new("fulfillmentOrders")
+                .first()
+                .select_type::<Edges<FulfillmentOrderNode>>(),
+        )
+    }
+}
+
+impl Selectable for FulfillmentOrderNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "orderId", "status"])
+            .object::<AssignedLocationNode>("assignedLocation")
+            .with(
+                Field::new("lineItems")
+                    .first()
+                    .select_type::<Edges<FulfillmentOrderLineItemNode>>(),
+            )
+    }
+}
+
+impl Selectable for AssignedLocationNode {
+    fn selection() -> Selection {
+        Selection::new().object::<LocationIdNode>("location")
+    }
+}
+
+impl Selectable for LocationIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for FulfillmentOrderLineItemNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("id")
+            .object::<LineItemIdNode>("lineItem")
+            .fields(&["totalQuantity", "remainingQuantity"])
+    }
+}
+
+impl Selectable for LineItemIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFulfillmentOrdersData {
+    pub order: Option<OrderFulfillmentOrdersNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFulfillmentOrdersNode {
+    pub fulfillment_orders: Edges<FulfillmentOrderNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentOrderNode {
+    pub id: String,
+    pub order_id: String,
+    pub status: String,
+    pub assigned_location: AssignedLocationNode,
+    pub line_items: Edges<FulfillmentOrderLineItemNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignedLocationNode {
+    pub location: Option<LocationIdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LocationIdNode {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfillmentOrderLineItemNode {
+    pub id: String,
+    pub line_item: LineItemIdNode,
+    pub total_quantity: i32,
+    pub remaining_quantity: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LineItemIdNode {
+    pub id: String,
+}
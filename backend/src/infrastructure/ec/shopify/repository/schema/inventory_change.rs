@@ -9,7 +9,11 @@ use crate::{
             quantity::quantity::InventoryType,
         },
     },
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::UserError},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::UserError,
+    },
     log_error,
 };
 
@@ -56,6 +60,7 @@ impl From<InventoryChangeReason> for String {
             InventoryChangeReason::ReservationCreated => "reservation_created",
             InventoryChangeReason::ReservationDeleted => "reservation_deleted",
             InventoryChangeReason::ReservationUpdated => "reservation_updated",
+            InventoryChangeReason::Restock => "restock",
         }
         .to_string()
     }
@@ -91,6 +96,31 @@ pub struct InventoryChangeInput {
     pub location_id: String,
 }
 
+impl Selectable for InventoryAdjustQuantities {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<InventoryAdjustmentGroupNode>("inventoryAdjustmentGroup")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for InventoryAdjustmentGroupNode {
+    fn selection() -> Selection {
+        // NOTE: By specifying quantityNames, only the results of the specified name will be responded to, so that the results acquired in the inventoryLevels field will not be duplicated.
+        Selection::new().with(
+            Field::new("changes")
+                .var("quantityNames")
+                .select_type::<InventoryChangeNode>(),
+        )
+    }
+}
+
+impl Selectable for InventoryChangeNode {
+    fn selection() -> Selection {
+        Selection::new().with(Field::new("item").select(InventoryItemNode::selection_with_level()))
+    }
+}
+
 impl InventoryAdjustmentGroupNode {
     #[allow(dead_code)]
     /// Convert to multiple InventoryLevels (multiple SKUs, multiple locations)
@@ -3,7 +3,11 @@ use serde::Deserialize;
 
 use crate::{
     domain::{error::error::DomainError, inventory_item::inventory_item::InventoryItem},
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::Edges},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
 };
 
 use super::inventory_level::InventoryLevelNode;
@@ -28,6 +32,49 @@ impl InventoryItemNode {
     }
 }
 
+impl Selectable for InventoryItemNode {
+    fn selection() -> Selection {
+        // Inventory levels are added by the queries that need them.
+        Selection::new()
+            .field("id")
+            .object::<VariantIdNode>("variant")
+            .fields(&["requiresShipping", "tracked", "createdAt", "updatedAt"])
+    }
+}
+
+impl InventoryItemNode {
+    /// Select the item with its inventory level at the location of the `$locationId` variable.
+    pub fn selection_with_level() -> Selection {
+        Self::selection().with(
+            Field::new("inventoryLevel")
+                .var("locationId")
+                .select_type::<InventoryLevelNode>(),
+        )
+    }
+
+    /// Select the item with a page of its inventory levels after the `$after` variable.
+    pub fn selection_with_levels() -> Selection {
+        Self::selection().with(
+            Field::new("inventoryLevels")
+                .first()
+                .var("after")
+                .select_type::<Edges<InventoryLevelNode>>(),
+        )
+    }
+}
+
+impl Selectable for VariantNodeForInventory {
+    fn selection() -> Selection {
+        Selection::new().object::<InventoryItemNode>("inventoryItem")
+    }
+}
+
+impl Selectable for VariantIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VariantsDataForInventory {
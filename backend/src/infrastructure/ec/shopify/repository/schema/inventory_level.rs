@@ -8,7 +8,10 @@ use crate::{
             quantity::quantity::{InventoryType, Quantity},
         },
     },
-    infrastructure::ec::shopify::gql_helper::ShopifyGQLHelper,
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Argument, Field, Selectable, Selection},
+    },
 };
 
 impl InventoryLevelNode {
@@ -51,6 +54,54 @@ impl QuantityNode {
     }
 }
 
+impl InventoryLevelNode {
+    const ALL_QUANTITY_NAMES: [&'static str; 6] = [
+        "incoming",
+        "available",
+        "committed",
+        "reserved",
+        "damaged",
+        "safety_stock",
+    ];
+}
+
+impl Selectable for InventoryLevelNode {
+    fn selection() -> Selection {
+        let names = Self::ALL_QUANTITY_NAMES
+            .iter()
+            .map(|name| Argument::String(name.to_string()))
+            .collect();
+
+        Selection::new()
+            .field("id")
+            .object::<InventoryItemIdNode>("item")
+            .object::<LocationIdNode>("location")
+            .with(
+                Field::new("quantities")
+                    .arg("names", Argument::List(names))
+                    .select_type::<QuantityNode>(),
+            )
+    }
+}
+
+impl Selectable for QuantityNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["name", "quantity"])
+    }
+}
+
+impl Selectable for LocationIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for InventoryItemIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct InventoryLevelNode {
     pub id: String,
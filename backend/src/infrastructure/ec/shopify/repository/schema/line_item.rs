@@ -8,7 +8,10 @@ use crate::{
             line_item::LineItem,
         },
     },
-    infrastructure::ec::shopify::gql_helper::ShopifyGQLHelper,
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+    },
 };
 
 use super::money::MoneyBagNode;
@@ -46,6 +49,32 @@ impl DiscountNode {
     }
 }
 
+impl Selectable for LineItemNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "custom"])
+            .object::<VariantIdNode>("variant")
+            .field("quantity")
+            .object::<DiscountNode>("appliedDiscount")
+            .object::<MoneyBagNode>("discountedTotalSet")
+            .object::<MoneyBagNode>("originalTotalSet")
+    }
+}
+
+impl Selectable for VariantIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for DiscountNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["title", "description", "value", "valueType"])
+            .object::<MoneyBagNode>("amountSet")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LineItemNode {
@@ -1,8 +1,17 @@
 use serde::Deserialize;
 
 use crate::{
-    domain::{address::address::Address, error::error::DomainError, location::location::Location},
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::Edges},
+    domain::{
+        address::address::{Address, AddressParams},
+        coordinates::coordinates::Coordinates,
+        error::error::DomainError,
+        location::location::Location,
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::Edges,
+    },
 };
 
 impl LocationNode {
@@ -32,21 +41,59 @@ impl LocationNode {
 
 impl LocationAddressNode {
     pub fn to_domain(self) -> Result<Address, DomainError> {
-        Address::new(
-            self.address1,
-            self.address2,
-            self.city,
-            true,
-            self.country,
-            None::<String>,
-            None::<String>,
-            self.province,
-            self.zip,
-            None::<String>,
-        )
+        let address = Address::new(AddressParams {
+            address1: self.address1,
+            address2: self.address2,
+            city: self.city,
+            coordinates_validated: true,
+            country: self.country,
+            country_code: self.country_code,
+            province: self.province,
+            province_code: self.province_code,
+            zip: self.zip,
+            ..Default::default()
+        })?;
+
+        match (self.latitude, self.longitude) {
+            (Some(latitude), Some(longitude)) => {
+                Ok(address.with_coordinates(Coordinates::new(latitude, longitude)?))
+            }
+            _ => Ok(address),
+        }
+    }
+}
+
+impl Selectable for LocationNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "name", "isActive", "fulfillsOnlineOrders"])
+            .object::<LocationAddressNode>("address")
+            .object::<LocationAddressNode>("suggestedAddresses")
     }
 }
 
+impl Selectable for LocationAddressNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&[
+            "address1",
+            "address2",
+            "city",
+            "country",
+            "countryCode",
+            "province",
+            "provinceCode",
+            "zip",
+            "latitude",
+            "longitude",
+        ])
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LocationData {
+    pub location: Option<LocationNode>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LocationsData {
     pub locations: Edges<LocationNode>,
@@ -70,6 +117,10 @@ pub struct LocationAddressNode {
     pub address2: Option<String>,
     pub city: Option<String>,
     pub country: Option<String>,
+    pub country_code: Option<String>,
     pub province: Option<String>,
+    pub province_code: Option<String>,
     pub zip: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{address::address::Address, location::location::Location},
+    infrastructure::ec::shopify::{
+        error::ShopifyUserError,
+        query_builder::{Selectable, Selection},
+    },
+};
+
+use super::location::LocationNode;
+
+impl From<&Location> for LocationAddInput {
+    fn from(location: &Location) -> Self {
+        Self {
+            name: location.name().to_owned(),
+            address: location.address().into(),
+            fulfills_online_orders: *location.fulfills_online_orders(),
+        }
+    }
+}
+
+impl From<&Location> for LocationEditInput {
+    fn from(location: &Location) -> Self {
+        Self {
+            name: location.name().to_owned(),
+            address: location.address().into(),
+            fulfills_online_orders: *location.fulfills_online_orders(),
+        }
+    }
+}
+
+impl From<&Address> for LocationAddressInput {
+    fn from(address: &Address) -> Self {
+        Self {
+            address1: address.address1().to_owned(),
+            address2: address.address2().to_owned(),
+            city: address.city().to_owned(),
+            country_code: address.country_code().to_owned(),
+            phone: address.phone().to_owned(),
+            province_code: address.province_code().to_owned(),
+            zip: address.zip().to_owned(),
+        }
+    }
+}
+
+impl Selectable for LocationMutation {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<LocationNode>("location")
+            .object::<LocationUserError>("userErrors")
+    }
+}
+
+impl Selectable for LocationDeactivate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<LocationNode>("location")
+            .object::<LocationUserError>("locationDeactivateUserErrors")
+    }
+}
+
+impl Selectable for LocationActivate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<LocationNode>("location")
+            .object::<LocationUserError>("locationActivateUserErrors")
+    }
+}
+
+impl Selectable for LocationUserError {
+    fn selection() -> Selection {
+        Selection::new().fields(&["code", "field", "message"])
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationAddInput {
+    pub name: String,
+    pub address: LocationAddressInput,
+    pub fulfills_online_orders: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationEditInput {
+    pub name: String,
+    pub address: LocationAddressInput,
+    pub fulfills_online_orders: bool,
+}
+
+/// Address input shared by `LocationAddAddressInput` and `LocationEditAddressInput`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationAddressInput {
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub city: Option<String>,
+    pub country_code: Option<String>,
+    pub phone: Option<String>,
+    pub province_code: Option<String>,
+    pub zip: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationAddData {
+    pub location_add: LocationMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationEditData {
+    pub location_edit: LocationMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationMutation {
+    pub location: Option<LocationNode>,
+    pub user_errors: Vec<LocationUserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationDeactivateData {
+    pub location_deactivate: LocationDeactivate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationDeactivate {
+    pub location: Option<LocationNode>,
+    pub location_deactivate_user_errors: Vec<LocationUserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationActivateData {
+    pub location_activate: LocationActivate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationActivate {
+    pub location: Option<LocationNode>,
+    pub location_activate_user_errors: Vec<LocationUserError>,
+}
+
+/// User errors of location mutations, which carry a code to identify the cause.
+#[derive(Debug, Deserialize)]
+pub struct LocationUserError {
+    pub code: Option<String>,
+    pub field: Option<Vec<String>>,
+    pub message: String,
+}
+
+impl ShopifyUserError for LocationUserError {
+    fn field(&self) -> Option<&[String]> {
+        self.field.as_deref()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    fn message(&self) -> &str {
+        &self.message
+    }
+}
@@ -7,47 +7,97 @@ use crate::{
         media::{
             associated_id::associated_id::AssociatedId,
             media::{Media, MediaStatus},
-            media_content::{image::image::Image, media_content::MediaContent},
+            media_content::{
+                image::image::Image,
+                media_content::MediaContent,
+                model3d::model3d::{BoundingBox, Model3d, Model3dSource},
+                video::video::{Video, VideoSource},
+            },
             src::src::Src,
         },
     },
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::Edges},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
 };
 
 impl MediaNode {
     pub fn to_domain(self, associated_id: Option<AssociatedId>) -> Result<Media, DomainError> {
-        let status = match self.file_status.as_str() {
-            "UPLOADED" | "READY" => Ok(MediaStatus::Active),
+        let status = match self.status.as_str() {
+            "READY" => Ok(MediaStatus::Active),
             "FAILED" => Ok(MediaStatus::Inactive),
-            "PROCESSING" => Ok(MediaStatus::InPreparation),
+            // Uploaded media is yet to be processed, so it is not ready to be published.
+            "UPLOADED" | "PROCESSING" => Ok(MediaStatus::InPreparation),
             _ => Err(DomainError::ConversionError),
         }?;
 
-        let image = match self.preview.and_then(|p| p.image) {
-            Some(i) => Some(MediaContent::Image(i.to_domain(associated_id)?)),
-            None => None,
+        let id = ShopifyGQLHelper::remove_gid_prefix(&self.id);
+        let preview_image = self.preview.and_then(|p| p.image);
+
+        let content = match self.media_content_type.as_str() {
+            "IMAGE" => match preview_image {
+                Some(i) => Some(MediaContent::Image(i.to_domain(associated_id)?)),
+                None => None,
+            },
+            "VIDEO" => Some(MediaContent::Video(Video::new(
+                id.clone(),
+                associated_id,
+                self.alt,
+                self.video_sources
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|source| source.to_domain())
+                    .collect::<Result<Vec<_>, _>>()?,
+                self.duration,
+                preview_image.map(|i| Src::new(i.url)).transpose()?,
+            )?)),
+            "MODEL_3D" => Some(MediaContent::Model3d(Model3d::new(
+                id.clone(),
+                associated_id,
+                self.alt,
+                self.model3d_sources
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|source| source.to_domain())
+                    .collect::<Result<Vec<_>, _>>()?,
+                self.bounding_box
+                    .map(|bounding_box| bounding_box.to_domain())
+                    .transpose()?,
+                preview_image.map(|i| Src::new(i.url)).transpose()?,
+            )?)),
+            // Media hosted outside of the EC platform, such as external videos, is not supported.
+            _ => None,
         };
 
         Media::new(
-            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            id,
             None::<String>,
             status,
-            image,
+            content,
             self.created_at,
             self.updated_at,
         )
     }
 
     pub fn to_domains(
-        schemas: Vec<Self>,
-        associated_ids: Vec<Option<AssociatedId>>,
+        schemas: Vec<(Self, Option<AssociatedId>)>,
     ) -> Result<Vec<Media>, DomainError> {
         schemas
             .into_iter()
-            .zip(associated_ids.into_iter())
             .map(|(schema, associated_id)| schema.to_domain(associated_id))
             .collect()
     }
+
+    /// Restore the gid of the media, whose type depends on the content.
+    pub fn to_gid(media: &Media) -> String {
+        match media.content() {
+            Some(MediaContent::Video(_)) => ShopifyGQLHelper::add_video_gid_prefix(media.id()),
+            Some(MediaContent::Model3d(_)) => ShopifyGQLHelper::add_model3d_gid_prefix(media.id()),
+            _ => ShopifyGQLHelper::add_media_image_gid_prefix(media.id()),
+        }
+    }
 }
 
 impl ImageNode {
@@ -62,21 +112,136 @@ impl ImageNode {
     }
 }
 
+impl VideoSourceNode {
+    pub fn to_domain(self) -> Result<VideoSource, DomainError> {
+        VideoSource::new(Src::new(self.url)?, self.mime_type, self.width, self.height)
+    }
+}
+
+impl Model3dSourceNode {
+    pub fn to_domain(self) -> Result<Model3dSource, DomainError> {
+        Model3dSource::new(
+            Src::new(self.url)?,
+            self.mime_type,
+            self.format,
+            self.filesize,
+        )
+    }
+}
+
+impl Model3dBoundingBoxNode {
+    pub fn to_domain(self) -> Result<BoundingBox, DomainError> {
+        BoundingBox::new(self.size.x, self.size.y, self.size.z)
+    }
+}
+
+impl Selectable for MediaNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "mediaContentType", "status", "alt"])
+            .object::<MediaPreviewImageNode>("preview")
+            .on(
+                "MediaImage",
+                Selection::new().fields(&["createdAt", "updatedAt"]),
+            )
+            .on(
+                "Video",
+                Selection::new()
+                    .fields(&["createdAt", "updatedAt", "duration"])
+                    .with(
+                        Field::new("sources")
+                            .alias("videoSources")
+                            .select_type::<VideoSourceNode>(),
+                    ),
+            )
+            .on(
+                "Model3d",
+                Selection::new()
+                    .with(
+                        Field::new("sources")
+                            .alias("model3dSources")
+                            .select_type::<Model3dSourceNode>(),
+                    )
+                    .object::<Model3dBoundingBoxNode>("boundingBox"),
+            )
+    }
+}
+
+impl Selectable for MediaPreviewImageNode {
+    fn selection() -> Selection {
+        Selection::new().object::<ImageNode>("image")
+    }
+}
+
+impl Selectable for ImageNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["altText", "height", "id", "url", "width"])
+    }
+}
+
+impl Selectable for VideoSourceNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["url", "mimeType", "width", "height"])
+    }
+}
+
+impl Selectable for Model3dSourceNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["url", "mimeType", "format", "filesize"])
+    }
+}
+
+impl Selectable for Model3dBoundingBoxNode {
+    fn selection() -> Selection {
+        Selection::new().object::<Vector3Node>("size")
+    }
+}
+
+impl Selectable for Vector3Node {
+    fn selection() -> Selection {
+        Selection::new().fields(&["x", "y", "z"])
+    }
+}
+
+impl Selectable for ProductMediaNode {
+    fn selection() -> Selection {
+        // The number of media associated with a single product shall not exceed 250.
+        Selection::new().with(
+            Field::new("media")
+                .first()
+                .select_type::<Edges<MediaNode>>(),
+        )
+    }
+}
+
 #[derive(Debug, Deserialize)]
-pub struct MediaData {
-    pub files: Edges<MediaNode>,
+pub struct ProductMediaData {
+    pub product: Option<ProductMediaNode>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ProductMediaNode {
+    pub media: Edges<MediaNode>,
+}
+
+/// Product media, typed as the `Media` interface.
+///
+/// Fields specific to a type of media are set only for that type.
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MediaNode {
     pub id: String,
-    pub file_status: String,
+    pub media_content_type: String,
+    pub status: String,
     pub alt: Option<String>,
     pub preview: Option<MediaPreviewImageNode>,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub duration: Option<u32>,
+    pub video_sources: Option<Vec<VideoSourceNode>>,
+    pub model3d_sources: Option<Vec<Model3dSourceNode>>,
+    pub bounding_box: Option<Model3dBoundingBoxNode>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -94,3 +259,33 @@ pub struct ImageNode {
     pub width: Option<i32>,
     pub url: String,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoSourceNode {
+    pub url: String,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Model3dSourceNode {
+    pub url: String,
+    pub mime_type: String,
+    pub format: String,
+    pub filesize: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Model3dBoundingBoxNode {
+    pub size: Vector3Node,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Vector3Node {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
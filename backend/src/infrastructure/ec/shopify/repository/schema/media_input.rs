@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        media::{
+            media::Media,
+            media_content::media_content::MediaContent,
+            src::src::Src,
+            staged_upload::staged_upload::{
+                StagedUpload, StagedUploadParameter, StagedUploadTarget,
+            },
+        },
+    },
+    infrastructure::ec::shopify::{
+        error::ShopifyUserError,
+        query_builder::{Selectable, Selection},
+        schema::UserError,
+    },
+    log_error,
+};
+
+use super::media::MediaNode;
+
+impl From<&StagedUpload> for StagedUploadInput {
+    fn from(staged_upload: &StagedUpload) -> Self {
+        Self {
+            filename: staged_upload.file_name().to_owned(),
+            mime_type: staged_upload.mime_type().to_owned(),
+            resource: "IMAGE".to_string(),
+            http_method: "POST".to_string(),
+            file_size: staged_upload.file_size().to_string(),
+        }
+    }
+}
+
+impl CreateMediaInput {
+    /// Build the input from media whose content has an uploaded source.
+    pub fn from_domain(media: &Media) -> Result<Self, DomainError> {
+        // Only images can be uploaded as staged uploads.
+        let image = match media.content() {
+            Some(MediaContent::Image(image)) => image,
+            _ => {
+                log_error!("Only images can be created as media.");
+                return Err(DomainError::ValidationError);
+            }
+        };
+
+        let original_source = match image.uploaded_src() {
+            Some(src) => src.value().to_owned(),
+            None => {
+                log_error!("Media without an uploaded source cannot be created.");
+                return Err(DomainError::ValidationError);
+            }
+        };
+
+        Ok(Self {
+            original_source,
+            alt: image.alt().to_owned(),
+            media_content_type: "IMAGE".to_string(),
+        })
+    }
+}
+
+impl From<&Media> for UpdateMediaInput {
+    fn from(media: &Media) -> Self {
+        Self {
+            id: MediaNode::to_gid(media),
+            alt: media
+                .content()
+                .as_ref()
+                .and_then(|content| content.alt().to_owned()),
+        }
+    }
+}
+
+impl StagedMediaUploadTargetNode {
+    pub fn to_domain(self) -> Result<StagedUploadTarget, DomainError> {
+        let (url, resource_url) = match (self.url, self.resource_url) {
+            (Some(url), Some(resource_url)) => (url, resource_url),
+            _ => {
+                log_error!("Staged upload target has no URL.");
+                return Err(DomainError::ConversionError);
+            }
+        };
+
+        StagedUploadTarget::new(
+            Src::new(url)?,
+            Src::new(resource_url)?,
+            self.parameters
+                .into_iter()
+                .map(|p| StagedUploadParameter::new(p.name, p.value))
+                .collect(),
+        )
+    }
+}
+
+impl Selectable for StagedUploadsCreate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<StagedMediaUploadTargetNode>("stagedTargets")
+            .object::<UserError>("userErrors")
+    }
+}
+
+impl Selectable for StagedMediaUploadTargetNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["url", "resourceUrl"])
+            .object::<StagedUploadParameterNode>("parameters")
+    }
+}
+
+impl Selectable for StagedUploadParameterNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["name", "value"])
+    }
+}
+
+impl Selectable for ProductMediaMutation {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<MediaNode>("media")
+            .object::<MediaUserError>("mediaUserErrors")
+    }
+}
+
+impl Selectable for ProductReorderMedia {
+    fn selection() -> Selection {
+        Selection::new().object::<MediaUserError>("mediaUserErrors")
+    }
+}
+
+impl Selectable for ProductDeleteMedia {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("deletedMediaIds")
+            .object::<MediaUserError>("mediaUserErrors")
+    }
+}
+
+impl Selectable for MediaUserError {
+    fn selection() -> Selection {
+        Selection::new().fields(&["code", "field", "message"])
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadInput {
+    pub filename: String,
+    pub mime_type: String,
+    pub resource: String,
+    pub http_method: String,
+    pub file_size: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMediaInput {
+    pub original_source: String,
+    pub alt: Option<String>,
+    pub media_content_type: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMediaInput {
+    pub id: String,
+    pub alt: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveInput {
+    pub id: String,
+    pub new_position: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadsCreateData {
+    pub staged_uploads_create: StagedUploadsCreate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadsCreate {
+    pub staged_targets: Option<Vec<StagedMediaUploadTargetNode>>,
+    pub user_errors: Vec<UserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedMediaUploadTargetNode {
+    pub url: Option<String>,
+    pub resource_url: Option<String>,
+    pub parameters: Vec<StagedUploadParameterNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StagedUploadParameterNode {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductCreateMediaData {
+    pub product_create_media: ProductMediaMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductUpdateMediaData {
+    pub product_update_media: ProductMediaMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductMediaMutation {
+    pub media: Option<Vec<MediaNode>>,
+    pub media_user_errors: Vec<MediaUserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductReorderMediaData {
+    pub product_reorder_media: ProductReorderMedia,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductReorderMedia {
+    pub media_user_errors: Vec<MediaUserError>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDeleteMediaData {
+    pub product_delete_media: ProductDeleteMedia,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDeleteMedia {
+    pub deleted_media_ids: Option<Vec<String>>,
+    pub media_user_errors: Vec<MediaUserError>,
+}
+
+/// User errors of product media mutations, which carry a code to identify the cause.
+#[derive(Debug, Deserialize)]
+pub struct MediaUserError {
+    pub code: Option<String>,
+    pub field: Option<Vec<String>>,
+    pub message: String,
+}
+
+impl ShopifyUserError for MediaUserError {
+    fn field(&self) -> Option<&[String]> {
+        self.field.as_deref()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    fn message(&self) -> &str {
+        &self.message
+    }
+}
@@ -1,11 +1,14 @@
 use serde::Deserialize;
 
-use crate::domain::{
-    error::error::DomainError,
-    money::{
-        amount::amount::Amount,
-        money::{CurrencyCode, Money},
+use crate::{
+    domain::{
+        error::error::DomainError,
+        money::{
+            amount::amount::Amount,
+            money::{CurrencyCode, Money},
+        },
     },
+    infrastructure::ec::shopify::query_builder::{Selectable, Selection},
 };
 
 impl MoneyBagNode {
@@ -22,6 +25,18 @@ impl MoneyNode {
     }
 }
 
+impl Selectable for MoneyBagNode {
+    fn selection() -> Selection {
+        Selection::new().object::<MoneyNode>("shopMoney")
+    }
+}
+
+impl Selectable for MoneyNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["amount", "currencyCode"])
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MoneyBagNode {
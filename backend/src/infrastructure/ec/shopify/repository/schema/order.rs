@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        customer::customer::Id as CustomerId,
+        error::error::DomainError,
+        line_item::line_item::LineItem,
+        order::order::{FinancialStatus, FulfillmentStatus, Order, OrderCancelReason, OrderParams},
+        user::user::Id as UserId,
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
+};
+
+use super::{
+    address::AddressNode,
+    draft_order::CustomerIdNode,
+    line_item::VariantIdNode,
+    money::{CurrencyCodeNode, MoneyBagNode},
+};
+
+impl OrderNode {
+    /// Id of the customer who placed the order, if any.
+    pub fn customer_id(&self) -> Option<CustomerId> {
+        self.customer
+            .as_ref()
+            .map(|c| ShopifyGQLHelper::remove_gid_prefix(&c.id))
+    }
+
+    /// Convert to the domain, owned by the user linked to the customer who placed the order.
+    ///
+    /// Orders of customers without a linked user have no owner,
+    /// so they are only visible to users who can read all orders.
+    pub fn to_domain(self, owners: &HashMap<CustomerId, UserId>) -> Result<Order, DomainError> {
+        let customer_id = self.customer_id();
+        let owner_user_id = customer_id
+            .as_ref()
+            .and_then(|customer_id| owners.get(customer_id).cloned())
+            .unwrap_or_default();
+
+        let financial_status = self
+            .display_financial_status
+            .map(|status| match status.as_str() {
+                "PENDING" => Ok(FinancialStatus::Pending),
+                "AUTHORIZED" => Ok(FinancialStatus::Authorized),
+                "PARTIALLY_PAID" => Ok(FinancialStatus::PartiallyPaid),
+                "PAID" => Ok(FinancialStatus::Paid),
+                "PARTIALLY_REFUNDED" => Ok(FinancialStatus::PartiallyRefunded),
+                "REFUNDED" => Ok(FinancialStatus::Refunded),
+                "VOIDED" => Ok(FinancialStatus::Voided),
+                "EXPIRED" => Ok(FinancialStatus::Expired),
+                _ => Err(DomainError::ConversionError),
+            })
+            .transpose()?;
+
+        let fulfillment_status = match self.display_fulfillment_status.as_str() {
+            "UNFULFILLED" | "OPEN" | "PENDING_FULFILLMENT" | "REQUEST_DECLINED" => {
+                Ok(FulfillmentStatus::Unfulfilled)
+            }
+            "PARTIALLY_FULFILLED" => Ok(FulfillmentStatus::PartiallyFulfilled),
+            "FULFILLED" => Ok(FulfillmentStatus::Fulfilled),
+            "RESTOCKED" => Ok(FulfillmentStatus::Restocked),
+            "IN_PROGRESS" => Ok(FulfillmentStatus::InProgress),
+            "ON_HOLD" => Ok(FulfillmentStatus::OnHold),
+            "SCHEDULED" => Ok(FulfillmentStatus::Scheduled),
+            _ => Err(DomainError::ConversionError),
+        }?;
+
+        let cancel_reason = self
+            .cancel_reason
+            .map(|reason| match reason.as_str() {
+                "CUSTOMER" => Ok(OrderCancelReason::Customer),
+                "DECLINED" => Ok(OrderCancelReason::Declined),
+                "FRAUD" => Ok(OrderCancelReason::Fraud),
+                "INVENTORY" => Ok(OrderCancelReason::Inventory),
+                "STAFF" => Ok(OrderCancelReason::Staff),
+                "OTHER" => Ok(OrderCancelReason::Other),
+                _ => Err(DomainError::ConversionError),
+            })
+            .transpose()?;
+
+        Order::new(OrderParams {
+            id: ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            name: self.name,
+            customer_id,
+            billing_address: self.billing_address.map(|a| a.to_domain()).transpose()?,
+            shipping_address: self.shipping_address.map(|a| a.to_domain()).transpose()?,
+            note: self.note,
+            line_items: self
+                .line_items
+                .edges
+                .into_iter()
+                .map(|node| node.node.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+            financial_status,
+            fulfillment_status,
+            subtotal_price_set: self.subtotal_price_set.to_domain()?,
+            taxes_included: self.taxes_included,
+            total_tax_set: self.total_tax_set.to_domain()?,
+            total_discounts_set: self.total_discounts_set.to_domain()?,
+            total_shipping_price_set: self.total_shipping_price_set.to_domain()?,
+            total_price_set: self.total_price_set.to_domain()?,
+            presentment_currency_code: self.presentment_currency_code.to_domain()?,
+            owner_user_id,
+            cancel_reason,
+            processed_at: self.processed_at,
+            canceled_at: self.cancelled_at,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+
+    pub fn to_domains(
+        schemas: Vec<Self>,
+        owners: &HashMap<CustomerId, UserId>,
+    ) -> Result<Vec<Order>, DomainError> {
+        schemas
+            .into_iter()
+            .map(|schema| schema.to_domain(owners))
+            .collect()
+    }
+}
+
+impl OrderLineItemNode {
+    pub fn to_domain(self) -> Result<LineItem, DomainError> {
+        LineItem::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            self.variant.is_none(),
+            self.variant
+                .map(|v| ShopifyGQLHelper::remove_gid_prefix(&v.id)),
+            self.quantity as u32,
+            None,
+            self.discounted_total_set.to_domain()?,
+            self.original_total_set.to_domain()?,
+        )
+    }
+}
+
+impl Selectable for OrderNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "name"])
+            .object::<CustomerIdNode>("customer")
+            .object::<AddressNode>("billingAddress")
+            .object::<AddressNode>("shippingAddress")
+            .field("note")
+            .with(
+                Field::new("lineItems")
+                    .first()
+                    .select_type::<Edges<OrderLineItemNode>>(),
+            )
+            .fields(&["displayFinancialStatus", "displayFulfillmentStatus"])
+            .object::<MoneyBagNode>("subtotalPriceSet")
+            .field("taxesIncluded")
+            .object::<MoneyBagNode>("totalTaxSet")
+            .object::<MoneyBagNode>("totalDiscountsSet")
+            .object::<MoneyBagNode>("totalShippingPriceSet")
+            .object::<MoneyBagNode>("totalPriceSet")
+            .field("presentmentCurrencyCode")
+            .fields(&[
+                "cancelReason",
+                "processedAt",
+                "cancelledAt",
+                "createdAt",
+                "updatedAt",
+            ])
+    }
+}
+
+impl Selectable for OrderLineItemNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .field("id")
+            .object::<VariantIdNode>("variant")
+            .field("quantity")
+            .object::<MoneyBagNode>("discountedTotalSet")
+            .object::<MoneyBagNode>("originalTotalSet")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderData {
+    pub order: Option<OrderNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersData {
+    pub orders: Edges<OrderNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderNode {
+    pub id: String,
+    pub name: String,
+
+    pub customer: Option<CustomerIdNode>,
+    pub billing_address: Option<AddressNode>,
+    pub shipping_address: Option<AddressNode>,
+    pub note: Option<String>,
+
+    pub line_items: Edges<OrderLineItemNode>,
+
+    pub display_financial_status: Option<String>,
+    pub display_fulfillment_status: String,
+
+    pub subtotal_price_set: MoneyBagNode,
+    pub taxes_included: bool,
+    pub total_tax_set: MoneyBagNode,
+    pub total_discounts_set: MoneyBagNode,
+    pub total_shipping_price_set: MoneyBagNode,
+    pub total_price_set: MoneyBagNode,
+    pub presentment_currency_code: CurrencyCodeNode,
+
+    pub cancel_reason: Option<String>,
+
+    pub processed_at: DateTime<Utc>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Unlike draft orders, line items of an order do not have a custom flag or an applied discount.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderLineItemNode {
+    pub id: String,
+    pub variant: Option<VariantIdNode>,
+    pub quantity: i32,
+    pub discounted_total_set: MoneyBagNode,
+    pub original_total_set: MoneyBagNode,
+}
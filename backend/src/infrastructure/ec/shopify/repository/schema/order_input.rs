@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use crate::{
+    domain::order::order::OrderCancelReason,
+    infrastructure::ec::shopify::{
+        query_builder::{Selectable, Selection},
+        schema::UserError,
+    },
+};
+
+impl From<OrderCancelReason> for String {
+    fn from(reason: OrderCancelReason) -> Self {
+        match reason {
+            OrderCancelReason::Customer => "CUSTOMER".to_string(),
+            OrderCancelReason::Declined => "DECLINED".to_string(),
+            OrderCancelReason::Fraud => "FRAUD".to_string(),
+            OrderCancelReason::Inventory => "INVENTORY".to_string(),
+            OrderCancelReason::Staff => "STAFF".to_string(),
+            OrderCancelReason::Other => "OTHER".to_string(),
+        }
+    }
+}
+
+impl Selectable for OrderCancel {
+    fn selection() -> Selection {
+        Selection::new().object::<UserError>("orderCancelUserErrors")
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancelData {
+    pub order_cancel: OrderCancel,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancel {
+    pub order_cancel_user_errors: Vec<UserError>,
+}
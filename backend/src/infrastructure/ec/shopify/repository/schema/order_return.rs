@@ -0,0 +1,198 @@
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        order_return::{
+            order_return::{OrderReturn, ReturnDeclineReason, ReturnStatus},
+            return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+        },
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
+};
+
+use super::draft_order::OrderIdNode;
+
+impl ReturnNode {
+    pub fn to_domain(self) -> Result<OrderReturn, DomainError> {
+        let status = match self.status.as_str() {
+            "REQUESTED" => Ok(ReturnStatus::Requested),
+            "OPEN" => Ok(ReturnStatus::Open),
+            "DECLINED" => Ok(ReturnStatus::Declined),
+            "CLOSED" => Ok(ReturnStatus::Closed),
+            "CANCELED" => Ok(ReturnStatus::Canceled),
+            _ => Err(DomainError::ConversionError),
+        }?;
+
+        let (decline_reason, decline_note) = match self.decline {
+            Some(decline) => (Some(decline.reason.to_domain()?), decline.note),
+            None => (None, None),
+        };
+
+        OrderReturn::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            ShopifyGQLHelper::remove_gid_prefix(&self.order.id),
+            status,
+            self.return_line_items
+                .edges
+                .into_iter()
+                // Items returned without a fulfillment cannot be associated with the order.
+                .filter(|node| node.node.fulfillment_line_item.is_some())
+                .map(|node| node.node.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+            decline_reason,
+            decline_note,
+        )
+    }
+
+    pub fn to_domains(schemas: Vec<Self>) -> Result<Vec<OrderReturn>, DomainError> {
+        schemas
+            .into_iter()
+            .map(|schema| schema.to_domain())
+            .collect()
+    }
+}
+
+impl ReturnLineItemNode {
+    pub fn to_domain(self) -> Result<ReturnLineItem, DomainError> {
+        let reason = match self.return_reason.as_str() {
+            "COLOR" => ReturnReason::Color,
+            "DEFECTIVE" => ReturnReason::Defective,
+            "NOT_AS_DESCRIBED" => ReturnReason::NotAsDescribed,
+            "SIZE_TOO_LARGE" => ReturnReason::SizeTooLarge,
+            "SIZE_TOO_SMALL" => ReturnReason::SizeTooSmall,
+            "STYLE" => ReturnReason::Style,
+            "UNWANTED" => ReturnReason::Unwanted,
+            "WRONG_ITEM" => ReturnReason::WrongItem,
+            "OTHER" => ReturnReason::Other,
+            _ => ReturnReason::Unknown,
+        };
+
+        ReturnLineItem::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            self.fulfillment_line_item
+                .map(|f| ShopifyGQLHelper::remove_gid_prefix(&f.id))
+                .unwrap_or_default(),
+            self.quantity as u32,
+            reason,
+            self.customer_note,
+        )
+    }
+}
+
+impl ReturnDeclineReasonNode {
+    pub fn to_domain(self) -> Result<ReturnDeclineReason, DomainError> {
+        match self.0.as_str() {
+            "FINAL_SALE" => Ok(ReturnDeclineReason::FinalSale),
+            "RETURN_PERIOD_ENDED" => Ok(ReturnDeclineReason::ReturnPeriodEnded),
+            "OTHER" => Ok(ReturnDeclineReason::Other),
+            _ => Err(DomainError::ConversionError),
+        }
+    }
+}
+
+impl Selectable for OrderReturnsNode {
+    fn selection() -> Selection {
+        Selection::new().with(
+            Field::new("returns")
+                .first()
+                .select_type::<Edges<ReturnNode>>(),
+        )
+    }
+}
+
+impl Selectable for ReturnNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "status"])
+            .object::<OrderIdNode>("order")
+            .object::<ReturnDeclineNode>("decline")
+            .with(
+                Field::new("returnLineItems")
+                    .first()
+                    .select_type::<Edges<ReturnLineItemNode>>(),
+            )
+    }
+}
+
+impl Selectable for ReturnLineItemNode {
+    fn selection() -> Selection {
+        // returnLineItems is a list of the ReturnLineItemType interface,
+        // and only ReturnLineItem refers to the fulfilled line item.
+        Selection::new()
+            .fields(&["id", "quantity", "returnReason", "customerNote"])
+            .on(
+                "ReturnLineItem",
+                Selection::new().object::<FulfillmentLineItemIdNode>("fulfillmentLineItem"),
+            )
+    }
+}
+
+impl Selectable for FulfillmentLineItemIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for ReturnDeclineNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["reason", "note"])
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnData {
+    #[serde(rename = "return")]
+    pub order_return: Option<ReturnNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReturnsData {
+    pub order: Option<OrderReturnsNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReturnsNode {
+    pub returns: Edges<ReturnNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnNode {
+    pub id: String,
+    pub status: String,
+    pub order: OrderIdNode,
+    pub decline: Option<ReturnDeclineNode>,
+    pub return_line_items: Edges<ReturnLineItemNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnLineItemNode {
+    pub id: String,
+    pub fulfillment_line_item: Option<FulfillmentLineItemIdNode>,
+    pub quantity: i32,
+    pub return_reason: String,
+    pub customer_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FulfillmentLineItemIdNode {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReturnDeclineNode {
+    pub reason: ReturnDeclineReasonNode,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReturnDeclineReasonNode(pub String);
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::order_return::{
+        order_return::{OrderReturn, ReturnDeclineReason},
+        return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::UserError,
+    },
+};
+
+use super::order_return::ReturnNode;
+
+impl From<OrderReturn> for ReturnRequestInput {
+    fn from(order_return: OrderReturn) -> Self {
+        Self {
+            order_id: ShopifyGQLHelper::add_order_gid_prefix(order_return.order_id()),
+            return_line_items: order_return
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.to_owned().into())
+                .collect(),
+        }
+    }
+}
+
+impl From<ReturnLineItem> for ReturnRequestLineItemInput {
+    fn from(line_item: ReturnLineItem) -> Self {
+        Self {
+            fulfillment_line_item_id: ShopifyGQLHelper::add_fulfillment_line_item_gid_prefix(
+                line_item.fulfillment_line_item_id(),
+            ),
+            quantity: *line_item.quantity() as i32,
+            return_reason: line_item.reason().to_owned().into(),
+            customer_note: line_item.customer_note().to_owned(),
+        }
+    }
+}
+
+impl From<ReturnReason> for String {
+    fn from(reason: ReturnReason) -> Self {
+        match reason {
+            ReturnReason::Color => "COLOR".to_string(),
+            ReturnReason::Defective => "DEFECTIVE".to_string(),
+            ReturnReason::NotAsDescribed => "NOT_AS_DESCRIBED".to_string(),
+            ReturnReason::SizeTooLarge => "SIZE_TOO_LARGE".to_string(),
+            ReturnReason::SizeTooSmall => "SIZE_TOO_SMALL".to_string(),
+            ReturnReason::Style => "STYLE".to_string(),
+            ReturnReason::Unwanted => "UNWANTED".to_string(),
+            ReturnReason::WrongItem => "WRONG_ITEM".to_string(),
+            ReturnReason::Other => "OTHER".to_string(),
+            ReturnReason::Unknown => "UNKNOWN".to_string(),
+        }
+    }
+}
+
+impl From<ReturnDeclineReason> for String {
+    fn from(reason: ReturnDeclineReason) -> Self {
+        match reason {
+            ReturnDeclineReason::FinalSale => "FINAL_SALE".to_string(),
+            ReturnDeclineReason::ReturnPeriodEnded => "RETURN_PERIOD_ENDED".to_string(),
+            ReturnDeclineReason::Other => "OTHER".to_string(),
+        }
+    }
+}
+
+impl Selectable for ReturnMutation {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<ReturnNode>("return")
+            .object::<UserError>("userErrors")
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnRequestInput {
+    pub order_id: String,
+    pub return_line_items: Vec<ReturnRequestLineItemInput>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnRequestLineItemInput {
+    pub fulfillment_line_item_id: String,
+    pub quantity: i32,
+    pub return_reason: String,
+    pub customer_note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReturnApproveRequestInput {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnDeclineRequestInput {
+    pub id: String,
+    pub decline_reason: String,
+    pub decline_note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnRequestData {
+    pub return_request: ReturnMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnApproveRequestData {
+    pub return_approve_request: ReturnMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnDeclineRequestData {
+    pub return_decline_request: ReturnMutation,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnMutation {
+    #[serde(rename = "return")]
+    pub order_return: Option<ReturnNode>,
+    pub user_errors: Vec<UserError>,
+}
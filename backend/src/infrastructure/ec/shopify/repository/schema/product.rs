@@ -16,7 +16,11 @@ use crate::{
             },
         },
     },
-    infrastructure::ec::shopify::{gql_helper::ShopifyGQLHelper, schema::Edges},
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Argument, Field, Selectable, Selection},
+        schema::Edges,
+    },
 };
 
 impl VariantNode {
@@ -109,6 +113,62 @@ impl VariantNode {
     }
 }
 
+impl VariantNode {
+    /// Select the fields of the variant itself, without the product it belongs to.
+    pub fn own_selection() -> Selection {
+        Selection::new()
+            .fields(&[
+                "id",
+                "title",
+                "sku",
+                "barcode",
+                "availableForSale",
+                "position",
+            ])
+            .object::<InventoryItemIdNode>("inventoryItem")
+            .fields(&[
+                "inventoryQuantity",
+                "inventoryPolicy",
+                "price",
+                "taxable",
+                "taxCode",
+                "createdAt",
+                "updatedAt",
+            ])
+    }
+}
+
+impl Selectable for VariantNode {
+    fn selection() -> Selection {
+        Self::own_selection().object::<ProductNode>("product")
+    }
+}
+
+impl Selectable for ProductNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "title"])
+            .with(Field::new("description").arg(
+                "truncateAt",
+                Argument::Int(Product::MAX_DESCRIPTION_LENGTH as i64),
+            ))
+            .field("status")
+            .object::<TaxonomyCategoryNode>("category")
+    }
+}
+
+impl Selectable for TaxonomyCategoryNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
+impl Selectable for InventoryItemIdNode {
+    fn selection() -> Selection {
+        Selection::new().field("id")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ProductsData {
     pub products: Edges<ProductNode>,
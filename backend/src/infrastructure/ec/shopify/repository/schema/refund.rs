@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        money::{amount::amount::Amount, money::Money},
+        refund::{
+            refund::{Refund, RefundParams},
+            refund_line_item::refund_line_item::RefundLineItem,
+        },
+    },
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Field, Selectable, Selection},
+        schema::Edges,
+    },
+};
+
+use super::{
+    draft_order::OrderIdNode,
+    fulfillment_order::{LineItemIdNode, LocationIdNode},
+    money::MoneyBagNode,
+};
+
+impl RefundNode {
+    pub fn to_domain(self) -> Result<Refund, DomainError> {
+        // All restocked units of a refund are returned to the same location.
+        let restock_location_id = self
+            .refund_line_items
+            .edges
+            .iter()
+            .filter(|node| node.node.restock_type != "NO_RESTOCK")
+            .find_map(|node| node.node.location.as_ref())
+            .map(|location| ShopifyGQLHelper::remove_gid_prefix(&location.id));
+
+        let total_refunded_set = self.total_refunded_set.to_domain()?;
+        let shipping_amount = self
+            .refund_shipping_lines
+            .edges
+            .into_iter()
+            .map(|node| node.node.subtotal_amount_set.to_domain())
+            .collect::<Result<Vec<_>, _>>()?
+            .iter()
+            .map(|money| money.amount().value())
+            .sum::<f64>();
+        let shipping_set = Money::new(
+            total_refunded_set.currency_code().to_owned(),
+            Amount::new(shipping_amount)?,
+        )?;
+
+        Refund::new(RefundParams {
+            id: ShopifyGQLHelper::remove_gid_prefix(&self.id),
+            order_id: ShopifyGQLHelper::remove_gid_prefix(&self.order.id),
+            note: self.note,
+            line_items: self
+                .refund_line_items
+                .edges
+                .into_iter()
+                .map(|node| node.node.to_domain())
+                .collect::<Result<Vec<_>, _>>()?,
+            shipping_set,
+            total_refunded_set,
+            restock_location_id,
+            created_at: self.created_at.unwrap_or_else(Utc::now),
+        })
+    }
+}
+
+impl RefundLineItemNode {
+    pub fn to_domain(self) -> Result<RefundLineItem, DomainError> {
+        RefundLineItem::new(
+            ShopifyGQLHelper::remove_gid_prefix(&self.line_item.id),
+            self.quantity as u32,
+            self.subtotal_set.to_domain()?,
+        )
+    }
+}
+
+impl Selectable for RefundNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .fields(&["id", "note"])
+            .object::<OrderIdNode>("order")
+            .object::<MoneyBagNode>("totalRefundedSet")
+            .with(
+                Field::new("refundLineItems")
+                    .first()
+                    .select_type::<Edges<RefundLineItemNode>>(),
+            )
+            .with(
+                Field::new("refundShippingLines")
+                    .first()
+                    .select_type::<Edges<RefundShippingLineNode>>(),
+            )
+            .field("createdAt")
+    }
+}
+
+impl Selectable for RefundLineItemNode {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<LineItemIdNode>("lineItem")
+            .field("quantity")
+            .object::<MoneyBagNode>("subtotalSet")
+            .field("restockType")
+            .object::<LocationIdNode>("location")
+    }
+}
+
+impl Selectable for RefundShippingLineNode {
+    fn selection() -> Selection {
+        Selection::new().object::<MoneyBagNode>("subtotalAmountSet")
+    }
+}
+
+impl Selectable for OrderRefundsNode {
+    fn selection() -> Selection {
+        Selection::new().with(Field::new("refunds").first().select_type::<RefundNode>())
+    }
+}
+
+impl Selectable for OrderTransactionsNode {
+    fn selection() -> Selection {
+        Selection::new().with(
+            Field::new("transactions")
+                .first()
+                .select_type::<TransactionNode>(),
+        )
+    }
+}
+
+impl Selectable for TransactionNode {
+    fn selection() -> Selection {
+        Selection::new().fields(&["id", "gateway", "kind", "status"])
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundNode {
+    pub id: String,
+    pub note: Option<String>,
+    pub order: OrderIdNode,
+    pub total_refunded_set: MoneyBagNode,
+    pub refund_line_items: Edges<RefundLineItemNode>,
+    pub refund_shipping_lines: Edges<RefundShippingLineNode>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundLineItemNode {
+    pub line_item: LineItemIdNode,
+    pub quantity: i32,
+    pub subtotal_set: MoneyBagNode,
+    pub restock_type: String,
+    pub location: Option<LocationIdNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundShippingLineNode {
+    pub subtotal_amount_set: MoneyBagNode,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRefundsData {
+    pub order: Option<OrderRefundsNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRefundsNode {
+    pub refunds: Vec<RefundNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderTransactionsData {
+    pub order: Option<OrderTransactionsNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderTransactionsNode {
+    pub transactions: Vec<TransactionNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionNode {
+    pub id: String,
+    pub gateway: Option<String>,
+    pub kind: String,
+    pub status: String,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::refund::refund::Refund,
+    infrastructure::ec::shopify::{
+        gql_helper::ShopifyGQLHelper,
+        query_builder::{Selectable, Selection},
+        schema::UserError,
+    },
+};
+
+use super::refund::RefundNode;
+
+impl RefundInput {
+    /// Build the input from a refund, returning the refunded amount through the given parent transaction.
+    pub fn from_domain(refund: Refund, parent_transaction: ParentTransaction) -> Self {
+        let order_id = ShopifyGQLHelper::add_order_gid_prefix(refund.order_id());
+        let (restock_type, location_id) = match refund.restock_location_id() {
+            Some(location_id) => (
+                "RETURN".to_string(),
+                Some(ShopifyGQLHelper::add_location_gid_prefix(location_id)),
+            ),
+            None => ("NO_RESTOCK".to_string(), None),
+        };
+
+        Self {
+            order_id: order_id.clone(),
+            note: refund.note().to_owned(),
+            refund_line_items: refund
+                .line_items()
+                .iter()
+                .map(|line_item| RefundLineItemInput {
+                    line_item_id: ShopifyGQLHelper::add_line_item_gid_prefix(
+                        line_item.line_item_id(),
+                    ),
+                    quantity: *line_item.quantity() as i32,
+                    restock_type: restock_type.clone(),
+                    location_id: location_id.clone(),
+                })
+                .collect(),
+            shipping: ShippingRefundInput {
+                amount: refund.shipping_set().amount().value().to_string(),
+            },
+            transactions: vec![OrderTransactionInput {
+                order_id,
+                gateway: parent_transaction.gateway,
+                kind: "REFUND".to_string(),
+                amount: refund.total_refunded_set().amount().value().to_string(),
+                parent_id: parent_transaction.id,
+            }],
+        }
+    }
+}
+
+/// The transaction a refund is returned through.
+impl Selectable for RefundCreate {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<RefundNode>("refund")
+            .object::<UserError>("userErrors")
+    }
+}
+
+#[derive(Debug)]
+pub struct ParentTransaction {
+    pub id: String,
+    pub gateway: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundInput {
+    pub order_id: String,
+    pub note: Option<String>,
+    pub refund_line_items: Vec<RefundLineItemInput>,
+    pub shipping: ShippingRefundInput,
+    pub transactions: Vec<OrderTransactionInput>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundLineItemInput {
+    pub line_item_id: String,
+    pub quantity: i32,
+    pub restock_type: String,
+    pub location_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShippingRefundInput {
+    pub amount: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderTransactionInput {
+    pub order_id: String,
+    pub gateway: String,
+    pub kind: String,
+    pub amount: String,
+    pub parent_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundCreateData {
+    pub refund_create: RefundCreate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundCreate {
+    pub refund: Option<RefundNode>,
+    pub user_errors: Vec<UserError>,
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+use crate::infrastructure::config::config::ShopifyHttpClientConfig;
+
+/// Policy for retrying requests to Shopify with exponential backoff and jitter.
+///
+/// Queries are idempotent and are retried on any transport failure, 5xx, 429 and throttling.
+/// Mutations are retried only when the connection could not be established,
+/// because the request is then known not to have reached Shopify.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// Whether another attempt is allowed after the given number of retries.
+    pub fn can_retry(&self, retries: u32) -> bool {
+        retries < self.max_retries
+    }
+
+    /// Delay before the next attempt, using full jitter over an exponentially growing cap.
+    pub fn backoff(&self, retries: u32) -> Duration {
+        let cap = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retries))
+            .min(self.max_delay);
+
+        let millis = cap.as_millis() as u64;
+        Duration::from_millis(rand::thread_rng().gen_range(0..=millis))
+    }
+
+    /// Whether a request that failed before a response was received can be retried.
+    pub fn is_retryable_error(&self, error: &reqwest::Error, idempotent: bool) -> bool {
+        if idempotent {
+            error.is_connect() || error.is_timeout() || error.is_request()
+        } else {
+            error.is_connect()
+        }
+    }
+
+    /// Whether a request that received the given status can be retried.
+    pub fn is_retryable_status(&self, status: StatusCode, idempotent: bool) -> bool {
+        idempotent && (status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS)
+    }
+}
+
+impl From<&ShopifyHttpClientConfig> for RetryPolicy {
+    fn from(config: &ShopifyHttpClientConfig) -> Self {
+        Self::new(
+            *config.max_retries(),
+            Duration::from_millis(*config.retry_base_delay()),
+            Duration::from_millis(*config.retry_max_delay()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(3, Duration::from_millis(100), Duration::from_millis(300))
+    }
+
+    #[test]
+    fn test_can_retry() {
+        let policy = policy();
+
+        assert!(policy.can_retry(0));
+        assert!(policy.can_retry(2));
+        assert!(!policy.can_retry(3));
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = policy();
+
+        for _ in 0..100 {
+            assert!(policy.backoff(0) <= Duration::from_millis(100));
+            assert!(policy.backoff(1) <= Duration::from_millis(200));
+            assert!(policy.backoff(5) <= Duration::from_millis(300));
+            assert!(policy.backoff(u32::MAX) <= Duration::from_millis(300));
+        }
+    }
+
+    #[test]
+    fn test_is_retryable_status() {
+        let policy = policy();
+
+        assert!(policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE, true));
+        assert!(policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS, true));
+        assert!(!policy.is_retryable_status(StatusCode::BAD_REQUEST, true));
+        assert!(!policy.is_retryable_status(StatusCode::SERVICE_UNAVAILABLE, false));
+        assert!(!policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS, false));
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::infrastructure::ec::ec_client_interface::ECClientResponse;
 
+use super::query_builder::{Selectable, Selection};
+
 #[derive(Debug, Deserialize)]
 pub struct GraphQLResponse<T> {
     pub data: Option<T>,
@@ -15,6 +17,12 @@ pub struct Node<T> {
     pub node: T,
 }
 
+impl<T: Selectable> Selectable for Node<T> {
+    fn selection() -> Selection {
+        Selection::new().object::<T>("node")
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Edges<T> {
@@ -22,6 +30,14 @@ pub struct Edges<T> {
     pub page_info: PageInfo,
 }
 
+impl<T: Selectable> Selectable for Edges<T> {
+    fn selection() -> Selection {
+        Selection::new()
+            .object::<Node<T>>("edges")
+            .object::<PageInfo>("pageInfo")
+    }
+}
+
 impl<T> Default for Edges<T> {
     fn default() -> Self {
         Edges {
@@ -46,6 +62,12 @@ pub struct PageInfo {
     pub end_cursor: Option<String>,
 }
 
+impl Selectable for PageInfo {
+    fn selection() -> Selection {
+        Selection::new().fields(&["hasPreviousPage", "hasNextPage", "startCursor", "endCursor"])
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct GraphQLError {
@@ -53,27 +75,74 @@ pub struct GraphQLError {
     pub extensions: Option<GraphQLErrorExtensions>,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct GraphQLErrorExtensions {
     pub code: Option<String>,
 }
 
-#[allow(dead_code)]
+/// Response `extensions` reported by Shopify alongside `data` and `errors`.
+#[derive(Debug, Deserialize)]
+pub struct GraphQLResponseExtensions {
+    pub cost: Option<QueryCost>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCost {
+    pub requested_query_cost: f64,
+    pub throttle_status: ThrottleStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThrottleStatus {
+    pub maximum_available: f64,
+    pub currently_available: f64,
+    pub restore_rate: f64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UserError {
     pub field: Vec<String>,
     pub message: String,
 }
 
+impl Selectable for UserError {
+    fn selection() -> Selection {
+        Selection::new().fields(&["field", "message"])
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Metafield<T> {
     pub value: T,
 }
 
+impl<T> Selectable for Metafield<T> {
+    fn selection() -> Selection {
+        Selection::new().field("value")
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct MetafieldInput<T> {
     pub key: String,
     pub namespace: String,
     pub value: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::ec::shopify::query_builder::assert_selection_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_selection_matches_schema() {
+        assert_selection_matches::<PageInfo>();
+        assert_selection_matches::<UserError>();
+        assert_selection_matches::<Metafield<String>>();
+        assert_selection_matches::<Node<UserError>>();
+        assert_selection_matches::<Edges<UserError>>();
+    }
+}
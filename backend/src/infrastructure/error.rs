@@ -15,6 +15,8 @@ pub enum InfrastructureError {
     CognitoInitiateAuthError(aws_sdk_cognitoidentityprovider::error::SdkError<InitiateAuthError>),
     #[display(fmt = "Database error.")]
     DatabaseError(sea_orm::DbErr),
+    #[display(fmt = "IO error.")]
+    IoError(std::io::Error),
 }
 
 pub struct InfrastructureErrorMapper;
@@ -26,6 +28,7 @@ impl InfrastructureErrorMapper {
             InfrastructureError::JwtError(_) => DomainError::AuthenticationError,
             InfrastructureError::CognitoInitiateAuthError(_) => DomainError::AuthenticationError,
             InfrastructureError::DatabaseError(_) => DomainError::SystemError,
+            InfrastructureError::IoError(_) => DomainError::SystemError,
         }
     }
 }
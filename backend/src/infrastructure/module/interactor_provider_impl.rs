@@ -10,57 +10,184 @@ use crate::{
             cognito::cognito_authenticator::CognitoAuthenticator,
             rbac::rbac_authorizer::RbacAuthorizer,
         },
-        config::config::{CognitoConfig, ShopifyConfig},
-        db::transaction_manager_interface::TransactionManager,
+        config::config::CognitoConfig,
+        db::{
+            repository::{
+                audit_log::audit_log_impl::AuditLogRepositoryImpl,
+                customer_segment::customer_segment_impl::CustomerSegmentRepositoryImpl,
+                user::user_impl::UserRepositoryImpl,
+                user_customer::user_customer_impl::UserCustomerRepositoryImpl,
+            },
+            transaction_manager_interface::TransactionManager,
+        },
+        ec::local::{
+            query_service::product::product_impl::ProductQueryServiceImpl as LocalProductQueryServiceImpl,
+            repository::{
+                inventory_item::inventory_item_impl::InventoryItemRepositoryImpl as LocalInventoryItemRepositoryImpl,
+                inventory_level::inventory_level_impl::InventoryLevelRepositoryImpl as LocalInventoryLevelRepositoryImpl,
+                media::media_impl::MediaRepositoryImpl as LocalMediaRepositoryImpl,
+                product::product_impl::ProductRepositoryImpl as LocalProductRepositoryImpl,
+            },
+            store::LocalStore,
+        },
         ec::shopify::{
             client_impl::ShopifyGQLClient,
             query_service::product::product_impl::ProductQueryServiceImpl,
             repository::{
                 customer::customer_impl::CustomerRepositoryImpl,
                 draft_order::draft_order_impl::DraftOrderRepositoryImpl,
+                fulfillment::fulfillment_impl::FulfillmentRepositoryImpl,
+                fulfillment_order::fulfillment_order_impl::FulfillmentOrderRepositoryImpl,
                 inventory_item::inventory_item_impl::InventoryItemRepositoryImpl,
                 inventory_level::inventory_level_impl::InventoryLevelRepositoryImpl,
                 location::location_impl::LocationRepositoryImpl,
-                media::media_impl::MediaRepositoryImpl,
+                media::media_impl::MediaRepositoryImpl, order::order_impl::OrderRepositoryImpl,
+                order_return::order_return_impl::OrderReturnRepositoryImpl,
                 product::product_impl::ProductRepositoryImpl,
+                refund::refund_impl::RefundRepositoryImpl,
             },
         },
+        tenant::tenant::{Tenant, DEFAULT_TENANT_ID},
     },
     interface::controller::interactor_provider_interface::InteractorProvider,
-    usecase::interactor::{
-        auth::auth_impl::AuthInteractorImpl, auth_interactor_interface::AuthInteractor,
-        customer::customer_impl::CustomerInteractorImpl,
-        customer_interactor_interface::CustomerInteractor,
-        draft_order::draft_order_impl::DraftOrderInteractorImpl,
-        draft_order_interactor_interface::DraftOrderInteractor,
-        inventory::inventory_impl::InventoryInteractorImpl,
-        inventory_interactor_interface::InventoryInteractor,
-        location::location_impl::LocationInteractorImpl,
-        location_interactor_interface::LocationInteractor, media::media_impl::MediaInteractorImpl,
-        media_interactor_interface::MediaInteractor, product::product_impl::ProductInteractorImpl,
-        product_interactor_interface::ProductInteractor,
+    usecase::{
+        interactor::{
+            auth::auth_impl::AuthInteractorImpl,
+            auth_interactor_interface::AuthInteractor,
+            customer::customer_impl::{CustomerInteractorImpl, CustomerInteractorParams},
+            customer_interactor_interface::CustomerInteractor,
+            draft_order::draft_order_impl::DraftOrderInteractorImpl,
+            draft_order_interactor_interface::DraftOrderInteractor,
+            fulfillment::fulfillment_impl::FulfillmentInteractorImpl,
+            fulfillment_interactor_interface::FulfillmentInteractor,
+            inventory::inventory_impl::InventoryInteractorImpl,
+            inventory_interactor_interface::InventoryInteractor,
+            location::location_impl::LocationInteractorImpl,
+            location_interactor_interface::LocationInteractor,
+            media::media_impl::MediaInteractorImpl,
+            media_interactor_interface::MediaInteractor,
+            order::order_impl::OrderInteractorImpl,
+            order_interactor_interface::OrderInteractor,
+            order_return::order_return_impl::OrderReturnInteractorImpl,
+            order_return_interactor_interface::OrderReturnInteractor,
+            product::product_impl::ProductInteractorImpl,
+            product_interactor_interface::ProductInteractor,
+            refund::refund_impl::RefundInteractorImpl,
+            refund_interactor_interface::RefundInteractor,
+        },
+        query_service::product_query_service_interface::ProductQueryService,
+        repository::{
+            inventory_item_repository_interface::InventoryItemRepository,
+            inventory_level_repository_interface::InventoryLevelRepository,
+            media_repository_interface::MediaRepository,
+            order_repository_interface::OrderRepository,
+            product_repository_interface::ProductRepository,
+        },
     },
 };
 
 /// Factory providing Interactor.
+///
+/// The catalog and inventory are served by the local store when it is given, and by Shopify otherwise.
+/// The other domains are always served by Shopify, with the client of the tenant when a tenant is given.
+/// Locations are among them, so the inventory of a location is always read from Shopify as well.
 pub struct InteractorProviderImpl {
-    shopify_config: ShopifyConfig,
+    shopify_client: ShopifyGQLClient,
+    local_store: Option<LocalStore>,
     cognito_config: CognitoConfig,
     aws_sdk_config: SdkConfig,
 }
 
 impl InteractorProviderImpl {
     pub fn new(
-        shopify_config: ShopifyConfig,
+        shopify_client: ShopifyGQLClient,
+        local_store: Option<LocalStore>,
         cognito_config: CognitoConfig,
         aws_sdk_config: SdkConfig,
     ) -> Self {
         Self {
-            shopify_config,
+            shopify_client,
+            local_store,
             cognito_config,
             aws_sdk_config,
         }
     }
+
+    /// Id of the tenant, or of the default tenant when there is no tenant.
+    fn tenant_id(tenant: &Option<Arc<Tenant>>) -> i32 {
+        tenant
+            .as_ref()
+            .map_or(DEFAULT_TENANT_ID, |tenant| *tenant.id())
+    }
+
+    /// Client of the shop of the tenant, or of the default shop when there is no tenant.
+    fn shopify_client(&self, tenant: &Option<Arc<Tenant>>) -> ShopifyGQLClient {
+        match tenant {
+            Some(tenant) => tenant.shopify_client().clone(),
+            None => self.shopify_client.clone(),
+        }
+    }
+
+    fn product_repository(&self, shopify_client: &ShopifyGQLClient) -> Box<dyn ProductRepository> {
+        match &self.local_store {
+            Some(store) => Box::new(LocalProductRepositoryImpl::new(store.clone())),
+            None => Box::new(ProductRepositoryImpl::new(shopify_client.clone())),
+        }
+    }
+
+    fn media_repository(&self, shopify_client: &ShopifyGQLClient) -> Box<dyn MediaRepository> {
+        match &self.local_store {
+            Some(_) => Box::new(LocalMediaRepositoryImpl::new()),
+            None => Box::new(MediaRepositoryImpl::new(shopify_client.clone())),
+        }
+    }
+
+    fn product_query_service(
+        &self,
+        shopify_client: &ShopifyGQLClient,
+    ) -> Box<dyn ProductQueryService> {
+        match &self.local_store {
+            Some(store) => Box::new(LocalProductQueryServiceImpl::new(store.clone())),
+            None => Box::new(ProductQueryServiceImpl::new(shopify_client.clone())),
+        }
+    }
+
+    fn inventory_item_repository(
+        &self,
+        shopify_client: &ShopifyGQLClient,
+    ) -> Box<dyn InventoryItemRepository> {
+        match &self.local_store {
+            Some(store) => Box::new(LocalInventoryItemRepositoryImpl::new(store.clone())),
+            None => Box::new(InventoryItemRepositoryImpl::new(shopify_client.clone())),
+        }
+    }
+
+    fn inventory_level_repository(
+        &self,
+        shopify_client: &ShopifyGQLClient,
+    ) -> Box<dyn InventoryLevelRepository> {
+        match &self.local_store {
+            Some(store) => Box::new(LocalInventoryLevelRepositoryImpl::new(store.clone())),
+            None => Box::new(InventoryLevelRepositoryImpl::new(shopify_client.clone())),
+        }
+    }
+
+    fn order_repository(
+        &self,
+        shopify_client: &ShopifyGQLClient,
+        transaction_manager: &Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: &Option<Arc<Tenant>>,
+    ) -> Box<dyn OrderRepository> {
+        Box::new(OrderRepositoryImpl::new(
+            shopify_client.clone(),
+            Box::new(UserCustomerRepositoryImpl::new(
+                Arc::clone(transaction_manager),
+                Self::tenant_id(tenant),
+            )),
+        ))
+    }
 }
 
 #[async_trait]
@@ -70,25 +197,31 @@ impl InteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>> for Intera
         transaction_manager: Arc<
             dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
         >,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn ProductInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
         Box::new(ProductInteractorImpl::new(
-            Box::new(ProductRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
-            Box::new(MediaRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
-            Box::new(ProductQueryServiceImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
+            self.product_repository(&shopify_client),
+            self.media_repository(&shopify_client),
+            self.product_query_service(&shopify_client),
             Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
         ))
     }
 
-    async fn provide_media_interactor(&self) -> Box<dyn MediaInteractor> {
-        Box::new(MediaInteractorImpl::new(Box::new(
-            MediaRepositoryImpl::new(ShopifyGQLClient::new(self.shopify_config.clone())),
-        )))
+    async fn provide_media_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn MediaInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(MediaInteractorImpl::new(
+            self.media_repository(&shopify_client),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
     }
 
     async fn provide_inventory_interactor(
@@ -96,14 +229,13 @@ impl InteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>> for Intera
         transaction_manager: Arc<
             dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
         >,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn InventoryInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
         Box::new(InventoryInteractorImpl::new(
-            Box::new(InventoryItemRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
-            Box::new(InventoryLevelRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
+            self.inventory_item_repository(&shopify_client),
+            self.inventory_level_repository(&shopify_client),
             Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
         ))
     }
@@ -113,22 +245,100 @@ impl InteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>> for Intera
         transaction_manager: Arc<
             dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
         >,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn DraftOrderInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
         Box::new(DraftOrderInteractorImpl::new(
-            Box::new(DraftOrderRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
-            Box::new(CustomerRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
-            ))),
+            Box::new(DraftOrderRepositoryImpl::new(shopify_client.clone())),
+            Box::new(CustomerRepositoryImpl::new(shopify_client.clone())),
             Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
         ))
     }
 
-    async fn provide_location_interactor(&self) -> Box<dyn LocationInteractor> {
-        Box::new(LocationInteractorImpl::new(Box::new(
-            LocationRepositoryImpl::new(ShopifyGQLClient::new(self.shopify_config.clone())),
-        )))
+    async fn provide_order_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn OrderInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(OrderInteractorImpl::new(
+            self.order_repository(&shopify_client, &transaction_manager, &tenant),
+            Box::new(CustomerRepositoryImpl::new(shopify_client.clone())),
+            Box::new(UserCustomerRepositoryImpl::new(
+                Arc::clone(&transaction_manager),
+                Self::tenant_id(&tenant),
+            )),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
+    }
+
+    async fn provide_fulfillment_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn FulfillmentInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(FulfillmentInteractorImpl::new(
+            Box::new(FulfillmentRepositoryImpl::new(shopify_client.clone())),
+            Box::new(FulfillmentOrderRepositoryImpl::new(shopify_client.clone())),
+            self.order_repository(&shopify_client, &transaction_manager, &tenant),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
+    }
+
+    async fn provide_order_return_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn OrderReturnInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(OrderReturnInteractorImpl::new(
+            Box::new(OrderReturnRepositoryImpl::new(shopify_client.clone())),
+            self.order_repository(&shopify_client, &transaction_manager, &tenant),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
+    }
+
+    async fn provide_refund_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn RefundInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(RefundInteractorImpl::new(
+            Box::new(RefundRepositoryImpl::new(shopify_client.clone())),
+            self.order_repository(&shopify_client, &transaction_manager, &tenant),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
+    }
+
+    async fn provide_location_interactor(
+        &self,
+        transaction_manager: Arc<
+            dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+        >,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn LocationInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(LocationInteractorImpl::new(
+            Box::new(LocationRepositoryImpl::new(shopify_client.clone())),
+            Box::new(InventoryLevelRepositoryImpl::new(shopify_client.clone())),
+            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        ))
     }
 
     async fn provide_customer_interactor(
@@ -136,13 +346,27 @@ impl InteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>> for Intera
         transaction_manager: Arc<
             dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
         >,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn CustomerInteractor> {
-        Box::new(CustomerInteractorImpl::new(
-            Box::new(CustomerRepositoryImpl::new(ShopifyGQLClient::new(
-                self.shopify_config.clone(),
+        let shopify_client = self.shopify_client(&tenant);
+
+        Box::new(CustomerInteractorImpl::new(CustomerInteractorParams {
+            customer_repository: Box::new(CustomerRepositoryImpl::new(shopify_client.clone())),
+            customer_segment_repository: Box::new(CustomerSegmentRepositoryImpl::new(Arc::clone(
+                &transaction_manager,
             ))),
-            Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
-        ))
+            draft_order_repository: Box::new(DraftOrderRepositoryImpl::new(shopify_client.clone())),
+            order_repository: self.order_repository(&shopify_client, &transaction_manager, &tenant),
+            user_repository: Box::new(UserRepositoryImpl::new(Arc::clone(&transaction_manager))),
+            user_customer_repository: Box::new(UserCustomerRepositoryImpl::new(
+                Arc::clone(&transaction_manager),
+                Self::tenant_id(&tenant),
+            )),
+            audit_log_repository: Box::new(AuditLogRepositoryImpl::new(Arc::clone(
+                &transaction_manager,
+            ))),
+            authorizer: Arc::new(RbacAuthorizer::new(Arc::clone(&transaction_manager))),
+        }))
     }
 
     async fn provide_auth_interactor(
@@ -150,14 +374,23 @@ impl InteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>> for Intera
         transaction_manager: Arc<
             dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
         >,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn AuthInteractor> {
+        let shopify_client = self.shopify_client(&tenant);
+        let mut authorizer = RbacAuthorizer::new(Arc::clone(&transaction_manager));
+        authorizer.scope_to_tenant(Self::tenant_id(&tenant));
+
         Box::new(AuthInteractorImpl::new(
             CognitoAuthenticator::new(
                 self.cognito_config.clone(),
                 self.aws_sdk_config.clone(),
-                RbacAuthorizer::new(Arc::clone(&transaction_manager)),
+                authorizer,
+            ),
+            CustomerRepositoryImpl::new(shopify_client.clone()),
+            UserCustomerRepositoryImpl::new(
+                Arc::clone(&transaction_manager),
+                Self::tenant_id(&tenant),
             ),
-            CustomerRepositoryImpl::new(ShopifyGQLClient::new(self.shopify_config.clone())),
         ))
     }
 }
@@ -1,10 +1,22 @@
 use crate::interface::controller::{
-    controller::Controller, get_customers::GetCustomersQueryParams,
-    get_draft_orders::GetDraftOrdersQueryParams, get_inventories::GetInventoriesQueryParams,
-    get_locations::GetLocationsQueryParams, get_products::GetProductsQueryParams,
-    interactor_provider_interface::InteractorProvider, post_draft_order::PostDraftOrderRequest,
-    post_sign_in::PostSignInRequest,
+    cancel_draft_order::CancelDraftOrderRequest, controller::Controller,
+    get_customers::GetCustomersQueryParams, get_draft_orders::GetDraftOrdersQueryParams,
+    get_inventories::GetInventoriesQueryParams, get_locations::GetLocationsQueryParams,
+    get_nearby_locations::GetNearbyLocationsQueryParams, get_orders::GetOrdersQueryParams,
+    get_products::GetProductsQueryParams, interactor_provider_interface::InteractorProvider,
+    post_customer::PostCustomerRequest, post_customer_address::PostCustomerAddressRequest,
+    post_customer_segment::PostCustomerSegmentRequest, post_draft_order::PostDraftOrderRequest,
+    post_fulfillment::PostFulfillmentRequest, post_location::PostLocationRequest,
+    post_order_cancel::PostOrderCancelRequest, post_product_media::PostProductMediaRequest,
+    post_product_media_reorder::PostProductMediaReorderRequest,
+    post_product_media_staged_upload::PostProductMediaStagedUploadRequest,
+    post_refund::PostRefundRequest, post_refund_calculate::PostRefundCalculateRequest,
+    post_return::PostReturnRequest, post_sign_in::PostSignInRequest,
+    put_customer::PutCustomerRequest, put_customer_address::PutCustomerAddressRequest,
+    put_fulfillment_tracking::PutFulfillmentTrackingRequest,
     put_inventory_quantity_by_sku::PutInventoryQuantityBySkuRequest,
+    put_location::PutLocationRequest, put_location_deactivate::PutLocationDeactivateRequest,
+    put_product_media::PutProductMediaRequest, put_return_decline::PutReturnDeclineRequest,
 };
 use actix_web::{web, HttpResponse};
 
@@ -41,6 +53,84 @@ where
                     },
                 ),
             )
+            .route(
+                "/products/{id}/media",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_product_media(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostProductMediaRequest>| async move {
+                        controller.post_product_media(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media/staged-uploads",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostProductMediaStagedUploadRequest>| async move {
+                        controller
+                            .post_product_media_staged_upload(request, path, body)
+                            .await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media/reorder",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostProductMediaReorderRequest>| async move {
+                        controller
+                            .post_product_media_reorder(request, path, body)
+                            .await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media/{media_id}",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>,
+                     body: web::Json<PutProductMediaRequest>| async move {
+                        controller.put_product_media(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media/{media_id}",
+                web::delete().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>| async move {
+                        controller.delete_product_media(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/products/{id}/media/{media_id}/featured",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>| async move {
+                        controller.put_product_media_featured(request, path).await
+                    },
+                ),
+            )
             .route(
                 "/products/related/{id}",
                 web::get().to(
@@ -114,12 +204,207 @@ where
                     },
                 ),
             )
+            .route(
+                "/orders/draft/cancel/{id}",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<CancelDraftOrderRequest>| async move {
+                        controller.cancel_draft_order(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/orders",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     params: web::Query<GetOrdersQueryParams>| async move {
+                        controller.get_orders(request, params).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_order(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/cancel",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostOrderCancelRequest>| async move {
+                        controller.post_order_cancel(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/fulfillment-orders",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_fulfillment_orders(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/fulfillments",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostFulfillmentRequest>| async move {
+                        controller.post_fulfillment(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/fulfillments/{id}/tracking",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PutFulfillmentTrackingRequest>| async move {
+                        controller
+                            .put_fulfillment_tracking(request, path, body)
+                            .await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/returns",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_returns(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/returns",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostReturnRequest>| async move {
+                        controller.post_return(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/returns/{id}/approve",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.put_return_approve(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/returns/{id}/decline",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PutReturnDeclineRequest>| async move {
+                        controller.put_return_decline(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/refunds/calculate",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostRefundCalculateRequest>| async move {
+                        controller.post_refund_calculate(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/orders/{id}/refunds",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostRefundRequest>| async move {
+                        controller.post_refund(request, path, body).await
+                    },
+                ),
+            )
             .route(
                 "/locations",
                 web::get().to(
                     |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
                      params: web::Query<GetLocationsQueryParams>| async move {
-                        controller.get_locations(params).await
+                        controller.get_locations(request, params).await
+                    },
+                ),
+            )
+            .route(
+                "/locations/nearby",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     params: web::Query<GetNearbyLocationsQueryParams>| async move {
+                        controller.get_nearby_locations(request, params).await
+                    },
+                ),
+            )
+            .route(
+                "/locations",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     body: web::Json<PostLocationRequest>| async move {
+                        controller.post_location(request, body).await
+                    },
+                ),
+            )
+            .route(
+                "/locations/{id}",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PutLocationRequest>| async move {
+                        controller.put_location(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/locations/{id}/deactivate",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PutLocationDeactivateRequest>| async move {
+                        controller
+                            .put_location_deactivate(request, path, body)
+                            .await
+                    },
+                ),
+            )
+            .route(
+                "/locations/{id}/activate",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.put_location_activate(request, path).await
                     },
                 ),
             )
@@ -133,6 +418,128 @@ where
                     },
                 ),
             )
+            .route(
+                "/customers",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     body: web::Json<PostCustomerRequest>| async move {
+                        controller.post_customer(request, body).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_customer(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PutCustomerRequest>| async move {
+                        controller.put_customer(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/deactivate",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.put_customer_deactivate(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/addresses",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>,
+                     body: web::Json<PostCustomerAddressRequest>| async move {
+                        controller.post_customer_address(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/addresses/{address_id}",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>,
+                     body: web::Json<PutCustomerAddressRequest>| async move {
+                        controller.put_customer_address(request, path, body).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/addresses/{address_id}",
+                web::delete().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>| async move {
+                        controller.delete_customer_address(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/addresses/{address_id}/default",
+                web::put().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String, String)>| async move {
+                        controller.put_customer_default_address(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/data-export",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.get_customer_data_export(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customers/{id}/data-erasure",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     path: web::Path<(String,)>| async move {
+                        controller.post_customer_data_erasure(request, path).await
+                    },
+                ),
+            )
+            .route(
+                "/customer-segments",
+                web::get().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest| async move {
+                        controller.get_customer_segments(request).await
+                    },
+                ),
+            )
+            .route(
+                "/customer-segments",
+                web::post().to(
+                    |controller: web::Data<Controller<I, T, C>>,
+                     request: actix_web::HttpRequest,
+                     body: web::Json<PostCustomerSegmentRequest>| async move {
+                        controller.post_customer_segment(request, body).await
+                    },
+                ),
+            )
             .route(
                 "/auth/sign-in",
                 web::post().to(
@@ -0,0 +1,4 @@
+pub mod tenant;
+pub mod tenant_middleware;
+pub mod tenant_registry;
+pub mod token_cipher;
@@ -0,0 +1,22 @@
+use derive_getters::Getters;
+
+use crate::infrastructure::ec::shopify::client_impl::ShopifyGQLClient;
+
+/// Id of the tenant standing for the shop served without tenant routing.
+/// It also holds the role assignments and user links made before tenants were introduced.
+pub const DEFAULT_TENANT_ID: i32 = 1;
+
+/// Shop that a request is routed to.
+///
+/// Each tenant has its own client, so the connection pool and the query cost budget are kept per shop.
+#[derive(Getters, Clone)]
+pub struct Tenant {
+    id: i32,
+    shopify_client: ShopifyGQLClient,
+}
+
+impl Tenant {
+    pub fn new(id: i32, shopify_client: ShopifyGQLClient) -> Self {
+        Self { id, shopify_client }
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    error,
+    middleware::Next,
+    web, Error, HttpMessage,
+};
+
+use crate::{domain::error::error::DomainError, log_error};
+
+use super::{tenant::Tenant, tenant_registry::TenantRegistry};
+
+/// Header naming the shop domain of the tenant, such as `acme.myshopify.com`.
+pub const SHOP_DOMAIN_HEADER: &str = "x-shop-domain";
+const SHOPIFY_DOMAIN: &str = "myshopify.com";
+const EXCLUDE_TENANT_PATHS: [&str; 1] = ["/health"];
+
+/// Middleware for resolving the tenant of a request.
+///
+/// The shop domain is taken from the `X-Shop-Domain` header, or else from the subdomain of the host under the base domain.
+/// Nothing is resolved when no registry is registered, and requests are served by the default shop.
+pub async fn tenant_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let registry = req.app_data::<web::Data<TenantRegistry>>().cloned();
+    let registry = match registry {
+        Some(registry) if !EXCLUDE_TENANT_PATHS.contains(&req.path()) => registry,
+        _ => return next.call(req).await,
+    };
+
+    let header = req
+        .headers()
+        .get(SHOP_DOMAIN_HEADER)
+        .and_then(|value| value.to_str().ok());
+    let shop_domain = shop_domain_from(
+        header,
+        req.connection_info().host(),
+        registry.config().base_domain().as_deref(),
+    )
+    .ok_or_else(|| error::ErrorBadRequest("Tenant is not specified"))?;
+
+    let tenant = registry.resolve(&shop_domain).await.map_err(|e| match e {
+        DomainError::NotFound => error::ErrorNotFound("Tenant not found"),
+        _ => {
+            log_error!("Failed to resolve tenant.", "shop_domain" => shop_domain);
+            error::ErrorInternalServerError("System error")
+        }
+    })?;
+
+    req.extensions_mut().insert::<Arc<Tenant>>(tenant);
+
+    next.call(req).await
+}
+
+/// Get the shop domain of the tenant from the header, or else from the subdomain of the host.
+fn shop_domain_from(header: Option<&str>, host: &str, base_domain: Option<&str>) -> Option<String> {
+    if let Some(shop_domain) = header.map(str::trim).filter(|value| !value.is_empty()) {
+        return Some(shop_domain.to_lowercase());
+    }
+
+    let host = host.split(':').next().unwrap_or(host).to_lowercase();
+    let shop_name = host.strip_suffix(&format!(".{}", base_domain?.to_lowercase()))?;
+    if shop_name.is_empty() || shop_name.contains('.') {
+        return None;
+    }
+
+    Some(format!("{shop_name}.{SHOPIFY_DOMAIN}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shop_domain_from_header() {
+        assert_eq!(
+            shop_domain_from(
+                Some(" Acme.myshopify.com "),
+                "other.example.com",
+                Some("example.com")
+            ),
+            Some("acme.myshopify.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shop_domain_from_subdomain() {
+        assert_eq!(
+            shop_domain_from(None, "acme.example.com:8080", Some("example.com")),
+            Some("acme.myshopify.com".to_string())
+        );
+        assert_eq!(
+            shop_domain_from(Some(""), "Acme.Example.com", Some("example.com")),
+            Some("acme.myshopify.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shop_domain_from_host_without_shop() {
+        assert_eq!(
+            shop_domain_from(None, "example.com", Some("example.com")),
+            None
+        );
+        assert_eq!(
+            shop_domain_from(None, "a.acme.example.com", Some("example.com")),
+            None
+        );
+        assert_eq!(
+            shop_domain_from(None, "acme.other.com", Some("example.com")),
+            None
+        );
+        assert_eq!(shop_domain_from(None, "acme.example.com", None), None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+};
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::error::error::DomainError,
+    infrastructure::{
+        config::config::{ShopifyConfig, ShopifyHttpClientConfig, TenantConfig},
+        db::model::{prelude::Tenant as TenantEntity, tenant},
+        ec::shopify::client_impl::ShopifyGQLClient,
+        error::{InfrastructureError, InfrastructureErrorMapper},
+    },
+    log_error,
+};
+
+use super::{tenant::Tenant, token_cipher::TokenCipher};
+
+struct CachedTenant {
+    tenant: Arc<Tenant>,
+    updated_at: DateTimeWithTimeZone,
+    loaded_at: Instant,
+}
+
+/// Registry of the shops that requests can be routed to.
+///
+/// Tenants are read from the database and kept for the configured duration.
+/// When a tenant is read again and has not been updated, its client is reused to keep the pooled connections.
+pub struct TenantRegistry {
+    connection: Arc<DatabaseConnection>,
+    config: TenantConfig,
+    http_client_config: ShopifyHttpClientConfig,
+    cipher: TokenCipher,
+    cache: RwLock<HashMap<String, CachedTenant>>,
+}
+
+impl TenantRegistry {
+    pub fn new(
+        connection: Arc<DatabaseConnection>,
+        config: TenantConfig,
+        http_client_config: ShopifyHttpClientConfig,
+    ) -> Result<Self, DomainError> {
+        let cipher =
+            TokenCipher::new(config.token_encryption_key().as_deref().ok_or_else(|| {
+                log_error!("The token encryption key is not set.");
+                DomainError::InitConfigError
+            })?)?;
+
+        Ok(Self {
+            connection,
+            config,
+            http_client_config,
+            cipher,
+            cache: RwLock::new(HashMap::new()),
+        })
+    }
+
+    pub fn config(&self) -> &TenantConfig {
+        &self.config
+    }
+
+    /// Get the enabled tenant of a shop domain.
+    /// Returns `DomainError::NotFound` if the shop is not registered or is disabled.
+    pub async fn resolve(&self, shop_domain: &str) -> Result<Arc<Tenant>, DomainError> {
+        let ttl = Duration::from_secs(*self.config.cache_ttl());
+        if let Some(cached) = self.cache.read().await.get(shop_domain) {
+            if cached.loaded_at.elapsed() < ttl {
+                return Ok(Arc::clone(&cached.tenant));
+            }
+        }
+
+        let model = match self.find_enabled_tenant(shop_domain).await? {
+            Some(model) => model,
+            None => {
+                self.cache.write().await.remove(shop_domain);
+                log_error!("No enabled tenant found.", "shop_domain" => shop_domain);
+                return Err(DomainError::NotFound);
+            }
+        };
+
+        let mut cache = self.cache.write().await;
+        if let Some(cached) = cache.get_mut(shop_domain) {
+            if cached.updated_at == model.updated_at {
+                cached.loaded_at = Instant::now();
+                return Ok(Arc::clone(&cached.tenant));
+            }
+        }
+
+        let tenant = Arc::new(self.build_tenant(&model)?);
+        cache.insert(
+            shop_domain.to_string(),
+            CachedTenant {
+                tenant: Arc::clone(&tenant),
+                updated_at: model.updated_at,
+                loaded_at: Instant::now(),
+            },
+        );
+
+        Ok(tenant)
+    }
+
+    async fn find_enabled_tenant(
+        &self,
+        shop_domain: &str,
+    ) -> Result<Option<tenant::Model>, DomainError> {
+        TenantEntity::find()
+            .filter(tenant::Column::ShopDomain.eq(shop_domain))
+            .filter(tenant::Column::IsEnabled.eq(true))
+            .one(self.connection.as_ref())
+            .await
+            .map_err(|e| {
+                log_error!("Failed to get tenant.", "shop_domain" => shop_domain, "error" => e);
+                InfrastructureErrorMapper::to_domain(InfrastructureError::DatabaseError(e))
+            })
+    }
+
+    fn build_tenant(&self, model: &tenant::Model) -> Result<Tenant, DomainError> {
+        if !ShopifyConfig::is_valid_api_version(&model.api_version) {
+            log_error!(
+                "The tenant has an invalid API version.",
+                "shop_domain" => model.shop_domain,
+                "api_version" => model.api_version
+            );
+            return Err(DomainError::SystemError);
+        }
+
+        let access_token = self.cipher.decrypt(&model.encrypted_access_token)?;
+        let shopify_client = ShopifyGQLClient::new(ShopifyConfig::for_shop(
+            &model.shop_domain,
+            &model.api_version,
+            access_token,
+            self.http_client_config.clone(),
+        ))?;
+
+        Ok(Tenant::new(model.id, shopify_client))
+    }
+}
@@ -0,0 +1,140 @@
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::{domain::error::error::DomainError, log_error};
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+
+/// Cipher for the access tokens of the tenants stored in the database.
+///
+/// A token is stored as the base64 of the nonce followed by the AES-256-GCM ciphertext.
+#[derive(Clone)]
+pub struct TokenCipher {
+    cipher: Aes256Gcm,
+}
+
+impl TokenCipher {
+    /// Create a cipher from a 32 byte key encoded in base64.
+    pub fn new(key: &str) -> Result<Self, DomainError> {
+        let key = STANDARD.decode(key).map_err(|e| {
+            log_error!("Failed to decode the token encryption key.", "error" => e);
+            DomainError::InitConfigError
+        })?;
+        if key.len() != KEY_LENGTH {
+            log_error!("The token encryption key must be 32 bytes.", "length" => key.len());
+            return Err(DomainError::InitConfigError);
+        }
+
+        Ok(Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+        })
+    }
+
+    /// Encrypt a token to store it when a tenant is registered.
+    #[allow(dead_code)]
+    pub fn encrypt(&self, token: &str) -> Result<String, DomainError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self.cipher.encrypt(&nonce, token.as_bytes()).map_err(|e| {
+            log_error!("Failed to encrypt the token.", "error" => e);
+            DomainError::SystemError
+        })?;
+
+        let mut encrypted = nonce.to_vec();
+        encrypted.extend(ciphertext);
+        Ok(STANDARD.encode(encrypted))
+    }
+
+    /// Decrypt a stored token.
+    pub fn decrypt(&self, encrypted: &str) -> Result<String, DomainError> {
+        let encrypted = STANDARD.decode(encrypted).map_err(|e| {
+            log_error!("Failed to decode the encrypted token.", "error" => e);
+            DomainError::SystemError
+        })?;
+        if encrypted.len() <= NONCE_LENGTH {
+            log_error!("The encrypted token is too short.", "length" => encrypted.len());
+            return Err(DomainError::SystemError);
+        }
+
+        let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+        let token = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|e| {
+                log_error!("Failed to decrypt the token.", "error" => e);
+                DomainError::SystemError
+            })?;
+
+        String::from_utf8(token).map_err(|e| {
+            log_error!("The decrypted token is not UTF-8.", "error" => e);
+            DomainError::SystemError
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> String {
+        STANDARD.encode([byte; KEY_LENGTH])
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let cipher = TokenCipher::new(&key(1)).unwrap();
+
+        let encrypted = cipher.encrypt("shpat_token").unwrap();
+
+        assert_ne!(encrypted, "shpat_token");
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "shpat_token");
+    }
+
+    #[test]
+    fn test_encrypt_uses_new_nonce() {
+        let cipher = TokenCipher::new(&key(1)).unwrap();
+
+        assert_ne!(
+            cipher.encrypt("shpat_token").unwrap(),
+            cipher.encrypt("shpat_token").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decrypt_with_other_key() {
+        let encrypted = TokenCipher::new(&key(1))
+            .unwrap()
+            .encrypt("shpat_token")
+            .unwrap();
+
+        let result = TokenCipher::new(&key(2)).unwrap().decrypt(&encrypted);
+
+        assert_eq!(result, Err(DomainError::SystemError));
+    }
+
+    #[test]
+    fn test_decrypt_invalid_value() {
+        let cipher = TokenCipher::new(&key(1)).unwrap();
+
+        assert_eq!(cipher.decrypt("not base64!"), Err(DomainError::SystemError));
+        assert_eq!(
+            cipher.decrypt(&STANDARD.encode([0; NONCE_LENGTH])),
+            Err(DomainError::SystemError)
+        );
+    }
+
+    #[test]
+    fn test_new_with_invalid_key() {
+        assert!(matches!(
+            TokenCipher::new("not base64!"),
+            Err(DomainError::InitConfigError)
+        ));
+        assert!(matches!(
+            TokenCipher::new(&STANDARD.encode([1; 16])),
+            Err(DomainError::InitConfigError)
+        ));
+    }
+}
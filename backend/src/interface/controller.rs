@@ -1,16 +1,53 @@
+pub mod cancel_draft_order;
 pub mod complete_draft_order;
 pub mod controller;
+pub mod delete_customer_address;
 pub mod delete_draft_order;
+pub mod delete_product_media;
+pub mod get_customer;
+pub mod get_customer_data_export;
+pub mod get_customer_segments;
 pub mod get_customers;
 pub mod get_draft_orders;
+pub mod get_fulfillment_orders;
 pub mod get_inventories;
 pub mod get_locations;
+pub mod get_nearby_locations;
+pub mod get_order;
+pub mod get_orders;
 pub mod get_product;
+pub mod get_product_media;
 pub mod get_products;
 pub mod get_related_products;
+pub mod get_returns;
 pub mod interactor_provider_interface;
+pub mod post_customer;
+pub mod post_customer_address;
+pub mod post_customer_data_erasure;
+pub mod post_customer_segment;
 pub mod post_draft_order;
+pub mod post_fulfillment;
+pub mod post_location;
+pub mod post_order_cancel;
+pub mod post_product_media;
+pub mod post_product_media_reorder;
+pub mod post_product_media_staged_upload;
+pub mod post_refund;
+pub mod post_refund_calculate;
+pub mod post_return;
 pub mod post_sign_in;
 pub mod post_sign_out;
+pub mod put_customer;
+pub mod put_customer_address;
+pub mod put_customer_deactivate;
+pub mod put_customer_default_address;
+pub mod put_fulfillment_tracking;
 pub mod put_inventory_quantity_by_sku;
+pub mod put_location;
+pub mod put_location_activate;
+pub mod put_location_deactivate;
+pub mod put_product_media;
+pub mod put_product_media_featured;
+pub mod put_return_approve;
+pub mod put_return_decline;
 mod schema;
@@ -4,7 +4,9 @@ use actix_web::HttpMessage;
 
 use crate::{
     domain::{error::error::DomainError, user::user::UserInterface},
-    infrastructure::db::transaction_manager_interface::TransactionManager,
+    infrastructure::{
+        db::transaction_manager_interface::TransactionManager, tenant::tenant::Tenant,
+    },
     log_error,
 };
 
@@ -55,6 +57,12 @@ where
         }
     }
 
+    /// Obtain the tenant resolved by middleware from the actix request.
+    /// There is no tenant when requests are not routed to several shops.
+    pub fn get_tenant(&self, request: &actix_web::HttpRequest) -> Option<Arc<Tenant>> {
+        request.extensions().get::<Arc<Tenant>>().cloned()
+    }
+
     /// Obtain the transaction manager from the actix request.
     pub fn get_transaction_manager(
         &self,
@@ -82,7 +90,11 @@ mod test {
     use crate::domain::user::user::UserInterface;
     use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
     use crate::infrastructure::{
-        auth::idp_user::IdpUser, db::sea_orm::sea_orm_manager::SeaOrmTransactionManager,
+        auth::idp_user::IdpUser,
+        config::config::{ShopifyConfig, ShopifyHttpClientConfig},
+        db::sea_orm::sea_orm_manager::SeaOrmTransactionManager,
+        ec::shopify::client_impl::ShopifyGQLClient,
+        tenant::tenant::Tenant,
     };
     use crate::interface::controller::controller::Controller;
     use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
@@ -141,4 +153,34 @@ mod test {
         let transaction_manager = controller.get_transaction_manager(&request);
         assert!(transaction_manager.is_err());
     }
+
+    #[test]
+    fn test_get_tenant_success() {
+        let interactor_provider = MockInteractorProvider::<(), ()>::new();
+
+        let controller = Controller::new(interactor_provider);
+        let request = TestRequest::default().to_http_request();
+        let client = ShopifyGQLClient::new(ShopifyConfig::for_shop(
+            "acme.myshopify.com",
+            "2025-01",
+            "token".to_string(),
+            ShopifyHttpClientConfig::default(),
+        ))
+        .unwrap();
+        let tenant = Arc::new(Tenant::new(1, client));
+        request.extensions_mut().insert(tenant.clone());
+
+        let result = controller.get_tenant(&request);
+        assert!(Arc::ptr_eq(result.as_ref().unwrap(), &tenant));
+    }
+
+    #[test]
+    fn test_get_tenant_without_tenant() {
+        let interactor_provider = MockInteractorProvider::<(), ()>::new();
+
+        let controller = Controller::new(interactor_provider);
+        let request = TestRequest::default().to_http_request();
+
+        assert!(controller.get_tenant(&request).is_none());
+    }
 }
@@ -0,0 +1,168 @@
+use actix_web::{web::Path, Responder};
+
+use crate::interface::presenter::{
+    customer::customer_impl::CustomerPresenterImpl, customer_presenter_interface::CustomerPresenter,
+};
+
+use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Delete an address from the address book of a customer.
+    pub async fn delete_customer_address(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String, String)>,
+    ) -> impl Responder {
+        let presenter = CustomerPresenterImpl::new();
+
+        let (id, address_id) = path.into_inner();
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_customer_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .delete_customer_address(user, &id, &address_id)
+            .await;
+
+        presenter.present_delete_customer_address(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_customers;
+    use crate::usecase::interactor::customer_interactor_interface::CustomerInteractor;
+    use crate::usecase::interactor::customer_interactor_interface::MockCustomerInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/customers";
+
+    async fn setup(
+        interactor: MockCustomerInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_customer_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn CustomerInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_customer_address_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_delete_customer_address()
+            .returning(|_, _, _| Ok(mock_customers(1).remove(0)));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("{BASE_URL}/0/addresses/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_customer_address_not_found() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_delete_customer_address()
+            .returning(|_, _, _| Err(DomainError::NotFound));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("{BASE_URL}/0/addresses/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_customer_address_bad_request() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_delete_customer_address()
+            .returning(|_, _, _| Err(DomainError::InvalidStateTransition));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("{BASE_URL}/0/addresses/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_customer_address_service_unavailable() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_delete_customer_address()
+            .returning(|_, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("{BASE_URL}/0/addresses/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
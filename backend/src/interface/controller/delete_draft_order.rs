@@ -23,10 +23,11 @@ where
 
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_draft_order_interactor(transaction_manager)
+            .provide_draft_order_interactor(transaction_manager, tenant)
             .await;
 
         let result = interactor
@@ -72,7 +73,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_draft_order_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn DraftOrderInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn DraftOrderInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
@@ -1,20 +1,45 @@
 use actix_web::{web, Responder};
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::{
-    domain::{email::email::Email, error::error::DomainError},
+    domain::error::error::DomainError,
     interface::presenter::{
         customer::customer_impl::CustomerPresenterImpl,
         customer_presenter_interface::CustomerPresenter,
     },
-    usecase::interactor::customer_interactor_interface::GetCustomersQuery,
+    usecase::interactor::customer_interactor_interface::{GetCustomersFilter, GetCustomersQuery},
 };
 
-use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+use super::{
+    controller::Controller,
+    interactor_provider_interface::InteractorProvider,
+    schema::component::component::{CustomerSearchCriteriaSchema, CustomerSortKeySchema},
+};
+
+/// Number of customers returned per page when not specified.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+/// Upper limit of customers per page accepted by Shopify.
+const MAX_PAGE_SIZE: u32 = 250;
 
 #[derive(Deserialize)]
 pub struct GetCustomersQueryParams {
+    name: Option<String>,
     email: Option<String>,
+    phone: Option<String>,
+    /// Comma-separated list of tags.
+    tags: Option<String>,
+    created_at_min: Option<String>,
+    created_at_max: Option<String>,
+    orders_count_min: Option<u32>,
+    orders_count_max: Option<u32>,
+    amount_spent_min: Option<f64>,
+    amount_spent_max: Option<f64>,
+    segment: Option<String>,
+    sort_key: Option<CustomerSortKeySchema>,
+    reverse: Option<bool>,
+    first: Option<u32>,
+    after: Option<String>,
 }
 
 impl<I, T, C> Controller<I, T, C>
@@ -23,7 +48,7 @@ where
     T: Send + Sync + 'static,
     C: Send + Sync + 'static,
 {
-    /// Get a list of customers.
+    /// Get a page of customers by search criteria or saved segment.
     pub async fn get_customers(
         &self,
         request: actix_web::HttpRequest,
@@ -31,13 +56,14 @@ where
     ) -> impl Responder {
         let presenter = CustomerPresenterImpl::new();
 
-        let query = validate_query_params(&params)?;
+        let query = validate_query_params(params.into_inner())?;
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_customer_interactor(transaction_manager)
+            .provide_customer_interactor(transaction_manager, tenant)
             .await;
         let results = interactor.get_customers(user, &query).await;
 
@@ -46,21 +72,89 @@ where
 }
 
 fn validate_query_params(
-    params: &GetCustomersQueryParams,
+    params: GetCustomersQueryParams,
 ) -> Result<GetCustomersQuery, DomainError> {
-    if let Some(email) = params.email.clone() {
-        if !email.is_empty() {
-            return Ok(GetCustomersQuery::Email(Email::new(email)?));
-        }
+    let first = params.first.unwrap_or(DEFAULT_PAGE_SIZE);
+    if first == 0 || first > MAX_PAGE_SIZE {
+        return Err(DomainError::InvalidRequest);
+    }
+    if params.after.as_ref().is_some_and(|after| after.is_empty()) {
+        return Err(DomainError::InvalidRequest);
     }
 
-    Err(DomainError::InvalidRequest)
+    let has_criteria = params.name.is_some()
+        || params.email.is_some()
+        || params.phone.is_some()
+        || params.tags.is_some()
+        || params.created_at_min.is_some()
+        || params.created_at_max.is_some()
+        || params.orders_count_min.is_some()
+        || params.orders_count_max.is_some()
+        || params.amount_spent_min.is_some()
+        || params.amount_spent_max.is_some();
+
+    let filter = match params.segment {
+        Some(segment) => {
+            // A segment already holds its own criteria, so mixing them is ambiguous.
+            if segment.is_empty() || has_criteria {
+                return Err(DomainError::InvalidRequest);
+            }
+            GetCustomersFilter::Segment(segment)
+        }
+        None => {
+            let non_empty = |value: Option<String>| match value {
+                Some(v) if v.is_empty() => Err(DomainError::InvalidRequest),
+                v => Ok(v),
+            };
+            let criteria = CustomerSearchCriteriaSchema {
+                name: non_empty(params.name)?,
+                email: non_empty(params.email)?,
+                phone: non_empty(params.phone)?,
+                tags: non_empty(params.tags)?
+                    .map(|tags| tags.split(',').map(|t| t.to_string()).collect())
+                    .unwrap_or_default(),
+                created_at_min: parse_date_time(params.created_at_min)?,
+                created_at_max: parse_date_time(params.created_at_max)?,
+                orders_count_min: params.orders_count_min,
+                orders_count_max: params.orders_count_max,
+                amount_spent_min: params.amount_spent_min,
+                amount_spent_max: params.amount_spent_max,
+            };
+            GetCustomersFilter::Criteria(criteria.to_domain()?)
+        }
+    };
+
+    Ok(GetCustomersQuery {
+        filter,
+        sort_key: params
+            .sort_key
+            .unwrap_or(CustomerSortKeySchema::Id)
+            .to_domain()?,
+        reverse: params.reverse.unwrap_or(false),
+        first,
+        after: params.after,
+    })
+}
+
+fn parse_date_time(value: Option<String>) -> Result<Option<DateTime<Utc>>, DomainError> {
+    value
+        .map(|v| {
+            DateTime::parse_from_rfc3339(&v)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| DomainError::InvalidRequest)
+        })
+        .transpose()
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
+    use crate::domain::customer::search_criteria::search_criteria::{
+        CustomerSearchCriteria, CustomerSearchCriteriaParams, CustomerSortKey,
+    };
+    use crate::domain::email::email::Email;
+    use crate::domain::phone::phone::Phone;
     use crate::domain::user::user::UserInterface;
     use crate::infrastructure::auth::idp_user::IdpUser;
     use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
@@ -71,6 +165,7 @@ mod tests {
     use crate::usecase::interactor::customer_interactor_interface::{
         CustomerInteractor, MockCustomerInteractor,
     };
+    use crate::usecase::repository::customer_repository_interface::CustomerPage;
 
     use super::*;
     use actix_http::Request;
@@ -90,7 +185,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_customer_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn CustomerInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn CustomerInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
@@ -105,6 +200,24 @@ mod tests {
         .await
     }
 
+    fn criteria_query(criteria: CustomerSearchCriteria) -> GetCustomersQuery {
+        GetCustomersQuery {
+            filter: GetCustomersFilter::Criteria(criteria),
+            sort_key: CustomerSortKey::Id,
+            reverse: false,
+            first: 50,
+            after: None,
+        }
+    }
+
+    fn mock_customer_page(count: usize) -> CustomerPage {
+        CustomerPage {
+            customers: mock_customers(count),
+            has_next_page: false,
+            end_cursor: None,
+        }
+    }
+
     fn add_extensions(req: &Request) {
         req.extensions_mut()
             .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
@@ -122,11 +235,17 @@ mod tests {
             .expect_get_customers()
             .with(
                 always(),
-                eq(GetCustomersQuery::Email(
-                    Email::new("john@example.com").expect("Failed to create email"),
+                eq(criteria_query(
+                    CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+                        email: Some(
+                            Email::new("john@example.com").expect("Failed to create email"),
+                        ),
+                        ..Default::default()
+                    })
+                    .unwrap(),
                 )),
             )
-            .returning(|_, _| Ok(mock_customers(10)));
+            .returning(|_, _| Ok(mock_customer_page(10)));
 
         let req = test::TestRequest::get()
             .uri(&format!("{BASE_URL}?email=john@example.com"))
@@ -138,6 +257,163 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::OK);
     }
 
+    #[actix_web::test]
+    async fn test_get_customers_by_phone_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_get_customers()
+            .with(
+                always(),
+                eq(criteria_query(
+                    CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+                        phone: Some(Phone::new("1234567890").expect("Failed to create phone")),
+                        ..Default::default()
+                    })
+                    .unwrap(),
+                )),
+            )
+            .returning(|_, _| Ok(mock_customer_page(1)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?phone=1234567890"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_by_criteria_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_get_customers()
+            .with(
+                always(),
+                eq(GetCustomersQuery {
+                    filter: GetCustomersFilter::Criteria(
+                        CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+                            name: Some("john".to_string()),
+                            tags: vec!["vip".to_string(), "wholesale".to_string()],
+                            created_at_min: Some(
+                                DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+                                    .unwrap()
+                                    .with_timezone(&Utc),
+                            ),
+                            orders_count_min: Some(1),
+                            ..Default::default()
+                        })
+                        .unwrap(),
+                    ),
+                    sort_key: CustomerSortKey::CreatedAt,
+                    reverse: true,
+                    first: 10,
+                    after: Some("cursor".to_string()),
+                }),
+            )
+            .returning(|_, _| Ok(mock_customer_page(10)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "{BASE_URL}?name=john&tags=vip,wholesale&created_at_min=2024-01-01T00:00:00Z&orders_count_min=1&sort_key=CreatedAt&reverse=true&first=10&after=cursor"
+            ))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_without_criteria_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_get_customers()
+            .with(
+                always(),
+                eq(criteria_query(CustomerSearchCriteria::default())),
+            )
+            .returning(|_, _| Ok(mock_customer_page(10)));
+
+        let req = test::TestRequest::get().uri(BASE_URL).to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_by_segment_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_get_customers()
+            .with(
+                always(),
+                eq(GetCustomersQuery {
+                    filter: GetCustomersFilter::Segment("1".to_string()),
+                    sort_key: CustomerSortKey::Id,
+                    reverse: false,
+                    first: 50,
+                    after: None,
+                }),
+            )
+            .returning(|_, _| Ok(mock_customer_page(10)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?segment=1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_segment_with_criteria() {
+        let interactor = MockCustomerInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?segment=1&name=john"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_page_size_exceeded() {
+        let interactor = MockCustomerInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?first=251"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_customers_invalid_range() {
+        let interactor = MockCustomerInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?orders_count_min=5&orders_count_max=1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[actix_web::test]
     async fn test_get_customers_not_specified_email() {
         let interactor = MockCustomerInteractor::new();
@@ -157,7 +433,7 @@ mod tests {
         let mut interactor = MockCustomerInteractor::new();
         interactor
             .expect_get_customers()
-            .returning(|_, _| Ok(vec![]));
+            .returning(|_, _| Ok(mock_customer_page(0)));
 
         let req = test::TestRequest::get()
             .uri(&format!("{BASE_URL}?email=john@example.com"))
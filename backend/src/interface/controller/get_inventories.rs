@@ -39,10 +39,11 @@ where
 
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_inventory_interactor(transaction_manager)
+            .provide_inventory_interactor(transaction_manager, tenant)
             .await;
         let results = interactor
             .get_inventories_from_all_locations(user, &query)
@@ -104,7 +105,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_inventory_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn InventoryInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn InventoryInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
@@ -22,13 +22,21 @@ where
     /// Get a list of locations.
     pub async fn get_locations(
         &self,
+        request: actix_web::HttpRequest,
         params: web::Query<GetLocationsQueryParams>,
     ) -> impl Responder {
         let presenter = LocationPresenterImpl::new();
 
-        let interactor = self.interactor_provider.provide_location_interactor().await;
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_location_interactor(transaction_manager, tenant)
+            .await;
         let results = interactor
-            .get_locations(&params.limit, &params.offset)
+            .get_locations(user, &params.limit, &params.offset)
             .await;
 
         presenter.present_get_locations(results).await
@@ -37,19 +45,27 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
     use crate::infrastructure::router::actix_router;
     use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
     use crate::interface::mock::domain_mock::mock_locations;
-    use crate::usecase::interactor::location_interactor_interface::{
-        LocationInteractor, MockLocationInteractor,
-    };
+    use crate::usecase::interactor::location_interactor_interface::LocationInteractor;
+    use crate::usecase::interactor::location_interactor_interface::MockLocationInteractor;
 
     use super::*;
     use actix_http::Request;
     use actix_web::dev::{Service, ServiceResponse};
     use actix_web::web;
+    use actix_web::HttpMessage;
     use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
 
     const BASE_URL: &'static str = "/ec-extension/locations";
 
@@ -57,30 +73,47 @@ mod tests {
         interactor: MockLocationInteractor,
     ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
         // Configure the mocks
-        let mut interactor_provider = MockInteractorProvider::<(), ()>::new();
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_location_interactor()
-            .return_once(move || Box::new(interactor) as Box<dyn LocationInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn LocationInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
         // Create an application for testing
-        test::init_service(
-            App::new().app_data(controller).configure(
-                actix_router::configure_routes::<MockInteractorProvider<(), ()>, (), ()>,
-            ),
-        )
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
         .await
     }
 
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
     #[actix_web::test]
     async fn test_get_locations_success() {
         let mut interactor = MockLocationInteractor::new();
         interactor
             .expect_get_locations()
-            .returning(|_, _| Ok(mock_locations(10)));
+            .returning(|_, _, _| Ok(mock_locations(10)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}"))
+            .to_request();
+        add_extensions(&req);
 
-        let req = test::TestRequest::get().uri(BASE_URL).to_request();
         let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
 
         assert_eq!(resp.status(), StatusCode::OK);
@@ -91,9 +124,13 @@ mod tests {
         let mut interactor = MockLocationInteractor::new();
         interactor
             .expect_get_locations()
-            .returning(|_, _| Err(DomainError::ValidationError));
+            .returning(|_, _, _| Err(DomainError::ValidationError));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}"))
+            .to_request();
+        add_extensions(&req);
 
-        let req = test::TestRequest::get().uri(BASE_URL).to_request();
         let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
 
         assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
@@ -104,9 +141,13 @@ mod tests {
         let mut interactor = MockLocationInteractor::new();
         interactor
             .expect_get_locations()
-            .returning(|_, _| Err(DomainError::SystemError));
+            .returning(|_, _, _| Err(DomainError::SystemError));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}"))
+            .to_request();
+        add_extensions(&req);
 
-        let req = test::TestRequest::get().uri(BASE_URL).to_request();
         let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
 
         assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
@@ -0,0 +1,299 @@
+use actix_web::{web, Responder};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        coordinates::coordinates::Coordinates, error::error::DomainError,
+        product::variant::sku::sku::Sku,
+    },
+    interface::presenter::{
+        location::location_impl::LocationPresenterImpl,
+        location_presenter_interface::LocationPresenter,
+    },
+    usecase::interactor::location_interactor_interface::{
+        GetNearbyLocationsQuery, NearbyLocationsOrigin,
+    },
+};
+
+use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+
+#[derive(Deserialize)]
+pub struct GetNearbyLocationsQueryParams {
+    lat: Option<f64>,
+    lng: Option<f64>,
+    postal_code: Option<String>,
+    sku: Option<String>,
+    radius_km: Option<f64>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Get active locations near the coordinates or in the postal code.
+    pub async fn get_nearby_locations(
+        &self,
+        request: actix_web::HttpRequest,
+        params: web::Query<GetNearbyLocationsQueryParams>,
+    ) -> impl Responder {
+        let presenter = LocationPresenterImpl::new();
+
+        let query = validate_query_params(params.into_inner())?;
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_location_interactor(transaction_manager, tenant)
+            .await;
+        let results = interactor.get_nearby_locations(user, &query).await;
+
+        presenter.present_get_nearby_locations(results).await
+    }
+}
+
+fn validate_query_params(
+    params: GetNearbyLocationsQueryParams,
+) -> Result<GetNearbyLocationsQuery, DomainError> {
+    // Exactly one origin must be given, either both coordinates or a postal code.
+    let origin = match (params.lat, params.lng, params.postal_code) {
+        (Some(lat), Some(lng), None) => {
+            NearbyLocationsOrigin::Coordinates(Coordinates::new(lat, lng)?)
+        }
+        (None, None, Some(postal_code)) if !postal_code.is_empty() => {
+            if params.radius_km.is_some() {
+                return Err(DomainError::InvalidRequest);
+            }
+            NearbyLocationsOrigin::PostalCode(postal_code)
+        }
+        _ => return Err(DomainError::InvalidRequest),
+    };
+
+    if params
+        .radius_km
+        .is_some_and(|radius_km| !radius_km.is_finite() || radius_km <= 0.0)
+    {
+        return Err(DomainError::InvalidRequest);
+    }
+
+    Ok(GetNearbyLocationsQuery {
+        origin,
+        sku: params.sku.map(Sku::new).transpose()?,
+        radius_km: params.radius_km,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_locations;
+    use crate::usecase::interactor::location_interactor_interface::LocationInteractor;
+    use crate::usecase::interactor::location_interactor_interface::MockLocationInteractor;
+    use crate::usecase::interactor::location_interactor_interface::NearbyLocation;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/locations/nearby";
+
+    async fn setup(
+        interactor: MockLocationInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_location_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn LocationInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_by_coordinates_success() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor.expect_get_nearby_locations().returning(|_, _| {
+            Ok(mock_locations(2)
+                .into_iter()
+                .map(|location| NearbyLocation {
+                    location,
+                    distance_km: Some(1.0),
+                })
+                .collect())
+        });
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "{BASE_URL}?lat=35.68&lng=139.76&sku=SKU1&radius_km=10"
+            ))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_by_postal_code_success() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor.expect_get_nearby_locations().returning(|_, _| {
+            Ok(mock_locations(2)
+                .into_iter()
+                .map(|location| NearbyLocation {
+                    location,
+                    distance_km: Some(1.0),
+                })
+                .collect())
+        });
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?postal_code=100-0001"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_without_origin() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?sku=SKU1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_with_both_origins() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "{BASE_URL}?lat=35.68&lng=139.76&postal_code=100-0001"
+            ))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_with_partial_coordinates() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?lat=35.68"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_with_invalid_latitude() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?lat=91&lng=139.76"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_with_invalid_radius() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?lat=35.68&lng=139.76&radius_km=0"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_with_radius_for_postal_code() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?postal_code=100-0001&radius_km=10"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_nearby_locations_service_unavailable() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor
+            .expect_get_nearby_locations()
+            .returning(|_, _| Err(DomainError::QueryError));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?lat=35.68&lng=139.76"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,167 @@
+use actix_web::{web::Path, Responder};
+
+use crate::interface::presenter::{
+    order::order_impl::OrderPresenterImpl, order_presenter_interface::OrderPresenter,
+};
+
+use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Get detailed order information.
+    pub async fn get_order(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+    ) -> impl Responder {
+        let presenter = OrderPresenterImpl::new();
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_order_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor.get_order(user, &path.into_inner().0).await;
+
+        presenter.present_get_order(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_orders;
+    use crate::usecase::interactor::order_interactor_interface::MockOrderInteractor;
+    use crate::usecase::interactor::order_interactor_interface::OrderInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use mockall::predicate::always;
+    use mockall::predicate::eq;
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockOrderInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_order_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn OrderInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_success() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_order()
+            .with(always(), eq("1".to_string()))
+            .returning(|_, _| Ok(mock_orders(1).remove(0)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_not_found() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_order()
+            .returning(|_, _| Err(DomainError::NotFound));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_bad_request() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_order()
+            .returning(|_, _| Err(DomainError::AuthorizationError));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_service_unavailable() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_order()
+            .returning(|_, _| Err(DomainError::SystemError));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}/1"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
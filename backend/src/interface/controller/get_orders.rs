@@ -0,0 +1,296 @@
+use actix_web::{web, Responder};
+use serde::Deserialize;
+
+use crate::{
+    domain::{email::email::Email, error::error::DomainError},
+    interface::presenter::{
+        order::order_impl::OrderPresenterImpl, order_presenter_interface::OrderPresenter,
+    },
+    usecase::interactor::order_interactor_interface::{GetOrdersFilter, GetOrdersQuery},
+};
+
+use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+
+/// Number of orders returned per page when not specified.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+/// Upper limit of orders per page accepted by Shopify.
+const MAX_PAGE_SIZE: u32 = 250;
+
+#[derive(Deserialize)]
+pub struct GetOrdersQueryParams {
+    email: Option<String>,
+    first: Option<u32>,
+    after: Option<String>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Get a page of orders.
+    pub async fn get_orders(
+        &self,
+        request: actix_web::HttpRequest,
+        params: web::Query<GetOrdersQueryParams>,
+    ) -> impl Responder {
+        let presenter = OrderPresenterImpl::new();
+
+        let query = match validate_query_params(&params) {
+            Ok(query) => query,
+            Err(error) => return presenter.present_get_orders(Err(error)).await,
+        };
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_order_interactor(transaction_manager, tenant)
+            .await;
+        let results = interactor.get_orders(user, &query).await;
+
+        presenter.present_get_orders(results).await
+    }
+}
+
+fn validate_query_params(params: &GetOrdersQueryParams) -> Result<GetOrdersQuery, DomainError> {
+    let first = params.first.unwrap_or(DEFAULT_PAGE_SIZE);
+    if first == 0 || first > MAX_PAGE_SIZE {
+        return Err(DomainError::InvalidRequest);
+    }
+    if params.after.as_ref().is_some_and(|after| after.is_empty()) {
+        return Err(DomainError::InvalidRequest);
+    }
+
+    let filter = match params.email.clone() {
+        Some(email) if email.is_empty() => return Err(DomainError::InvalidRequest),
+        Some(email) => GetOrdersFilter::Email(Email::new(email)?),
+        None => GetOrdersFilter::All,
+    };
+
+    Ok(GetOrdersQuery {
+        filter,
+        first,
+        after: params.after.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_orders;
+    use crate::usecase::interactor::order_interactor_interface::MockOrderInteractor;
+    use crate::usecase::interactor::order_interactor_interface::OrderInteractor;
+    use crate::usecase::repository::order_repository_interface::OrderPage;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use mockall::predicate::always;
+    use mockall::predicate::eq;
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockOrderInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_order_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn OrderInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn mock_order_page(count: usize) -> OrderPage {
+        OrderPage {
+            orders: mock_orders(count),
+            has_next_page: false,
+            end_cursor: None,
+        }
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_success() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_orders()
+            .with(
+                always(),
+                eq(GetOrdersQuery {
+                    filter: GetOrdersFilter::All,
+                    first: DEFAULT_PAGE_SIZE,
+                    after: None,
+                }),
+            )
+            .returning(|_, _| Ok(mock_order_page(10)));
+
+        let req = test::TestRequest::get().uri(BASE_URL).to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_success_with_email() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_orders()
+            .with(
+                always(),
+                eq(GetOrdersQuery {
+                    filter: GetOrdersFilter::Email(
+                        Email::new("john@example.com").expect("Failed to create email"),
+                    ),
+                    first: DEFAULT_PAGE_SIZE,
+                    after: None,
+                }),
+            )
+            .returning(|_, _| Ok(mock_order_page(10)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?email=john@example.com"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_empty_email() {
+        let interactor = MockOrderInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?email="))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_success_with_page() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_orders()
+            .with(
+                always(),
+                eq(GetOrdersQuery {
+                    filter: GetOrdersFilter::All,
+                    first: 10,
+                    after: Some("cursor".to_string()),
+                }),
+            )
+            .returning(|_, _| Ok(mock_order_page(10)));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?first=10&after=cursor"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_page_size_exceeded() {
+        let interactor = MockOrderInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?first=251"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_empty_cursor() {
+        let interactor = MockOrderInteractor::new();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("{BASE_URL}?after="))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_not_found() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_orders()
+            .returning(|_, _| Ok(mock_order_page(0)));
+
+        let req = test::TestRequest::get().uri(BASE_URL).to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_service_unavailable() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_get_orders()
+            .returning(|_, _| Err(DomainError::SystemError));
+
+        let req = test::TestRequest::get().uri(BASE_URL).to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
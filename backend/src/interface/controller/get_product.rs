@@ -23,10 +23,11 @@ where
         let id = &path.into_inner().0;
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let product_interactor = self
             .interactor_provider
-            .provide_product_interactor(transaction_manager)
+            .provide_product_interactor(transaction_manager, tenant)
             .await;
         let result = product_interactor.get_product_with_media(user, id).await;
 
@@ -69,7 +70,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_product_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn ProductInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn ProductInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
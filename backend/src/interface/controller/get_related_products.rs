@@ -24,10 +24,11 @@ where
 
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let product_interactor = self
             .interactor_provider
-            .provide_product_interactor(transaction_manager)
+            .provide_product_interactor(transaction_manager, tenant)
             .await;
         let result = product_interactor.get_related_products(user, id).await;
 
@@ -70,7 +71,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_product_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn ProductInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn ProductInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
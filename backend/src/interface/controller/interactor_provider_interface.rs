@@ -4,15 +4,22 @@ use async_trait::async_trait;
 use mockall::automock;
 
 use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+use crate::infrastructure::tenant::tenant::Tenant;
 use crate::usecase::interactor::auth_interactor_interface::AuthInteractor;
 use crate::usecase::interactor::customer_interactor_interface::CustomerInteractor;
 use crate::usecase::interactor::draft_order_interactor_interface::DraftOrderInteractor;
+use crate::usecase::interactor::fulfillment_interactor_interface::FulfillmentInteractor;
 use crate::usecase::interactor::inventory_interactor_interface::InventoryInteractor;
 use crate::usecase::interactor::location_interactor_interface::LocationInteractor;
 use crate::usecase::interactor::media_interactor_interface::MediaInteractor;
+use crate::usecase::interactor::order_interactor_interface::OrderInteractor;
+use crate::usecase::interactor::order_return_interactor_interface::OrderReturnInteractor;
 use crate::usecase::interactor::product_interactor_interface::ProductInteractor;
+use crate::usecase::interactor::refund_interactor_interface::RefundInteractor;
 
 /// Factory interface providing Interactor.
+///
+/// Interactors are bound to the tenant of the request, or to the default shop when there is no tenant.
 #[allow(dead_code)]
 #[automock]
 #[async_trait]
@@ -25,29 +32,66 @@ where
     async fn provide_product_interactor(
         &self,
         transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn ProductInteractor>;
     /// Provide Interactor for media.
-    async fn provide_media_interactor(&self) -> Box<dyn MediaInteractor>;
+    async fn provide_media_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn MediaInteractor>;
     /// Provide Interactor for inventory.
     async fn provide_inventory_interactor(
         &self,
         transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn InventoryInteractor>;
     /// Provide Interactor for draft order.
     async fn provide_draft_order_interactor(
         &self,
         transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn DraftOrderInteractor>;
+    /// Provide Interactor for order.
+    async fn provide_order_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn OrderInteractor>;
+    /// Provide Interactor for fulfillment.
+    async fn provide_fulfillment_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn FulfillmentInteractor>;
+    /// Provide Interactor for return.
+    async fn provide_order_return_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn OrderReturnInteractor>;
+    /// Provide Interactor for refund.
+    async fn provide_refund_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn RefundInteractor>;
     /// Provide Interactor for location.
-    async fn provide_location_interactor(&self) -> Box<dyn LocationInteractor>;
+    async fn provide_location_interactor(
+        &self,
+        transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
+    ) -> Box<dyn LocationInteractor>;
     /// Provide Interactor for customer.
     async fn provide_customer_interactor(
         &self,
         transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn CustomerInteractor>;
     /// Provide Interactor for auth.
     async fn provide_auth_interactor(
         &self,
         transaction_manager: Arc<dyn TransactionManager<T, C>>,
+        tenant: Option<Arc<Tenant>>,
     ) -> Box<dyn AuthInteractor>;
 }
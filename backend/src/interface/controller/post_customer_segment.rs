@@ -0,0 +1,235 @@
+use actix_web::{web, Responder};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    customer::customer_impl::CustomerPresenterImpl, customer_presenter_interface::CustomerPresenter,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::CustomerSearchCriteriaSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostCustomerSegmentRequest {
+    name: String,
+    criteria: CustomerSearchCriteriaSchema,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Save a named set of customer search criteria.
+    pub async fn post_customer_segment(
+        &self,
+        request: actix_web::HttpRequest,
+        body: web::Json<PostCustomerSegmentRequest>,
+    ) -> impl Responder {
+        let presenter = CustomerPresenterImpl::new();
+
+        let body = body.into_inner();
+        let criteria = body.criteria.to_domain()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_customer_interactor(transaction_manager, tenant)
+            .await;
+        let result = interactor
+            .create_customer_segment(user, body.name, criteria)
+            .await;
+
+        presenter.present_post_customer_segment(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_customer_segments;
+    use crate::usecase::interactor::customer_interactor_interface::CustomerInteractor;
+    use crate::usecase::interactor::customer_interactor_interface::MockCustomerInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/customer-segments";
+
+    async fn setup(
+        interactor: MockCustomerInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_customer_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn CustomerInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_post_customer_segment_success() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_create_customer_segment()
+            .returning(|_, _, _| Ok(mock_customer_segments(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostCustomerSegmentRequest {
+                name: "VIP".to_string(),
+                criteria: CustomerSearchCriteriaSchema {
+                    name: None,
+                    email: None,
+                    phone: None,
+                    tags: vec!["vip".to_string()],
+                    created_at_min: None,
+                    created_at_max: None,
+                    orders_count_min: Some(1),
+                    orders_count_max: None,
+                    amount_spent_min: None,
+                    amount_spent_max: None,
+                },
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_customer_segment_invalid_criteria() {
+        let interactor = MockCustomerInteractor::new();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostCustomerSegmentRequest {
+                name: "VIP".to_string(),
+                criteria: CustomerSearchCriteriaSchema {
+                    name: None,
+                    email: None,
+                    phone: None,
+                    tags: vec!["vip".to_string()],
+                    created_at_min: None,
+                    created_at_max: None,
+                    orders_count_min: Some(5),
+                    orders_count_max: Some(1),
+                    amount_spent_min: None,
+                    amount_spent_max: None,
+                },
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_customer_segment_bad_request() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_create_customer_segment()
+            .returning(|_, _, _| Err(DomainError::ValidationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostCustomerSegmentRequest {
+                name: "VIP".to_string(),
+                criteria: CustomerSearchCriteriaSchema {
+                    name: None,
+                    email: None,
+                    phone: None,
+                    tags: vec!["vip".to_string()],
+                    created_at_min: None,
+                    created_at_max: None,
+                    orders_count_min: Some(1),
+                    orders_count_max: None,
+                    amount_spent_min: None,
+                    amount_spent_max: None,
+                },
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_customer_segment_service_unavailable() {
+        let mut interactor = MockCustomerInteractor::new();
+        interactor
+            .expect_create_customer_segment()
+            .returning(|_, _, _| Err(DomainError::SystemError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostCustomerSegmentRequest {
+                name: "VIP".to_string(),
+                criteria: CustomerSearchCriteriaSchema {
+                    name: None,
+                    email: None,
+                    phone: None,
+                    tags: vec!["vip".to_string()],
+                    created_at_min: None,
+                    created_at_max: None,
+                    orders_count_min: Some(1),
+                    orders_count_max: None,
+                    amount_spent_min: None,
+                    amount_spent_max: None,
+                },
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -93,10 +93,11 @@ where
 
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_draft_order_interactor(transaction_manager)
+            .provide_draft_order_interactor(transaction_manager, tenant)
             .await;
 
         let result = interactor
@@ -154,7 +155,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_draft_order_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn DraftOrderInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn DraftOrderInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
@@ -198,8 +199,10 @@ mod tests {
                     address1: Some("123 Main St".to_string()),
                     address2: None,
                     city: Some("Anytown".to_string()),
-                    province: Some("CA".to_string()),
-                    country: Some("US".to_string()),
+                    province: Some("California".to_string()),
+                    province_code: Some("CA".to_string()),
+                    country: Some("United States".to_string()),
+                    country_code: Some("US".to_string()),
                     zip: Some("12345".to_string()),
                     phone: Some("555-1234".to_string()),
                 }),
@@ -209,8 +212,10 @@ mod tests {
                     address1: Some("123 Main St".to_string()),
                     address2: None,
                     city: Some("Anytown".to_string()),
-                    province: Some("CA".to_string()),
-                    country: Some("US".to_string()),
+                    province: Some("California".to_string()),
+                    province_code: Some("CA".to_string()),
+                    country: Some("United States".to_string()),
+                    country_code: Some("US".to_string()),
                     zip: Some("12345".to_string()),
                     phone: Some("555-1234".to_string()),
                 }),
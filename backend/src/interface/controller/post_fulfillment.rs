@@ -0,0 +1,247 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::error::error::DomainError,
+    interface::presenter::{
+        fulfillment::fulfillment_impl::FulfillmentPresenterImpl,
+        fulfillment_presenter_interface::FulfillmentPresenter,
+    },
+    log_error,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::TrackingInfoSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostFulfillmentRequest {
+    location_id: String,
+    line_items: Vec<PostFulfillmentLineItemSchema>,
+    tracking_info: Option<TrackingInfoSchema>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PostFulfillmentLineItemSchema {
+    fulfillment_order_line_item_id: String,
+    quantity: u32,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Create a fulfillment of an order.
+    pub async fn post_fulfillment(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PostFulfillmentRequest>,
+    ) -> impl Responder {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let body = body.into_inner();
+        if body.line_items.is_empty() {
+            log_error!("Line items cannot be empty.");
+            return presenter
+                .present_post_fulfillment(Err(DomainError::InvalidRequest))
+                .await;
+        }
+
+        let line_items = body
+            .line_items
+            .into_iter()
+            .map(|li| (li.fulfillment_order_line_item_id, li.quantity))
+            .collect();
+
+        let tracking_info = body.tracking_info.map(|t| t.to_domain()).transpose()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_fulfillment_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .create_fulfillment(
+                user,
+                &path.into_inner().0,
+                &body.location_id,
+                line_items,
+                tracking_info,
+            )
+            .await;
+
+        presenter.present_post_fulfillment(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_fulfillments;
+    use crate::usecase::interactor::fulfillment_interactor_interface::FulfillmentInteractor;
+    use crate::usecase::interactor::fulfillment_interactor_interface::MockFulfillmentInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockFulfillmentInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_fulfillment_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn FulfillmentInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    fn mock_request(tracking_number: &str) -> PostFulfillmentRequest {
+        PostFulfillmentRequest {
+            location_id: "0".to_string(),
+            line_items: vec![PostFulfillmentLineItemSchema {
+                fulfillment_order_line_item_id: "0".to_string(),
+                quantity: 1,
+            }],
+            tracking_info: Some(TrackingInfoSchema {
+                company: Some("UPS".to_string()),
+                number: tracking_number.to_string(),
+                url: None,
+            }),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_post_fulfillment_success() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_create_fulfillment()
+            .returning(|_, _, _, _, _| Ok(mock_fulfillments(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/fulfillments"))
+            .set_json(mock_request("123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_fulfillment_with_empty_line_items() {
+        let interactor = MockFulfillmentInteractor::new();
+
+        let mut request = mock_request("123");
+        request.line_items = vec![];
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/fulfillments"))
+            .set_json(request)
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_fulfillment_with_invalid_tracking_info() {
+        let interactor = MockFulfillmentInteractor::new();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/fulfillments"))
+            .set_json(mock_request(""))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_fulfillment_bad_request() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_create_fulfillment()
+            .returning(|_, _, _, _, _| Err(DomainError::ValidationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/fulfillments"))
+            .set_json(mock_request("123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_fulfillment_service_unavailable() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_create_fulfillment()
+            .returning(|_, _, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/fulfillments"))
+            .set_json(mock_request("123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
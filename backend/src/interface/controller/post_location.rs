@@ -0,0 +1,245 @@
+use actix_web::{web, Responder};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    location::location_impl::LocationPresenterImpl, location_presenter_interface::LocationPresenter,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::AddressSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostLocationRequest {
+    name: String,
+    address: AddressSchema,
+    fulfills_online_orders: bool,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Create a location.
+    pub async fn post_location(
+        &self,
+        request: actix_web::HttpRequest,
+        body: web::Json<PostLocationRequest>,
+    ) -> impl Responder {
+        let presenter = LocationPresenterImpl::new();
+
+        let body = body.into_inner();
+        let address = body.address.to_domain()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_location_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .create_location(user, body.name, address, body.fulfills_online_orders)
+            .await;
+
+        presenter.present_post_location(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_locations;
+    use crate::usecase::interactor::location_interactor_interface::LocationInteractor;
+    use crate::usecase::interactor::location_interactor_interface::MockLocationInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/locations";
+
+    async fn setup(
+        interactor: MockLocationInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_location_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn LocationInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_post_location_success() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor
+            .expect_create_location()
+            .returning(|_, _, _, _| Ok(mock_locations(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostLocationRequest {
+                name: "Warehouse".to_string(),
+                address: AddressSchema {
+                    first_name: None,
+                    last_name: None,
+                    address1: Some("1-1 Chiyoda".to_string()),
+                    address2: None,
+                    city: Some("Chiyoda-ku".to_string()),
+                    province: Some("Tokyo".to_string()),
+                    province_code: Some("13".to_string()),
+                    country: Some("Japan".to_string()),
+                    country_code: Some("JP".to_string()),
+                    zip: Some("100-0001".to_string()),
+                    phone: None,
+                },
+                fulfills_online_orders: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_location_with_invalid_country_code() {
+        let interactor = MockLocationInteractor::new();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostLocationRequest {
+                name: "Warehouse".to_string(),
+                address: AddressSchema {
+                    first_name: None,
+                    last_name: None,
+                    address1: Some("1-1 Chiyoda".to_string()),
+                    address2: None,
+                    city: Some("Chiyoda-ku".to_string()),
+                    province: Some("Tokyo".to_string()),
+                    province_code: Some("13".to_string()),
+                    country: Some("Japan".to_string()),
+                    country_code: Some("INVALID".to_string()),
+                    zip: Some("100-0001".to_string()),
+                    phone: None,
+                },
+                fulfills_online_orders: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_location_bad_request() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor
+            .expect_create_location()
+            .returning(|_, _, _, _| Err(DomainError::ValidationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostLocationRequest {
+                name: "Warehouse".to_string(),
+                address: AddressSchema {
+                    first_name: None,
+                    last_name: None,
+                    address1: Some("1-1 Chiyoda".to_string()),
+                    address2: None,
+                    city: Some("Chiyoda-ku".to_string()),
+                    province: Some("Tokyo".to_string()),
+                    province_code: Some("13".to_string()),
+                    country: Some("Japan".to_string()),
+                    country_code: Some("JP".to_string()),
+                    zip: Some("100-0001".to_string()),
+                    phone: None,
+                },
+                fulfills_online_orders: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_location_service_unavailable() {
+        let mut interactor = MockLocationInteractor::new();
+        interactor
+            .expect_create_location()
+            .returning(|_, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}"))
+            .set_json(PostLocationRequest {
+                name: "Warehouse".to_string(),
+                address: AddressSchema {
+                    first_name: None,
+                    last_name: None,
+                    address1: Some("1-1 Chiyoda".to_string()),
+                    address2: None,
+                    city: Some("Chiyoda-ku".to_string()),
+                    province: Some("Tokyo".to_string()),
+                    province_code: Some("13".to_string()),
+                    country: Some("Japan".to_string()),
+                    country_code: Some("JP".to_string()),
+                    zip: Some("100-0001".to_string()),
+                    phone: None,
+                },
+                fulfills_online_orders: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
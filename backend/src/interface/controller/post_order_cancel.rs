@@ -0,0 +1,216 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    order::order_impl::OrderPresenterImpl, order_presenter_interface::OrderPresenter,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::OrderCancelReasonSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostOrderCancelRequest {
+    reason: OrderCancelReasonSchema,
+    refund: bool,
+    restock: bool,
+    notify_customer: bool,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Cancel an unfulfilled order.
+    pub async fn post_order_cancel(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PostOrderCancelRequest>,
+    ) -> impl Responder {
+        let presenter = OrderPresenterImpl::new();
+
+        let body = body.into_inner();
+        let reason = body.reason.to_domain()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_order_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .cancel_order(
+                user,
+                &path.into_inner().0,
+                reason,
+                body.refund,
+                body.restock,
+                body.notify_customer,
+            )
+            .await;
+
+        presenter.present_post_order_cancel(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_orders;
+    use crate::usecase::interactor::order_interactor_interface::MockOrderInteractor;
+    use crate::usecase::interactor::order_interactor_interface::OrderInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockOrderInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_order_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn OrderInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_post_order_cancel_success() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_cancel_order()
+            .returning(|_, _, _, _, _, _| Ok(mock_orders(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/cancel"))
+            .set_json(PostOrderCancelRequest {
+                reason: OrderCancelReasonSchema::Customer,
+                refund: true,
+                restock: true,
+                notify_customer: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_order_cancel_not_found() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_cancel_order()
+            .returning(|_, _, _, _, _, _| Err(DomainError::NotFound));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/cancel"))
+            .set_json(PostOrderCancelRequest {
+                reason: OrderCancelReasonSchema::Customer,
+                refund: true,
+                restock: true,
+                notify_customer: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_post_order_cancel_bad_request() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_cancel_order()
+            .returning(|_, _, _, _, _, _| Err(DomainError::InvalidStateTransition));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/cancel"))
+            .set_json(PostOrderCancelRequest {
+                reason: OrderCancelReasonSchema::Customer,
+                refund: true,
+                restock: true,
+                notify_customer: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_order_cancel_service_unavailable() {
+        let mut interactor = MockOrderInteractor::new();
+        interactor
+            .expect_cancel_order()
+            .returning(|_, _, _, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/cancel"))
+            .set_json(PostOrderCancelRequest {
+                reason: OrderCancelReasonSchema::Customer,
+                refund: true,
+                restock: true,
+                notify_customer: true,
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,174 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    media::media_impl::MediaPresenterImpl, media_presenter_interface::MediaPresenter,
+};
+
+use super::{controller::Controller, interactor_provider_interface::InteractorProvider};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostProductMediaRequest {
+    original_source: String,
+    alt: Option<String>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Register an uploaded file as product media.
+    pub async fn post_product_media(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PostProductMediaRequest>,
+    ) -> impl Responder {
+        let presenter = MediaPresenterImpl::new();
+
+        let body = body.into_inner();
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_media_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .create_media(user, &path.into_inner().0, body.original_source, body.alt)
+            .await;
+
+        presenter.present_post_product_media(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_media;
+    use crate::usecase::interactor::media_interactor_interface::MediaInteractor;
+    use crate::usecase::interactor::media_interactor_interface::MockMediaInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/products";
+
+    async fn setup(
+        interactor: MockMediaInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_media_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn MediaInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    #[actix_web::test]
+    async fn test_post_product_media_success() {
+        let mut interactor = MockMediaInteractor::new();
+        interactor
+            .expect_create_media()
+            .returning(|_, _, _, _| Ok(mock_media(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/media"))
+            .set_json(PostProductMediaRequest {
+                original_source: "https://example.com/resource".to_string(),
+                alt: Some("alt".to_string()),
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_product_media_bad_request() {
+        let mut interactor = MockMediaInteractor::new();
+        interactor
+            .expect_create_media()
+            .returning(|_, _, _, _| Err(DomainError::ValidationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/media"))
+            .set_json(PostProductMediaRequest {
+                original_source: "https://example.com/resource".to_string(),
+                alt: Some("alt".to_string()),
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_product_media_service_unavailable() {
+        let mut interactor = MockMediaInteractor::new();
+        interactor
+            .expect_create_media()
+            .returning(|_, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/media"))
+            .set_json(PostProductMediaRequest {
+                original_source: "https://example.com/resource".to_string(),
+                alt: Some("alt".to_string()),
+            })
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,213 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::money::amount::amount::Amount,
+    interface::presenter::{
+        refund::refund_impl::RefundPresenterImpl, refund_presenter_interface::RefundPresenter,
+    },
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::RefundLineItemSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostRefundRequest {
+    line_items: Vec<RefundLineItemSchema>,
+    shipping_amount: Option<f64>,
+    restock_location_id: Option<String>,
+    note: Option<String>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Refund an order, restocking the refunded items if a location is given.
+    pub async fn post_refund(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PostRefundRequest>,
+    ) -> impl Responder {
+        let presenter = RefundPresenterImpl::new();
+
+        let body = body.into_inner();
+        let line_items = body
+            .line_items
+            .into_iter()
+            .map(|li| (li.line_item_id, li.quantity))
+            .collect();
+        let shipping_amount = body.shipping_amount.map(Amount::new).transpose()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_refund_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .refund_order(
+                user,
+                &path.into_inner().0,
+                line_items,
+                shipping_amount,
+                body.restock_location_id,
+                body.note,
+            )
+            .await;
+
+        presenter.present_post_refund(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_refunds;
+    use crate::usecase::interactor::refund_interactor_interface::MockRefundInteractor;
+    use crate::usecase::interactor::refund_interactor_interface::RefundInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockRefundInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_refund_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn RefundInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    fn mock_request(shipping_amount: f64) -> PostRefundRequest {
+        PostRefundRequest {
+            line_items: vec![RefundLineItemSchema {
+                line_item_id: "0".to_string(),
+                quantity: 1,
+            }],
+            shipping_amount: Some(shipping_amount),
+            restock_location_id: Some("0".to_string()),
+            note: Some("Damaged items".to_string()),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_post_refund_success() {
+        let mut interactor = MockRefundInteractor::new();
+        interactor
+            .expect_refund_order()
+            .returning(|_, _, _, _, _, _| Ok(mock_refunds(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/refunds"))
+            .set_json(mock_request(10.0))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_refund_with_negative_shipping_amount() {
+        let interactor = MockRefundInteractor::new();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/refunds"))
+            .set_json(mock_request(-1.0))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_refund_bad_request() {
+        let mut interactor = MockRefundInteractor::new();
+        interactor
+            .expect_refund_order()
+            .returning(|_, _, _, _, _, _| Err(DomainError::AuthorizationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/refunds"))
+            .set_json(mock_request(10.0))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_refund_service_unavailable() {
+        let mut interactor = MockRefundInteractor::new();
+        interactor
+            .expect_refund_order()
+            .returning(|_, _, _, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/refunds"))
+            .set_json(mock_request(10.0))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,228 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::error::error::DomainError,
+    interface::presenter::{
+        order_return::order_return_impl::OrderReturnPresenterImpl,
+        order_return_presenter_interface::OrderReturnPresenter,
+    },
+    log_error,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::ReturnLineItemSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PostReturnRequest {
+    line_items: Vec<ReturnLineItemSchema>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Request a return of fulfilled items of an order.
+    pub async fn post_return(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PostReturnRequest>,
+    ) -> impl Responder {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let body = body.into_inner();
+        if body.line_items.is_empty() {
+            log_error!("Line items cannot be empty.");
+            return presenter
+                .present_post_return(Err(DomainError::InvalidRequest))
+                .await;
+        }
+
+        let line_items = body
+            .line_items
+            .into_iter()
+            .map(|li| li.to_domain())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_order_return_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .request_return(user, &path.into_inner().0, line_items)
+            .await;
+
+        presenter.present_post_return(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::controller::schema::component::component::ReturnReasonSchema;
+    use crate::interface::mock::domain_mock::mock_order_returns;
+    use crate::usecase::interactor::order_return_interactor_interface::MockOrderReturnInteractor;
+    use crate::usecase::interactor::order_return_interactor_interface::OrderReturnInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/orders";
+
+    async fn setup(
+        interactor: MockOrderReturnInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_order_return_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn OrderReturnInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    fn mock_request(quantity: u32) -> PostReturnRequest {
+        PostReturnRequest {
+            line_items: vec![ReturnLineItemSchema {
+                fulfillment_line_item_id: "0".to_string(),
+                quantity,
+                reason: ReturnReasonSchema::Defective,
+                customer_note: Some("Broken on arrival".to_string()),
+            }],
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_post_return_success() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_request_return()
+            .returning(|_, _, _| Ok(mock_order_returns(1).remove(0)));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/returns"))
+            .set_json(mock_request(1))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_return_with_empty_line_items() {
+        let interactor = MockOrderReturnInteractor::new();
+
+        let mut request = mock_request(1);
+        request.line_items = vec![];
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/returns"))
+            .set_json(request)
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_return_with_invalid_quantity() {
+        let interactor = MockOrderReturnInteractor::new();
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/returns"))
+            .set_json(mock_request(0))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_return_bad_request() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_request_return()
+            .returning(|_, _, _| Err(DomainError::AuthorizationError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/returns"))
+            .set_json(mock_request(1))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_post_return_service_unavailable() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_request_return()
+            .returning(|_, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::post()
+            .uri(&format!("{BASE_URL}/0/returns"))
+            .set_json(mock_request(1))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
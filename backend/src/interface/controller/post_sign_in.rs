@@ -30,10 +30,11 @@ where
         let id_token = body.id_token;
         let refresh_token = body.refresh_token;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_auth_interactor(transaction_manager)
+            .provide_auth_interactor(transaction_manager, tenant)
             .await;
         let result = interactor.authenticate(&id_token, &refresh_token).await;
 
@@ -76,7 +77,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_auth_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn AuthInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn AuthInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
@@ -0,0 +1,193 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    fulfillment::fulfillment_impl::FulfillmentPresenterImpl,
+    fulfillment_presenter_interface::FulfillmentPresenter,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::TrackingInfoSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PutFulfillmentTrackingRequest {
+    tracking_info: TrackingInfoSchema,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Update the tracking information of a fulfillment.
+    pub async fn put_fulfillment_tracking(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PutFulfillmentTrackingRequest>,
+    ) -> impl Responder {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let tracking_info = body.into_inner().tracking_info.to_domain()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_fulfillment_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .update_fulfillment_tracking(user, &path.into_inner().0, tracking_info)
+            .await;
+
+        presenter.present_put_fulfillment_tracking(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_fulfillments;
+    use crate::usecase::interactor::fulfillment_interactor_interface::FulfillmentInteractor;
+    use crate::usecase::interactor::fulfillment_interactor_interface::MockFulfillmentInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/fulfillments";
+
+    async fn setup(
+        interactor: MockFulfillmentInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_fulfillment_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn FulfillmentInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    fn mock_request(url: &str) -> PutFulfillmentTrackingRequest {
+        PutFulfillmentTrackingRequest {
+            tracking_info: TrackingInfoSchema {
+                company: Some("UPS".to_string()),
+                number: "123".to_string(),
+                url: Some(url.to_string()),
+            },
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_put_fulfillment_tracking_success() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_update_fulfillment_tracking()
+            .returning(|_, _, _| Ok(mock_fulfillments(1).remove(0)));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/tracking"))
+            .set_json(mock_request("https://example.com/123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_put_fulfillment_tracking_with_invalid_url() {
+        let interactor = MockFulfillmentInteractor::new();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/tracking"))
+            .set_json(mock_request("invalid"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_put_fulfillment_tracking_not_found() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_update_fulfillment_tracking()
+            .returning(|_, _, _| Err(DomainError::NotFound));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/tracking"))
+            .set_json(mock_request("https://example.com/123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_put_fulfillment_tracking_service_unavailable() {
+        let mut interactor = MockFulfillmentInteractor::new();
+        interactor
+            .expect_update_fulfillment_tracking()
+            .returning(|_, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/tracking"))
+            .set_json(mock_request("https://example.com/123"))
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -79,6 +79,7 @@ where
             "reservation_created" => InventoryChangeReason::ReservationCreated,
             "reservation_deleted" => InventoryChangeReason::ReservationDeleted,
             "reservation_updated" => InventoryChangeReason::ReservationUpdated,
+            "restock" => InventoryChangeReason::Restock,
             _ => {
                 log_error!("Invalid inventory change reason.", "reason" => body.reason.clone());
                 Err(DomainError::InvalidRequest)?
@@ -93,10 +94,11 @@ where
 
         let user = self.get_user(&request)?;
         let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
 
         let interactor = self
             .interactor_provider
-            .provide_inventory_interactor(transaction_manager)
+            .provide_inventory_interactor(transaction_manager, tenant)
             .await;
 
         let result = interactor
@@ -149,7 +151,7 @@ mod tests {
             MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
         interactor_provider
             .expect_provide_inventory_interactor()
-            .return_once(move |_| Box::new(interactor) as Box<dyn InventoryInteractor>);
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn InventoryInteractor>);
 
         let controller = web::Data::new(Controller::new(interactor_provider));
 
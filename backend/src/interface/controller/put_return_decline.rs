@@ -0,0 +1,177 @@
+use actix_web::{
+    web::{self, Path},
+    Responder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::interface::presenter::{
+    order_return::order_return_impl::OrderReturnPresenterImpl,
+    order_return_presenter_interface::OrderReturnPresenter,
+};
+
+use super::{
+    controller::Controller, interactor_provider_interface::InteractorProvider,
+    schema::component::component::ReturnDeclineReasonSchema,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PutReturnDeclineRequest {
+    reason: ReturnDeclineReasonSchema,
+    note: Option<String>,
+}
+
+impl<I, T, C> Controller<I, T, C>
+where
+    I: InteractorProvider<T, C>,
+    T: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Decline a requested return.
+    pub async fn put_return_decline(
+        &self,
+        request: actix_web::HttpRequest,
+        path: Path<(String,)>,
+        body: web::Json<PutReturnDeclineRequest>,
+    ) -> impl Responder {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let body = body.into_inner();
+        let reason = body.reason.to_domain()?;
+
+        let user = self.get_user(&request)?;
+        let transaction_manager = self.get_transaction_manager(&request)?;
+        let tenant = self.get_tenant(&request);
+
+        let interactor = self
+            .interactor_provider
+            .provide_order_return_interactor(transaction_manager, tenant)
+            .await;
+
+        let result = interactor
+            .decline_return(user, &path.into_inner().0, reason, body.note)
+            .await;
+
+        presenter.present_put_return_decline(result).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::domain::error::error::DomainError;
+    use crate::domain::user::user::UserInterface;
+    use crate::infrastructure::auth::idp_user::IdpUser;
+    use crate::infrastructure::db::sea_orm::sea_orm_manager::SeaOrmTransactionManager;
+    use crate::infrastructure::db::transaction_manager_interface::TransactionManager;
+    use crate::infrastructure::router::actix_router;
+    use crate::interface::controller::interactor_provider_interface::MockInteractorProvider;
+    use crate::interface::mock::domain_mock::mock_order_returns;
+    use crate::usecase::interactor::order_return_interactor_interface::MockOrderReturnInteractor;
+    use crate::usecase::interactor::order_return_interactor_interface::OrderReturnInteractor;
+
+    use super::*;
+    use actix_http::Request;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::web;
+    use actix_web::HttpMessage;
+    use actix_web::{http::StatusCode, test, App, Error};
+    use sea_orm::DatabaseConnection;
+    use sea_orm::DatabaseTransaction;
+
+    const BASE_URL: &'static str = "/ec-extension/returns";
+
+    async fn setup(
+        interactor: MockOrderReturnInteractor,
+    ) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+        // Configure the mocks
+        let mut interactor_provider =
+            MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+        interactor_provider
+            .expect_provide_order_return_interactor()
+            .return_once(move |_, _| Box::new(interactor) as Box<dyn OrderReturnInteractor>);
+
+        let controller = web::Data::new(Controller::new(interactor_provider));
+
+        // Create an application for testing
+        test::init_service(App::new().app_data(controller).configure(
+            actix_router::configure_routes::<
+                MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+                DatabaseTransaction,
+                Arc<DatabaseConnection>,
+            >,
+        ))
+        .await
+    }
+
+    fn add_extensions(req: &Request) {
+        req.extensions_mut()
+            .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+        req.extensions_mut()
+            .insert(Arc::new(SeaOrmTransactionManager::default())
+                as Arc<
+                    dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+                >);
+    }
+
+    fn mock_request() -> PutReturnDeclineRequest {
+        PutReturnDeclineRequest {
+            reason: ReturnDeclineReasonSchema::FinalSale,
+            note: Some("Final sale item".to_string()),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_put_return_decline_success() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_decline_return()
+            .returning(|_, _, _, _| Ok(mock_order_returns(1).remove(0)));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/decline"))
+            .set_json(mock_request())
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_put_return_decline_bad_request() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_decline_return()
+            .returning(|_, _, _, _| Err(DomainError::AuthorizationError));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/decline"))
+            .set_json(mock_request())
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_put_return_decline_service_unavailable() {
+        let mut interactor = MockOrderReturnInteractor::new();
+        interactor
+            .expect_decline_return()
+            .returning(|_, _, _, _| Err(DomainError::SaveError));
+
+        let req = test::TestRequest::put()
+            .uri(&format!("{BASE_URL}/0/decline"))
+            .set_json(mock_request())
+            .to_request();
+        add_extensions(&req);
+
+        let resp: ServiceResponse = test::call_service(&setup(interactor).await, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -4,6 +4,7 @@
 ///
 /// * The top-level schema specific to each URI is not defined here (e.g. ~Request, ~Response)
 /// * To avoid naming conflicts with the domain, each component should be suffixed with “Schema
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -12,9 +13,11 @@ pub struct AddressSchema {
     pub address2: Option<String>,
     pub city: Option<String>,
     pub country: Option<String>,
+    pub country_code: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub province: Option<String>,
+    pub province_code: Option<String>,
     pub zip: Option<String>,
     pub phone: Option<String>,
 }
@@ -54,3 +57,78 @@ pub enum CurrencyCodeSchema {
     GBP,
     JPY,
 }
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TrackingInfoSchema {
+    pub company: Option<String>,
+    pub number: String,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReturnLineItemSchema {
+    pub fulfillment_line_item_id: String,
+    pub quantity: u32,
+    pub reason: ReturnReasonSchema,
+    pub customer_note: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ReturnReasonSchema {
+    Color,
+    Defective,
+    NotAsDescribed,
+    SizeTooLarge,
+    SizeTooSmall,
+    Style,
+    Unwanted,
+    WrongItem,
+    Other,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ReturnDeclineReasonSchema {
+    FinalSale,
+    ReturnPeriodEnded,
+    Other,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RefundLineItemSchema {
+    pub line_item_id: String,
+    pub quantity: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum OrderCancelReasonSchema {
+    Customer,
+    Declined,
+    Fraud,
+    Inventory,
+    Staff,
+    Other,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CustomerSearchCriteriaSchema {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub created_at_min: Option<DateTime<Utc>>,
+    pub created_at_max: Option<DateTime<Utc>>,
+    pub orders_count_min: Option<u32>,
+    pub orders_count_max: Option<u32>,
+    pub amount_spent_min: Option<f64>,
+    pub amount_spent_max: Option<f64>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum CustomerSortKeySchema {
+    CreatedAt,
+    Id,
+    Name,
+    Relevance,
+    UpdatedAt,
+}
@@ -1,31 +1,47 @@
 use crate::domain::{
-    address::address::Address,
+    address::address::{Address, AddressParams},
+    customer::search_criteria::search_criteria::{
+        CustomerSearchCriteria, CustomerSearchCriteriaParams, CustomerSortKey,
+    },
+    email::email::Email,
     error::error::DomainError,
+    fulfillment::tracking_info::tracking_info::TrackingInfo,
     line_item::discount::discount::{Discount, DiscountValueType},
     money::{
         amount::amount::Amount,
         money::{CurrencyCode, Money},
     },
+    order::order::OrderCancelReason,
+    order_return::{
+        order_return::ReturnDeclineReason,
+        return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+    },
+    phone::phone::Phone,
 };
 
 use super::component::{
-    AddressSchema, CurrencyCodeSchema, DiscountSchema, DiscountValueTypeSchema, MoneySchema,
+    AddressSchema, CurrencyCodeSchema, CustomerSearchCriteriaSchema, CustomerSortKeySchema,
+    DiscountSchema, DiscountValueTypeSchema, MoneySchema, OrderCancelReasonSchema,
+    ReturnDeclineReasonSchema, ReturnLineItemSchema, ReturnReasonSchema, TrackingInfoSchema,
 };
 
 impl AddressSchema {
     pub fn to_domain(self) -> Result<Address, DomainError> {
-        Address::new(
-            self.address1.to_owned(),
-            self.address2.to_owned(),
-            self.city.to_owned(),
-            false,
-            self.country.to_owned(),
-            self.first_name.to_owned(),
-            self.last_name.to_owned(),
-            self.province.to_owned(),
-            self.zip.to_owned(),
-            self.phone.to_owned(),
-        )
+        Address::new(AddressParams {
+            address1: self.address1.to_owned(),
+            address2: self.address2.to_owned(),
+            city: self.city.to_owned(),
+            coordinates_validated: false,
+            country: self.country.to_owned(),
+            country_code: self.country_code.to_owned(),
+            first_name: self.first_name.to_owned(),
+            last_name: self.last_name.to_owned(),
+            province: self.province.to_owned(),
+            province_code: self.province_code.to_owned(),
+            zip: self.zip.to_owned(),
+            phone: self.phone.to_owned(),
+            ..Default::default()
+        })
     }
 }
 
@@ -81,3 +97,90 @@ impl CurrencyCodeSchema {
         }
     }
 }
+
+impl TrackingInfoSchema {
+    pub fn to_domain(self) -> Result<TrackingInfo, DomainError> {
+        TrackingInfo::new(self.company, self.number, self.url)
+    }
+}
+
+impl ReturnLineItemSchema {
+    pub fn to_domain(self) -> Result<ReturnLineItem, DomainError> {
+        ReturnLineItem::create(
+            self.fulfillment_line_item_id,
+            self.quantity,
+            self.reason.to_domain()?,
+            self.customer_note,
+        )
+    }
+}
+
+impl ReturnReasonSchema {
+    pub fn to_domain(self) -> Result<ReturnReason, DomainError> {
+        match self {
+            ReturnReasonSchema::Color => Ok(ReturnReason::Color),
+            ReturnReasonSchema::Defective => Ok(ReturnReason::Defective),
+            ReturnReasonSchema::NotAsDescribed => Ok(ReturnReason::NotAsDescribed),
+            ReturnReasonSchema::SizeTooLarge => Ok(ReturnReason::SizeTooLarge),
+            ReturnReasonSchema::SizeTooSmall => Ok(ReturnReason::SizeTooSmall),
+            ReturnReasonSchema::Style => Ok(ReturnReason::Style),
+            ReturnReasonSchema::Unwanted => Ok(ReturnReason::Unwanted),
+            ReturnReasonSchema::WrongItem => Ok(ReturnReason::WrongItem),
+            ReturnReasonSchema::Other => Ok(ReturnReason::Other),
+        }
+    }
+}
+
+impl ReturnDeclineReasonSchema {
+    pub fn to_domain(self) -> Result<ReturnDeclineReason, DomainError> {
+        match self {
+            ReturnDeclineReasonSchema::FinalSale => Ok(ReturnDeclineReason::FinalSale),
+            ReturnDeclineReasonSchema::ReturnPeriodEnded => {
+                Ok(ReturnDeclineReason::ReturnPeriodEnded)
+            }
+            ReturnDeclineReasonSchema::Other => Ok(ReturnDeclineReason::Other),
+        }
+    }
+}
+
+impl OrderCancelReasonSchema {
+    pub fn to_domain(self) -> Result<OrderCancelReason, DomainError> {
+        match self {
+            OrderCancelReasonSchema::Customer => Ok(OrderCancelReason::Customer),
+            OrderCancelReasonSchema::Declined => Ok(OrderCancelReason::Declined),
+            OrderCancelReasonSchema::Fraud => Ok(OrderCancelReason::Fraud),
+            OrderCancelReasonSchema::Inventory => Ok(OrderCancelReason::Inventory),
+            OrderCancelReasonSchema::Staff => Ok(OrderCancelReason::Staff),
+            OrderCancelReasonSchema::Other => Ok(OrderCancelReason::Other),
+        }
+    }
+}
+
+impl CustomerSearchCriteriaSchema {
+    pub fn to_domain(self) -> Result<CustomerSearchCriteria, DomainError> {
+        CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+            name: self.name,
+            email: self.email.map(Email::new).transpose()?,
+            phone: self.phone.map(Phone::new).transpose()?,
+            tags: self.tags,
+            created_at_min: self.created_at_min,
+            created_at_max: self.created_at_max,
+            orders_count_min: self.orders_count_min,
+            orders_count_max: self.orders_count_max,
+            amount_spent_min: self.amount_spent_min.map(Amount::new).transpose()?,
+            amount_spent_max: self.amount_spent_max.map(Amount::new).transpose()?,
+        })
+    }
+}
+
+impl CustomerSortKeySchema {
+    pub fn to_domain(self) -> Result<CustomerSortKey, DomainError> {
+        match self {
+            CustomerSortKeySchema::CreatedAt => Ok(CustomerSortKey::CreatedAt),
+            CustomerSortKeySchema::Id => Ok(CustomerSortKey::Id),
+            CustomerSortKeySchema::Name => Ok(CustomerSortKey::Name),
+            CustomerSortKeySchema::Relevance => Ok(CustomerSortKey::Relevance),
+            CustomerSortKeySchema::UpdatedAt => Ok(CustomerSortKey::UpdatedAt),
+        }
+    }
+}
@@ -1,10 +1,23 @@
 /// Define functions for common use in interface layer tests.
 /// Generate a mock of the domain.
 use crate::domain::{
-    address::address::Address,
-    customer::customer::{Customer, CustomerStatus},
-    draft_order::draft_order::{DraftOrder, DraftOrderStatus},
+    address::address::{Address, AddressParams},
+    customer::{
+        customer::{Customer, CustomerParams, CustomerStatus},
+        search_criteria::search_criteria::{CustomerSearchCriteria, CustomerSearchCriteriaParams},
+    },
+    customer_segment::customer_segment::CustomerSegment,
+    draft_order::draft_order::{DraftOrder, DraftOrderParams, DraftOrderStatus},
     email::email::Email,
+    fulfillment::{
+        fulfillment::{Fulfillment, FulfillmentStatus as FulfillmentEntityStatus},
+        fulfillment_line_item::fulfillment_line_item::FulfillmentLineItem,
+        tracking_info::tracking_info::TrackingInfo,
+    },
+    fulfillment_order::{
+        fulfillment_order::{FulfillmentOrder, FulfillmentOrderStatus},
+        fulfillment_order_line_item::fulfillment_order_line_item::FulfillmentOrderLineItem,
+    },
     inventory_item::inventory_item::{Id as InventoryItemId, InventoryItem},
     inventory_level::{
         inventory_level::InventoryLevel,
@@ -25,6 +38,11 @@ use crate::domain::{
         amount::amount::Amount,
         money::{CurrencyCode, Money},
     },
+    order::order::{FinancialStatus, FulfillmentStatus, Order, OrderParams},
+    order_return::{
+        order_return::{OrderReturn, ReturnStatus},
+        return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+    },
     phone::phone::Phone,
     product::{
         product::{Product, ProductStatus},
@@ -34,6 +52,10 @@ use crate::domain::{
             variant::{InventoryPolicy, Variant},
         },
     },
+    refund::{
+        refund::{Refund, RefundParams},
+        refund_line_item::refund_line_item::RefundLineItem,
+    },
 };
 
 use chrono::Utc;
@@ -41,18 +63,21 @@ use chrono::Utc;
 use std::collections::HashMap;
 
 pub fn mock_address() -> Address {
-    Address::new(
-        Some("123 Main St"),
-        None::<String>,
-        Some("City"),
-        true,
-        Some("Country"),
-        Some("John"),
-        Some("Doe"),
-        Some("Province"),
-        Some("12345"),
-        Some("+1234567890"),
-    )
+    Address::new(AddressParams {
+        id: Some("1".to_string()),
+        address1: Some("123 Main St".to_string()),
+        city: Some("City".to_string()),
+        coordinates_validated: true,
+        country: Some("Country".to_string()),
+        country_code: Some("JP".to_string()),
+        first_name: Some("John".to_string()),
+        last_name: Some("Doe".to_string()),
+        province: Some("Province".to_string()),
+        province_code: Some("13".to_string()),
+        zip: Some("12345".to_string()),
+        phone: Some("+1234567890".to_string()),
+        ..Default::default()
+    })
     .expect("Failed to create mock address")
 }
 
@@ -108,32 +133,153 @@ pub fn mock_line_items(count: usize) -> Vec<LineItem> {
 pub fn mock_draft_orders(count: usize) -> Vec<DraftOrder> {
     (0..count)
         .map(|i| {
-            DraftOrder::new(
+            DraftOrder::new(DraftOrderParams {
+                id: format!("{i}"),
+                name: format!("Test Order {i}"),
+                status: DraftOrderStatus::Open,
+                customer_id: None,
+                billing_address: Some(mock_address()),
+                shipping_address: Some(mock_address()),
+                note: None,
+                line_items: mock_line_items(2),
+                reserve_inventory_until: None,
+                discount: Some(mock_discount()),
+                subtotal_price_set: mock_money(),
+                taxes_included: true,
+                tax_exempt: false,
+                total_tax_set: mock_money(),
+                total_discounts_set: mock_money(),
+                total_shipping_price_set: mock_money(),
+                total_price_set: mock_money(),
+                presentment_currency_code: CurrencyCode::JPY,
+                order_id: None,
+                owner_user_id: "Owner".to_string(),
+                cancel_reason: None,
+                completed_at: None,
+                canceled_at: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+            .expect("Failed to create mock draft order")
+        })
+        .collect()
+}
+
+pub fn mock_orders(count: usize) -> Vec<Order> {
+    (0..count)
+        .map(|i| {
+            Order::new(OrderParams {
+                id: format!("{i}"),
+                name: format!("#100{i}"),
+                customer_id: None,
+                billing_address: Some(mock_address()),
+                shipping_address: Some(mock_address()),
+                note: None,
+                line_items: mock_line_items(2),
+                financial_status: Some(FinancialStatus::Paid),
+                fulfillment_status: FulfillmentStatus::Unfulfilled,
+                subtotal_price_set: mock_money(),
+                taxes_included: true,
+                total_tax_set: mock_money(),
+                total_discounts_set: mock_money(),
+                total_shipping_price_set: mock_money(),
+                total_price_set: mock_money(),
+                presentment_currency_code: CurrencyCode::JPY,
+                owner_user_id: "Owner".to_string(),
+                cancel_reason: None,
+                processed_at: Utc::now(),
+                canceled_at: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+            .expect("Failed to create mock order")
+        })
+        .collect()
+}
+
+pub fn mock_fulfillment_orders(count: usize) -> Vec<FulfillmentOrder> {
+    (0..count)
+        .map(|i| {
+            FulfillmentOrder::new(
                 format!("{i}"),
-                format!("Test Order {i}"),
-                DraftOrderStatus::Open,
-                None,
-                Some(mock_address()),
-                Some(mock_address()),
-                None,
-                mock_line_items(2),
-                None,
-                Some(mock_discount()),
-                mock_money(),
-                true,
-                false,
-                mock_money(),
-                mock_money(),
-                mock_money(),
-                mock_money(),
-                CurrencyCode::JPY,
-                None,
-                "Owner".to_string(),
-                None,
+                "0",
+                Some("0".to_string()),
+                FulfillmentOrderStatus::Open,
+                vec![
+                    FulfillmentOrderLineItem::new(format!("{i}"), format!("{i}"), 2, 1)
+                        .expect("Failed to create mock fulfillment order line item"),
+                ],
+            )
+            .expect("Failed to create mock fulfillment order")
+        })
+        .collect()
+}
+
+pub fn mock_fulfillments(count: usize) -> Vec<Fulfillment> {
+    (0..count)
+        .map(|i| {
+            Fulfillment::new(
+                format!("{i}"),
+                "0",
+                FulfillmentEntityStatus::Success,
+                vec![
+                    FulfillmentLineItem::new(format!("{i}"), format!("{i}"), None, None, 1)
+                        .expect("Failed to create mock fulfillment line item"),
+                ],
+                vec![TrackingInfo::new(
+                    Some("UPS"),
+                    format!("{i}"),
+                    Some(format!("https://example.com/{i}")),
+                )
+                .expect("Failed to create mock tracking info")],
                 Utc::now(),
                 Utc::now(),
             )
-            .expect("Failed to create mock draft order")
+            .expect("Failed to create mock fulfillment")
+        })
+        .collect()
+}
+
+pub fn mock_order_returns(count: usize) -> Vec<OrderReturn> {
+    (0..count)
+        .map(|i| {
+            OrderReturn::new(
+                format!("{i}"),
+                "0",
+                ReturnStatus::Requested,
+                vec![ReturnLineItem::new(
+                    format!("{i}"),
+                    format!("{i}"),
+                    1,
+                    ReturnReason::Defective,
+                    Some("Broken on arrival".to_string()),
+                )
+                .expect("Failed to create mock return line item")],
+                None,
+                None,
+            )
+            .expect("Failed to create mock return")
+        })
+        .collect()
+}
+
+pub fn mock_refunds(count: usize) -> Vec<Refund> {
+    (0..count)
+        .map(|i| {
+            Refund::new(RefundParams {
+                id: format!("{i}"),
+                order_id: "0".to_string(),
+                note: None,
+                line_items: vec![RefundLineItem::new(format!("{i}"), 1, mock_money())
+                    .expect("Failed to create mock refund line item")],
+                shipping_set: Money::new(CurrencyCode::USD, Amount::new(10.0).unwrap())
+                    .expect("Failed to create mock money"),
+                total_refunded_set: Money::new(CurrencyCode::USD, Amount::new(110.0).unwrap())
+                    .expect("Failed to create mock money"),
+                restock_location_id: Some("0".to_string()),
+                created_at: Utc::now(),
+            })
+            .expect("Failed to create mock refund")
         })
         .collect()
 }
@@ -203,8 +349,8 @@ pub fn mock_media(count: usize) -> Vec<Media> {
                     )
                     .unwrap(),
                 )),
-                Utc::now(),
-                Utc::now(),
+                Some(Utc::now()),
+                Some(Utc::now()),
             )
             .expect("Failed to create mock media")
         })
@@ -264,24 +410,46 @@ pub fn mock_inventory_levels(count: usize) -> Vec<InventoryLevel> {
 pub fn mock_customers(count: usize) -> Vec<Customer> {
     (0..count)
         .map(|i| {
-            Customer::new(
+            Customer::new(CustomerParams {
+                id: format!("{i}"),
+                user_id: format!("user_{i}"),
+                addresses: vec![mock_address()],
+                default_address: Some(mock_address()),
+                display_name: format!("Test Customer {i}"),
+                email: Some(Email::new(format!("{i}@example.com")).unwrap()),
+                first_name: Some("John".to_string()),
+                last_name: Some("Doe".to_string()),
+                image: None,
+                phone: Some(Phone::new("+1234567890").unwrap()),
+                note: Some("Note".to_string()),
+                status: CustomerStatus::Active,
+                verified_email: true,
+                email_marketing_consent: false,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+            .expect("Failed to create mock customer")
+        })
+        .collect()
+}
+
+pub fn mock_customer_segments(count: usize) -> Vec<CustomerSegment> {
+    (0..count)
+        .map(|i| {
+            CustomerSegment::new(
                 format!("{i}"),
-                format!("user_{i}"),
-                vec![mock_address()],
-                Some(mock_address()),
-                format!("Test Customer {i}"),
-                Some(Email::new(format!("{i}@example.com")).unwrap()),
-                Some("John"),
-                Some("Doe"),
-                None,
-                Some(Phone::new("+1234567890").unwrap()),
-                Some("Note"),
-                CustomerStatus::Active,
-                true,
+                format!("Segment {i}"),
+                CustomerSearchCriteria::new(CustomerSearchCriteriaParams {
+                    name: Some(format!("customer {i}")),
+                    tags: vec!["vip".to_string()],
+                    orders_count_min: Some(1),
+                    ..Default::default()
+                })
+                .unwrap(),
                 Utc::now(),
                 Utc::now(),
             )
-            .expect("Failed to create mock customer")
+            .expect("Failed to create mock customer segment")
         })
         .collect()
 }
@@ -6,12 +6,21 @@ pub mod customer;
 pub mod customer_presenter_interface;
 pub mod draft_order;
 pub mod draft_order_presenter_interface;
+pub mod fulfillment;
+pub mod fulfillment_presenter_interface;
 pub mod inventory;
 pub mod inventory_presenter_interface;
 pub mod line_item;
 pub mod location;
 pub mod location_presenter_interface;
 pub mod media;
+pub mod media_presenter_interface;
 pub mod money;
+pub mod order;
+pub mod order_presenter_interface;
+pub mod order_return;
+pub mod order_return_presenter_interface;
 pub mod product;
 pub mod product_presenter_interface;
+pub mod refund;
+pub mod refund_presenter_interface;
@@ -5,14 +5,19 @@ use super::schema::AddressSchema;
 impl From<Address> for AddressSchema {
     fn from(address: Address) -> Self {
         Self {
+            id: address.id().to_owned(),
             address1: address.address1().to_owned(),
             address2: address.address2().to_owned(),
             city: address.city().to_owned(),
             coordinates_validated: *address.coordinates_validated(),
+            latitude: address.coordinates().as_ref().map(|c| *c.latitude()),
+            longitude: address.coordinates().as_ref().map(|c| *c.longitude()),
             country: address.country().to_owned(),
+            country_code: address.country_code().to_owned(),
             first_name: address.first_name().to_owned(),
             last_name: address.last_name().to_owned(),
             province: address.province().to_owned(),
+            province_code: address.province_code().to_owned(),
             zip: address.zip().to_owned(),
             phone: address.phone().to_owned(),
         }
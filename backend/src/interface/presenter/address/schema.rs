@@ -2,14 +2,19 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AddressSchema {
+    pub id: Option<String>,
     pub address1: Option<String>,
     pub address2: Option<String>,
     pub city: Option<String>,
     pub coordinates_validated: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub country: Option<String>,
+    pub country_code: Option<String>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub province: Option<String>,
+    pub province_code: Option<String>,
     pub zip: Option<String>,
     pub phone: Option<String>,
 }
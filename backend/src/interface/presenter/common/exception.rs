@@ -4,6 +4,8 @@ use actix_web::{
 };
 use serde::Serialize;
 
+use crate::domain::error::error::FieldError;
+
 /// Struct representing a standardized error response schema for API responses.
 ///
 /// This structure is used to define the format of JSON responses for error cases,
@@ -15,11 +17,34 @@ use serde::Serialize;
 /// * `code` - A short error code representing the error type, typically derived from the HTTP status code.
 /// * `message` - A user-friendly description of the error.
 /// * `status` - The HTTP status code corresponding to the error response.
+/// * `details` - Field-level details of the error, omitted when there are none.
 #[derive(Serialize)]
 struct ErrorResponseSchema {
     code: String,
     message: String,
     status: u16,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    details: Vec<ErrorDetailSchema>,
+}
+
+/// Detail of an input field that caused the error.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ErrorDetailSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl From<&FieldError> for ErrorDetailSchema {
+    fn from(error: &FieldError) -> Self {
+        Self {
+            field: error.field().clone(),
+            code: error.code().clone(),
+            message: error.message().clone(),
+        }
+    }
 }
 
 /// Trait providing a method for generating standardized error responses.
@@ -48,6 +73,7 @@ pub trait ErrorResponseBuilder: std::fmt::Display {
                 .to_string(),
             message: self.to_string(),
             status: status_code.as_u16(),
+            details: self.details(),
         };
         HttpResponse::build(status_code)
             .insert_header(ContentType::json())
@@ -59,6 +85,12 @@ pub trait ErrorResponseBuilder: std::fmt::Display {
     fn status_code(&self) -> StatusCode {
         StatusCode::INTERNAL_SERVER_ERROR
     }
+
+    /// Defines the field-level details included in the response.
+    /// Override this method for errors that carry details of invalid input.
+    fn details(&self) -> Vec<ErrorDetailSchema> {
+        Vec::new()
+    }
 }
 
 /// Macro to define standardized error enums with support for custom error responses.
@@ -80,6 +112,8 @@ pub trait ErrorResponseBuilder: std::fmt::Display {
 /// * `NotFound` - Represents a resource-not-found error, taking an `object_name` parameter for
 ///                specifying the missing resource.
 /// * `BadRequest` - Represents an error related to an invalid request.
+/// * `InvalidInput` - Represents an invalid request, with the details of each invalid field.
+/// * `TooManyRequests` - Represents an error indicating the request was throttled.
 /// * `ServiceUnavailable` - Represents an error indicating the service is temporarily unavailable.
 #[macro_export]
 macro_rules! define_error_response {
@@ -92,6 +126,14 @@ macro_rules! define_error_response {
             #[display(fmt = "Bad request.")]
             BadRequest,
 
+            #[display(fmt = "Invalid input.")]
+            InvalidInput {
+                errors: Vec<$crate::domain::error::error::FieldError>,
+            },
+
+            #[display(fmt = "Too many requests.")]
+            TooManyRequests,
+
             #[display(fmt = "Service unavailable.")]
             ServiceUnavailable,
         }
@@ -104,7 +146,10 @@ macro_rules! define_error_response {
                     },
                     DomainError::InvalidRequest
                     | DomainError::ValidationError
-                    | DomainError::AuthorizationError => $name::BadRequest,
+                    | DomainError::AuthorizationError
+                    | DomainError::InvalidStateTransition => $name::BadRequest,
+                    DomainError::InvalidInput { errors } => $name::InvalidInput { errors },
+                    DomainError::Throttled => $name::TooManyRequests,
                     _ => $name::ServiceUnavailable,
                 }
             }
@@ -114,10 +159,20 @@ macro_rules! define_error_response {
             fn status_code(&self) -> StatusCode {
                 match self {
                     $name::NotFound { .. } => StatusCode::NOT_FOUND,
-                    $name::BadRequest => StatusCode::BAD_REQUEST,
+                    $name::BadRequest | $name::InvalidInput { .. } => StatusCode::BAD_REQUEST,
+                    $name::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
                     $name::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
                 }
             }
+
+            fn details(
+                &self,
+            ) -> Vec<$crate::interface::presenter::common::exception::ErrorDetailSchema> {
+                match self {
+                    $name::InvalidInput { errors } => errors.iter().map(Into::into).collect(),
+                    _ => Vec::new(),
+                }
+            }
         }
 
         impl ResponseError for $name {
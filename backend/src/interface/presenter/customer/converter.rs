@@ -1,6 +1,16 @@
-use crate::domain::customer::customer::{Customer, CustomerStatus};
+use crate::domain::{
+    customer::{
+        customer::{Customer, CustomerStatus},
+        search_criteria::search_criteria::CustomerSearchCriteria,
+    },
+    customer_segment::customer_segment::CustomerSegment,
+};
+use crate::usecase::repository::user_repository_interface::UserRecord;
 
-use super::schema::{CustomerSchema, CustomerStatusEnum};
+use super::schema::{
+    CustomerSchema, CustomerSearchCriteriaSchema, CustomerSegmentSchema, CustomerStatusEnum,
+    LocalUserSchema,
+};
 
 impl From<Customer> for CustomerSchema {
     fn from(customer: Customer) -> Self {
@@ -38,6 +48,7 @@ impl From<Customer> for CustomerSchema {
             note: customer.note().as_ref().map(|note| note.to_string()),
             status: customer.status().to_owned().into(),
             verified_email: *customer.verified_email(),
+            email_marketing_consent: *customer.email_marketing_consent(),
             created_at: *customer.created_at(),
             updated_at: *customer.updated_at(),
         }
@@ -52,3 +63,42 @@ impl From<CustomerStatus> for CustomerStatusEnum {
         }
     }
 }
+
+impl From<CustomerSegment> for CustomerSegmentSchema {
+    fn from(customer_segment: CustomerSegment) -> Self {
+        CustomerSegmentSchema {
+            id: customer_segment.id().to_string(),
+            name: customer_segment.name().to_string(),
+            criteria: customer_segment.criteria().into(),
+            created_at: *customer_segment.created_at(),
+            updated_at: *customer_segment.updated_at(),
+        }
+    }
+}
+
+impl From<&CustomerSearchCriteria> for CustomerSearchCriteriaSchema {
+    fn from(criteria: &CustomerSearchCriteria) -> Self {
+        CustomerSearchCriteriaSchema {
+            name: criteria.name().to_owned(),
+            email: criteria.email().as_ref().map(|e| e.value().to_string()),
+            phone: criteria.phone().as_ref().map(|p| p.value().to_string()),
+            tags: criteria.tags().to_owned(),
+            created_at_min: *criteria.created_at_min(),
+            created_at_max: *criteria.created_at_max(),
+            orders_count_min: *criteria.orders_count_min(),
+            orders_count_max: *criteria.orders_count_max(),
+            amount_spent_min: criteria.amount_spent_min().as_ref().map(|a| *a.value()),
+            amount_spent_max: criteria.amount_spent_max().as_ref().map(|a| *a.value()),
+        }
+    }
+}
+
+impl From<UserRecord> for LocalUserSchema {
+    fn from(user: UserRecord) -> Self {
+        LocalUserSchema {
+            id: user.id,
+            name: user.name,
+            user_group_ids: user.user_group_ids,
+        }
+    }
+}
@@ -1,12 +1,36 @@
-use actix_web::web::{self, Json};
+use actix_web::{
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{self, Json},
+    HttpResponse,
+};
 use async_trait::async_trait;
 
 use crate::{
-    domain::{customer::customer::Customer, error::error::DomainError},
+    domain::{
+        customer::customer::{Customer, Id as CustomerId},
+        customer_segment::customer_segment::CustomerSegment,
+        error::error::DomainError,
+    },
     interface::presenter::customer_presenter_interface::CustomerPresenter,
+    usecase::{
+        interactor::customer_interactor_interface::CustomerDataExport,
+        repository::customer_repository_interface::CustomerPage,
+    },
 };
 
-use super::schema::{CustomerSchema, GetCustomersErrorResponse, GetCustomersResponse};
+use super::schema::{
+    CustomerSchema, CustomerSegmentSchema, DeleteCustomerAddressErrorResponse,
+    DeleteCustomerAddressResponse, GetCustomerDataExportErrorResponse,
+    GetCustomerDataExportResponse, GetCustomerErrorResponse, GetCustomerResponse,
+    GetCustomerSegmentsErrorResponse, GetCustomerSegmentsResponse, GetCustomersErrorResponse,
+    GetCustomersResponse, PageInfoSchema, PostCustomerAddressErrorResponse,
+    PostCustomerAddressResponse, PostCustomerDataErasureErrorResponse,
+    PostCustomerDataErasureResponse, PostCustomerErrorResponse, PostCustomerResponse,
+    PostCustomerSegmentErrorResponse, PostCustomerSegmentResponse, PutCustomerAddressErrorResponse,
+    PutCustomerAddressResponse, PutCustomerDeactivateErrorResponse, PutCustomerDeactivateResponse,
+    PutCustomerDefaultAddressErrorResponse, PutCustomerDefaultAddressResponse,
+    PutCustomerErrorResponse, PutCustomerResponse,
+};
 
 /// Generate a response schema for the customers.
 pub struct CustomerPresenterImpl;
@@ -22,39 +46,232 @@ impl CustomerPresenter for CustomerPresenterImpl {
     type GetCustomersErrorResponse = GetCustomersErrorResponse;
     async fn present_get_customers(
         &self,
-        result: Result<Vec<Customer>, DomainError>,
+        result: Result<CustomerPage, DomainError>,
     ) -> Result<Self::GetCustomersResponse, Self::GetCustomersErrorResponse> {
-        let customers = result?;
-        if customers.is_empty() {
+        let page = result?;
+        if page.customers.is_empty() {
             return Err(GetCustomersErrorResponse::NotFound {
                 object_name: "Customer".to_string(),
             });
         }
 
-        let response: Vec<CustomerSchema> = customers
+        let response: Vec<CustomerSchema> = page
+            .customers
             .into_iter()
             .map(|customer| customer.into())
             .collect();
 
         Ok(web::Json(GetCustomersResponse {
             customers: response,
+            page_info: PageInfoSchema {
+                has_next_page: page.has_next_page,
+                end_cursor: page.end_cursor,
+            },
+        }))
+    }
+
+    type GetCustomerResponse = Json<GetCustomerResponse>;
+    type GetCustomerErrorResponse = GetCustomerErrorResponse;
+    async fn present_get_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::GetCustomerResponse, Self::GetCustomerErrorResponse> {
+        Ok(web::Json(GetCustomerResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PostCustomerResponse = Json<PostCustomerResponse>;
+    type PostCustomerErrorResponse = PostCustomerErrorResponse;
+    async fn present_post_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PostCustomerResponse, Self::PostCustomerErrorResponse> {
+        Ok(web::Json(PostCustomerResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PutCustomerResponse = Json<PutCustomerResponse>;
+    type PutCustomerErrorResponse = PutCustomerErrorResponse;
+    async fn present_put_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerResponse, Self::PutCustomerErrorResponse> {
+        Ok(web::Json(PutCustomerResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PutCustomerDeactivateResponse = Json<PutCustomerDeactivateResponse>;
+    type PutCustomerDeactivateErrorResponse = PutCustomerDeactivateErrorResponse;
+    async fn present_put_customer_deactivate(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerDeactivateResponse, Self::PutCustomerDeactivateErrorResponse> {
+        Ok(web::Json(PutCustomerDeactivateResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PostCustomerAddressResponse = Json<PostCustomerAddressResponse>;
+    type PostCustomerAddressErrorResponse = PostCustomerAddressErrorResponse;
+    async fn present_post_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PostCustomerAddressResponse, Self::PostCustomerAddressErrorResponse> {
+        Ok(web::Json(PostCustomerAddressResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PutCustomerAddressResponse = Json<PutCustomerAddressResponse>;
+    type PutCustomerAddressErrorResponse = PutCustomerAddressErrorResponse;
+    async fn present_put_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerAddressResponse, Self::PutCustomerAddressErrorResponse> {
+        Ok(web::Json(PutCustomerAddressResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type DeleteCustomerAddressResponse = Json<DeleteCustomerAddressResponse>;
+    type DeleteCustomerAddressErrorResponse = DeleteCustomerAddressErrorResponse;
+    async fn present_delete_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::DeleteCustomerAddressResponse, Self::DeleteCustomerAddressErrorResponse> {
+        Ok(web::Json(DeleteCustomerAddressResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type PutCustomerDefaultAddressResponse = Json<PutCustomerDefaultAddressResponse>;
+    type PutCustomerDefaultAddressErrorResponse = PutCustomerDefaultAddressErrorResponse;
+    async fn present_put_customer_default_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerDefaultAddressResponse, Self::PutCustomerDefaultAddressErrorResponse>
+    {
+        Ok(web::Json(PutCustomerDefaultAddressResponse {
+            customer: result?.into(),
+        }))
+    }
+
+    type GetCustomerSegmentsResponse = Json<GetCustomerSegmentsResponse>;
+    type GetCustomerSegmentsErrorResponse = GetCustomerSegmentsErrorResponse;
+    async fn present_get_customer_segments(
+        &self,
+        result: Result<Vec<CustomerSegment>, DomainError>,
+    ) -> Result<Self::GetCustomerSegmentsResponse, Self::GetCustomerSegmentsErrorResponse> {
+        let customer_segments = result?;
+        if customer_segments.is_empty() {
+            return Err(GetCustomerSegmentsErrorResponse::NotFound {
+                object_name: "Customer segment".to_string(),
+            });
+        }
+
+        let response: Vec<CustomerSegmentSchema> = customer_segments
+            .into_iter()
+            .map(|customer_segment| customer_segment.into())
+            .collect();
+
+        Ok(web::Json(GetCustomerSegmentsResponse {
+            customer_segments: response,
+        }))
+    }
+
+    type PostCustomerSegmentResponse = Json<PostCustomerSegmentResponse>;
+    type PostCustomerSegmentErrorResponse = PostCustomerSegmentErrorResponse;
+    async fn present_post_customer_segment(
+        &self,
+        result: Result<CustomerSegment, DomainError>,
+    ) -> Result<Self::PostCustomerSegmentResponse, Self::PostCustomerSegmentErrorResponse> {
+        Ok(web::Json(PostCustomerSegmentResponse {
+            customer_segment: result?.into(),
+        }))
+    }
+
+    type GetCustomerDataExportResponse = HttpResponse;
+    type GetCustomerDataExportErrorResponse = GetCustomerDataExportErrorResponse;
+    async fn present_get_customer_data_export(
+        &self,
+        result: Result<CustomerDataExport, DomainError>,
+    ) -> Result<Self::GetCustomerDataExportResponse, Self::GetCustomerDataExportErrorResponse> {
+        let export = result?;
+        let file_name = format!("customer-{}-data.json", export.customer.id());
+
+        let response = GetCustomerDataExportResponse {
+            customer: export.customer.into(),
+            draft_orders: export
+                .draft_orders
+                .into_iter()
+                .map(|draft_order| draft_order.into())
+                .collect(),
+            orders: export
+                .orders
+                .into_iter()
+                .map(|order| order.into())
+                .collect(),
+            user: export.user.map(|user| user.into()),
+            exported_at: export.exported_at,
+        };
+
+        Ok(HttpResponse::Ok()
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(file_name)],
+            })
+            .json(response))
+    }
+
+    type PostCustomerDataErasureResponse = Json<PostCustomerDataErasureResponse>;
+    type PostCustomerDataErasureErrorResponse = PostCustomerDataErasureErrorResponse;
+    async fn present_post_customer_data_erasure(
+        &self,
+        result: Result<CustomerId, DomainError>,
+    ) -> Result<Self::PostCustomerDataErasureResponse, Self::PostCustomerDataErasureErrorResponse>
+    {
+        Ok(web::Json(PostCustomerDataErasureResponse {
+            customer_id: result?,
         }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::interface::mock::domain_mock::mock_customers;
+    use actix_web::{body::to_bytes, http::StatusCode, ResponseError};
+    use chrono::Utc;
+    use serde_json::{json, Value};
+
+    use crate::{
+        domain::{
+            customer::search_criteria::search_criteria::CustomerSearchCriteria,
+            error::error::FieldError,
+        },
+        interface::{
+            mock::domain_mock::{mock_customers, mock_draft_orders, mock_orders},
+            presenter::customer::schema::CustomerStatusEnum,
+        },
+        usecase::repository::user_repository_interface::UserRecord,
+    };
 
     use super::*;
 
+    fn mock_customer_page(count: usize) -> CustomerPage {
+        CustomerPage {
+            customers: mock_customers(count),
+            has_next_page: true,
+            end_cursor: Some("cursor".to_string()),
+        }
+    }
+
     #[actix_web::test]
     async fn test_present_get_customers_success() {
         let presenter = CustomerPresenterImpl::new();
-        let customers = mock_customers(10);
-
         let result = presenter
-            .present_get_customers(Ok(customers))
+            .present_get_customers(Ok(mock_customer_page(10)))
             .await
             .unwrap();
 
@@ -67,13 +284,18 @@ mod tests {
         assert_eq!(result.customers[9].id, "9");
         assert_eq!(result.customers[9].display_name, "Test Customer 9");
         assert_eq!(result.customers[9].email, Some("9@example.com".to_string()));
+
+        assert!(result.page_info.has_next_page);
+        assert_eq!(result.page_info.end_cursor, Some("cursor".to_string()));
     }
 
     #[actix_web::test]
     async fn test_present_get_customers_not_found() {
         let presenter = CustomerPresenterImpl::new();
 
-        let result = presenter.present_get_customers(Ok(vec![])).await;
+        let result = presenter
+            .present_get_customers(Ok(mock_customer_page(0)))
+            .await;
 
         assert!(matches!(
             result,
@@ -105,4 +327,338 @@ mod tests {
             Err(GetCustomersErrorResponse::ServiceUnavailable)
         ));
     }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let customer = mock_customers(1).remove(0);
+
+        let result = presenter.present_get_customer(Ok(customer)).await.unwrap();
+
+        assert_eq!(result.customer.id, "0");
+        assert_eq!(result.customer.display_name, "Test Customer 0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_not_found() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_get_customer(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetCustomerErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let customer = mock_customers(1).remove(0);
+
+        let result = presenter.present_post_customer(Ok(customer)).await.unwrap();
+
+        assert_eq!(result.customer.id, "0");
+        assert!(!result.customer.email_marketing_consent);
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_bad_request() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_post_customer(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(result, Err(PostCustomerErrorResponse::BadRequest)));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_invalid_input() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_post_customer(Err(DomainError::InvalidInput {
+                errors: vec![FieldError::new(
+                    Some("email".to_string()),
+                    Some("TAKEN".to_string()),
+                    "Email has already been taken",
+                )],
+            }))
+            .await;
+
+        let response = ResponseError::error_response(&result.err().unwrap());
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value =
+            serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        assert_eq!(body["message"], "Invalid input.");
+        assert_eq!(
+            body["details"],
+            json!([{
+                "field": "email",
+                "code": "TAKEN",
+                "message": "Email has already been taken",
+            }])
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let customer = mock_customers(1).remove(0);
+
+        let result = presenter.present_put_customer(Ok(customer)).await.unwrap();
+
+        assert_eq!(result.customer.id, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_service_unavailable() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_put_customer(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutCustomerErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_too_many_requests() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_put_customer(Err(DomainError::Throttled))
+            .await;
+
+        let response = ResponseError::error_response(&result.err().unwrap());
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let body: Value =
+            serde_json::from_slice(&to_bytes(response.into_body()).await.unwrap()).unwrap();
+        assert!(body.get("details").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_deactivate_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let mut customer = mock_customers(1).remove(0);
+        customer.deactivate().unwrap();
+
+        let result = presenter
+            .present_put_customer_deactivate(Ok(customer))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            result.customer.status,
+            CustomerStatusEnum::Inactive
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_deactivate_bad_request() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_put_customer_deactivate(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutCustomerDeactivateErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_address_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let customer = mock_customers(1).remove(0);
+
+        let result = presenter
+            .present_post_customer_address(Ok(customer))
+            .await
+            .unwrap();
+
+        assert_eq!(result.customer.addresses.len(), 1);
+        assert_eq!(result.customer.addresses[0].id, Some("1".to_string()));
+        assert_eq!(
+            result
+                .customer
+                .default_address
+                .as_ref()
+                .unwrap()
+                .country_code
+                .as_deref(),
+            Some("JP")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_address_not_found() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_put_customer_address(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutCustomerAddressErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_delete_customer_address_bad_request() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_delete_customer_address(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DeleteCustomerAddressErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_customer_default_address_service_unavailable() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_put_customer_default_address(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutCustomerDefaultAddressErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_segments_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let customer_segment = CustomerSegment::new(
+            "1",
+            "VIP",
+            CustomerSearchCriteria::default(),
+            Utc::now(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        let result = presenter
+            .present_get_customer_segments(Ok(vec![customer_segment]))
+            .await
+            .unwrap();
+
+        assert_eq!(result.customer_segments.len(), 1);
+        assert_eq!(result.customer_segments[0].id, "1");
+        assert_eq!(result.customer_segments[0].name, "VIP");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_segments_not_found() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter.present_get_customer_segments(Ok(vec![])).await;
+
+        assert!(matches!(
+            result,
+            Err(GetCustomerSegmentsErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_segment_bad_request() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_post_customer_segment(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostCustomerSegmentErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_data_export_success() {
+        let presenter = CustomerPresenterImpl::new();
+        let export = CustomerDataExport {
+            customer: mock_customers(1).remove(0),
+            draft_orders: mock_draft_orders(2),
+            orders: mock_orders(3),
+            user: Some(UserRecord {
+                id: "user_0".to_string(),
+                name: "name".to_string(),
+                user_group_ids: vec![1],
+            }),
+            exported_at: Utc::now(),
+        };
+
+        let result = presenter
+            .present_get_customer_data_export(Ok(export))
+            .await
+            .unwrap();
+
+        assert_eq!(result.status(), actix_web::http::StatusCode::OK);
+        assert_eq!(
+            result
+                .headers()
+                .get(actix_web::http::header::CONTENT_DISPOSITION)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "attachment; filename=\"customer-0-data.json\""
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_customer_data_export_not_found() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_get_customer_data_export(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetCustomerDataExportErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_data_erasure_success() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_post_customer_data_erasure(Ok("0".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.customer_id, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_customer_data_erasure_service_unavailable() {
+        let presenter = CustomerPresenterImpl::new();
+
+        let result = presenter
+            .present_post_customer_data_erasure(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostCustomerDataErasureErrorResponse::ServiceUnavailable)
+        ));
+    }
 }
@@ -6,7 +6,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::domain::error::error::DomainError;
+use crate::interface::presenter::draft_order::schema::DraftOrderSchema;
 use crate::interface::presenter::media::schema::ImageSchema;
+use crate::interface::presenter::order::schema::OrderSchema;
 use crate::{
     define_error_response,
     interface::presenter::{
@@ -29,6 +31,7 @@ pub struct CustomerSchema {
     pub(super) note: Option<String>,
     pub(super) status: CustomerStatusEnum,
     pub(super) verified_email: bool,
+    pub(super) email_marketing_consent: bool,
     pub(super) created_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
 }
@@ -39,9 +42,134 @@ pub enum CustomerStatusEnum {
     Inactive,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PageInfoSchema {
+    pub(super) has_next_page: bool,
+    pub(super) end_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerSegmentSchema {
+    pub(super) id: String,
+    pub(super) name: String,
+    pub(super) criteria: CustomerSearchCriteriaSchema,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerSearchCriteriaSchema {
+    pub(super) name: Option<String>,
+    pub(super) email: Option<String>,
+    pub(super) phone: Option<String>,
+    pub(super) tags: Vec<String>,
+    pub(super) created_at_min: Option<DateTime<Utc>>,
+    pub(super) created_at_max: Option<DateTime<Utc>>,
+    pub(super) orders_count_min: Option<u32>,
+    pub(super) orders_count_max: Option<u32>,
+    pub(super) amount_spent_min: Option<f64>,
+    pub(super) amount_spent_max: Option<f64>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetCustomersResponse {
     pub customers: Vec<CustomerSchema>,
+    pub page_info: PageInfoSchema,
 }
 
 define_error_response!(GetCustomersErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCustomerResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(GetCustomerErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostCustomerResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PostCustomerErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutCustomerResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PutCustomerErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutCustomerDeactivateResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PutCustomerDeactivateErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostCustomerAddressResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PostCustomerAddressErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutCustomerAddressResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PutCustomerAddressErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteCustomerAddressResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(DeleteCustomerAddressErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutCustomerDefaultAddressResponse {
+    pub customer: CustomerSchema,
+}
+
+define_error_response!(PutCustomerDefaultAddressErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCustomerSegmentsResponse {
+    pub customer_segments: Vec<CustomerSegmentSchema>,
+}
+
+define_error_response!(GetCustomerSegmentsErrorResponse, "Customer segment");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostCustomerSegmentResponse {
+    pub customer_segment: CustomerSegmentSchema,
+}
+
+define_error_response!(PostCustomerSegmentErrorResponse, "Customer segment");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocalUserSchema {
+    pub(super) id: String,
+    pub(super) name: String,
+    pub(super) user_group_ids: Vec<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCustomerDataExportResponse {
+    pub customer: CustomerSchema,
+    pub draft_orders: Vec<DraftOrderSchema>,
+    pub orders: Vec<OrderSchema>,
+    pub user: Option<LocalUserSchema>,
+    pub exported_at: DateTime<Utc>,
+}
+
+define_error_response!(GetCustomerDataExportErrorResponse, "Customer");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostCustomerDataErasureResponse {
+    pub customer_id: String,
+}
+
+define_error_response!(PostCustomerDataErasureErrorResponse, "Customer");
@@ -1,15 +1,121 @@
 use async_trait::async_trait;
 
-use crate::domain::{customer::customer::Customer, error::error::DomainError};
+use crate::{
+    domain::{
+        customer::customer::{Customer, Id as CustomerId},
+        customer_segment::customer_segment::CustomerSegment,
+        error::error::DomainError,
+    },
+    usecase::{
+        interactor::customer_interactor_interface::CustomerDataExport,
+        repository::customer_repository_interface::CustomerPage,
+    },
+};
 
 /// Interface to generate response schema for customers.
 #[async_trait]
 pub trait CustomerPresenter {
     type GetCustomersResponse;
     type GetCustomersErrorResponse;
-    /// Generate a list response of customer information.
+    /// Generate a paginated list response of customer information.
     async fn present_get_customers(
         &self,
-        result: Result<Vec<Customer>, DomainError>,
+        result: Result<CustomerPage, DomainError>,
     ) -> Result<Self::GetCustomersResponse, Self::GetCustomersErrorResponse>;
+
+    type GetCustomerResponse;
+    type GetCustomerErrorResponse;
+    /// Generate a response of customer information.
+    async fn present_get_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::GetCustomerResponse, Self::GetCustomerErrorResponse>;
+
+    type PostCustomerResponse;
+    type PostCustomerErrorResponse;
+    /// Generate a response of the created customer.
+    async fn present_post_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PostCustomerResponse, Self::PostCustomerErrorResponse>;
+
+    type PutCustomerResponse;
+    type PutCustomerErrorResponse;
+    /// Generate a response of the updated customer.
+    async fn present_put_customer(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerResponse, Self::PutCustomerErrorResponse>;
+
+    type PutCustomerDeactivateResponse;
+    type PutCustomerDeactivateErrorResponse;
+    /// Generate a response of the deactivated customer.
+    async fn present_put_customer_deactivate(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerDeactivateResponse, Self::PutCustomerDeactivateErrorResponse>;
+
+    type PostCustomerAddressResponse;
+    type PostCustomerAddressErrorResponse;
+    /// Generate a response of the customer after adding an address.
+    async fn present_post_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PostCustomerAddressResponse, Self::PostCustomerAddressErrorResponse>;
+
+    type PutCustomerAddressResponse;
+    type PutCustomerAddressErrorResponse;
+    /// Generate a response of the customer after updating an address.
+    async fn present_put_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerAddressResponse, Self::PutCustomerAddressErrorResponse>;
+
+    type DeleteCustomerAddressResponse;
+    type DeleteCustomerAddressErrorResponse;
+    /// Generate a response of the customer after deleting an address.
+    async fn present_delete_customer_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::DeleteCustomerAddressResponse, Self::DeleteCustomerAddressErrorResponse>;
+
+    type PutCustomerDefaultAddressResponse;
+    type PutCustomerDefaultAddressErrorResponse;
+    /// Generate a response of the customer after changing the default address.
+    async fn present_put_customer_default_address(
+        &self,
+        result: Result<Customer, DomainError>,
+    ) -> Result<Self::PutCustomerDefaultAddressResponse, Self::PutCustomerDefaultAddressErrorResponse>;
+
+    type GetCustomerSegmentsResponse;
+    type GetCustomerSegmentsErrorResponse;
+    /// Generate a list response of customer segments.
+    async fn present_get_customer_segments(
+        &self,
+        result: Result<Vec<CustomerSegment>, DomainError>,
+    ) -> Result<Self::GetCustomerSegmentsResponse, Self::GetCustomerSegmentsErrorResponse>;
+
+    type PostCustomerSegmentResponse;
+    type PostCustomerSegmentErrorResponse;
+    /// Generate a response of the created customer segment.
+    async fn present_post_customer_segment(
+        &self,
+        result: Result<CustomerSegment, DomainError>,
+    ) -> Result<Self::PostCustomerSegmentResponse, Self::PostCustomerSegmentErrorResponse>;
+
+    type GetCustomerDataExportResponse;
+    type GetCustomerDataExportErrorResponse;
+    /// Generate a downloadable archive of all data held about a customer.
+    async fn present_get_customer_data_export(
+        &self,
+        result: Result<CustomerDataExport, DomainError>,
+    ) -> Result<Self::GetCustomerDataExportResponse, Self::GetCustomerDataExportErrorResponse>;
+
+    type PostCustomerDataErasureResponse;
+    type PostCustomerDataErasureErrorResponse;
+    /// Generate a response of the customer whose data was erased.
+    async fn present_post_customer_data_erasure(
+        &self,
+        result: Result<CustomerId, DomainError>,
+    ) -> Result<Self::PostCustomerDataErasureResponse, Self::PostCustomerDataErasureErrorResponse>;
 }
@@ -27,7 +27,9 @@ impl From<DraftOrder> for DraftOrderSchema {
             total_price_set: draft_order.total_price_set().to_owned().into(),
             presentment_currency_code: draft_order.presentment_currency_code().to_owned().into(),
             order_id: draft_order.order_id().as_ref().map(|id| id.to_string()),
+            cancel_reason: draft_order.cancel_reason().to_owned(),
             completed_at: *draft_order.completed_at(),
+            canceled_at: *draft_order.canceled_at(),
             created_at: *draft_order.created_at(),
             updated_at: *draft_order.updated_at(),
         }
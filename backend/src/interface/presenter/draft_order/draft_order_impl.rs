@@ -10,9 +10,10 @@ use crate::{
 };
 
 use super::schema::{
-    CompleteDraftOrderErrorResponse, CompleteDraftOrderResponse, DeleteDraftOrderErrorResponse,
-    DeleteDraftOrderResponse, DraftOrderSchema, GetDraftOrdersErrorResponse,
-    GetDraftOrdersResponse, PostDraftOrderErrorResponse, PostDraftOrderResponse,
+    CancelDraftOrderErrorResponse, CancelDraftOrderResponse, CompleteDraftOrderErrorResponse,
+    CompleteDraftOrderResponse, DeleteDraftOrderErrorResponse, DeleteDraftOrderResponse,
+    DraftOrderSchema, GetDraftOrdersErrorResponse, GetDraftOrdersResponse,
+    PostDraftOrderErrorResponse, PostDraftOrderResponse,
 };
 
 /// Generate a response schema for the draft orders.
@@ -70,6 +71,17 @@ impl DraftOrderPresenter for DraftOrderPresenterImpl {
         }))
     }
 
+    type CancelDraftOrderResponse = Json<CancelDraftOrderResponse>;
+    type CancelDraftOrderErrorResponse = CancelDraftOrderErrorResponse;
+    async fn present_cancel_draft_order(
+        &self,
+        result: Result<DraftOrder, DomainError>,
+    ) -> Result<Self::CancelDraftOrderResponse, Self::CancelDraftOrderErrorResponse> {
+        Ok(web::Json(CancelDraftOrderResponse {
+            draft_order: result?.into(),
+        }))
+    }
+
     type DeleteDraftOrderResponse = Json<DeleteDraftOrderResponse>;
     type DeleteDraftOrderErrorResponse = DeleteDraftOrderErrorResponse;
     async fn present_delete_draft_order(
@@ -235,6 +247,53 @@ mod tests {
         ));
     }
 
+    #[actix_web::test]
+    async fn test_present_cancel_draft_order_success() {
+        let presenter = DraftOrderPresenterImpl::new();
+        let mut draft_order = mock_draft_orders(1).remove(0);
+        draft_order.cancel("Customer request").unwrap();
+
+        let result = presenter
+            .present_cancel_draft_order(Ok(draft_order))
+            .await
+            .unwrap();
+
+        assert_eq!(result.draft_order.id, "0");
+        assert_eq!(
+            result.draft_order.cancel_reason,
+            Some("Customer request".to_string())
+        );
+        assert!(result.draft_order.canceled_at.is_some());
+    }
+
+    #[actix_web::test]
+    async fn test_present_cancel_draft_order_bad_request() {
+        let presenter = DraftOrderPresenterImpl::new();
+
+        let result = presenter
+            .present_cancel_draft_order(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CancelDraftOrderErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_cancel_draft_order_service_unavailable() {
+        let presenter = DraftOrderPresenterImpl::new();
+
+        let result = presenter
+            .present_cancel_draft_order(Err(DomainError::SystemError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CancelDraftOrderErrorResponse::ServiceUnavailable)
+        ));
+    }
+
     #[actix_web::test]
     async fn test_present_delete_draft_order_success() {
         let presenter = DraftOrderPresenterImpl::new();
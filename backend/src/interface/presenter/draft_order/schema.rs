@@ -36,7 +36,9 @@ pub struct DraftOrderSchema {
     pub(super) total_price_set: MoneySchema,
     pub(super) presentment_currency_code: CustomerStatusEnum,
     pub(super) order_id: Option<String>,
+    pub(super) cancel_reason: Option<String>,
     pub(super) completed_at: Option<DateTime<Utc>>,
+    pub(super) canceled_at: Option<DateTime<Utc>>,
     pub(super) created_at: DateTime<Utc>,
     pub(super) updated_at: DateTime<Utc>,
 }
@@ -69,6 +71,13 @@ pub struct CompleteDraftOrderResponse {
 
 define_error_response!(CompleteDraftOrderErrorResponse, "DraftOrder");
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelDraftOrderResponse {
+    pub draft_order: DraftOrderSchema,
+}
+
+define_error_response!(CancelDraftOrderErrorResponse, "DraftOrder");
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeleteDraftOrderResponse {
     pub id: String,
@@ -32,6 +32,14 @@ pub trait DraftOrderPresenter {
         result: Result<DraftOrder, DomainError>,
     ) -> Result<Self::CompleteDraftOrderResponse, Self::CompleteDraftOrderErrorResponse>;
 
+    type CancelDraftOrderResponse;
+    type CancelDraftOrderErrorResponse;
+    /// Generate an cancel response for draft order.
+    async fn present_cancel_draft_order(
+        &self,
+        result: Result<DraftOrder, DomainError>,
+    ) -> Result<Self::CancelDraftOrderResponse, Self::CancelDraftOrderErrorResponse>;
+
     type DeleteDraftOrderResponse;
     type DeleteDraftOrderErrorResponse;
     /// Generate an delete response for draft order.
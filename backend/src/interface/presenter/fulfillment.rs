@@ -0,0 +1,3 @@
+pub mod converter;
+pub mod fulfillment_impl;
+pub mod schema;
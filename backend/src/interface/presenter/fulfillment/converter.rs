@@ -0,0 +1,112 @@
+use crate::domain::{
+    fulfillment::{
+        fulfillment::{Fulfillment, FulfillmentStatus},
+        fulfillment_line_item::fulfillment_line_item::FulfillmentLineItem,
+        tracking_info::tracking_info::TrackingInfo,
+    },
+    fulfillment_order::{
+        fulfillment_order::{FulfillmentOrder, FulfillmentOrderStatus},
+        fulfillment_order_line_item::fulfillment_order_line_item::FulfillmentOrderLineItem,
+    },
+};
+
+use super::schema::{
+    FulfillmentLineItemSchema, FulfillmentOrderLineItemSchema, FulfillmentOrderSchema,
+    FulfillmentOrderStatusEnum, FulfillmentSchema, FulfillmentStatusEnum, TrackingInfoSchema,
+};
+
+impl From<FulfillmentOrder> for FulfillmentOrderSchema {
+    fn from(fulfillment_order: FulfillmentOrder) -> Self {
+        FulfillmentOrderSchema {
+            id: fulfillment_order.id().to_string(),
+            order_id: fulfillment_order.order_id().to_string(),
+            assigned_location_id: fulfillment_order.assigned_location_id().to_owned(),
+            status: fulfillment_order.status().to_owned().into(),
+            line_items: fulfillment_order
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.into())
+                .collect(),
+        }
+    }
+}
+
+impl From<&FulfillmentOrderLineItem> for FulfillmentOrderLineItemSchema {
+    fn from(line_item: &FulfillmentOrderLineItem) -> Self {
+        FulfillmentOrderLineItemSchema {
+            id: line_item.id().to_string(),
+            line_item_id: line_item.line_item_id().to_string(),
+            total_quantity: *line_item.total_quantity(),
+            remaining_quantity: *line_item.remaining_quantity(),
+        }
+    }
+}
+
+impl From<FulfillmentOrderStatus> for FulfillmentOrderStatusEnum {
+    fn from(status: FulfillmentOrderStatus) -> Self {
+        match status {
+            FulfillmentOrderStatus::Open => FulfillmentOrderStatusEnum::Open,
+            FulfillmentOrderStatus::InProgress => FulfillmentOrderStatusEnum::InProgress,
+            FulfillmentOrderStatus::Scheduled => FulfillmentOrderStatusEnum::Scheduled,
+            FulfillmentOrderStatus::OnHold => FulfillmentOrderStatusEnum::OnHold,
+            FulfillmentOrderStatus::Incomplete => FulfillmentOrderStatusEnum::Incomplete,
+            FulfillmentOrderStatus::Closed => FulfillmentOrderStatusEnum::Closed,
+            FulfillmentOrderStatus::Cancelled => FulfillmentOrderStatusEnum::Cancelled,
+        }
+    }
+}
+
+impl From<Fulfillment> for FulfillmentSchema {
+    fn from(fulfillment: Fulfillment) -> Self {
+        FulfillmentSchema {
+            id: fulfillment.id().to_string(),
+            order_id: fulfillment.order_id().to_string(),
+            status: fulfillment.status().to_owned().into(),
+            line_items: fulfillment
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.into())
+                .collect(),
+            tracking_info: fulfillment
+                .tracking_info()
+                .iter()
+                .map(|tracking_info| tracking_info.into())
+                .collect(),
+            created_at: *fulfillment.created_at(),
+            updated_at: *fulfillment.updated_at(),
+        }
+    }
+}
+
+impl From<&FulfillmentLineItem> for FulfillmentLineItemSchema {
+    fn from(line_item: &FulfillmentLineItem) -> Self {
+        FulfillmentLineItemSchema {
+            id: line_item.id().to_string(),
+            line_item_id: line_item.line_item_id().to_string(),
+            quantity: *line_item.quantity(),
+        }
+    }
+}
+
+impl From<&TrackingInfo> for TrackingInfoSchema {
+    fn from(tracking_info: &TrackingInfo) -> Self {
+        TrackingInfoSchema {
+            company: tracking_info.company().to_owned(),
+            number: tracking_info.number().to_string(),
+            url: tracking_info.url().to_owned(),
+        }
+    }
+}
+
+impl From<FulfillmentStatus> for FulfillmentStatusEnum {
+    fn from(status: FulfillmentStatus) -> Self {
+        match status {
+            FulfillmentStatus::Pending => FulfillmentStatusEnum::Pending,
+            FulfillmentStatus::Open => FulfillmentStatusEnum::Open,
+            FulfillmentStatus::Success => FulfillmentStatusEnum::Success,
+            FulfillmentStatus::Cancelled => FulfillmentStatusEnum::Cancelled,
+            FulfillmentStatus::Error => FulfillmentStatusEnum::Error,
+            FulfillmentStatus::Failure => FulfillmentStatusEnum::Failure,
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use actix_web::web::{self, Json};
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError, fulfillment::fulfillment::Fulfillment,
+        fulfillment_order::fulfillment_order::FulfillmentOrder,
+    },
+    interface::presenter::fulfillment_presenter_interface::FulfillmentPresenter,
+};
+
+use super::schema::{
+    FulfillmentOrderSchema, GetFulfillmentOrdersErrorResponse, GetFulfillmentOrdersResponse,
+    PostFulfillmentErrorResponse, PostFulfillmentResponse, PutFulfillmentTrackingErrorResponse,
+    PutFulfillmentTrackingResponse,
+};
+
+/// Generate a response schema for the fulfillments.
+pub struct FulfillmentPresenterImpl;
+impl FulfillmentPresenterImpl {
+    pub fn new() -> Self {
+        FulfillmentPresenterImpl
+    }
+}
+
+impl Default for FulfillmentPresenterImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FulfillmentPresenter for FulfillmentPresenterImpl {
+    type GetFulfillmentOrdersResponse = Json<GetFulfillmentOrdersResponse>;
+    type GetFulfillmentOrdersErrorResponse = GetFulfillmentOrdersErrorResponse;
+    async fn present_get_fulfillment_orders(
+        &self,
+        result: Result<Vec<FulfillmentOrder>, DomainError>,
+    ) -> Result<Self::GetFulfillmentOrdersResponse, Self::GetFulfillmentOrdersErrorResponse> {
+        let fulfillment_orders = result?;
+        if fulfillment_orders.is_empty() {
+            return Err(GetFulfillmentOrdersErrorResponse::NotFound {
+                object_name: "FulfillmentOrder".to_string(),
+            });
+        }
+
+        let response: Vec<FulfillmentOrderSchema> = fulfillment_orders
+            .into_iter()
+            .map(|fulfillment_order| fulfillment_order.into())
+            .collect();
+
+        Ok(web::Json(GetFulfillmentOrdersResponse {
+            fulfillment_orders: response,
+        }))
+    }
+
+    type PostFulfillmentResponse = Json<PostFulfillmentResponse>;
+    type PostFulfillmentErrorResponse = PostFulfillmentErrorResponse;
+    async fn present_post_fulfillment(
+        &self,
+        result: Result<Fulfillment, DomainError>,
+    ) -> Result<Self::PostFulfillmentResponse, Self::PostFulfillmentErrorResponse> {
+        Ok(web::Json(PostFulfillmentResponse {
+            fulfillment: result?.into(),
+        }))
+    }
+
+    type PutFulfillmentTrackingResponse = Json<PutFulfillmentTrackingResponse>;
+    type PutFulfillmentTrackingErrorResponse = PutFulfillmentTrackingErrorResponse;
+    async fn present_put_fulfillment_tracking(
+        &self,
+        result: Result<Fulfillment, DomainError>,
+    ) -> Result<Self::PutFulfillmentTrackingResponse, Self::PutFulfillmentTrackingErrorResponse>
+    {
+        Ok(web::Json(PutFulfillmentTrackingResponse {
+            fulfillment: result?.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::mock::domain_mock::{mock_fulfillment_orders, mock_fulfillments};
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_present_get_fulfillment_orders_success() {
+        let presenter = FulfillmentPresenterImpl::new();
+        let fulfillment_orders = mock_fulfillment_orders(5);
+
+        let result = presenter
+            .present_get_fulfillment_orders(Ok(fulfillment_orders))
+            .await
+            .unwrap();
+
+        assert_eq!(result.fulfillment_orders.len(), 5);
+
+        assert_eq!(result.fulfillment_orders[0].id, "0");
+        assert_eq!(result.fulfillment_orders[0].order_id, "0");
+        assert_eq!(
+            result.fulfillment_orders[0].line_items[0].remaining_quantity,
+            1
+        );
+
+        assert_eq!(result.fulfillment_orders[4].id, "4");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_fulfillment_orders_not_found() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter.present_get_fulfillment_orders(Ok(vec![])).await;
+
+        assert!(matches!(
+            result,
+            Err(GetFulfillmentOrdersErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_fulfillment_orders_bad_request() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_get_fulfillment_orders(Err(DomainError::AuthorizationError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetFulfillmentOrdersErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_fulfillment_orders_service_unavailable() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_get_fulfillment_orders(Err(DomainError::QueryError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetFulfillmentOrdersErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_fulfillment_success() {
+        let presenter = FulfillmentPresenterImpl::new();
+        let fulfillment = mock_fulfillments(1).remove(0);
+
+        let result = presenter
+            .present_post_fulfillment(Ok(fulfillment))
+            .await
+            .unwrap();
+
+        assert_eq!(result.fulfillment.id, "0");
+        assert_eq!(result.fulfillment.line_items.len(), 1);
+        assert_eq!(result.fulfillment.tracking_info[0].number, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_fulfillment_bad_request() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_post_fulfillment(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostFulfillmentErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_fulfillment_service_unavailable() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_post_fulfillment(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostFulfillmentErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_fulfillment_tracking_success() {
+        let presenter = FulfillmentPresenterImpl::new();
+        let fulfillment = mock_fulfillments(1).remove(0);
+
+        let result = presenter
+            .present_put_fulfillment_tracking(Ok(fulfillment))
+            .await
+            .unwrap();
+
+        assert_eq!(result.fulfillment.id, "0");
+        assert_eq!(
+            result.fulfillment.tracking_info[0].url,
+            Some("https://example.com/0".to_string())
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_fulfillment_tracking_not_found() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_put_fulfillment_tracking(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutFulfillmentTrackingErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_fulfillment_tracking_service_unavailable() {
+        let presenter = FulfillmentPresenterImpl::new();
+
+        let result = presenter
+            .present_put_fulfillment_tracking(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutFulfillmentTrackingErrorResponse::ServiceUnavailable)
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use actix_web::{HttpResponse, ResponseError};
+use derive_more::{Display, Error};
+
+use actix_http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::error::DomainError;
+use crate::{define_error_response, interface::presenter::common::exception::ErrorResponseBuilder};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FulfillmentOrderSchema {
+    pub(super) id: String,
+    pub(super) order_id: String,
+    pub(super) assigned_location_id: Option<String>,
+    pub(super) status: FulfillmentOrderStatusEnum,
+    pub(super) line_items: Vec<FulfillmentOrderLineItemSchema>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FulfillmentOrderLineItemSchema {
+    pub(super) id: String,
+    pub(super) line_item_id: String,
+    pub(super) total_quantity: u32,
+    pub(super) remaining_quantity: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FulfillmentOrderStatusEnum {
+    Open,
+    InProgress,
+    Scheduled,
+    OnHold,
+    Incomplete,
+    Closed,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FulfillmentSchema {
+    pub(super) id: String,
+    pub(super) order_id: String,
+    pub(super) status: FulfillmentStatusEnum,
+    pub(super) line_items: Vec<FulfillmentLineItemSchema>,
+    pub(super) tracking_info: Vec<TrackingInfoSchema>,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FulfillmentLineItemSchema {
+    pub(super) id: String,
+    pub(super) line_item_id: String,
+    pub(super) quantity: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackingInfoSchema {
+    pub(super) company: Option<String>,
+    pub(super) number: String,
+    pub(super) url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FulfillmentStatusEnum {
+    Pending,
+    Open,
+    Success,
+    Cancelled,
+    Error,
+    Failure,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetFulfillmentOrdersResponse {
+    pub fulfillment_orders: Vec<FulfillmentOrderSchema>,
+}
+
+define_error_response!(GetFulfillmentOrdersErrorResponse, "FulfillmentOrder");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostFulfillmentResponse {
+    pub fulfillment: FulfillmentSchema,
+}
+
+define_error_response!(PostFulfillmentErrorResponse, "Fulfillment");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutFulfillmentTrackingResponse {
+    pub fulfillment: FulfillmentSchema,
+}
+
+define_error_response!(PutFulfillmentTrackingErrorResponse, "Fulfillment");
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError, fulfillment::fulfillment::Fulfillment,
+    fulfillment_order::fulfillment_order::FulfillmentOrder,
+};
+
+/// Interface to generate response schema for fulfillments.
+#[async_trait]
+pub trait FulfillmentPresenter {
+    type GetFulfillmentOrdersResponse;
+    type GetFulfillmentOrdersErrorResponse;
+    /// Generate a list response of fulfillment order information.
+    async fn present_get_fulfillment_orders(
+        &self,
+        result: Result<Vec<FulfillmentOrder>, DomainError>,
+    ) -> Result<Self::GetFulfillmentOrdersResponse, Self::GetFulfillmentOrdersErrorResponse>;
+
+    type PostFulfillmentResponse;
+    type PostFulfillmentErrorResponse;
+    /// Generate a response of the created fulfillment.
+    async fn present_post_fulfillment(
+        &self,
+        result: Result<Fulfillment, DomainError>,
+    ) -> Result<Self::PostFulfillmentResponse, Self::PostFulfillmentErrorResponse>;
+
+    type PutFulfillmentTrackingResponse;
+    type PutFulfillmentTrackingErrorResponse;
+    /// Generate a response of the fulfillment with updated tracking information.
+    async fn present_put_fulfillment_tracking(
+        &self,
+        result: Result<Fulfillment, DomainError>,
+    ) -> Result<Self::PutFulfillmentTrackingResponse, Self::PutFulfillmentTrackingErrorResponse>;
+}
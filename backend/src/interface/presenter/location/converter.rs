@@ -1,6 +1,9 @@
-use crate::domain::location::location::Location;
+use crate::{
+    domain::location::location::Location,
+    usecase::interactor::location_interactor_interface::NearbyLocation,
+};
 
-use super::schema::LocationSchema;
+use super::schema::{LocationSchema, NearbyLocationSchema};
 
 impl From<Location> for LocationSchema {
     fn from(location: Location) -> Self {
@@ -19,3 +22,12 @@ impl From<Location> for LocationSchema {
         }
     }
 }
+
+impl From<NearbyLocation> for NearbyLocationSchema {
+    fn from(nearby_location: NearbyLocation) -> Self {
+        Self {
+            location: nearby_location.location.into(),
+            distance_km: nearby_location.distance_km,
+        }
+    }
+}
@@ -4,9 +4,16 @@ use async_trait::async_trait;
 use crate::{
     domain::{error::error::DomainError, location::location::Location},
     interface::presenter::location_presenter_interface::LocationPresenter,
+    usecase::interactor::location_interactor_interface::NearbyLocation,
 };
 
-use super::schema::{GetLocationsErrorResponse, GetLocationsResponse};
+use super::schema::{
+    GetLocationsErrorResponse, GetLocationsResponse, GetNearbyLocationsErrorResponse,
+    GetNearbyLocationsResponse, PostLocationErrorResponse, PostLocationResponse,
+    PutLocationActivateErrorResponse, PutLocationActivateResponse,
+    PutLocationDeactivateErrorResponse, PutLocationDeactivateResponse, PutLocationErrorResponse,
+    PutLocationResponse,
+};
 
 /// Generate a response schema for the location.
 pub struct LocationPresenterImpl;
@@ -28,6 +35,61 @@ impl LocationPresenter for LocationPresenterImpl {
             locations: result?.into_iter().map(|l| l.into()).collect(),
         }))
     }
+
+    type GetNearbyLocationsResponse = Json<GetNearbyLocationsResponse>;
+    type GetNearbyLocationsErrorResponse = GetNearbyLocationsErrorResponse;
+    async fn present_get_nearby_locations(
+        &self,
+        result: Result<Vec<NearbyLocation>, DomainError>,
+    ) -> Result<Self::GetNearbyLocationsResponse, Self::GetNearbyLocationsErrorResponse> {
+        Ok(web::Json(GetNearbyLocationsResponse {
+            locations: result?.into_iter().map(|l| l.into()).collect(),
+        }))
+    }
+
+    type PostLocationResponse = Json<PostLocationResponse>;
+    type PostLocationErrorResponse = PostLocationErrorResponse;
+    async fn present_post_location(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PostLocationResponse, Self::PostLocationErrorResponse> {
+        Ok(web::Json(PostLocationResponse {
+            location: result?.into(),
+        }))
+    }
+
+    type PutLocationResponse = Json<PutLocationResponse>;
+    type PutLocationErrorResponse = PutLocationErrorResponse;
+    async fn present_put_location(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationResponse, Self::PutLocationErrorResponse> {
+        Ok(web::Json(PutLocationResponse {
+            location: result?.into(),
+        }))
+    }
+
+    type PutLocationDeactivateResponse = Json<PutLocationDeactivateResponse>;
+    type PutLocationDeactivateErrorResponse = PutLocationDeactivateErrorResponse;
+    async fn present_put_location_deactivate(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationDeactivateResponse, Self::PutLocationDeactivateErrorResponse> {
+        Ok(web::Json(PutLocationDeactivateResponse {
+            location: result?.into(),
+        }))
+    }
+
+    type PutLocationActivateResponse = Json<PutLocationActivateResponse>;
+    type PutLocationActivateErrorResponse = PutLocationActivateErrorResponse;
+    async fn present_put_location_activate(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationActivateResponse, Self::PutLocationActivateErrorResponse> {
+        Ok(web::Json(PutLocationActivateResponse {
+            location: result?.into(),
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -87,4 +149,119 @@ mod tests {
             Err(GetLocationsErrorResponse::ServiceUnavailable)
         ));
     }
+
+    #[actix_web::test]
+    async fn test_present_get_nearby_locations_success() {
+        let presenter = LocationPresenterImpl::new();
+        let nearby_locations = mock_locations(2)
+            .into_iter()
+            .enumerate()
+            .map(|(i, location)| NearbyLocation {
+                location,
+                distance_km: Some(i as f64),
+            })
+            .collect();
+
+        let result = presenter
+            .present_get_nearby_locations(Ok(nearby_locations))
+            .await
+            .unwrap();
+
+        assert_eq!(result.locations.len(), 2);
+        assert_eq!(result.locations[0].location.id, "0");
+        assert_eq!(result.locations[1].distance_km, Some(1.0));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_nearby_locations_service_unavailable() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_get_nearby_locations(Err(DomainError::QueryError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetNearbyLocationsErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_location_success() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_post_location(Ok(mock_locations(1).remove(0)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.location.id, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_location_bad_request() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_post_location(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(result, Err(PostLocationErrorResponse::BadRequest)));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_location_not_found() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_put_location(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutLocationErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_location_deactivate_success() {
+        let presenter = LocationPresenterImpl::new();
+        let mut location = mock_locations(1).remove(0);
+        location.deactivate().unwrap();
+
+        let result = presenter
+            .present_put_location_deactivate(Ok(location))
+            .await
+            .unwrap();
+
+        assert!(!result.location.is_active);
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_location_deactivate_bad_request() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_put_location_deactivate(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutLocationDeactivateErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_location_activate_service_unavailable() {
+        let presenter = LocationPresenterImpl::new();
+
+        let result = presenter
+            .present_put_location_activate(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutLocationActivateErrorResponse::ServiceUnavailable)
+        ));
+    }
 }
@@ -24,3 +24,44 @@ pub struct GetLocationsResponse {
 }
 
 define_error_response!(GetLocationsErrorResponse, "Locations");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NearbyLocationSchema {
+    pub(super) location: LocationSchema,
+    pub(super) distance_km: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetNearbyLocationsResponse {
+    pub locations: Vec<NearbyLocationSchema>,
+}
+
+define_error_response!(GetNearbyLocationsErrorResponse, "Locations");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostLocationResponse {
+    pub location: LocationSchema,
+}
+
+define_error_response!(PostLocationErrorResponse, "Location");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutLocationResponse {
+    pub location: LocationSchema,
+}
+
+define_error_response!(PutLocationErrorResponse, "Location");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutLocationDeactivateResponse {
+    pub location: LocationSchema,
+}
+
+define_error_response!(PutLocationDeactivateErrorResponse, "Location");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutLocationActivateResponse {
+    pub location: LocationSchema,
+}
+
+define_error_response!(PutLocationActivateErrorResponse, "Location");
@@ -1,6 +1,9 @@
 use async_trait::async_trait;
 
-use crate::domain::{error::error::DomainError, location::location::Location};
+use crate::{
+    domain::{error::error::DomainError, location::location::Location},
+    usecase::interactor::location_interactor_interface::NearbyLocation,
+};
 
 /// Interface to generate response schema for locations.
 #[async_trait]
@@ -12,4 +15,44 @@ pub trait LocationPresenter {
         &self,
         result: Result<Vec<Location>, DomainError>,
     ) -> Result<Self::GetLocationsResponse, Self::GetLocationsErrorResponse>;
+
+    type GetNearbyLocationsResponse;
+    type GetNearbyLocationsErrorResponse;
+    /// Generate a list response of locations near the origin.
+    async fn present_get_nearby_locations(
+        &self,
+        result: Result<Vec<NearbyLocation>, DomainError>,
+    ) -> Result<Self::GetNearbyLocationsResponse, Self::GetNearbyLocationsErrorResponse>;
+
+    type PostLocationResponse;
+    type PostLocationErrorResponse;
+    /// Generate a response of the created location.
+    async fn present_post_location(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PostLocationResponse, Self::PostLocationErrorResponse>;
+
+    type PutLocationResponse;
+    type PutLocationErrorResponse;
+    /// Generate a response of the updated location.
+    async fn present_put_location(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationResponse, Self::PutLocationErrorResponse>;
+
+    type PutLocationDeactivateResponse;
+    type PutLocationDeactivateErrorResponse;
+    /// Generate a response of the deactivated location.
+    async fn present_put_location_deactivate(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationDeactivateResponse, Self::PutLocationDeactivateErrorResponse>;
+
+    type PutLocationActivateResponse;
+    type PutLocationActivateErrorResponse;
+    /// Generate a response of the activated location.
+    async fn present_put_location_activate(
+        &self,
+        result: Result<Location, DomainError>,
+    ) -> Result<Self::PutLocationActivateResponse, Self::PutLocationActivateErrorResponse>;
 }
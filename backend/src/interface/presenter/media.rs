@@ -1,2 +1,3 @@
 pub mod converter;
+pub mod media_impl;
 pub mod schema;
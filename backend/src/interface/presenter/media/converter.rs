@@ -1,17 +1,20 @@
 use crate::domain::media::{
     media::{Media, MediaStatus},
-    media_content::{image::image::Image, media_content::MediaContent},
+    media_content::{
+        image::image::Image, media_content::MediaContent, model3d::model3d::Model3d,
+        video::video::Video,
+    },
+    staged_upload::staged_upload::StagedUploadTarget,
 };
 
-use super::schema::{ImageSchema, MediaContentSchema, MediaSchema, MediaStatusEnum};
+use super::schema::{
+    BoundingBoxSchema, ImageSchema, MediaContentSchema, MediaSchema, MediaStatusEnum,
+    Model3dSchema, Model3dSourceSchema, StagedUploadParameterSchema, StagedUploadTargetSchema,
+    VideoSchema, VideoSourceSchema,
+};
 
 impl From<Media> for MediaSchema {
     fn from(media: Media) -> Self {
-        let image = match media.content() {
-            Some(MediaContent::Image(image)) => Some(image),
-            None => None,
-        };
-
         MediaSchema {
             id: media.id().to_string(),
             name: media.name().to_owned(),
@@ -20,15 +23,29 @@ impl From<Media> for MediaSchema {
                 MediaStatus::Inactive => MediaStatusEnum::Inactive,
                 MediaStatus::InPreparation => MediaStatusEnum::InPreparation,
             },
-            content: Some(MediaContentSchema {
-                image: image.map(|image| ImageSchema::from(image)),
-            }),
+            content: media.content().as_ref().map(MediaContentSchema::from),
             created_at: media.created_at().to_owned(),
             updated_at: media.updated_at().to_owned(),
         }
     }
 }
 
+impl From<&MediaContent> for MediaContentSchema {
+    fn from(content: &MediaContent) -> Self {
+        let mut schema = MediaContentSchema {
+            image: None,
+            video: None,
+            model3d: None,
+        };
+        match content {
+            MediaContent::Image(image) => schema.image = Some(ImageSchema::from(image)),
+            MediaContent::Video(video) => schema.video = Some(VideoSchema::from(video)),
+            MediaContent::Model3d(model3d) => schema.model3d = Some(Model3dSchema::from(model3d)),
+        }
+        schema
+    }
+}
+
 impl From<&Image> for ImageSchema {
     fn from(image: &Image) -> Self {
         ImageSchema {
@@ -41,3 +58,75 @@ impl From<&Image> for ImageSchema {
         }
     }
 }
+
+impl From<&Video> for VideoSchema {
+    fn from(video: &Video) -> Self {
+        VideoSchema {
+            id: video.id().to_string(),
+            alt: video.alt().to_owned(),
+            sources: video
+                .sources()
+                .iter()
+                .map(|source| VideoSourceSchema {
+                    src: source.src().value().to_string(),
+                    mime_type: source.mime_type().to_string(),
+                    width: *source.width(),
+                    height: *source.height(),
+                })
+                .collect(),
+            duration_ms: video.duration_ms().to_owned(),
+            preview_src: video
+                .preview_src()
+                .as_ref()
+                .map(|src| src.value().to_string()),
+        }
+    }
+}
+
+impl From<&Model3d> for Model3dSchema {
+    fn from(model3d: &Model3d) -> Self {
+        Model3dSchema {
+            id: model3d.id().to_string(),
+            alt: model3d.alt().to_owned(),
+            sources: model3d
+                .sources()
+                .iter()
+                .map(|source| Model3dSourceSchema {
+                    src: source.src().value().to_string(),
+                    mime_type: source.mime_type().to_string(),
+                    format: source.format().to_string(),
+                    file_size: source.file_size().to_owned(),
+                })
+                .collect(),
+            bounding_box: model3d
+                .bounding_box()
+                .as_ref()
+                .map(|bounding_box| BoundingBoxSchema {
+                    x: *bounding_box.x(),
+                    y: *bounding_box.y(),
+                    z: *bounding_box.z(),
+                }),
+            preview_src: model3d
+                .preview_src()
+                .as_ref()
+                .map(|src| src.value().to_string()),
+        }
+    }
+}
+
+impl From<StagedUploadTarget> for StagedUploadTargetSchema {
+    fn from(target: StagedUploadTarget) -> Self {
+        StagedUploadTargetSchema {
+            url: target.url().value().to_string(),
+            resource_url: target.resource_url().value().to_string(),
+            parameters: target
+                .parameters()
+                .iter()
+                .map(|parameter| StagedUploadParameterSchema {
+                    name: parameter.name().to_string(),
+                    value: parameter.value().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
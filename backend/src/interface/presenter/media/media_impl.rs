@@ -0,0 +1,263 @@
+use actix_web::web::{self, Json};
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::error::DomainError,
+        media::{
+            media::{Id as MediaId, Media},
+            staged_upload::staged_upload::StagedUploadTarget,
+        },
+    },
+    interface::presenter::media_presenter_interface::MediaPresenter,
+};
+
+use super::schema::{
+    DeleteProductMediaErrorResponse, DeleteProductMediaResponse, GetProductMediaErrorResponse,
+    GetProductMediaResponse, PostProductMediaErrorResponse, PostProductMediaReorderErrorResponse,
+    PostProductMediaReorderResponse, PostProductMediaResponse,
+    PostProductMediaStagedUploadErrorResponse, PostProductMediaStagedUploadResponse,
+    PutProductMediaErrorResponse, PutProductMediaFeaturedErrorResponse,
+    PutProductMediaFeaturedResponse, PutProductMediaResponse,
+};
+
+/// Generate a response schema for the media.
+pub struct MediaPresenterImpl;
+impl MediaPresenterImpl {
+    pub fn new() -> Self {
+        MediaPresenterImpl
+    }
+}
+
+impl Default for MediaPresenterImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MediaPresenter for MediaPresenterImpl {
+    type GetProductMediaResponse = Json<GetProductMediaResponse>;
+    type GetProductMediaErrorResponse = GetProductMediaErrorResponse;
+    async fn present_get_product_media(
+        &self,
+        result: Result<Vec<Media>, DomainError>,
+    ) -> Result<Self::GetProductMediaResponse, Self::GetProductMediaErrorResponse> {
+        Ok(web::Json(GetProductMediaResponse {
+            media: result?.into_iter().map(|m| m.into()).collect(),
+        }))
+    }
+
+    type PostProductMediaStagedUploadResponse = Json<PostProductMediaStagedUploadResponse>;
+    type PostProductMediaStagedUploadErrorResponse = PostProductMediaStagedUploadErrorResponse;
+    async fn present_post_product_media_staged_upload(
+        &self,
+        result: Result<StagedUploadTarget, DomainError>,
+    ) -> Result<
+        Self::PostProductMediaStagedUploadResponse,
+        Self::PostProductMediaStagedUploadErrorResponse,
+    > {
+        Ok(web::Json(PostProductMediaStagedUploadResponse {
+            staged_upload: result?.into(),
+        }))
+    }
+
+    type PostProductMediaResponse = Json<PostProductMediaResponse>;
+    type PostProductMediaErrorResponse = PostProductMediaErrorResponse;
+    async fn present_post_product_media(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PostProductMediaResponse, Self::PostProductMediaErrorResponse> {
+        Ok(web::Json(PostProductMediaResponse {
+            media: result?.into(),
+        }))
+    }
+
+    type PutProductMediaResponse = Json<PutProductMediaResponse>;
+    type PutProductMediaErrorResponse = PutProductMediaErrorResponse;
+    async fn present_put_product_media(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PutProductMediaResponse, Self::PutProductMediaErrorResponse> {
+        Ok(web::Json(PutProductMediaResponse {
+            media: result?.into(),
+        }))
+    }
+
+    type PostProductMediaReorderResponse = Json<PostProductMediaReorderResponse>;
+    type PostProductMediaReorderErrorResponse = PostProductMediaReorderErrorResponse;
+    async fn present_post_product_media_reorder(
+        &self,
+        result: Result<Vec<Media>, DomainError>,
+    ) -> Result<Self::PostProductMediaReorderResponse, Self::PostProductMediaReorderErrorResponse>
+    {
+        Ok(web::Json(PostProductMediaReorderResponse {
+            media: result?.into_iter().map(|m| m.into()).collect(),
+        }))
+    }
+
+    type DeleteProductMediaResponse = Json<DeleteProductMediaResponse>;
+    type DeleteProductMediaErrorResponse = DeleteProductMediaErrorResponse;
+    async fn present_delete_product_media(
+        &self,
+        result: Result<MediaId, DomainError>,
+    ) -> Result<Self::DeleteProductMediaResponse, Self::DeleteProductMediaErrorResponse> {
+        Ok(web::Json(DeleteProductMediaResponse {
+            id: result?.to_string(),
+        }))
+    }
+
+    type PutProductMediaFeaturedResponse = Json<PutProductMediaFeaturedResponse>;
+    type PutProductMediaFeaturedErrorResponse = PutProductMediaFeaturedErrorResponse;
+    async fn present_put_product_media_featured(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PutProductMediaFeaturedResponse, Self::PutProductMediaFeaturedErrorResponse>
+    {
+        Ok(web::Json(PutProductMediaFeaturedResponse {
+            media: result?.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::media::{
+            src::src::Src,
+            staged_upload::staged_upload::{StagedUploadParameter, StagedUploadTarget},
+        },
+        interface::mock::domain_mock::mock_media,
+    };
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_present_get_product_media_success() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_get_product_media(Ok(mock_media(3)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.media.len(), 3);
+        assert_eq!(result.media[0].id, "0");
+        assert_eq!(result.media[2].id, "2");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_product_media_service_unavailable() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_get_product_media(Err(DomainError::QueryError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetProductMediaErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_product_media_staged_upload_success() {
+        let presenter = MediaPresenterImpl::new();
+        let target = StagedUploadTarget::new(
+            Src::new("https://example.com/upload").unwrap(),
+            Src::new("https://example.com/resource").unwrap(),
+            vec![StagedUploadParameter::new("key", "value")],
+        )
+        .unwrap();
+
+        let result = presenter
+            .present_post_product_media_staged_upload(Ok(target))
+            .await
+            .unwrap();
+
+        assert_eq!(result.staged_upload.url, "https://example.com/upload");
+        assert_eq!(
+            result.staged_upload.resource_url,
+            "https://example.com/resource"
+        );
+        assert_eq!(result.staged_upload.parameters[0].name, "key");
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_product_media_staged_upload_bad_request() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_post_product_media_staged_upload(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostProductMediaStagedUploadErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_product_media_success() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_post_product_media(Ok(mock_media(1).remove(0)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.media.id, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_product_media_not_found() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_put_product_media(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutProductMediaErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_product_media_reorder_success() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_post_product_media_reorder(Ok(mock_media(2)))
+            .await
+            .unwrap();
+
+        assert_eq!(result.media.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_present_delete_product_media_success() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_delete_product_media(Ok("0".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.id, "0");
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_product_media_featured_bad_request() {
+        let presenter = MediaPresenterImpl::new();
+
+        let result = presenter
+            .present_put_product_media_featured(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutProductMediaFeaturedErrorResponse::BadRequest)
+        ));
+    }
+}
@@ -1,14 +1,21 @@
+use actix_web::{HttpResponse, ResponseError};
+use derive_more::{Display, Error};
+
+use actix_http::StatusCode;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::error::error::DomainError;
+use crate::{define_error_response, interface::presenter::common::exception::ErrorResponseBuilder};
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MediaSchema {
     pub id: String,
     pub name: Option<String>,
     pub status: MediaStatusEnum,
     pub content: Option<MediaContentSchema>,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,9 +25,12 @@ pub enum MediaStatusEnum {
     InPreparation,
 }
 
+/// Only the field for the type of the content is set.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MediaContentSchema {
     pub image: Option<ImageSchema>,
+    pub video: Option<VideoSchema>,
+    pub model3d: Option<Model3dSchema>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -29,3 +39,106 @@ pub struct ImageSchema {
     pub alt: Option<String>,
     pub src: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VideoSchema {
+    pub id: String,
+    pub alt: Option<String>,
+    pub sources: Vec<VideoSourceSchema>,
+    pub duration_ms: Option<u32>,
+    pub preview_src: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VideoSourceSchema {
+    pub src: String,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Model3dSchema {
+    pub id: String,
+    pub alt: Option<String>,
+    pub sources: Vec<Model3dSourceSchema>,
+    pub bounding_box: Option<BoundingBoxSchema>,
+    pub preview_src: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Model3dSourceSchema {
+    pub src: String,
+    pub mime_type: String,
+    pub format: String,
+    pub file_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BoundingBoxSchema {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StagedUploadTargetSchema {
+    pub(super) url: String,
+    pub(super) resource_url: String,
+    pub(super) parameters: Vec<StagedUploadParameterSchema>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StagedUploadParameterSchema {
+    pub(super) name: String,
+    pub(super) value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetProductMediaResponse {
+    pub media: Vec<MediaSchema>,
+}
+
+define_error_response!(GetProductMediaErrorResponse, "Media");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostProductMediaStagedUploadResponse {
+    pub staged_upload: StagedUploadTargetSchema,
+}
+
+define_error_response!(PostProductMediaStagedUploadErrorResponse, "StagedUpload");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostProductMediaResponse {
+    pub media: MediaSchema,
+}
+
+define_error_response!(PostProductMediaErrorResponse, "Media");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutProductMediaResponse {
+    pub media: MediaSchema,
+}
+
+define_error_response!(PutProductMediaErrorResponse, "Media");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostProductMediaReorderResponse {
+    pub media: Vec<MediaSchema>,
+}
+
+define_error_response!(PostProductMediaReorderErrorResponse, "Media");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteProductMediaResponse {
+    pub id: String,
+}
+
+define_error_response!(DeleteProductMediaErrorResponse, "Media");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutProductMediaFeaturedResponse {
+    pub media: MediaSchema,
+}
+
+define_error_response!(PutProductMediaFeaturedErrorResponse, "Media");
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError,
+    media::{
+        media::{Id as MediaId, Media},
+        staged_upload::staged_upload::StagedUploadTarget,
+    },
+};
+
+/// Interface to generate response schema for media.
+#[async_trait]
+pub trait MediaPresenter {
+    type GetProductMediaResponse;
+    type GetProductMediaErrorResponse;
+    /// Generate a list response of product media.
+    async fn present_get_product_media(
+        &self,
+        result: Result<Vec<Media>, DomainError>,
+    ) -> Result<Self::GetProductMediaResponse, Self::GetProductMediaErrorResponse>;
+
+    type PostProductMediaStagedUploadResponse;
+    type PostProductMediaStagedUploadErrorResponse;
+    /// Generate a response of the target to upload a file to.
+    async fn present_post_product_media_staged_upload(
+        &self,
+        result: Result<StagedUploadTarget, DomainError>,
+    ) -> Result<
+        Self::PostProductMediaStagedUploadResponse,
+        Self::PostProductMediaStagedUploadErrorResponse,
+    >;
+
+    type PostProductMediaResponse;
+    type PostProductMediaErrorResponse;
+    /// Generate a response of the created product media.
+    async fn present_post_product_media(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PostProductMediaResponse, Self::PostProductMediaErrorResponse>;
+
+    type PutProductMediaResponse;
+    type PutProductMediaErrorResponse;
+    /// Generate a response of the updated product media.
+    async fn present_put_product_media(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PutProductMediaResponse, Self::PutProductMediaErrorResponse>;
+
+    type PostProductMediaReorderResponse;
+    type PostProductMediaReorderErrorResponse;
+    /// Generate a list response of the reordered product media.
+    async fn present_post_product_media_reorder(
+        &self,
+        result: Result<Vec<Media>, DomainError>,
+    ) -> Result<Self::PostProductMediaReorderResponse, Self::PostProductMediaReorderErrorResponse>;
+
+    type DeleteProductMediaResponse;
+    type DeleteProductMediaErrorResponse;
+    /// Generate a response of the deleted product media id.
+    async fn present_delete_product_media(
+        &self,
+        result: Result<MediaId, DomainError>,
+    ) -> Result<Self::DeleteProductMediaResponse, Self::DeleteProductMediaErrorResponse>;
+
+    type PutProductMediaFeaturedResponse;
+    type PutProductMediaFeaturedErrorResponse;
+    /// Generate a response of the featured product media.
+    async fn present_put_product_media_featured(
+        &self,
+        result: Result<Media, DomainError>,
+    ) -> Result<Self::PutProductMediaFeaturedResponse, Self::PutProductMediaFeaturedErrorResponse>;
+}
@@ -0,0 +1,3 @@
+pub mod converter;
+pub mod order_impl;
+pub mod schema;
@@ -0,0 +1,79 @@
+use crate::domain::order::order::{FinancialStatus, FulfillmentStatus, Order, OrderCancelReason};
+
+use super::schema::{
+    FinancialStatusEnum, FulfillmentStatusEnum, OrderCancelReasonEnum, OrderSchema,
+};
+
+impl From<Order> for OrderSchema {
+    fn from(order: Order) -> Self {
+        OrderSchema {
+            id: order.id().to_string(),
+            name: order.name().to_string(),
+            customer_id: order.customer_id().as_ref().map(|id| id.to_string()),
+            billing_address: order.billing_address().to_owned().map(|a| a.into()),
+            shipping_address: order.shipping_address().to_owned().map(|a| a.into()),
+            note: order.note().as_ref().map(|note| note.to_string()),
+            line_items: order
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.into())
+                .collect(),
+            financial_status: order.financial_status().to_owned().map(|s| s.into()),
+            fulfillment_status: order.fulfillment_status().to_owned().into(),
+            subtotal_price_set: order.subtotal_price_set().to_owned().into(),
+            taxes_included: *order.taxes_included(),
+            total_tax_set: order.total_tax_set().to_owned().into(),
+            total_discounts_set: order.total_discounts_set().to_owned().into(),
+            total_shipping_price_set: order.total_shipping_price_set().to_owned().into(),
+            total_price_set: order.total_price_set().to_owned().into(),
+            presentment_currency_code: order.presentment_currency_code().to_owned().into(),
+            cancel_reason: order.cancel_reason().to_owned().map(|r| r.into()),
+            processed_at: *order.processed_at(),
+            canceled_at: *order.canceled_at(),
+            created_at: *order.created_at(),
+            updated_at: *order.updated_at(),
+        }
+    }
+}
+
+impl From<FinancialStatus> for FinancialStatusEnum {
+    fn from(status: FinancialStatus) -> Self {
+        match status {
+            FinancialStatus::Pending => FinancialStatusEnum::Pending,
+            FinancialStatus::Authorized => FinancialStatusEnum::Authorized,
+            FinancialStatus::PartiallyPaid => FinancialStatusEnum::PartiallyPaid,
+            FinancialStatus::Paid => FinancialStatusEnum::Paid,
+            FinancialStatus::PartiallyRefunded => FinancialStatusEnum::PartiallyRefunded,
+            FinancialStatus::Refunded => FinancialStatusEnum::Refunded,
+            FinancialStatus::Voided => FinancialStatusEnum::Voided,
+            FinancialStatus::Expired => FinancialStatusEnum::Expired,
+        }
+    }
+}
+
+impl From<FulfillmentStatus> for FulfillmentStatusEnum {
+    fn from(status: FulfillmentStatus) -> Self {
+        match status {
+            FulfillmentStatus::Unfulfilled => FulfillmentStatusEnum::Unfulfilled,
+            FulfillmentStatus::PartiallyFulfilled => FulfillmentStatusEnum::PartiallyFulfilled,
+            FulfillmentStatus::Fulfilled => FulfillmentStatusEnum::Fulfilled,
+            FulfillmentStatus::Restocked => FulfillmentStatusEnum::Restocked,
+            FulfillmentStatus::InProgress => FulfillmentStatusEnum::InProgress,
+            FulfillmentStatus::OnHold => FulfillmentStatusEnum::OnHold,
+            FulfillmentStatus::Scheduled => FulfillmentStatusEnum::Scheduled,
+        }
+    }
+}
+
+impl From<OrderCancelReason> for OrderCancelReasonEnum {
+    fn from(reason: OrderCancelReason) -> Self {
+        match reason {
+            OrderCancelReason::Customer => OrderCancelReasonEnum::Customer,
+            OrderCancelReason::Declined => OrderCancelReasonEnum::Declined,
+            OrderCancelReason::Fraud => OrderCancelReasonEnum::Fraud,
+            OrderCancelReason::Inventory => OrderCancelReasonEnum::Inventory,
+            OrderCancelReason::Staff => OrderCancelReasonEnum::Staff,
+            OrderCancelReason::Other => OrderCancelReasonEnum::Other,
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use actix_web::web::{self, Json};
+use async_trait::async_trait;
+
+use crate::{
+    domain::{error::error::DomainError, order::order::Order},
+    interface::presenter::order_presenter_interface::OrderPresenter,
+    usecase::repository::order_repository_interface::OrderPage,
+};
+
+use super::schema::{
+    GetOrderErrorResponse, GetOrderResponse, GetOrdersErrorResponse, GetOrdersResponse,
+    OrderSchema, PageInfoSchema, PostOrderCancelErrorResponse, PostOrderCancelResponse,
+};
+
+/// Generate a response schema for the orders.
+pub struct OrderPresenterImpl;
+impl OrderPresenterImpl {
+    pub fn new() -> Self {
+        OrderPresenterImpl
+    }
+}
+
+impl Default for OrderPresenterImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OrderPresenter for OrderPresenterImpl {
+    type GetOrdersResponse = Json<GetOrdersResponse>;
+    type GetOrdersErrorResponse = GetOrdersErrorResponse;
+    async fn present_get_orders(
+        &self,
+        result: Result<OrderPage, DomainError>,
+    ) -> Result<Self::GetOrdersResponse, Self::GetOrdersErrorResponse> {
+        let page = result?;
+        if page.orders.is_empty() {
+            return Err(GetOrdersErrorResponse::NotFound {
+                object_name: "Order".to_string(),
+            });
+        }
+
+        let response: Vec<OrderSchema> =
+            page.orders.into_iter().map(|order| order.into()).collect();
+
+        Ok(web::Json(GetOrdersResponse {
+            orders: response,
+            page_info: PageInfoSchema {
+                has_next_page: page.has_next_page,
+                end_cursor: page.end_cursor,
+            },
+        }))
+    }
+
+    type GetOrderResponse = Json<GetOrderResponse>;
+    type GetOrderErrorResponse = GetOrderErrorResponse;
+    async fn present_get_order(
+        &self,
+        result: Result<Order, DomainError>,
+    ) -> Result<Self::GetOrderResponse, Self::GetOrderErrorResponse> {
+        Ok(web::Json(GetOrderResponse {
+            order: result?.into(),
+        }))
+    }
+
+    type PostOrderCancelResponse = Json<PostOrderCancelResponse>;
+    type PostOrderCancelErrorResponse = PostOrderCancelErrorResponse;
+    async fn present_post_order_cancel(
+        &self,
+        result: Result<Order, DomainError>,
+    ) -> Result<Self::PostOrderCancelResponse, Self::PostOrderCancelErrorResponse> {
+        Ok(web::Json(PostOrderCancelResponse {
+            order: result?.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::order::order::OrderCancelReason,
+        interface::{
+            mock::domain_mock::mock_orders, presenter::order::schema::OrderCancelReasonEnum,
+        },
+    };
+
+    use super::*;
+
+    fn mock_order_page(count: usize) -> OrderPage {
+        OrderPage {
+            orders: mock_orders(count),
+            has_next_page: true,
+            end_cursor: Some("cursor".to_string()),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_orders_success() {
+        let presenter = OrderPresenterImpl::new();
+        let page = mock_order_page(10);
+
+        let result = presenter.present_get_orders(Ok(page)).await.unwrap();
+
+        assert_eq!(result.orders.len(), 10);
+        assert!(result.page_info.has_next_page);
+        assert_eq!(result.page_info.end_cursor, Some("cursor".to_string()));
+
+        assert_eq!(result.orders[0].id, "0");
+        assert_eq!(result.orders[0].name, "#1000");
+        assert_eq!(result.orders[0].total_price_set.amount, 100.0);
+
+        assert_eq!(result.orders[9].id, "9");
+        assert_eq!(result.orders[9].name, "#1009");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_orders_not_found() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter.present_get_orders(Ok(mock_order_page(0))).await;
+
+        assert!(matches!(
+            result,
+            Err(GetOrdersErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_orders_bad_request() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_get_orders(Err(DomainError::AuthorizationError))
+            .await;
+
+        assert!(matches!(result, Err(GetOrdersErrorResponse::BadRequest)));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_orders_service_unavailable() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_get_orders(Err(DomainError::SystemError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetOrdersErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_order_success() {
+        let presenter = OrderPresenterImpl::new();
+        let order = mock_orders(1).remove(0);
+
+        let result = presenter.present_get_order(Ok(order)).await.unwrap();
+
+        assert_eq!(result.order.id, "0");
+        assert_eq!(result.order.name, "#1000");
+        assert_eq!(result.order.line_items.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_order_not_found() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_get_order(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetOrderErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_order_service_unavailable() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_get_order(Err(DomainError::SystemError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetOrderErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_order_cancel_success() {
+        let presenter = OrderPresenterImpl::new();
+        let mut order = mock_orders(1).remove(0);
+        order
+            .cancel(OrderCancelReason::Customer)
+            .expect("Failed to cancel mock order");
+
+        let result = presenter
+            .present_post_order_cancel(Ok(order))
+            .await
+            .unwrap();
+
+        assert_eq!(result.order.id, "0");
+        assert!(matches!(
+            result.order.cancel_reason,
+            Some(OrderCancelReasonEnum::Customer)
+        ));
+        assert!(result.order.canceled_at.is_some());
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_order_cancel_bad_request() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_post_order_cancel(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostOrderCancelErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_order_cancel_service_unavailable() {
+        let presenter = OrderPresenterImpl::new();
+
+        let result = presenter
+            .present_post_order_cancel(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostOrderCancelErrorResponse::ServiceUnavailable)
+        ));
+    }
+}
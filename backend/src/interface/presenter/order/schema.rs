@@ -0,0 +1,103 @@
+use actix_web::{HttpResponse, ResponseError};
+use derive_more::{Display, Error};
+
+use actix_http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::error::DomainError;
+use crate::{
+    define_error_response,
+    interface::presenter::{
+        address::schema::AddressSchema,
+        common::exception::ErrorResponseBuilder,
+        line_item::schema::LineItemSchema,
+        money::schema::{CustomerStatusEnum, MoneySchema},
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrderSchema {
+    pub(super) id: String,
+    pub(super) name: String,
+    pub(super) customer_id: Option<String>,
+    pub(super) billing_address: Option<AddressSchema>,
+    pub(super) shipping_address: Option<AddressSchema>,
+    pub(super) note: Option<String>,
+    pub(super) line_items: Vec<LineItemSchema>,
+    pub(super) financial_status: Option<FinancialStatusEnum>,
+    pub(super) fulfillment_status: FulfillmentStatusEnum,
+    pub(super) subtotal_price_set: MoneySchema,
+    pub(super) taxes_included: bool,
+    pub(super) total_tax_set: MoneySchema,
+    pub(super) total_discounts_set: MoneySchema,
+    pub(super) total_shipping_price_set: MoneySchema,
+    pub(super) total_price_set: MoneySchema,
+    pub(super) presentment_currency_code: CustomerStatusEnum,
+    pub(super) cancel_reason: Option<OrderCancelReasonEnum>,
+    pub(super) processed_at: DateTime<Utc>,
+    pub(super) canceled_at: Option<DateTime<Utc>>,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FinancialStatusEnum {
+    Pending,
+    Authorized,
+    PartiallyPaid,
+    Paid,
+    PartiallyRefunded,
+    Refunded,
+    Voided,
+    Expired,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FulfillmentStatusEnum {
+    Unfulfilled,
+    PartiallyFulfilled,
+    Fulfilled,
+    Restocked,
+    InProgress,
+    OnHold,
+    Scheduled,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum OrderCancelReasonEnum {
+    Customer,
+    Declined,
+    Fraud,
+    Inventory,
+    Staff,
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PageInfoSchema {
+    pub(super) has_next_page: bool,
+    pub(super) end_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetOrdersResponse {
+    pub orders: Vec<OrderSchema>,
+    pub page_info: PageInfoSchema,
+}
+
+define_error_response!(GetOrdersErrorResponse, "Order");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetOrderResponse {
+    pub order: OrderSchema,
+}
+
+define_error_response!(GetOrderErrorResponse, "Order");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostOrderCancelResponse {
+    pub order: OrderSchema,
+}
+
+define_error_response!(PostOrderCancelErrorResponse, "Order");
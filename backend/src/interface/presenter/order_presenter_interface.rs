@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{error::error::DomainError, order::order::Order},
+    usecase::repository::order_repository_interface::OrderPage,
+};
+
+/// Interface to generate response schema for orders.
+#[async_trait]
+pub trait OrderPresenter {
+    type GetOrdersResponse;
+    type GetOrdersErrorResponse;
+    /// Generate a paginated list response of order information.
+    async fn present_get_orders(
+        &self,
+        result: Result<OrderPage, DomainError>,
+    ) -> Result<Self::GetOrdersResponse, Self::GetOrdersErrorResponse>;
+
+    type GetOrderResponse;
+    type GetOrderErrorResponse;
+    /// Generate a response of order information.
+    async fn present_get_order(
+        &self,
+        result: Result<Order, DomainError>,
+    ) -> Result<Self::GetOrderResponse, Self::GetOrderErrorResponse>;
+
+    type PostOrderCancelResponse;
+    type PostOrderCancelErrorResponse;
+    /// Generate a response of the canceled order.
+    async fn present_post_order_cancel(
+        &self,
+        result: Result<Order, DomainError>,
+    ) -> Result<Self::PostOrderCancelResponse, Self::PostOrderCancelErrorResponse>;
+}
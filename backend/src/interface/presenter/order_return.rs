@@ -0,0 +1,3 @@
+pub mod converter;
+pub mod order_return_impl;
+pub mod schema;
@@ -0,0 +1,79 @@
+use crate::domain::order_return::{
+    order_return::{OrderReturn, ReturnDeclineReason, ReturnStatus},
+    return_line_item::return_line_item::{ReturnLineItem, ReturnReason},
+};
+
+use super::schema::{
+    ReturnDeclineReasonEnum, ReturnLineItemSchema, ReturnReasonEnum, ReturnSchema, ReturnStatusEnum,
+};
+
+impl From<OrderReturn> for ReturnSchema {
+    fn from(order_return: OrderReturn) -> Self {
+        ReturnSchema {
+            id: order_return.id().to_string(),
+            order_id: order_return.order_id().to_string(),
+            status: order_return.status().to_owned().into(),
+            line_items: order_return
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.into())
+                .collect(),
+            decline_reason: order_return
+                .decline_reason()
+                .to_owned()
+                .map(|reason| reason.into()),
+            decline_note: order_return.decline_note().to_owned(),
+        }
+    }
+}
+
+impl From<&ReturnLineItem> for ReturnLineItemSchema {
+    fn from(line_item: &ReturnLineItem) -> Self {
+        ReturnLineItemSchema {
+            id: line_item.id().to_string(),
+            fulfillment_line_item_id: line_item.fulfillment_line_item_id().to_string(),
+            quantity: *line_item.quantity(),
+            reason: line_item.reason().to_owned().into(),
+            customer_note: line_item.customer_note().to_owned(),
+        }
+    }
+}
+
+impl From<ReturnStatus> for ReturnStatusEnum {
+    fn from(status: ReturnStatus) -> Self {
+        match status {
+            ReturnStatus::Requested => ReturnStatusEnum::Requested,
+            ReturnStatus::Open => ReturnStatusEnum::Open,
+            ReturnStatus::Declined => ReturnStatusEnum::Declined,
+            ReturnStatus::Closed => ReturnStatusEnum::Closed,
+            ReturnStatus::Canceled => ReturnStatusEnum::Canceled,
+        }
+    }
+}
+
+impl From<ReturnReason> for ReturnReasonEnum {
+    fn from(reason: ReturnReason) -> Self {
+        match reason {
+            ReturnReason::Color => ReturnReasonEnum::Color,
+            ReturnReason::Defective => ReturnReasonEnum::Defective,
+            ReturnReason::NotAsDescribed => ReturnReasonEnum::NotAsDescribed,
+            ReturnReason::SizeTooLarge => ReturnReasonEnum::SizeTooLarge,
+            ReturnReason::SizeTooSmall => ReturnReasonEnum::SizeTooSmall,
+            ReturnReason::Style => ReturnReasonEnum::Style,
+            ReturnReason::Unwanted => ReturnReasonEnum::Unwanted,
+            ReturnReason::WrongItem => ReturnReasonEnum::WrongItem,
+            ReturnReason::Other => ReturnReasonEnum::Other,
+            ReturnReason::Unknown => ReturnReasonEnum::Unknown,
+        }
+    }
+}
+
+impl From<ReturnDeclineReason> for ReturnDeclineReasonEnum {
+    fn from(reason: ReturnDeclineReason) -> Self {
+        match reason {
+            ReturnDeclineReason::FinalSale => ReturnDeclineReasonEnum::FinalSale,
+            ReturnDeclineReason::ReturnPeriodEnded => ReturnDeclineReasonEnum::ReturnPeriodEnded,
+            ReturnDeclineReason::Other => ReturnDeclineReasonEnum::Other,
+        }
+    }
+}
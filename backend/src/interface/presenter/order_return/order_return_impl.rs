@@ -0,0 +1,192 @@
+use actix_web::web::{self, Json};
+use async_trait::async_trait;
+
+use crate::{
+    domain::{error::error::DomainError, order_return::order_return::OrderReturn},
+    interface::presenter::order_return_presenter_interface::OrderReturnPresenter,
+};
+
+use super::schema::{
+    GetReturnsErrorResponse, GetReturnsResponse, PostReturnErrorResponse, PostReturnResponse,
+    PutReturnApproveErrorResponse, PutReturnApproveResponse, PutReturnDeclineErrorResponse,
+    PutReturnDeclineResponse, ReturnSchema,
+};
+
+/// Generate a response schema for the returns.
+pub struct OrderReturnPresenterImpl;
+impl OrderReturnPresenterImpl {
+    pub fn new() -> Self {
+        OrderReturnPresenterImpl
+    }
+}
+
+impl Default for OrderReturnPresenterImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl OrderReturnPresenter for OrderReturnPresenterImpl {
+    type GetReturnsResponse = Json<GetReturnsResponse>;
+    type GetReturnsErrorResponse = GetReturnsErrorResponse;
+    async fn present_get_returns(
+        &self,
+        result: Result<Vec<OrderReturn>, DomainError>,
+    ) -> Result<Self::GetReturnsResponse, Self::GetReturnsErrorResponse> {
+        let returns = result?;
+        if returns.is_empty() {
+            return Err(GetReturnsErrorResponse::NotFound {
+                object_name: "Return".to_string(),
+            });
+        }
+
+        let response: Vec<ReturnSchema> = returns
+            .into_iter()
+            .map(|order_return| order_return.into())
+            .collect();
+
+        Ok(web::Json(GetReturnsResponse { returns: response }))
+    }
+
+    type PostReturnResponse = Json<PostReturnResponse>;
+    type PostReturnErrorResponse = PostReturnErrorResponse;
+    async fn present_post_return(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PostReturnResponse, Self::PostReturnErrorResponse> {
+        Ok(web::Json(PostReturnResponse {
+            order_return: result?.into(),
+        }))
+    }
+
+    type PutReturnApproveResponse = Json<PutReturnApproveResponse>;
+    type PutReturnApproveErrorResponse = PutReturnApproveErrorResponse;
+    async fn present_put_return_approve(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PutReturnApproveResponse, Self::PutReturnApproveErrorResponse> {
+        Ok(web::Json(PutReturnApproveResponse {
+            order_return: result?.into(),
+        }))
+    }
+
+    type PutReturnDeclineResponse = Json<PutReturnDeclineResponse>;
+    type PutReturnDeclineErrorResponse = PutReturnDeclineErrorResponse;
+    async fn present_put_return_decline(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PutReturnDeclineResponse, Self::PutReturnDeclineErrorResponse> {
+        Ok(web::Json(PutReturnDeclineResponse {
+            order_return: result?.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::{
+        mock::domain_mock::mock_order_returns, presenter::order_return::schema::ReturnStatusEnum,
+    };
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_present_get_returns_success() {
+        let presenter = OrderReturnPresenterImpl::new();
+        let returns = mock_order_returns(5);
+
+        let result = presenter.present_get_returns(Ok(returns)).await.unwrap();
+
+        assert_eq!(result.returns.len(), 5);
+
+        assert_eq!(result.returns[0].id, "0");
+        assert_eq!(result.returns[0].order_id, "0");
+        assert!(matches!(
+            result.returns[0].status,
+            ReturnStatusEnum::Requested
+        ));
+        assert_eq!(result.returns[0].line_items[0].quantity, 1);
+
+        assert_eq!(result.returns[4].id, "4");
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_returns_not_found() {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let result = presenter.present_get_returns(Ok(vec![])).await;
+
+        assert!(matches!(
+            result,
+            Err(GetReturnsErrorResponse::NotFound { .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_get_returns_service_unavailable() {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let result = presenter
+            .present_get_returns(Err(DomainError::QueryError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(GetReturnsErrorResponse::ServiceUnavailable)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_return_success() {
+        let presenter = OrderReturnPresenterImpl::new();
+        let order_return = mock_order_returns(1).remove(0);
+
+        let result = presenter
+            .present_post_return(Ok(order_return))
+            .await
+            .unwrap();
+
+        assert_eq!(result.order_return.id, "0");
+        assert_eq!(result.order_return.line_items.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_return_bad_request() {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let result = presenter
+            .present_post_return(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(result, Err(PostReturnErrorResponse::BadRequest)));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_return_approve_bad_request() {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let result = presenter
+            .present_put_return_approve(Err(DomainError::InvalidStateTransition))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutReturnApproveErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_put_return_decline_not_found() {
+        let presenter = OrderReturnPresenterImpl::new();
+
+        let result = presenter
+            .present_put_return_decline(Err(DomainError::NotFound))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PutReturnDeclineErrorResponse::NotFound { .. })
+        ));
+    }
+}
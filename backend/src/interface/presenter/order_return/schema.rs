@@ -0,0 +1,88 @@
+use actix_web::{HttpResponse, ResponseError};
+use derive_more::{Display, Error};
+
+use actix_http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::error::DomainError;
+use crate::{define_error_response, interface::presenter::common::exception::ErrorResponseBuilder};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReturnSchema {
+    pub(super) id: String,
+    pub(super) order_id: String,
+    pub(super) status: ReturnStatusEnum,
+    pub(super) line_items: Vec<ReturnLineItemSchema>,
+    pub(super) decline_reason: Option<ReturnDeclineReasonEnum>,
+    pub(super) decline_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReturnLineItemSchema {
+    pub(super) id: String,
+    pub(super) fulfillment_line_item_id: String,
+    pub(super) quantity: u32,
+    pub(super) reason: ReturnReasonEnum,
+    pub(super) customer_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ReturnStatusEnum {
+    Requested,
+    Open,
+    Declined,
+    Closed,
+    Canceled,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ReturnReasonEnum {
+    Color,
+    Defective,
+    NotAsDescribed,
+    SizeTooLarge,
+    SizeTooSmall,
+    Style,
+    Unwanted,
+    WrongItem,
+    Other,
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ReturnDeclineReasonEnum {
+    FinalSale,
+    ReturnPeriodEnded,
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetReturnsResponse {
+    pub returns: Vec<ReturnSchema>,
+}
+
+define_error_response!(GetReturnsErrorResponse, "Return");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostReturnResponse {
+    #[serde(rename = "return")]
+    pub order_return: ReturnSchema,
+}
+
+define_error_response!(PostReturnErrorResponse, "Return");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutReturnApproveResponse {
+    #[serde(rename = "return")]
+    pub order_return: ReturnSchema,
+}
+
+define_error_response!(PutReturnApproveErrorResponse, "Return");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutReturnDeclineResponse {
+    #[serde(rename = "return")]
+    pub order_return: ReturnSchema,
+}
+
+define_error_response!(PutReturnDeclineErrorResponse, "Return");
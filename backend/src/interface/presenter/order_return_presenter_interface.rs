@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::domain::{error::error::DomainError, order_return::order_return::OrderReturn};
+
+/// Interface to generate response schema for returns.
+#[async_trait]
+pub trait OrderReturnPresenter {
+    type GetReturnsResponse;
+    type GetReturnsErrorResponse;
+    /// Generate a list response of return information.
+    async fn present_get_returns(
+        &self,
+        result: Result<Vec<OrderReturn>, DomainError>,
+    ) -> Result<Self::GetReturnsResponse, Self::GetReturnsErrorResponse>;
+
+    type PostReturnResponse;
+    type PostReturnErrorResponse;
+    /// Generate a response of the requested return.
+    async fn present_post_return(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PostReturnResponse, Self::PostReturnErrorResponse>;
+
+    type PutReturnApproveResponse;
+    type PutReturnApproveErrorResponse;
+    /// Generate a response of the approved return.
+    async fn present_put_return_approve(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PutReturnApproveResponse, Self::PutReturnApproveErrorResponse>;
+
+    type PutReturnDeclineResponse;
+    type PutReturnDeclineErrorResponse;
+    /// Generate a response of the declined return.
+    async fn present_put_return_decline(
+        &self,
+        result: Result<OrderReturn, DomainError>,
+    ) -> Result<Self::PutReturnDeclineResponse, Self::PutReturnDeclineErrorResponse>;
+}
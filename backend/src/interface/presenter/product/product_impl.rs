@@ -6,10 +6,7 @@ use async_trait::async_trait;
 use crate::{
     domain::{
         error::error::DomainError,
-        media::{
-            associated_id::associated_id::AssociatedId, media::Media,
-            media_content::media_content::MediaContent,
-        },
+        media::{associated_id::associated_id::AssociatedId, media::Media},
         product::product::Product,
     },
     interface::presenter::{
@@ -43,12 +40,12 @@ impl ProductPresenter for ProductPresenterImpl {
         let (product, media) = result?;
 
         for medium in media.iter() {
-            let image = match medium.content() {
-                Some(MediaContent::Image(image)) => image,
+            let content = match medium.content() {
+                Some(content) => content,
                 None => continue,
             };
 
-            let _ = match image.associated_id() {
+            let _ = match content.associated_id() {
                 Some(id) if id.clone() != AssociatedId::Product(product.id().clone()) => {
                     Err(DomainError::SystemError)
                 }
@@ -71,15 +68,12 @@ impl ProductPresenter for ProductPresenterImpl {
 
         let mut media_map: HashMap<AssociatedId, Vec<Media>> =
             media.into_iter().fold(HashMap::new(), |mut accum, medium| {
-                let image = match medium.content() {
-                    Some(MediaContent::Image(image)) => Some(image),
-                    None => None,
+                let content = match medium.content() {
+                    Some(content) => content,
+                    None => return accum,
                 };
-                if image.is_none() {
-                    return accum;
-                }
 
-                if let Some(associated_id) = image.unwrap().associated_id() {
+                if let Some(associated_id) = content.associated_id() {
                     accum
                         .entry(associated_id.to_owned())
                         .or_insert_with(Vec::new)
@@ -0,0 +1,3 @@
+pub mod converter;
+pub mod refund_impl;
+pub mod schema;
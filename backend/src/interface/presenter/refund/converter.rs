@@ -0,0 +1,32 @@
+use crate::domain::refund::{refund::Refund, refund_line_item::refund_line_item::RefundLineItem};
+
+use super::schema::{RefundLineItemSchema, RefundSchema};
+
+impl From<Refund> for RefundSchema {
+    fn from(refund: Refund) -> Self {
+        RefundSchema {
+            id: refund.id().to_string(),
+            order_id: refund.order_id().to_string(),
+            note: refund.note().to_owned(),
+            line_items: refund
+                .line_items()
+                .iter()
+                .map(|line_item| line_item.into())
+                .collect(),
+            shipping_set: refund.shipping_set().to_owned().into(),
+            total_refunded_set: refund.total_refunded_set().to_owned().into(),
+            restock_location_id: refund.restock_location_id().to_owned(),
+            created_at: *refund.created_at(),
+        }
+    }
+}
+
+impl From<&RefundLineItem> for RefundLineItemSchema {
+    fn from(line_item: &RefundLineItem) -> Self {
+        RefundLineItemSchema {
+            line_item_id: line_item.line_item_id().to_string(),
+            quantity: *line_item.quantity(),
+            subtotal_set: line_item.subtotal_set().to_owned().into(),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use actix_web::web::{self, Json};
+use async_trait::async_trait;
+
+use crate::{
+    domain::{error::error::DomainError, refund::refund::Refund},
+    interface::presenter::refund_presenter_interface::RefundPresenter,
+};
+
+use super::schema::{
+    PostRefundCalculateErrorResponse, PostRefundCalculateResponse, PostRefundErrorResponse,
+    PostRefundResponse,
+};
+
+/// Generate a response schema for the refunds.
+pub struct RefundPresenterImpl;
+impl RefundPresenterImpl {
+    pub fn new() -> Self {
+        RefundPresenterImpl
+    }
+}
+
+impl Default for RefundPresenterImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl RefundPresenter for RefundPresenterImpl {
+    type PostRefundCalculateResponse = Json<PostRefundCalculateResponse>;
+    type PostRefundCalculateErrorResponse = PostRefundCalculateErrorResponse;
+    async fn present_post_refund_calculate(
+        &self,
+        result: Result<Refund, DomainError>,
+    ) -> Result<Self::PostRefundCalculateResponse, Self::PostRefundCalculateErrorResponse> {
+        Ok(web::Json(PostRefundCalculateResponse {
+            refund: result?.into(),
+        }))
+    }
+
+    type PostRefundResponse = Json<PostRefundResponse>;
+    type PostRefundErrorResponse = PostRefundErrorResponse;
+    async fn present_post_refund(
+        &self,
+        result: Result<Refund, DomainError>,
+    ) -> Result<Self::PostRefundResponse, Self::PostRefundErrorResponse> {
+        Ok(web::Json(PostRefundResponse {
+            refund: result?.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::mock::domain_mock::mock_refunds;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_present_post_refund_calculate_success() {
+        let presenter = RefundPresenterImpl::new();
+        let refund = mock_refunds(1).remove(0);
+
+        let result = presenter
+            .present_post_refund_calculate(Ok(refund))
+            .await
+            .unwrap();
+
+        assert_eq!(result.refund.order_id, "0");
+        assert_eq!(result.refund.line_items.len(), 1);
+        assert_eq!(result.refund.total_refunded_set.amount, 110.0);
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_refund_calculate_bad_request() {
+        let presenter = RefundPresenterImpl::new();
+
+        let result = presenter
+            .present_post_refund_calculate(Err(DomainError::ValidationError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostRefundCalculateErrorResponse::BadRequest)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_refund_success() {
+        let presenter = RefundPresenterImpl::new();
+        let refund = mock_refunds(1).remove(0);
+
+        let result = presenter.present_post_refund(Ok(refund)).await.unwrap();
+
+        assert_eq!(result.refund.id, "0");
+        assert_eq!(result.refund.restock_location_id, Some("0".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_present_post_refund_service_unavailable() {
+        let presenter = RefundPresenterImpl::new();
+
+        let result = presenter
+            .present_post_refund(Err(DomainError::SaveError))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PostRefundErrorResponse::ServiceUnavailable)
+        ));
+    }
+}
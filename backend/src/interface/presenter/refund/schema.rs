@@ -0,0 +1,43 @@
+use actix_web::{HttpResponse, ResponseError};
+use derive_more::{Display, Error};
+
+use actix_http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::error::DomainError;
+use crate::interface::presenter::money::schema::MoneySchema;
+use crate::{define_error_response, interface::presenter::common::exception::ErrorResponseBuilder};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefundSchema {
+    pub(super) id: String,
+    pub(super) order_id: String,
+    pub(super) note: Option<String>,
+    pub(super) line_items: Vec<RefundLineItemSchema>,
+    pub(super) shipping_set: MoneySchema,
+    pub(super) total_refunded_set: MoneySchema,
+    pub(super) restock_location_id: Option<String>,
+    pub(super) created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefundLineItemSchema {
+    pub(super) line_item_id: String,
+    pub(super) quantity: u32,
+    pub(super) subtotal_set: MoneySchema,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostRefundCalculateResponse {
+    pub refund: RefundSchema,
+}
+
+define_error_response!(PostRefundCalculateErrorResponse, "Refund");
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostRefundResponse {
+    pub refund: RefundSchema,
+}
+
+define_error_response!(PostRefundErrorResponse, "Refund");
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::{error::error::DomainError, refund::refund::Refund};
+
+/// Interface to generate response schema for refunds.
+#[async_trait]
+pub trait RefundPresenter {
+    type PostRefundCalculateResponse;
+    type PostRefundCalculateErrorResponse;
+    /// Generate a response of the calculated refund.
+    async fn present_post_refund_calculate(
+        &self,
+        result: Result<Refund, DomainError>,
+    ) -> Result<Self::PostRefundCalculateResponse, Self::PostRefundCalculateErrorResponse>;
+
+    type PostRefundResponse;
+    type PostRefundErrorResponse;
+    /// Generate a response of the executed refund.
+    async fn present_post_refund(
+        &self,
+        result: Result<Refund, DomainError>,
+    ) -> Result<Self::PostRefundResponse, Self::PostRefundErrorResponse>;
+}
@@ -5,13 +5,17 @@ use env_logger::Env;
 use infrastructure::auth::auth_middleware::AuthTransform;
 use infrastructure::auth::cognito::cognito_authenticator::CognitoAuthenticator;
 use infrastructure::auth::rbac::rbac_authorizer::RbacAuthorizer;
-use infrastructure::config::config::ConfigProvider;
+use infrastructure::config::config::{ConfigProvider, ECPlatform};
 use infrastructure::db::sea_orm::sea_orm_manager::{
     SeaOrmConnectionProvider, SeaOrmTransactionManager,
 };
 use infrastructure::db::sea_orm::sea_orm_transaction_middleware;
+use infrastructure::ec::local::store::LocalStore;
+use infrastructure::ec::shopify::client_impl::ShopifyGQLClient;
 use infrastructure::module::interactor_provider_impl::InteractorProviderImpl;
 use infrastructure::router::actix_router;
+use infrastructure::tenant::tenant_middleware::{self, SHOP_DOMAIN_HEADER};
+use infrastructure::tenant::tenant_registry::TenantRegistry;
 use interface::controller::controller::Controller;
 use library::tracing::middleware::{set_trace_id_middleware, XRayRootSpanBuilder};
 use sea_orm::{DatabaseConnection, DatabaseTransaction};
@@ -49,8 +53,41 @@ async fn main() -> std::io::Result<()> {
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
     );
 
+    // Share one pooled client for all requests to Shopify.
+    let shopify_client = ShopifyGQLClient::new(config_provider.shopify_config().clone())
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+    let ec_config = config_provider.ec_config();
+    let local_store = match ec_config.platform() {
+        ECPlatform::Shopify => None,
+        ECPlatform::Local => Some(
+            LocalStore::open(ec_config.local_store_path().clone().unwrap_or_default())
+                .await
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        ),
+    };
+
+    // Requests are routed to the shop of their tenant only when a registry is registered.
+    let tenant_config = config_provider.tenant_config();
+    let tenant_registry = if *tenant_config.enabled() {
+        Some(web::Data::new(
+            TenantRegistry::new(
+                connection_provider.get_connection(),
+                tenant_config.clone(),
+                config_provider
+                    .shopify_config()
+                    .http_client_config()
+                    .clone(),
+            )
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+        ))
+    } else {
+        None
+    };
+
     let controller = web::Data::new(Controller::new(InteractorProviderImpl::new(
-        config_provider.shopify_config().clone(),
+        shopify_client,
+        local_store,
         config_provider.cognito_config().clone(),
         config_provider.aws_sdk_config().clone(),
     )));
@@ -68,11 +105,17 @@ async fn main() -> std::io::Result<()> {
                 http::header::CONTENT_TYPE,
                 http::header::ACCEPT,
                 http::header::HeaderName::from_static("x-amzn-trace-id"),
+                http::header::HeaderName::from_static(SHOP_DOMAIN_HEADER),
             ])
             .supports_credentials()
             .max_age(0);
 
-        App::new()
+        let app = match &tenant_registry {
+            Some(tenant_registry) => App::new().app_data(tenant_registry.clone()),
+            None => App::new(),
+        };
+
+        app
             // Definition of middleware
             // NOTE: Executed in the order of last written.
             .wrap(AuthTransform::new(CognitoAuthenticator::new(
@@ -83,6 +126,7 @@ async fn main() -> std::io::Result<()> {
             .wrap(from_fn(
                 sea_orm_transaction_middleware::sea_orm_transaction_middleware,
             ))
+            .wrap(from_fn(tenant_middleware::tenant_middleware))
             .wrap(Logger::default().exclude(app_config.health_check_path()))
             .wrap(cors)
             .wrap(from_fn(set_trace_id_middleware))
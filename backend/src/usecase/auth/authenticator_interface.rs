@@ -35,4 +35,7 @@ pub trait Authenticator: Send + Sync + Clone {
         &self,
         refresh_token: &str,
     ) -> Result<String, DomainError>;
+
+    /// Limit the roles of verified users to the global ones and those assigned in the tenant.
+    fn scope_to_tenant(&mut self, tenant_id: i32);
 }
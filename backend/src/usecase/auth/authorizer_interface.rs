@@ -2,7 +2,9 @@ use async_trait::async_trait;
 use mockall::automock;
 use std::sync::Arc;
 
-use crate::domain::authorized_resource::authorized_resource::{AuthorizedResource, ResourceAction};
+use crate::domain::authorized_resource::authorized_resource::{
+    AuthorizedResource, ResourceAction, ResourceType,
+};
 use crate::domain::error::error::DomainError;
 use crate::domain::user::user::UserInterface;
 
@@ -33,4 +35,28 @@ pub trait Authorizer: Send + Sync {
         resources: Vec<&'a dyn AuthorizedResource>,
         action: &ResourceAction,
     ) -> Result<(), DomainError>;
+
+    /// Authorize the given action on all resources of the type, whoever owns them.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - Users who manipulate resources.
+    /// * `resource_type` - Type of the resources to be manipulated.
+    /// * `action` - Action on Resources.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), DomainError>` - The result of the operation.
+    ///   - `Ok()` - That the resource operation was authorized.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * If the authorization is limited to the own resources or not held at all.
+    async fn authorize_all(
+        &self,
+        user: Arc<dyn UserInterface>,
+        resource_type: &ResourceType,
+        action: &ResourceAction,
+    ) -> Result<(), DomainError>;
 }
@@ -4,11 +4,19 @@ pub mod customer;
 pub mod customer_interactor_interface;
 pub mod draft_order;
 pub mod draft_order_interactor_interface;
+pub mod fulfillment;
+pub mod fulfillment_interactor_interface;
 pub mod inventory;
 pub mod inventory_interactor_interface;
 pub mod location;
 pub mod location_interactor_interface;
 pub mod media;
 pub mod media_interactor_interface;
+pub mod order;
+pub mod order_interactor_interface;
+pub mod order_return;
+pub mod order_return_interactor_interface;
 pub mod product;
 pub mod product_interactor_interface;
+pub mod refund;
+pub mod refund_interactor_interface;
@@ -1,42 +1,104 @@
 use async_trait::async_trait;
 
 use crate::{
-    domain::{customer::customer::Customer, email::email::Email, error::error::DomainError},
+    domain::{
+        customer::customer::Customer, email::email::Email, error::error::DomainError,
+        user::user::Id as UserId,
+    },
     usecase::{
         auth::authenticator_interface::Authenticator,
         interactor::auth_interactor_interface::AuthInteractor,
-        repository::customer_repository_interface::CustomerRepository,
+        repository::{
+            customer_repository_interface::CustomerRepository,
+            user_customer_repository_interface::UserCustomerRepository,
+        },
     },
 };
 
 /// Auth Interactor.
-pub struct AuthInteractorImpl<A, C>
+pub struct AuthInteractorImpl<A, C, U>
 where
     A: Authenticator,
     C: CustomerRepository,
+    U: UserCustomerRepository,
 {
     authenticator: A,
     customer_repository: C,
+    user_customer_repository: U,
 }
 
-impl<A, C> AuthInteractorImpl<A, C>
+impl<A, C, U> AuthInteractorImpl<A, C, U>
 where
     A: Authenticator,
     C: CustomerRepository,
+    U: UserCustomerRepository,
 {
-    pub fn new(authenticator: A, customer_repository: C) -> Self {
+    pub fn new(authenticator: A, customer_repository: C, user_customer_repository: U) -> Self {
         Self {
             authenticator,
             customer_repository,
+            user_customer_repository,
+        }
+    }
+
+    /// Get the customer linked to the user.
+    /// If the customer was removed from the EC platform, the link is treated as missing.
+    async fn find_linked_customer(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<Customer>, DomainError> {
+        let customer_id = match self
+            .user_customer_repository
+            .find_customer_id_by_user_id(user_id)
+            .await
+        {
+            Ok(customer_id) => customer_id,
+            Err(DomainError::NotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        match self
+            .customer_repository
+            .find_customer_by_id(&customer_id)
+            .await
+        {
+            Ok(customer) => Ok(Some(customer)),
+            Err(DomainError::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the customer with the email of the user, or create one on the first sign-in.
+    async fn find_or_create_customer(
+        &self,
+        user_id: &UserId,
+        email: &Email,
+    ) -> Result<Customer, DomainError> {
+        match self.customer_repository.find_customer_by_email(email).await {
+            Ok(customer) => Ok(customer),
+            Err(DomainError::NotFound) => {
+                let customer = Customer::create(
+                    user_id.to_owned(),
+                    Some(email.to_owned()),
+                    None,
+                    None,
+                    None,
+                    None,
+                    false,
+                )?;
+                self.customer_repository.create(customer).await
+            }
+            Err(e) => Err(e),
         }
     }
 }
 
 #[async_trait]
-impl<A, C> AuthInteractor for AuthInteractorImpl<A, C>
+impl<A, C, U> AuthInteractor for AuthInteractorImpl<A, C, U>
 where
     A: Authenticator,
     C: CustomerRepository,
+    U: UserCustomerRepository,
 {
     async fn authenticate(
         &self,
@@ -49,10 +111,24 @@ where
             .verify_token(id_token.as_deref(), refresh_token.as_deref())
             .await?;
 
-        let customer = self
-            .customer_repository
-            .find_customer_by_email(&Email::new(user.email())?)
-            .await?;
+        let user_id = user.id().to_string();
+        let email = Email::new(user.email())?;
+
+        let (mut customer, is_linked) = match self.find_linked_customer(&user_id).await? {
+            Some(customer) => (customer, true),
+            None => (self.find_or_create_customer(&user_id, &email).await?, false),
+        };
+
+        // The link is kept by user id, so the customer follows when the email is changed with the IdP.
+        let changed = customer.link_user(user_id.clone(), email)?;
+        if !is_linked {
+            self.user_customer_repository
+                .save(&user_id, customer.id())
+                .await?;
+        }
+        if changed {
+            customer = self.customer_repository.update(customer).await?;
+        }
 
         Ok((customer, new_id_token))
     }
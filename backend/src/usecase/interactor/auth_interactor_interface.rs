@@ -10,6 +10,9 @@ use crate::domain::error::error::DomainError;
 pub trait AuthInteractor {
     /// Authentication by token.
     ///
+    /// The customer linked to the user is returned. On the first sign-in, the customer is looked up by email,
+    /// or created when none exists, and the link is persisted.
+    ///
     /// # Arguments
     ///
     /// * `id_token` - ID Token issued by Idp
@@ -1,35 +1,113 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::Utc;
 
 use crate::{
     domain::{
-        authorized_resource::authorized_resource::ResourceAction, customer::customer::Customer,
-        error::error::DomainError, user::user::UserInterface,
+        address::address::{Address, Id as AddressId},
+        audit_log::audit_log::{AuditAction, AuditLog},
+        authorized_resource::authorized_resource::{
+            AuthorizedResource, Resource, ResourceAction, ResourceType,
+        },
+        customer::{
+            customer::{Customer, Id as CustomerId},
+            search_criteria::search_criteria::CustomerSearchCriteria,
+        },
+        customer_segment::customer_segment::CustomerSegment,
+        email::email::Email,
+        error::error::DomainError,
+        user::user::{Id as UserId, UserInterface},
     },
     usecase::{
         auth::authorizer_interface::Authorizer,
-        interactor::customer_interactor_interface::{CustomerInteractor, GetCustomersQuery},
-        repository::customer_repository_interface::CustomerRepository,
+        interactor::customer_interactor_interface::{
+            CustomerDataExport, CustomerInteractor, CustomerProfile, GetCustomersFilter,
+            GetCustomersQuery,
+        },
+        repository::{
+            audit_log_repository_interface::AuditLogRepository,
+            customer_repository_interface::{CustomerPage, CustomerRepository},
+            customer_segment_repository_interface::CustomerSegmentRepository,
+            draft_order_repository_interface::DraftOrderRepository,
+            order_repository_interface::OrderRepository,
+            user_customer_repository_interface::UserCustomerRepository,
+            user_repository_interface::{UserRecord, UserRepository},
+        },
     },
 };
 
 /// Customer Interactor.
 pub struct CustomerInteractorImpl {
     customer_repository: Box<dyn CustomerRepository>,
+    customer_segment_repository: Box<dyn CustomerSegmentRepository>,
+    draft_order_repository: Box<dyn DraftOrderRepository>,
+    order_repository: Box<dyn OrderRepository>,
+    user_repository: Box<dyn UserRepository>,
+    user_customer_repository: Box<dyn UserCustomerRepository>,
+    audit_log_repository: Box<dyn AuditLogRepository>,
     authorizer: Arc<dyn Authorizer>,
 }
 
+/// Dependencies to construct a `CustomerInteractorImpl` from.
+pub struct CustomerInteractorParams {
+    pub customer_repository: Box<dyn CustomerRepository>,
+    pub customer_segment_repository: Box<dyn CustomerSegmentRepository>,
+    pub draft_order_repository: Box<dyn DraftOrderRepository>,
+    pub order_repository: Box<dyn OrderRepository>,
+    pub user_repository: Box<dyn UserRepository>,
+    pub user_customer_repository: Box<dyn UserCustomerRepository>,
+    pub audit_log_repository: Box<dyn AuditLogRepository>,
+    pub authorizer: Arc<dyn Authorizer>,
+}
+
 impl CustomerInteractorImpl {
-    pub fn new(
-        customer_repository: Box<dyn CustomerRepository>,
-        authorizer: Arc<dyn Authorizer>,
-    ) -> Self {
+    pub fn new(params: CustomerInteractorParams) -> Self {
+        let CustomerInteractorParams {
+            customer_repository,
+            customer_segment_repository,
+            draft_order_repository,
+            order_repository,
+            user_repository,
+            user_customer_repository,
+            audit_log_repository,
+            authorizer,
+        } = params;
+
         Self {
             customer_repository,
+            customer_segment_repository,
+            draft_order_repository,
+            order_repository,
+            user_repository,
+            user_customer_repository,
+            audit_log_repository,
             authorizer,
         }
     }
+
+    /// Get the locally held user linked to the customer.
+    /// Customers who have never signed in have no local user.
+    async fn find_linked_user(
+        &self,
+        customer: &Customer,
+    ) -> Result<Option<UserRecord>, DomainError> {
+        let user_id = match self
+            .user_customer_repository
+            .find_user_id_by_customer_id(customer.id())
+            .await
+        {
+            Ok(user_id) => user_id,
+            Err(DomainError::NotFound) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        match self.user_repository.find_user_by_id(&user_id).await {
+            Ok(user) => Ok(Some(user)),
+            Err(DomainError::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[async_trait]
@@ -38,19 +116,336 @@ impl CustomerInteractor for CustomerInteractorImpl {
         &self,
         user: Arc<dyn UserInterface>,
         query: &GetCustomersQuery,
-    ) -> Result<Vec<Customer>, DomainError> {
-        match query {
-            GetCustomersQuery::Email(email) => {
-                let customer = self
-                    .customer_repository
-                    .find_customer_by_email(email)
-                    .await?;
+    ) -> Result<CustomerPage, DomainError> {
+        let criteria = match &query.filter {
+            GetCustomersFilter::Criteria(criteria) => criteria.clone(),
+            GetCustomersFilter::Segment(segment_id) => self
+                .customer_segment_repository
+                .find_customer_segment_by_id(segment_id)
+                .await?
+                .criteria()
+                .clone(),
+        };
+
+        let page = self
+            .customer_repository
+            .find_customers_by_criteria(
+                &criteria,
+                &query.sort_key,
+                query.reverse,
+                query.first,
+                query.after.clone(),
+            )
+            .await?;
+
+        self.authorizer
+            .authorize(
+                user,
+                page.customers
+                    .iter()
+                    .map(|c| c as &dyn AuthorizedResource)
+                    .collect(),
+                &ResourceAction::Read,
+            )
+            .await?;
+        Ok(page)
+    }
+
+    async fn get_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<Customer, DomainError> {
+        let customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Read)
+            .await?;
+
+        Ok(customer)
+    }
+
+    async fn create_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        email: Option<Email>,
+        profile: CustomerProfile,
+    ) -> Result<Customer, DomainError> {
+        self.authorizer
+            .authorize(
+                user.clone(),
+                vec![&Resource::new(ResourceType::Customer, None)],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        // The customer is owned by the IdP user linked on sign-in, not by the operator creating it.
+        let customer = Customer::create(
+            UserId::new(),
+            email,
+            profile.first_name,
+            profile.last_name,
+            profile.phone,
+            profile.note,
+            profile.email_marketing_consent,
+        )?;
+
+        self.customer_repository.create(customer).await
+    }
+
+    async fn update_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        profile: CustomerProfile,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Write)
+            .await?;
+
+        customer.update_profile(
+            profile.first_name,
+            profile.last_name,
+            profile.phone,
+            profile.note,
+            profile.email_marketing_consent,
+        )?;
+
+        self.customer_repository.update(customer).await
+    }
+
+    async fn deactivate_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Delete)
+            .await?;
+
+        customer.deactivate()?;
 
-                self.authorizer
-                    .authorize(user, vec![&customer], &ResourceAction::Read)
-                    .await?;
-                Ok(vec![customer])
+        self.customer_repository.update(customer).await
+    }
+
+    async fn add_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Write)
+            .await?;
+
+        customer.add_address(address.clone(), set_default)?;
+        let set_default = customer.is_default_address(&address);
+
+        self.customer_repository
+            .create_address(customer.id(), address, set_default)
+            .await?;
+
+        self.customer_repository.find_customer_by_id(id).await
+    }
+
+    async fn update_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Write)
+            .await?;
+
+        let address = address.with_id(address_id.clone());
+        customer.update_address(address_id, address.clone(), set_default)?;
+        let set_default = customer.is_default_address(&address);
+
+        self.customer_repository
+            .update_address(customer.id(), address, set_default)
+            .await?;
+
+        self.customer_repository.find_customer_by_id(id).await
+    }
+
+    async fn delete_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Write)
+            .await?;
+
+        let default_address_id = customer
+            .default_address()
+            .as_ref()
+            .and_then(|a| a.id().clone());
+        customer.delete_address(address_id)?;
+
+        self.customer_repository
+            .delete_address(customer.id(), address_id)
+            .await?;
+
+        // Reassign the default explicitly when it was deleted, so the EC does not pick a different one.
+        if default_address_id.as_ref() == Some(address_id) {
+            if let Some(new_default_id) = customer
+                .default_address()
+                .as_ref()
+                .and_then(|a| a.id().clone())
+            {
+                return self
+                    .customer_repository
+                    .update_default_address(customer.id(), &new_default_id)
+                    .await;
             }
         }
+
+        self.customer_repository.find_customer_by_id(id).await
+    }
+
+    async fn set_default_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError> {
+        let mut customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&customer], &ResourceAction::Write)
+            .await?;
+
+        customer.set_default_address(address_id)?;
+
+        self.customer_repository
+            .update_default_address(customer.id(), address_id)
+            .await
+    }
+
+    async fn get_customer_segments(
+        &self,
+        user: Arc<dyn UserInterface>,
+    ) -> Result<Vec<CustomerSegment>, DomainError> {
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(ResourceType::CustomerSegment, None)],
+                &ResourceAction::Read,
+            )
+            .await?;
+
+        self.customer_segment_repository
+            .find_customer_segments()
+            .await
+    }
+
+    async fn create_customer_segment(
+        &self,
+        user: Arc<dyn UserInterface>,
+        name: String,
+        criteria: CustomerSearchCriteria,
+    ) -> Result<CustomerSegment, DomainError> {
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(ResourceType::CustomerSegment, None)],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        let customer_segment = CustomerSegment::create(name, criteria)?;
+
+        self.customer_segment_repository
+            .create(customer_segment)
+            .await
+    }
+
+    async fn export_customer_data(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<CustomerDataExport, DomainError> {
+        let customer = self.customer_repository.find_customer_by_id(id).await?;
+        let draft_orders = self
+            .draft_order_repository
+            .find_draft_orders_by_customer_id(id)
+            .await?;
+        let orders = self.order_repository.find_orders_by_customer_id(id).await?;
+
+        let mut resources: Vec<&dyn AuthorizedResource> = vec![&customer];
+        resources.extend(draft_orders.iter().map(|d| d as &dyn AuthorizedResource));
+        resources.extend(orders.iter().map(|o| o as &dyn AuthorizedResource));
+        self.authorizer
+            .authorize(user.clone(), resources, &ResourceAction::Read)
+            .await?;
+
+        let linked_user = self.find_linked_user(&customer).await?;
+
+        self.audit_log_repository
+            .create(AuditLog::create(
+                user.id(),
+                AuditAction::ExportCustomerData,
+                ResourceType::Customer,
+                customer.id(),
+            )?)
+            .await?;
+
+        Ok(CustomerDataExport {
+            customer,
+            draft_orders,
+            orders,
+            user: linked_user,
+            exported_at: Utc::now(),
+        })
+    }
+
+    async fn erase_customer_data(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<CustomerId, DomainError> {
+        let customer = self.customer_repository.find_customer_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user.clone(), vec![&customer], &ResourceAction::Delete)
+            .await?;
+
+        if let Some(linked_user) = self.find_linked_user(&customer).await? {
+            self.user_repository.anonymize(&linked_user.id).await?;
+        }
+        self.user_customer_repository
+            .delete_by_customer_id(customer.id())
+            .await?;
+
+        self.audit_log_repository
+            .create(AuditLog::create(
+                user.id(),
+                AuditAction::EraseCustomerData,
+                ResourceType::Customer,
+                customer.id(),
+            )?)
+            .await?;
+
+        // Erasure on the EC platform cannot be undone, so it is requested only after the local changes,
+        // which are rolled back together with the transaction if the request fails.
+        self.customer_repository
+            .request_data_erasure(customer.id())
+            .await
     }
 }
@@ -1,22 +1,69 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::automock;
 use std::sync::Arc;
 
-use crate::domain::customer::customer::Customer;
+use crate::domain::address::address::{Address, Id as AddressId};
+use crate::domain::customer::customer::{Customer, Id as CustomerId};
+use crate::domain::customer::search_criteria::search_criteria::{
+    CustomerSearchCriteria, CustomerSortKey,
+};
+use crate::domain::customer_segment::customer_segment::{CustomerSegment, Id as CustomerSegmentId};
+use crate::domain::draft_order::draft_order::DraftOrder;
 use crate::domain::email::email::Email;
 use crate::domain::error::error::DomainError;
+use crate::domain::order::order::Order;
+use crate::domain::phone::phone::Phone;
 use crate::domain::user::user::UserInterface;
+use crate::usecase::repository::customer_repository_interface::CustomerPage;
+use crate::usecase::repository::user_repository_interface::UserRecord;
 
+/// Which customers to get.
 #[derive(Debug, Clone, PartialEq)]
-pub enum GetCustomersQuery {
-    Email(Email),
+pub enum GetCustomersFilter {
+    /// Customers matching the given criteria.
+    Criteria(CustomerSearchCriteria),
+    /// Customers matching the criteria of a saved segment.
+    Segment(CustomerSegmentId),
+}
+
+/// Query to get a page of customers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetCustomersQuery {
+    pub filter: GetCustomersFilter,
+    pub sort_key: CustomerSortKey,
+    pub reverse: bool,
+    pub first: u32,
+    pub after: Option<String>,
+}
+
+/// Profile of a customer given when it is created or updated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomerProfile {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub phone: Option<Phone>,
+    pub note: Option<String>,
+    /// Whether the customer agreed to receive marketing emails.
+    pub email_marketing_consent: bool,
+}
+
+/// Everything held about a customer, assembled to answer a data subject request.
+#[derive(Debug)]
+pub struct CustomerDataExport {
+    pub customer: Customer,
+    pub draft_orders: Vec<DraftOrder>,
+    pub orders: Vec<Order>,
+    /// The locally held user linked to the customer, if any.
+    pub user: Option<UserRecord>,
+    pub exported_at: DateTime<Utc>,
 }
 
 /// Interactor interface for customer.
 #[automock]
 #[async_trait]
 pub trait CustomerInteractor {
-    /// Get a list of customer by query.
+    /// Get a page of customers by query.
     ///
     /// # Arguments
     ///
@@ -25,16 +72,327 @@ pub trait CustomerInteractor {
     ///
     /// # Returns
     ///
-    /// * `Result<Vec<Customer>, DomainError>` - The result of the operation.
-    ///   - `Ok(Vec<Customer>)` - The customers.
+    /// * `Result<CustomerPage, DomainError>` - The result of the operation.
+    ///   - `Ok(CustomerPage)` - The customers and the cursor of the next page.
     ///   - `Err(DomainError)` - The error.
     ///
     /// # Errors
     ///
     /// * Returns a domain error if the customer repository fails.
+    /// * Returns a domain error if the customer segment repository fails.
+    /// * If the user is not allowed to read any of the customers.
     async fn get_customers(
         &self,
         user: Arc<dyn UserInterface>,
         query: &GetCustomersQuery,
-    ) -> Result<Vec<Customer>, DomainError>;
+    ) -> Result<CustomerPage, DomainError>;
+
+    /// Get a customer by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The customer.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to read the customer.
+    async fn get_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<Customer, DomainError>;
+
+    /// Create a customer.
+    ///
+    /// The created customer has no owner until an IdP user is linked to it on sign-in.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `email` - The email of the customer.
+    /// * `profile` - The profile of the customer.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The created customer.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If neither email nor phone is given.
+    async fn create_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        email: Option<Email>,
+        profile: CustomerProfile,
+    ) -> Result<Customer, DomainError>;
+
+    /// Update the profile of a customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    /// * `profile` - The new profile of the customer.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The updated customer.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to write the customer.
+    /// * If the customer is inactive.
+    async fn update_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        profile: CustomerProfile,
+    ) -> Result<Customer, DomainError>;
+
+    /// Deactivate a customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The deactivated customer.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to delete the customer.
+    /// * If the customer is already inactive.
+    async fn deactivate_customer(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<Customer, DomainError>;
+
+    /// Add an address to the address book of a customer.
+    ///
+    /// The first address of a customer always becomes the default address.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    /// * `address` - The address to add.
+    /// * `set_default` - Whether to set the address as the default address.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The customer with the updated address book.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to write the customer.
+    /// * If the customer is inactive.
+    async fn add_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Customer, DomainError>;
+
+    /// Update an address in the address book of a customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    /// * `address_id` - The id of the address to update.
+    /// * `address` - The new content of the address.
+    /// * `set_default` - Whether to set the address as the default address.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The customer with the updated address book.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to write the customer.
+    /// * If the customer is inactive.
+    /// * If the address is not in the address book.
+    async fn update_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Customer, DomainError>;
+
+    /// Delete an address from the address book of a customer.
+    ///
+    /// If the default address is deleted, the first remaining address becomes the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    /// * `address_id` - The id of the address to delete.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The customer with the updated address book.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to write the customer.
+    /// * If the customer is inactive.
+    /// * If the address is not in the address book.
+    async fn delete_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError>;
+
+    /// Set the default address of a customer.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    /// * `address_id` - The id of the address to set as the default.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Customer, DomainError>` - The result of the operation.
+    ///   - `Ok(Customer)` - The customer with the updated default address.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer repository fails.
+    /// * If the user is not allowed to write the customer.
+    /// * If the customer is inactive.
+    /// * If the address is not in the address book.
+    async fn set_default_customer_address(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError>;
+
+    /// Get all customer segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<CustomerSegment>, DomainError>` - The result of the operation.
+    ///   - `Ok(Vec<CustomerSegment>)` - The customer segments.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer segment repository fails.
+    /// * If the user is not allowed to read customers.
+    async fn get_customer_segments(
+        &self,
+        user: Arc<dyn UserInterface>,
+    ) -> Result<Vec<CustomerSegment>, DomainError>;
+
+    /// Save a customer segment to re-run its criteria later.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `name` - The name of the segment.
+    /// * `criteria` - The criteria of the customers belonging to the segment.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CustomerSegment, DomainError>` - The result of the operation.
+    ///   - `Ok(CustomerSegment)` - The created customer segment.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the customer segment repository fails.
+    /// * If the user is not allowed to write customers.
+    async fn create_customer_segment(
+        &self,
+        user: Arc<dyn UserInterface>,
+        name: String,
+        criteria: CustomerSearchCriteria,
+    ) -> Result<CustomerSegment, DomainError>;
+
+    /// Assemble all data held about a customer for export.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CustomerDataExport, DomainError>` - The result of the operation.
+    ///   - `Ok(CustomerDataExport)` - The data held about the customer.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if any repository fails.
+    /// * If the user is not allowed to read the customer or its orders.
+    async fn export_customer_data(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<CustomerDataExport, DomainError>;
+
+    /// Erase the personal data of a customer from the EC platform and the local users.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The customer id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CustomerId, DomainError>` - The result of the operation.
+    ///   - `Ok(CustomerId)` - The id of the customer whose erasure was requested.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if any repository fails.
+    /// * If the user is not allowed to delete the customer.
+    async fn erase_customer_data(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &CustomerId,
+    ) -> Result<CustomerId, DomainError>;
 }
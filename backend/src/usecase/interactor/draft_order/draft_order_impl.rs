@@ -136,6 +136,26 @@ impl DraftOrderInteractor for DraftOrderInteractorImpl {
         self.draft_order_repository.update(draft_order).await
     }
 
+    async fn cancel_draft_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &DraftOrderId,
+        reason: String,
+    ) -> Result<DraftOrder, DomainError> {
+        let mut draft_order = self
+            .draft_order_repository
+            .find_draft_order_by_id(id)
+            .await?;
+
+        self.authorizer
+            .authorize(user.clone(), vec![&draft_order], &ResourceAction::Write)
+            .await?;
+
+        draft_order.cancel(reason)?;
+
+        self.draft_order_repository.update(draft_order).await
+    }
+
     async fn delete_draft_order(
         &self,
         user: Arc<dyn UserInterface>,
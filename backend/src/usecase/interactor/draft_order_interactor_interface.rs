@@ -99,13 +99,39 @@ pub trait DraftOrderInteractor {
     /// # Errors
     ///
     /// * Returns a domain error if the draft order repository fails.
-    /// * If a draft order has already been completed.
+    /// * If a draft order is not open.
     async fn complete_draft_order(
         &self,
         user: Arc<dyn UserInterface>,
         id: &DraftOrderId,
     ) -> Result<DraftOrder, DomainError>;
 
+    /// Cancel a draft order.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The draft order id.
+    /// * `reason` - The reason for the cancellation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<DraftOrder, DomainError>` - The result of the operation.
+    ///   - `Ok(DraftOrder)` - The draft order.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the draft order repository fails.
+    /// * If a draft order is not open.
+    /// * If the reason is empty.
+    async fn cancel_draft_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &DraftOrderId,
+        reason: String,
+    ) -> Result<DraftOrder, DomainError>;
+
     /// Delete a draft order.
     ///
     /// # Arguments
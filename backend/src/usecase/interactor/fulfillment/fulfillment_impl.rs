@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::{Resource, ResourceAction, ResourceType},
+        error::error::DomainError,
+        fulfillment::{
+            fulfillment::{Fulfillment, Id as FulfillmentId},
+            tracking_info::tracking_info::TrackingInfo,
+        },
+        fulfillment_order::{
+            fulfillment_order::FulfillmentOrder,
+            fulfillment_order_line_item::fulfillment_order_line_item::Id as FulfillmentOrderLineItemId,
+        },
+        location::location::Id as LocationId,
+        order::order::Id as OrderId,
+        user::user::UserInterface,
+    },
+    usecase::{
+        auth::authorizer_interface::Authorizer,
+        interactor::fulfillment_interactor_interface::FulfillmentInteractor,
+        repository::{
+            fulfillment_order_repository_interface::FulfillmentOrderRepository,
+            fulfillment_repository_interface::FulfillmentRepository,
+            order_repository_interface::OrderRepository,
+        },
+    },
+};
+
+/// Fulfillment Interactor.
+pub struct FulfillmentInteractorImpl {
+    fulfillment_repository: Box<dyn FulfillmentRepository>,
+    fulfillment_order_repository: Box<dyn FulfillmentOrderRepository>,
+    order_repository: Box<dyn OrderRepository>,
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl FulfillmentInteractorImpl {
+    pub fn new(
+        fulfillment_repository: Box<dyn FulfillmentRepository>,
+        fulfillment_order_repository: Box<dyn FulfillmentOrderRepository>,
+        order_repository: Box<dyn OrderRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
+        Self {
+            fulfillment_repository,
+            fulfillment_order_repository,
+            order_repository,
+            authorizer,
+        }
+    }
+}
+
+#[async_trait]
+impl FulfillmentInteractor for FulfillmentInteractorImpl {
+    async fn get_fulfillment_orders(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+    ) -> Result<Vec<FulfillmentOrder>, DomainError> {
+        let order = self.order_repository.find_order_by_id(order_id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&order], &ResourceAction::Read)
+            .await?;
+
+        self.fulfillment_order_repository
+            .find_fulfillment_orders_by_order_id(order.id())
+            .await
+    }
+
+    async fn create_fulfillment(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        location_id: &LocationId,
+        line_items: Vec<(FulfillmentOrderLineItemId, u32)>,
+        tracking_info: Option<TrackingInfo>,
+    ) -> Result<Fulfillment, DomainError> {
+        // Customers can write their own orders, so fulfilling them is authorized separately.
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(ResourceType::Fulfillment, None)],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        let order = self.order_repository.find_order_by_id(order_id).await?;
+
+        let fulfillment_orders = self
+            .fulfillment_order_repository
+            .find_fulfillment_orders_by_order_id(order.id())
+            .await?;
+
+        let fulfillment = Fulfillment::create(
+            order.id(),
+            location_id,
+            &fulfillment_orders,
+            line_items,
+            tracking_info,
+        )?;
+
+        self.fulfillment_repository.create(fulfillment).await
+    }
+
+    async fn update_fulfillment_tracking(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &FulfillmentId,
+        tracking_info: TrackingInfo,
+    ) -> Result<Fulfillment, DomainError> {
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(ResourceType::Fulfillment, None)],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        let mut fulfillment = self
+            .fulfillment_repository
+            .find_fulfillment_by_id(id)
+            .await?;
+
+        fulfillment.update_tracking_info(tracking_info)?;
+
+        self.fulfillment_repository.update(fulfillment).await
+    }
+}
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::domain::user::user::UserInterface;
+use crate::domain::{
+    error::error::DomainError,
+    fulfillment::{
+        fulfillment::{Fulfillment, Id as FulfillmentId},
+        tracking_info::tracking_info::TrackingInfo,
+    },
+    fulfillment_order::{
+        fulfillment_order::FulfillmentOrder,
+        fulfillment_order_line_item::fulfillment_order_line_item::Id as FulfillmentOrderLineItemId,
+    },
+    location::location::Id as LocationId,
+    order::order::Id as OrderId,
+};
+
+/// Interactor interface for fulfillments.
+#[automock]
+#[async_trait]
+pub trait FulfillmentInteractor {
+    /// Get the fulfillment orders of an order.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<FulfillmentOrder>, DomainError>` - The result of the operation.
+    ///   - `Ok(Vec<FulfillmentOrder>)` - The fulfillment orders.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order or fulfillment order repository fails.
+    /// * If the user is not allowed to read the order.
+    async fn get_fulfillment_orders(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+    ) -> Result<Vec<FulfillmentOrder>, DomainError>;
+
+    /// Create a fulfillment for line items of an order shipped from a location.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    /// * `location_id` - The location the line items are shipped from.
+    /// * `line_items` - The fulfillment order line item ids and the quantities to fulfill.
+    /// * `tracking_info` - The tracking information of the shipment.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Fulfillment, DomainError>` - The result of the operation.
+    ///   - `Ok(Fulfillment)` - The created fulfillment.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the order.
+    /// * If the quantities exceed the quantities remaining to be fulfilled.
+    async fn create_fulfillment(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        location_id: &LocationId,
+        line_items: Vec<(FulfillmentOrderLineItemId, u32)>,
+        tracking_info: Option<TrackingInfo>,
+    ) -> Result<Fulfillment, DomainError>;
+
+    /// Update the tracking information of a fulfillment.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The fulfillment id.
+    /// * `tracking_info` - The new tracking information.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Fulfillment, DomainError>` - The result of the operation.
+    ///   - `Ok(Fulfillment)` - The updated fulfillment.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the order of the fulfillment.
+    /// * If the fulfillment has been cancelled.
+    async fn update_fulfillment_tracking(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &FulfillmentId,
+        tracking_info: TrackingInfo,
+    ) -> Result<Fulfillment, DomainError>;
+}
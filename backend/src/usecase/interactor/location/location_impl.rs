@@ -1,33 +1,263 @@
+use std::{cmp::Ordering, collections::HashMap, sync::Arc};
+
 use async_trait::async_trait;
 
 use crate::{
-    domain::{error::error::DomainError, location::location::Location},
+    domain::{
+        address::address::Address,
+        authorized_resource::authorized_resource::{
+            AuthorizedResource, Resource, ResourceAction, ResourceType,
+        },
+        error::error::DomainError,
+        location::location::{Id as LocationId, Location},
+        user::user::UserInterface,
+    },
+    log_error,
     usecase::{
-        interactor::location_interactor_interface::LocationInteractor,
-        repository::location_repository_interface::LocationRepository,
+        auth::authorizer_interface::Authorizer,
+        interactor::location_interactor_interface::{
+            GetNearbyLocationsQuery, LocationInteractor, NearbyLocation, NearbyLocationsOrigin,
+        },
+        repository::{
+            inventory_level_repository_interface::InventoryLevelRepository,
+            location_repository_interface::LocationRepository,
+        },
     },
 };
 
 /// Location Interactor.
 pub struct LocationInteractorImpl {
     location_repository: Box<dyn LocationRepository>,
+    inventory_level_repository: Box<dyn InventoryLevelRepository>,
+    authorizer: Arc<dyn Authorizer>,
 }
 
 impl LocationInteractorImpl {
-    pub fn new(location_repository: Box<dyn LocationRepository>) -> Self {
+    pub fn new(
+        location_repository: Box<dyn LocationRepository>,
+        inventory_level_repository: Box<dyn InventoryLevelRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
         Self {
             location_repository,
+            inventory_level_repository,
+            authorizer,
         }
     }
+
+    /// Narrow down the locations to those near the origin.
+    fn to_nearby_locations(
+        locations: Vec<Location>,
+        origin: &NearbyLocationsOrigin,
+        radius_km: Option<f64>,
+    ) -> Vec<NearbyLocation> {
+        let mut nearby_locations: Vec<NearbyLocation> = locations
+            .into_iter()
+            .filter_map(|location| match origin {
+                NearbyLocationsOrigin::Coordinates(coordinates) => {
+                    // Locations whose coordinates are unknown cannot be ranked, so they are excluded.
+                    let distance_km = location.distance_km(coordinates)?;
+                    if radius_km.is_some_and(|radius_km| distance_km > radius_km) {
+                        return None;
+                    }
+                    Some(NearbyLocation {
+                        location,
+                        distance_km: Some(distance_km),
+                    })
+                }
+                NearbyLocationsOrigin::PostalCode(postal_code) => location
+                    .is_in_postal_code(postal_code)
+                    .then_some(NearbyLocation {
+                        location,
+                        distance_km: None,
+                    }),
+            })
+            .collect();
+
+        nearby_locations.sort_by(|a, b| {
+            a.distance_km
+                .partial_cmp(&b.distance_km)
+                .unwrap_or(Ordering::Equal)
+        });
+        nearby_locations
+    }
 }
 
 #[async_trait]
 impl LocationInteractor for LocationInteractorImpl {
     async fn get_locations(
         &self,
+        user: Arc<dyn UserInterface>,
         limit: &Option<u32>,
         offset: &Option<u32>,
     ) -> Result<Vec<Location>, DomainError> {
-        self.location_repository.find_locations(limit, offset).await
+        let locations = self
+            .location_repository
+            .find_locations(limit, offset)
+            .await?;
+
+        self.authorizer
+            .authorize(
+                user,
+                locations
+                    .iter()
+                    .map(|l| l as &dyn AuthorizedResource)
+                    .collect(),
+                &ResourceAction::Read,
+            )
+            .await?;
+        Ok(locations)
+    }
+
+    async fn get_nearby_locations(
+        &self,
+        user: Arc<dyn UserInterface>,
+        query: &GetNearbyLocationsQuery,
+    ) -> Result<Vec<NearbyLocation>, DomainError> {
+        let locations: Vec<Location> = self
+            .location_repository
+            .find_locations(&None, &None)
+            .await?
+            .into_iter()
+            .filter(|location| *location.is_active())
+            .collect();
+
+        let mut nearby_locations =
+            Self::to_nearby_locations(locations, &query.origin, query.radius_km);
+
+        self.authorizer
+            .authorize(
+                user.clone(),
+                nearby_locations
+                    .iter()
+                    .map(|l| &l.location as &dyn AuthorizedResource)
+                    .collect(),
+                &ResourceAction::Read,
+            )
+            .await?;
+
+        if let Some(sku) = &query.sku {
+            let inventory_levels = self
+                .inventory_level_repository
+                .find_inventory_levels_by_sku(sku)
+                .await?;
+
+            self.authorizer
+                .authorize(
+                    user,
+                    inventory_levels
+                        .iter()
+                        .map(|l| l as &dyn AuthorizedResource)
+                        .collect(),
+                    &ResourceAction::Read,
+                )
+                .await?;
+
+            let available_quantities: HashMap<&LocationId, i32> = inventory_levels
+                .iter()
+                .map(|level| (level.location_id(), level.available_quantity()))
+                .collect();
+
+            nearby_locations.retain(|nearby_location| {
+                available_quantities
+                    .get(nearby_location.location.id())
+                    .is_some_and(|quantity| *quantity > 0)
+            });
+        }
+
+        Ok(nearby_locations)
+    }
+
+    async fn create_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        name: String,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<Location, DomainError> {
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(ResourceType::Location, None)],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        let location = Location::create(name, address, fulfills_online_orders)?;
+
+        self.location_repository.create(location).await
+    }
+
+    async fn update_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+        name: String,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<Location, DomainError> {
+        let mut location = self.location_repository.find_location_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&location], &ResourceAction::Write)
+            .await?;
+
+        location.update(name, address, fulfills_online_orders)?;
+
+        self.location_repository.update(location).await
+    }
+
+    async fn deactivate_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+        destination_location_id: Option<LocationId>,
+    ) -> Result<Location, DomainError> {
+        let mut location = self.location_repository.find_location_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user.clone(), vec![&location], &ResourceAction::Delete)
+            .await?;
+
+        location.deactivate()?;
+
+        if let Some(destination_location_id) = &destination_location_id {
+            if destination_location_id == location.id() {
+                log_error!("Inventory cannot be transferred to the location being deactivated.", "id" => id);
+                return Err(DomainError::InvalidRequest);
+            }
+
+            let destination = self
+                .location_repository
+                .find_location_by_id(destination_location_id)
+                .await?;
+            self.authorizer
+                .authorize(user, vec![&destination], &ResourceAction::Write)
+                .await?;
+            if !destination.is_active() {
+                log_error!("Inventory cannot be transferred to an inactive location.", "id" => id, "destination_location_id" => destination_location_id);
+                return Err(DomainError::InvalidStateTransition);
+            }
+        }
+
+        self.location_repository
+            .deactivate(location.id(), &destination_location_id)
+            .await
+    }
+
+    async fn activate_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+    ) -> Result<Location, DomainError> {
+        let mut location = self.location_repository.find_location_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&location], &ResourceAction::Write)
+            .await?;
+
+        location.activate()?;
+
+        self.location_repository.activate(location.id()).await
     }
 }
@@ -1,8 +1,41 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use mockall::automock;
 
+use crate::domain::address::address::Address;
+use crate::domain::coordinates::coordinates::Coordinates;
 use crate::domain::error::error::DomainError;
-use crate::domain::location::location::Location;
+use crate::domain::location::location::{Id as LocationId, Location};
+use crate::domain::product::variant::sku::sku::Sku;
+use crate::domain::user::user::UserInterface;
+
+/// Where to look for nearby locations from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NearbyLocationsOrigin {
+    /// Locations ranked by the distance from the coordinates.
+    Coordinates(Coordinates),
+    /// Locations in the postal code.
+    PostalCode(String),
+}
+
+/// Query to get locations near the origin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetNearbyLocationsQuery {
+    pub origin: NearbyLocationsOrigin,
+    /// Only locations with stock available for the SKU, if specified.
+    pub sku: Option<Sku>,
+    /// Only locations within the radius from the coordinates, if specified.
+    pub radius_km: Option<f64>,
+}
+
+/// A location with its distance from the origin.
+#[derive(Debug)]
+pub struct NearbyLocation {
+    pub location: Location,
+    /// Unknown when searched by postal code.
+    pub distance_km: Option<f64>,
+}
 
 /// Interactor interface for locations.
 #[automock]
@@ -12,6 +45,7 @@ pub trait LocationInteractor {
     ///
     /// # Arguments
     ///
+    /// * `user` - The user interface.
     /// * `limit` - Maximum number of locations to return
     /// * `offset` - Number of locations to skip
     ///
@@ -22,9 +56,134 @@ pub trait LocationInteractor {
     /// # Errors
     ///
     /// * Returns a domain error if the location repository fails.
+    /// * If the user is not allowed to read locations.
     async fn get_locations(
         &self,
+        user: Arc<dyn UserInterface>,
         limit: &Option<u32>,
         offset: &Option<u32>,
     ) -> Result<Vec<Location>, DomainError>;
+
+    /// Get active locations near the origin, nearest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `query` - The query to get nearby locations.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<NearbyLocation>, DomainError>` - Nearby locations.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the location or inventory level repository fails.
+    /// * If the user is not allowed to read the locations or the inventory levels.
+    async fn get_nearby_locations(
+        &self,
+        user: Arc<dyn UserInterface>,
+        query: &GetNearbyLocationsQuery,
+    ) -> Result<Vec<NearbyLocation>, DomainError>;
+
+    /// Create a location such as a warehouse or a store.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `name` - The name of the location.
+    /// * `address` - The address of the location.
+    /// * `fulfills_online_orders` - Whether the location fulfills online orders.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Location, DomainError>` - The created location.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the location repository fails.
+    /// * If the user is not allowed to write locations.
+    /// * If the name is empty or the address has no country code.
+    async fn create_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        name: String,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<Location, DomainError>;
+
+    /// Update the name, address and fulfillment setting of a location.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The location id.
+    /// * `name` - The name of the location.
+    /// * `address` - The address of the location.
+    /// * `fulfills_online_orders` - Whether the location fulfills online orders.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Location, DomainError>` - The updated location.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the location repository fails.
+    /// * If the user is not allowed to write locations.
+    /// * If the name is empty or the address has no country code.
+    async fn update_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+        name: String,
+        address: Address,
+        fulfills_online_orders: bool,
+    ) -> Result<Location, DomainError>;
+
+    /// Deactivate a location.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The location id.
+    /// * `destination_location_id` - The active location to transfer the remaining inventory to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Location, DomainError>` - The deactivated location.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the location repository fails.
+    /// * If the user is not allowed to delete locations.
+    /// * If the location is already inactive.
+    /// * If inventory remains at the location and no destination is specified, with the field to specify in the details.
+    /// * If the destination is the location itself or is inactive.
+    async fn deactivate_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+        destination_location_id: Option<LocationId>,
+    ) -> Result<Location, DomainError>;
+
+    /// Activate a location.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The location id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Location, DomainError>` - The activated location.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the location repository fails.
+    /// * If the user is not allowed to write locations.
+    /// * If the location is already active.
+    async fn activate_location(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &LocationId,
+    ) -> Result<Location, DomainError>;
 }
@@ -1,8 +1,24 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
 use async_trait::async_trait;
 
 use crate::{
-    domain::{error::error::DomainError, media::media::Media, product::product::Id as ProductId},
+    domain::{
+        authorized_resource::authorized_resource::{Resource, ResourceAction, ResourceType},
+        error::error::DomainError,
+        media::{
+            associated_id::associated_id::AssociatedId,
+            media::{Id as MediaId, Media},
+            media_content::{image::image::Image, media_content::MediaContent},
+            src::src::Src,
+            staged_upload::staged_upload::{StagedUpload, StagedUploadTarget},
+        },
+        product::product::Id as ProductId,
+        user::user::UserInterface,
+    },
+    log_error, log_warn,
     usecase::{
+        auth::authorizer_interface::Authorizer,
         interactor::media_interactor_interface::MediaInteractor,
         repository::media_repository_interface::MediaRepository,
     },
@@ -11,24 +27,219 @@ use crate::{
 /// Media Interactor.
 pub struct MediaInteractorImpl {
     media_repository: Box<dyn MediaRepository>,
+    authorizer: Arc<dyn Authorizer>,
 }
 
 impl MediaInteractorImpl {
-    pub fn new(media_repository: Box<dyn MediaRepository>) -> Self {
+    /// Maximum number of times to check whether created media has been processed.
+    const PREPARATION_POLLING_ATTEMPTS: u32 = 10;
+    /// Interval between checks of whether created media has been processed.
+    const PREPARATION_POLLING_INTERVAL: Duration = Duration::from_secs(1);
+
+    pub fn new(
+        media_repository: Box<dyn MediaRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
         Self {
-            media_repository: media_repository,
+            media_repository,
+            authorizer,
         }
     }
+
+    async fn authorize_product(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        action: &ResourceAction,
+    ) -> Result<(), DomainError> {
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(
+                    ResourceType::Product,
+                    Some(product_id.clone()),
+                )],
+                action,
+            )
+            .await
+    }
+
+    /// Find media among the media associated with the product.
+    async fn find_product_media(
+        &self,
+        product_id: &ProductId,
+        id: &MediaId,
+    ) -> Result<Media, DomainError> {
+        self.media_repository
+            .find_media_by_product_id(product_id)
+            .await?
+            .into_iter()
+            .find(|media| media.id() == id)
+            .ok_or_else(|| {
+                log_error!("Media is not associated with the product.", "product_id" => product_id, "id" => id);
+                DomainError::NotFound
+            })
+    }
+
+    /// Wait until the media is processed, returning it as it is if processing does not finish in time.
+    async fn wait_for_preparation(
+        &self,
+        product_id: &ProductId,
+        media: Media,
+    ) -> Result<Media, DomainError> {
+        let mut media = media;
+        for _ in 0..Self::PREPARATION_POLLING_ATTEMPTS {
+            if !media.is_in_preparation() {
+                return Ok(media);
+            }
+            tokio::time::sleep(Self::PREPARATION_POLLING_INTERVAL).await;
+            media = self.find_product_media(product_id, media.id()).await?;
+        }
+
+        if media.is_in_preparation() {
+            log_warn!("Media is still in preparation.", "id" => media.id());
+        }
+        Ok(media)
+    }
 }
 
 #[async_trait]
 impl MediaInteractor for MediaInteractorImpl {
     async fn get_media_by_product_id(
         &self,
+        user: Arc<dyn UserInterface>,
         product_id: &ProductId,
     ) -> Result<Vec<Media>, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Read)
+            .await?;
+
         self.media_repository
             .find_media_by_product_id(product_id)
             .await
     }
+
+    async fn create_staged_upload(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        staged_upload: StagedUpload,
+    ) -> Result<StagedUploadTarget, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Write)
+            .await?;
+
+        self.media_repository
+            .create_staged_upload(&staged_upload)
+            .await
+    }
+
+    async fn create_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        original_source: String,
+        alt: Option<String>,
+    ) -> Result<Media, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Write)
+            .await?;
+
+        let image = Image::create(
+            Some(AssociatedId::Product(product_id.clone())),
+            alt,
+            Src::new(original_source)?,
+        )?;
+        let media = Media::create(MediaContent::Image(image));
+
+        let media = self.media_repository.create(product_id, media).await?;
+
+        self.wait_for_preparation(product_id, media).await
+    }
+
+    async fn update_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+        alt: Option<String>,
+    ) -> Result<Media, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Write)
+            .await?;
+
+        let mut media = self.find_product_media(product_id, id).await?;
+        media.update_alt(alt)?;
+
+        self.media_repository.update(product_id, media).await
+    }
+
+    async fn reorder_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        media_ids: Vec<MediaId>,
+    ) -> Result<Vec<Media>, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Write)
+            .await?;
+
+        if media_ids.is_empty() {
+            log_error!("No media to reorder.", "product_id" => product_id);
+            return Err(DomainError::InvalidRequest);
+        }
+        let unique_ids: HashSet<&MediaId> = media_ids.iter().collect();
+        if unique_ids.len() != media_ids.len() {
+            log_error!("Media ids to reorder are duplicated.", "media_ids" => media_ids);
+            return Err(DomainError::InvalidRequest);
+        }
+
+        let media = self
+            .media_repository
+            .find_media_by_product_id(product_id)
+            .await?;
+        let product_media_ids: HashSet<&MediaId> = media.iter().map(|m| m.id()).collect();
+        if let Some(id) = media_ids.iter().find(|id| !product_media_ids.contains(id)) {
+            log_error!("Media is not associated with the product.", "product_id" => product_id, "id" => id);
+            return Err(DomainError::NotFound);
+        }
+
+        let (mut moved, rest): (Vec<Media>, Vec<Media>) =
+            media.into_iter().partition(|m| unique_ids.contains(m.id()));
+        moved.sort_by_key(|m| media_ids.iter().position(|id| id == m.id()));
+
+        self.media_repository
+            .reorder(product_id, moved.iter().collect())
+            .await?;
+
+        moved.extend(rest);
+        Ok(moved)
+    }
+
+    async fn delete_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+    ) -> Result<MediaId, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Delete)
+            .await?;
+
+        let media = self.find_product_media(product_id, id).await?;
+
+        self.media_repository.delete(product_id, &media).await
+    }
+
+    async fn set_featured_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+    ) -> Result<Media, DomainError> {
+        self.authorize_product(user, product_id, &ResourceAction::Write)
+            .await?;
+
+        let media = self.find_product_media(product_id, id).await?;
+        media.verify_featurable()?;
+
+        self.media_repository
+            .reorder(product_id, vec![&media])
+            .await?;
+        Ok(media)
+    }
 }
@@ -1,12 +1,19 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use mockall::automock;
 
-use crate::domain::{error::error::DomainError, product::product::Id as ProductId};
-
-use crate::domain::media::media::Media;
+use crate::domain::{
+    error::error::DomainError,
+    media::{
+        media::{Id as MediaId, Media},
+        staged_upload::staged_upload::{StagedUpload, StagedUploadTarget},
+    },
+    product::product::Id as ProductId,
+    user::user::UserInterface,
+};
 
 /// Interactor interface for media.
-#[allow(dead_code)]
 #[automock]
 #[async_trait]
 pub trait MediaInteractor {
@@ -14,6 +21,7 @@ pub trait MediaInteractor {
     ///
     /// # Arguments
     ///
+    /// * `user` - The user interface.
     /// * `product_id` - The product id.
     ///
     /// # Returns
@@ -23,8 +31,159 @@ pub trait MediaInteractor {
     /// # Errors
     ///
     /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to read the product.
     async fn get_media_by_product_id(
         &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+    ) -> Result<Vec<Media>, DomainError>;
+
+    /// Create a target to upload a file to before it is registered as product media.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `staged_upload` - The file to be uploaded.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StagedUploadTarget, DomainError>` - Where to upload the file.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to write the product.
+    async fn create_staged_upload(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        staged_upload: StagedUpload,
+    ) -> Result<StagedUploadTarget, DomainError>;
+
+    /// Register an uploaded file as product media.
+    ///
+    /// The media is polled while it is in preparation, for a limited time.
+    /// If processing does not finish in time, the media is returned still in preparation.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `original_source` - The URL of the uploaded file, such as the resource URL of a staged upload.
+    /// * `alt` - The alternative text of the media.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Media, DomainError>` - The created media.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to write the product.
+    async fn create_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        original_source: String,
+        alt: Option<String>,
+    ) -> Result<Media, DomainError>;
+
+    /// Update the alternative text of product media.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `id` - The media id.
+    /// * `alt` - The alternative text of the media.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Media, DomainError>` - The updated media.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to write the product.
+    /// * If the media is not associated with the product.
+    async fn update_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+        alt: Option<String>,
+    ) -> Result<Media, DomainError>;
+
+    /// Reorder product media.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `media_ids` - The media ids to be placed at the front, in order.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Media>, DomainError>` - The media of the product in the new order.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to write the product.
+    /// * If the media ids are empty, duplicated or not associated with the product.
+    async fn reorder_media(
+        &self,
+        user: Arc<dyn UserInterface>,
         product_id: &ProductId,
+        media_ids: Vec<MediaId>,
     ) -> Result<Vec<Media>, DomainError>;
+
+    /// Delete product media.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `id` - The media id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<MediaId, DomainError>` - The deleted media id.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to delete the product.
+    /// * If the media is not associated with the product.
+    async fn delete_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+    ) -> Result<MediaId, DomainError>;
+
+    /// Set the featured image of a product by moving the media to the front.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `product_id` - The product id.
+    /// * `id` - The media id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Media, DomainError>` - The featured media.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the media repository fails.
+    /// * If the user is not allowed to write the product.
+    /// * If the media is not associated with the product or failed to be processed.
+    async fn set_featured_media(
+        &self,
+        user: Arc<dyn UserInterface>,
+        product_id: &ProductId,
+        id: &MediaId,
+    ) -> Result<Media, DomainError>;
 }
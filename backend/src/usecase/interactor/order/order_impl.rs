@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::{ResourceAction, ResourceType},
+        error::error::DomainError,
+        order::order::{Id as OrderId, Order, OrderCancelReason},
+        user::user::UserInterface,
+    },
+    usecase::{
+        auth::authorizer_interface::Authorizer,
+        interactor::order_interactor_interface::{
+            GetOrdersFilter, GetOrdersQuery, OrderInteractor,
+        },
+        repository::{
+            customer_repository_interface::CustomerRepository,
+            order_repository_interface::{OrderPage, OrderRepository},
+            user_customer_repository_interface::UserCustomerRepository,
+        },
+    },
+};
+
+/// Order Interactor.
+pub struct OrderInteractorImpl {
+    order_repository: Box<dyn OrderRepository>,
+    customer_repository: Box<dyn CustomerRepository>,
+    user_customer_repository: Box<dyn UserCustomerRepository>,
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl OrderInteractorImpl {
+    pub fn new(
+        order_repository: Box<dyn OrderRepository>,
+        customer_repository: Box<dyn CustomerRepository>,
+        user_customer_repository: Box<dyn UserCustomerRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
+        Self {
+            order_repository,
+            customer_repository,
+            user_customer_repository,
+            authorizer,
+        }
+    }
+
+    /// Get a page of the orders of the customer linked to the user.
+    /// Users without a linked customer have no orders of their own.
+    async fn find_own_orders(
+        &self,
+        user: &dyn UserInterface,
+        query: &GetOrdersQuery,
+    ) -> Result<OrderPage, DomainError> {
+        let customer_id = match self
+            .user_customer_repository
+            .find_customer_id_by_user_id(&user.id().to_string())
+            .await
+        {
+            Ok(customer_id) => customer_id,
+            Err(DomainError::NotFound) => {
+                return Ok(OrderPage {
+                    orders: Vec::new(),
+                    has_next_page: false,
+                    end_cursor: None,
+                })
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.order_repository
+            .find_orders(Some(&customer_id), query.first, query.after.clone())
+            .await
+    }
+}
+
+#[async_trait]
+impl OrderInteractor for OrderInteractorImpl {
+    async fn get_orders(
+        &self,
+        user: Arc<dyn UserInterface>,
+        query: &GetOrdersQuery,
+    ) -> Result<OrderPage, DomainError> {
+        let page = match &query.filter {
+            GetOrdersFilter::Email(email) => {
+                let customer = self
+                    .customer_repository
+                    .find_customer_by_email(email)
+                    .await?;
+                self.order_repository
+                    .find_orders(Some(customer.id()), query.first, query.after.clone())
+                    .await
+            }
+            GetOrdersFilter::All => {
+                match self
+                    .authorizer
+                    .authorize_all(user.clone(), &ResourceType::Order, &ResourceAction::Read)
+                    .await
+                {
+                    Ok(()) => {
+                        self.order_repository
+                            .find_orders(None, query.first, query.after.clone())
+                            .await
+                    }
+                    Err(DomainError::AuthorizationError) => {
+                        self.find_own_orders(user.as_ref(), query).await
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }?;
+
+        // Users with only their own read permission receive just their orders instead of an error.
+        let mut authorized_orders = Vec::new();
+        for order in page.orders {
+            match self
+                .authorizer
+                .authorize(user.clone(), vec![&order], &ResourceAction::Read)
+                .await
+            {
+                Ok(()) => authorized_orders.push(order),
+                Err(DomainError::AuthorizationError) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(OrderPage {
+            orders: authorized_orders,
+            has_next_page: page.has_next_page,
+            end_cursor: page.end_cursor,
+        })
+    }
+
+    async fn get_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &OrderId,
+    ) -> Result<Order, DomainError> {
+        let order = self.order_repository.find_order_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&order], &ResourceAction::Read)
+            .await?;
+
+        Ok(order)
+    }
+
+    async fn cancel_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &OrderId,
+        reason: OrderCancelReason,
+        refund: bool,
+        restock: bool,
+        notify_customer: bool,
+    ) -> Result<Order, DomainError> {
+        let mut order = self.order_repository.find_order_by_id(id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&order], &ResourceAction::Delete)
+            .await?;
+
+        order.cancel(reason)?;
+
+        self.order_repository
+            .cancel(order, refund, restock, notify_customer)
+            .await
+    }
+}
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::domain::user::user::UserInterface;
+use crate::domain::{
+    email::email::Email,
+    error::error::DomainError,
+    order::order::{Id as OrderId, Order, OrderCancelReason},
+};
+use crate::usecase::repository::order_repository_interface::OrderPage;
+
+/// Which orders to get.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetOrdersFilter {
+    Email(Email),
+    All,
+}
+
+/// Query to get a page of orders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetOrdersQuery {
+    pub filter: GetOrdersFilter,
+    pub first: u32,
+    pub after: Option<String>,
+}
+
+/// Interactor interface for orders.
+#[automock]
+#[async_trait]
+pub trait OrderInteractor {
+    /// Get a page of orders by query.
+    ///
+    /// Only the orders that the user is allowed to read are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `query` - The query to get orders.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OrderPage, DomainError>` - The result of the operation.
+    ///   - `Ok(OrderPage)` - The page of orders.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order repository fails.
+    async fn get_orders(
+        &self,
+        user: Arc<dyn UserInterface>,
+        query: &GetOrdersQuery,
+    ) -> Result<OrderPage, DomainError>;
+
+    /// Get an order by id.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The order id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Order, DomainError>` - The result of the operation.
+    ///   - `Ok(Order)` - The order.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order repository fails.
+    /// * If the user is not allowed to read the order.
+    async fn get_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &OrderId,
+    ) -> Result<Order, DomainError>;
+
+    /// Cancel an order.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The order id.
+    /// * `reason` - The reason for the cancellation.
+    /// * `refund` - Whether to refund the payment to the original payment method.
+    /// * `restock` - Whether to restock the items.
+    /// * `notify_customer` - Whether to notify the customer of the cancellation.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Order, DomainError>` - The result of the operation.
+    ///   - `Ok(Order)` - The canceled order.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order repository fails.
+    /// * If the user is not allowed to delete the order.
+    /// * If the order is not unfulfilled or has already been canceled.
+    async fn cancel_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &OrderId,
+        reason: OrderCancelReason,
+        refund: bool,
+        restock: bool,
+        notify_customer: bool,
+    ) -> Result<Order, DomainError>;
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::{Resource, ResourceAction, ResourceType},
+        error::error::DomainError,
+        order::order::Id as OrderId,
+        order_return::{
+            order_return::{Id as ReturnId, OrderReturn, ReturnDeclineReason},
+            return_line_item::return_line_item::ReturnLineItem,
+        },
+        user::user::UserInterface,
+    },
+    usecase::{
+        auth::authorizer_interface::Authorizer,
+        interactor::order_return_interactor_interface::OrderReturnInteractor,
+        repository::{
+            order_repository_interface::OrderRepository,
+            order_return_repository_interface::OrderReturnRepository,
+        },
+    },
+};
+
+/// Return Interactor.
+pub struct OrderReturnInteractorImpl {
+    order_return_repository: Box<dyn OrderReturnRepository>,
+    order_repository: Box<dyn OrderRepository>,
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl OrderReturnInteractorImpl {
+    pub fn new(
+        order_return_repository: Box<dyn OrderReturnRepository>,
+        order_repository: Box<dyn OrderRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
+        Self {
+            order_return_repository,
+            order_repository,
+            authorizer,
+        }
+    }
+
+    /// Load a return and authorize the user to review it.
+    ///
+    /// Reviewing a return is limited to users with permission on returns of all orders,
+    /// so that customers cannot approve their own requests.
+    async fn find_return_to_review(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &ReturnId,
+    ) -> Result<OrderReturn, DomainError> {
+        let order_return = self.order_return_repository.find_return_by_id(id).await?;
+        let order = self
+            .order_repository
+            .find_order_by_id(order_return.order_id())
+            .await?;
+
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(
+                    ResourceType::Return,
+                    Some(order.owner_user_id().to_owned()),
+                )],
+                &ResourceAction::Write,
+            )
+            .await?;
+
+        Ok(order_return)
+    }
+}
+
+#[async_trait]
+impl OrderReturnInteractor for OrderReturnInteractorImpl {
+    async fn get_returns(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+    ) -> Result<Vec<OrderReturn>, DomainError> {
+        let order = self.order_repository.find_order_by_id(order_id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&order], &ResourceAction::Read)
+            .await?;
+
+        self.order_return_repository
+            .find_returns_by_order_id(order.id())
+            .await
+    }
+
+    async fn request_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<ReturnLineItem>,
+    ) -> Result<OrderReturn, DomainError> {
+        let order = self.order_repository.find_order_by_id(order_id).await?;
+
+        self.authorizer
+            .authorize(user, vec![&order], &ResourceAction::Write)
+            .await?;
+
+        let order_return = OrderReturn::request(order.id(), line_items)?;
+
+        self.order_return_repository.create(order_return).await
+    }
+
+    async fn approve_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &ReturnId,
+    ) -> Result<OrderReturn, DomainError> {
+        let mut order_return = self.find_return_to_review(user, id).await?;
+
+        order_return.approve()?;
+
+        self.order_return_repository.update(order_return).await
+    }
+
+    async fn decline_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &ReturnId,
+        reason: ReturnDeclineReason,
+        note: Option<String>,
+    ) -> Result<OrderReturn, DomainError> {
+        let mut order_return = self.find_return_to_review(user, id).await?;
+
+        order_return.decline(reason, note)?;
+
+        self.order_return_repository.update(order_return).await
+    }
+}
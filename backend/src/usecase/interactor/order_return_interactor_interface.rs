@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::domain::user::user::UserInterface;
+use crate::domain::{
+    error::error::DomainError,
+    order::order::Id as OrderId,
+    order_return::{
+        order_return::{Id as ReturnId, OrderReturn, ReturnDeclineReason},
+        return_line_item::return_line_item::ReturnLineItem,
+    },
+};
+
+/// Interactor interface for returns.
+#[automock]
+#[async_trait]
+pub trait OrderReturnInteractor {
+    /// Get the returns of an order.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<OrderReturn>, DomainError>` - The result of the operation.
+    ///   - `Ok(Vec<OrderReturn>)` - The returns.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order or return repository fails.
+    /// * If the user is not allowed to read the order.
+    async fn get_returns(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+    ) -> Result<Vec<OrderReturn>, DomainError>;
+
+    /// Request a return of fulfilled line items of an order.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    /// * `line_items` - The fulfilled line items to be returned with their reasons.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OrderReturn, DomainError>` - The result of the operation.
+    ///   - `Ok(OrderReturn)` - The requested return.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the order.
+    async fn request_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<ReturnLineItem>,
+    ) -> Result<OrderReturn, DomainError>;
+
+    /// Approve a requested return.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The return id.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OrderReturn, DomainError>` - The result of the operation.
+    ///   - `Ok(OrderReturn)` - The approved return.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the return.
+    /// * If the return is not in the requested status.
+    async fn approve_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &ReturnId,
+    ) -> Result<OrderReturn, DomainError>;
+
+    /// Decline a requested return.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `id` - The return id.
+    /// * `reason` - The reason for declining.
+    /// * `note` - An optional note to the customer.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<OrderReturn, DomainError>` - The result of the operation.
+    ///   - `Ok(OrderReturn)` - The declined return.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the return.
+    /// * If the return is not in the requested status.
+    async fn decline_return(
+        &self,
+        user: Arc<dyn UserInterface>,
+        id: &ReturnId,
+        reason: ReturnDeclineReason,
+        note: Option<String>,
+    ) -> Result<OrderReturn, DomainError>;
+}
@@ -0,0 +1 @@
+pub mod refund_impl;
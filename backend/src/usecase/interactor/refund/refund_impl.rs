@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        authorized_resource::authorized_resource::{Resource, ResourceAction, ResourceType},
+        error::error::DomainError,
+        line_item::line_item::Id as LineItemId,
+        location::location::Id as LocationId,
+        money::amount::amount::Amount,
+        order::order::{Id as OrderId, Order},
+        refund::refund::Refund,
+        user::user::UserInterface,
+    },
+    usecase::{
+        auth::authorizer_interface::Authorizer,
+        interactor::refund_interactor_interface::RefundInteractor,
+        repository::{
+            order_repository_interface::OrderRepository,
+            refund_repository_interface::RefundRepository,
+        },
+    },
+};
+
+/// Refund Interactor.
+pub struct RefundInteractorImpl {
+    refund_repository: Box<dyn RefundRepository>,
+    order_repository: Box<dyn OrderRepository>,
+    authorizer: Arc<dyn Authorizer>,
+}
+
+impl RefundInteractorImpl {
+    pub fn new(
+        refund_repository: Box<dyn RefundRepository>,
+        order_repository: Box<dyn OrderRepository>,
+        authorizer: Arc<dyn Authorizer>,
+    ) -> Self {
+        Self {
+            refund_repository,
+            order_repository,
+            authorizer,
+        }
+    }
+
+    /// Load an order and authorize the user on refunds of it.
+    async fn find_order_to_refund(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        action: &ResourceAction,
+    ) -> Result<Order, DomainError> {
+        let order = self.order_repository.find_order_by_id(order_id).await?;
+
+        self.authorizer
+            .authorize(
+                user,
+                vec![&Resource::new(
+                    ResourceType::Return,
+                    Some(order.owner_user_id().to_owned()),
+                )],
+                action,
+            )
+            .await?;
+
+        Ok(order)
+    }
+}
+
+#[async_trait]
+impl RefundInteractor for RefundInteractorImpl {
+    async fn calculate_refund(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<(LineItemId, u32)>,
+        shipping_amount: Option<Amount>,
+        restock_location_id: Option<LocationId>,
+    ) -> Result<Refund, DomainError> {
+        let order = self
+            .find_order_to_refund(user, order_id, &ResourceAction::Read)
+            .await?;
+
+        let refunds = self
+            .refund_repository
+            .find_refunds_by_order_id(order.id())
+            .await?;
+
+        Refund::calculate(
+            &order,
+            &refunds,
+            line_items,
+            shipping_amount,
+            restock_location_id,
+            None,
+        )
+    }
+
+    async fn refund_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<(LineItemId, u32)>,
+        shipping_amount: Option<Amount>,
+        restock_location_id: Option<LocationId>,
+        note: Option<String>,
+    ) -> Result<Refund, DomainError> {
+        let order = self
+            .find_order_to_refund(user, order_id, &ResourceAction::Write)
+            .await?;
+
+        let refunds = self
+            .refund_repository
+            .find_refunds_by_order_id(order.id())
+            .await?;
+
+        let refund = Refund::calculate(
+            &order,
+            &refunds,
+            line_items,
+            shipping_amount,
+            restock_location_id,
+            note,
+        )?;
+
+        self.refund_repository.create(refund).await
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+use crate::domain::user::user::UserInterface;
+use crate::domain::{
+    error::error::DomainError, line_item::line_item::Id as LineItemId,
+    location::location::Id as LocationId, money::amount::amount::Amount,
+    order::order::Id as OrderId, refund::refund::Refund,
+};
+
+/// Interactor interface for refunds.
+#[automock]
+#[async_trait]
+pub trait RefundInteractor {
+    /// Calculate the amounts to be refunded without executing the refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    /// * `line_items` - The line item ids and the quantities to refund.
+    /// * `shipping_amount` - The shipping amount to refund.
+    /// * `restock_location_id` - The location to restock the refunded units to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Refund, DomainError>` - The result of the operation.
+    ///   - `Ok(Refund)` - The calculated refund.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the order repository fails.
+    /// * If the user is not allowed to read the returns of the order.
+    /// * If the amounts exceed what was charged for the order.
+    async fn calculate_refund(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<(LineItemId, u32)>,
+        shipping_amount: Option<Amount>,
+        restock_location_id: Option<LocationId>,
+    ) -> Result<Refund, DomainError>;
+
+    /// Refund an order, restocking the refunded units if a location is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - The user interface.
+    /// * `order_id` - The order id.
+    /// * `line_items` - The line item ids and the quantities to refund.
+    /// * `shipping_amount` - The shipping amount to refund.
+    /// * `restock_location_id` - The location to restock the refunded units to.
+    /// * `note` - An optional note about the refund.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Refund, DomainError>` - The result of the operation.
+    ///   - `Ok(Refund)` - The executed refund.
+    ///   - `Err(DomainError)` - The error.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a domain error if the repositories fail.
+    /// * If the user is not allowed to write the returns of the order.
+    /// * If the amounts exceed what was charged for the order.
+    async fn refund_order(
+        &self,
+        user: Arc<dyn UserInterface>,
+        order_id: &OrderId,
+        line_items: Vec<(LineItemId, u32)>,
+        shipping_amount: Option<Amount>,
+        restock_location_id: Option<LocationId>,
+        note: Option<String>,
+    ) -> Result<Refund, DomainError>;
+}
@@ -1,7 +1,16 @@
+pub mod audit_log_repository_interface;
 pub mod customer_repository_interface;
+pub mod customer_segment_repository_interface;
 pub mod draft_order_repository_interface;
+pub mod fulfillment_order_repository_interface;
+pub mod fulfillment_repository_interface;
 pub mod inventory_item_repository_interface;
 pub mod inventory_level_repository_interface;
 pub mod location_repository_interface;
 pub mod media_repository_interface;
+pub mod order_repository_interface;
+pub mod order_return_repository_interface;
 pub mod product_repository_interface;
+pub mod refund_repository_interface;
+pub mod user_customer_repository_interface;
+pub mod user_repository_interface;
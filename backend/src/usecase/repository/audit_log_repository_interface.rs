@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::{audit_log::audit_log::AuditLog, error::error::DomainError};
+
+/// Repository interface for the audit trail.
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Record an audit log.
+    async fn create(&self, audit_log: AuditLog) -> Result<AuditLog, DomainError>;
+}
@@ -1,10 +1,83 @@
 use async_trait::async_trait;
 
-use crate::domain::{customer::customer::Customer, email::email::Email, error::error::DomainError};
+use crate::domain::{
+    address::address::{Address, Id as AddressId},
+    customer::{
+        customer::{Customer, Id as CustomerId},
+        search_criteria::search_criteria::{CustomerSearchCriteria, CustomerSortKey},
+    },
+    email::email::Email,
+    error::error::DomainError,
+};
+
+/// A page of customers fetched with cursor pagination.
+///
+/// # Fields
+/// - `customers` - The customers in the page.
+/// - `has_next_page` - Whether there are more customers after this page.
+/// - `end_cursor` - The cursor to fetch the next page, if any.
+#[derive(Debug)]
+pub struct CustomerPage {
+    pub customers: Vec<Customer>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
 
 /// Repository interface for customers.
 #[async_trait]
 pub trait CustomerRepository: Send + Sync {
+    /// Retrieve customer information by id.
+    async fn find_customer_by_id(&self, id: &CustomerId) -> Result<Customer, DomainError>;
+
     /// Retrieve customer information by email.
     async fn find_customer_by_email(&self, email: &Email) -> Result<Customer, DomainError>;
+
+    /// Search customers matching the criteria, one page at a time.
+    async fn find_customers_by_criteria(
+        &self,
+        criteria: &CustomerSearchCriteria,
+        sort_key: &CustomerSortKey,
+        reverse: bool,
+        first: u32,
+        after: Option<String>,
+    ) -> Result<CustomerPage, DomainError>;
+
+    /// Create a customer.
+    async fn create(&self, customer: Customer) -> Result<Customer, DomainError>;
+
+    /// Update a customer.
+    async fn update(&self, customer: Customer) -> Result<Customer, DomainError>;
+
+    /// Add an address to the address book of a customer.
+    async fn create_address(
+        &self,
+        customer_id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Address, DomainError>;
+
+    /// Update an address in the address book of a customer.
+    async fn update_address(
+        &self,
+        customer_id: &CustomerId,
+        address: Address,
+        set_default: bool,
+    ) -> Result<Address, DomainError>;
+
+    /// Delete an address from the address book of a customer.
+    async fn delete_address(
+        &self,
+        customer_id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<AddressId, DomainError>;
+
+    /// Set the default address of a customer.
+    async fn update_default_address(
+        &self,
+        customer_id: &CustomerId,
+        address_id: &AddressId,
+    ) -> Result<Customer, DomainError>;
+
+    /// Request the EC platform to erase the personal data of a customer.
+    async fn request_data_erasure(&self, id: &CustomerId) -> Result<CustomerId, DomainError>;
 }
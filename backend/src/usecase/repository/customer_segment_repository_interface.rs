@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    customer_segment::customer_segment::{CustomerSegment, Id as CustomerSegmentId},
+    error::error::DomainError,
+};
+
+/// Repository interface for customer segments.
+#[async_trait]
+pub trait CustomerSegmentRepository: Send + Sync {
+    /// Retrieve customer segment information by id.
+    async fn find_customer_segment_by_id(
+        &self,
+        id: &CustomerSegmentId,
+    ) -> Result<CustomerSegment, DomainError>;
+
+    /// Retrieve all customer segments.
+    async fn find_customer_segments(&self) -> Result<Vec<CustomerSegment>, DomainError>;
+
+    /// Create a customer segment.
+    async fn create(
+        &self,
+        customer_segment: CustomerSegment,
+    ) -> Result<CustomerSegment, DomainError>;
+}
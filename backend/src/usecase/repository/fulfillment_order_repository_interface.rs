@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError, fulfillment_order::fulfillment_order::FulfillmentOrder,
+    order::order::Id as OrderId,
+};
+
+/// Repository interface for fulfillment orders.
+#[async_trait]
+pub trait FulfillmentOrderRepository: Send + Sync {
+    /// Retrieve fulfillment orders of an order.
+    async fn find_fulfillment_orders_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<FulfillmentOrder>, DomainError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError,
+    fulfillment::fulfillment::{Fulfillment, Id as FulfillmentId},
+};
+
+/// Repository interface for fulfillments.
+#[async_trait]
+pub trait FulfillmentRepository: Send + Sync {
+    /// Retrieve fulfillment information by id.
+    async fn find_fulfillment_by_id(&self, id: &FulfillmentId) -> Result<Fulfillment, DomainError>;
+
+    /// Create a fulfillment.
+    async fn create(&self, fulfillment: Fulfillment) -> Result<Fulfillment, DomainError>;
+
+    /// Update the tracking information of a fulfillment.
+    async fn update(&self, fulfillment: Fulfillment) -> Result<Fulfillment, DomainError>;
+}
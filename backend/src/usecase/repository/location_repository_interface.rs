@@ -8,9 +8,8 @@ use crate::domain::{
 /// Repository interface for locations.
 #[async_trait]
 pub trait LocationRepository: Send + Sync {
-    /// Get id on all locations.
-    #[allow(dead_code)]
-    async fn find_all_location_ids(&self) -> Result<Vec<LocationId>, DomainError>;
+    /// Retrieve location information by id.
+    async fn find_location_by_id(&self, id: &LocationId) -> Result<Location, DomainError>;
 
     /// Retrieve multiple locations.
     async fn find_locations(
@@ -18,4 +17,23 @@ pub trait LocationRepository: Send + Sync {
         limit: &Option<u32>,
         offset: &Option<u32>,
     ) -> Result<Vec<Location>, DomainError>;
+
+    /// Create a location.
+    async fn create(&self, location: Location) -> Result<Location, DomainError>;
+
+    /// Update the name, address and fulfillment setting of a location.
+    async fn update(&self, location: Location) -> Result<Location, DomainError>;
+
+    /// Deactivate a location.
+    ///
+    /// If `destination_location_id` is given, the inventory remaining at the location is transferred there.
+    /// Otherwise, deactivation fails with `InvalidStateTransition` while inventory remains.
+    async fn deactivate(
+        &self,
+        id: &LocationId,
+        destination_location_id: &Option<LocationId>,
+    ) -> Result<Location, DomainError>;
+
+    /// Activate a location.
+    async fn activate(&self, id: &LocationId) -> Result<Location, DomainError>;
 }
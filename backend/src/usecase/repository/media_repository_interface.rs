@@ -1,13 +1,18 @@
 use async_trait::async_trait;
 
 use crate::domain::{
-    error::error::DomainError, media::media::Media, product::product::Id as ProductId,
+    error::error::DomainError,
+    media::{
+        media::{Id as MediaId, Media},
+        staged_upload::staged_upload::{StagedUpload, StagedUploadTarget},
+    },
+    product::product::Id as ProductId,
 };
 
 /// Repository interface for media.
 #[async_trait]
 pub trait MediaRepository: Send + Sync {
-    /// Obtain media associated with a single product ID.
+    /// Obtain media associated with a single product ID, in the order of the product media.
     async fn find_media_by_product_id(
         &self,
         product_id: &ProductId,
@@ -18,4 +23,26 @@ pub trait MediaRepository: Send + Sync {
         &self,
         product_ids: Vec<&ProductId>,
     ) -> Result<Vec<Media>, DomainError>;
+
+    /// Create a target to upload a file to before it is registered as media.
+    async fn create_staged_upload(
+        &self,
+        staged_upload: &StagedUpload,
+    ) -> Result<StagedUploadTarget, DomainError>;
+
+    /// Register media from its uploaded source and associate it with a product.
+    ///
+    /// The returned media may still be in preparation.
+    async fn create(&self, product_id: &ProductId, media: Media) -> Result<Media, DomainError>;
+
+    /// Update the alternative text of product media.
+    async fn update(&self, product_id: &ProductId, media: Media) -> Result<Media, DomainError>;
+
+    /// Move product media to the front in the given order.
+    ///
+    /// Media not specified keeps its relative order after the moved media.
+    async fn reorder(&self, product_id: &ProductId, media: Vec<&Media>) -> Result<(), DomainError>;
+
+    /// Delete product media.
+    async fn delete(&self, product_id: &ProductId, media: &Media) -> Result<MediaId, DomainError>;
 }
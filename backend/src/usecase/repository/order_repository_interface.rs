@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    customer::customer::Id as CustomerId,
+    error::error::DomainError,
+    order::order::{Id as OrderId, Order},
+};
+
+/// A page of orders fetched with cursor pagination.
+///
+/// # Fields
+/// - `orders` - The orders in the page.
+/// - `has_next_page` - Whether there are more orders after this page.
+/// - `end_cursor` - The cursor to fetch the next page, if any.
+#[derive(Debug)]
+pub struct OrderPage {
+    pub orders: Vec<Order>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
+/// Repository interface for orders.
+#[async_trait]
+pub trait OrderRepository: Send + Sync {
+    /// Retrieve order information by id.
+    async fn find_order_by_id(&self, id: &OrderId) -> Result<Order, DomainError>;
+
+    /// Retrieve all orders of the customer, most recent first.
+    async fn find_orders_by_customer_id(
+        &self,
+        customer_id: &CustomerId,
+    ) -> Result<Vec<Order>, DomainError>;
+
+    /// Retrieve orders one page at a time, most recent first.
+    ///
+    /// Only the orders of the customer are retrieved when it is given.
+    async fn find_orders(
+        &self,
+        customer_id: Option<&CustomerId>,
+        first: u32,
+        after: Option<String>,
+    ) -> Result<OrderPage, DomainError>;
+
+    /// Cancel an order that has been canceled in the domain.
+    ///
+    /// `refund` refunds the payment to the original payment method, `restock` returns the items to the inventory,
+    /// and `notify_customer` sends a cancellation notice to the customer.
+    async fn cancel(
+        &self,
+        order: Order,
+        refund: bool,
+        restock: bool,
+        notify_customer: bool,
+    ) -> Result<Order, DomainError>;
+}
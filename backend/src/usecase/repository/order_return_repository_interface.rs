@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError,
+    order::order::Id as OrderId,
+    order_return::order_return::{Id as ReturnId, OrderReturn},
+};
+
+/// Repository interface for returns.
+#[async_trait]
+pub trait OrderReturnRepository: Send + Sync {
+    /// Retrieve return information by id.
+    async fn find_return_by_id(&self, id: &ReturnId) -> Result<OrderReturn, DomainError>;
+
+    /// Retrieve returns of an order.
+    async fn find_returns_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<OrderReturn>, DomainError>;
+
+    /// Create a return request.
+    async fn create(&self, order_return: OrderReturn) -> Result<OrderReturn, DomainError>;
+
+    /// Update the status of a return.
+    ///
+    /// Only approval and decline of a requested return are supported.
+    async fn update(&self, order_return: OrderReturn) -> Result<OrderReturn, DomainError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::error::DomainError, order::order::Id as OrderId, refund::refund::Refund,
+};
+
+/// Repository interface for refunds.
+#[async_trait]
+pub trait RefundRepository: Send + Sync {
+    /// Retrieve the refunds already made on the order.
+    async fn find_refunds_by_order_id(
+        &self,
+        order_id: &OrderId,
+    ) -> Result<Vec<Refund>, DomainError>;
+
+    /// Create a refund, returning the payment to the customer and restocking the items if requested.
+    async fn create(&self, refund: Refund) -> Result<Refund, DomainError>;
+}
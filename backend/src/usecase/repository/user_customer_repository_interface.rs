@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::{
+    customer::customer::Id as CustomerId, error::error::DomainError, user::user::Id as UserId,
+};
+
+/// Repository interface for the link between IdP users and customers.
+#[automock]
+#[async_trait]
+pub trait UserCustomerRepository: Send + Sync {
+    /// Retrieve the id of the customer linked to the user.
+    async fn find_customer_id_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<CustomerId, DomainError>;
+
+    /// Retrieve the id of the user linked to the customer.
+    async fn find_user_id_by_customer_id(
+        &self,
+        customer_id: &CustomerId,
+    ) -> Result<UserId, DomainError>;
+
+    /// Retrieve the ids of the users linked to the customers, keyed by the customer id.
+    ///
+    /// Customers without a linked user are left out.
+    async fn find_user_ids_by_customer_ids(
+        &self,
+        customer_ids: &[CustomerId],
+    ) -> Result<HashMap<CustomerId, UserId>, DomainError>;
+
+    /// Link the user to the customer, replacing the existing link of the user if any.
+    async fn save(&self, user_id: &UserId, customer_id: &CustomerId) -> Result<(), DomainError>;
+
+    /// Remove the links of the customer.
+    async fn delete_by_customer_id(&self, customer_id: &CustomerId) -> Result<(), DomainError>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::{error::error::DomainError, user::user::Id as UserId};
+
+/// A user held locally for authorization.
+///
+/// # Fields
+/// - `id` - The identifier of the user issued by the IdP.
+/// - `name` - The name of the user.
+/// - `user_group_ids` - The identifiers of the user groups the user belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserRecord {
+    pub id: UserId,
+    pub name: String,
+    pub user_group_ids: Vec<i32>,
+}
+
+/// Repository interface for locally held users.
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// Retrieve user information by id.
+    async fn find_user_by_id(&self, id: &UserId) -> Result<UserRecord, DomainError>;
+
+    /// Remove the user from all user groups and replace the personal data with placeholders.
+    async fn anonymize(&self, id: &UserId) -> Result<UserId, DomainError>;
+}
@@ -1,3 +1,5 @@
+pub mod shopify_stub;
+
 use std::sync::Arc;
 
 use actix_http::Request;
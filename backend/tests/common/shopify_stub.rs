@@ -0,0 +1,252 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::TcpListener,
+    sync::Mutex,
+};
+
+use actix_web::{
+    dev::ServerHandle, http::StatusCode, web, App, HttpRequest, HttpResponse, HttpServer,
+};
+use backend::infrastructure::config::config::{ShopifyConfig, ShopifyHttpClientConfig};
+use regex::Regex;
+use serde_json::{json, Value};
+
+/// Local stand-in for the Shopify Admin GraphQL API.
+///
+/// Responses are registered per operation name, e.g. `locations` for `query locations(...)`,
+/// and are served in the order they were registered. The last response of an operation
+/// keeps being served once the others are used up, so a single registration covers any number of calls.
+/// Use `config()` to point the client of the application at the server.
+pub struct ShopifyStubServer {
+    url: String,
+    state: web::Data<StubState>,
+    handle: ServerHandle,
+}
+
+/// Request received by the stub server.
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub operation_name: String,
+    pub access_token: Option<String>,
+    pub variables: Value,
+}
+
+enum StubResponse {
+    Body(Value),
+    Status(StatusCode),
+}
+
+#[derive(Default)]
+struct StubState {
+    responses: Mutex<HashMap<String, VecDeque<StubResponse>>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl StubState {
+    fn push(&self, operation_name: &str, response: StubResponse) {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(operation_name.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    fn next(&self, operation_name: &str) -> Option<StubResponse> {
+        let mut responses = self.responses.lock().unwrap();
+        let queue = responses.get_mut(operation_name)?;
+        if queue.len() > 1 {
+            return queue.pop_front();
+        }
+        queue.front().map(|response| match response {
+            StubResponse::Body(body) => StubResponse::Body(body.clone()),
+            StubResponse::Status(status) => StubResponse::Status(*status),
+        })
+    }
+}
+
+impl ShopifyStubServer {
+    pub const ACCESS_TOKEN: &'static str = "shpat_stub";
+
+    const API_VERSION: &'static str = ShopifyConfig::DEFAULT_API_VERSION;
+    const ACCESS_TOKEN_HEADER: &'static str = "X-Shopify-Access-Token";
+
+    /// Start the server on a free local port.
+    pub async fn start() -> Self {
+        Self::start_on("127.0.0.1:0").await
+    }
+
+    /// Start the server on the given address, such as `127.0.0.1:8090`.
+    pub async fn start_on(address: &str) -> Self {
+        let listener = TcpListener::bind(address).unwrap();
+        let url = format!(
+            "http://{}/admin/api/{}/graphql.json",
+            listener.local_addr().unwrap(),
+            Self::API_VERSION
+        );
+
+        let state = web::Data::new(StubState::default());
+        let app_state = state.clone();
+        let server = HttpServer::new(move || {
+            App::new()
+                .app_data(app_state.clone())
+                .default_service(web::to(Self::handle))
+        })
+        .workers(1)
+        .listen(listener)
+        .unwrap()
+        .run();
+        let handle = server.handle();
+        actix_web::rt::spawn(server);
+
+        Self { url, state, handle }
+    }
+
+    /// URL of the GraphQL endpoint.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Shopify settings that point the client at this server.
+    pub fn config(&self) -> ShopifyConfig {
+        ShopifyConfig::from_values(
+            self.url().to_string(),
+            Self::API_VERSION.to_string(),
+            Self::ACCESS_TOKEN.to_string(),
+            ShopifyHttpClientConfig::default(),
+        )
+    }
+
+    /// Serve `data` as the `data` of the response to the operation.
+    pub fn respond(&self, operation_name: &str, data: Value) {
+        self.respond_with_body(operation_name, json!({ "data": data }));
+    }
+
+    /// Serve `body` as the whole response to the operation.
+    pub fn respond_with_body(&self, operation_name: &str, body: Value) {
+        self.state.push(operation_name, StubResponse::Body(body));
+    }
+
+    /// Serve an empty response with the given HTTP status to the operation.
+    pub fn respond_with_status(&self, operation_name: &str, status: StatusCode) {
+        self.state
+            .push(operation_name, StubResponse::Status(status));
+    }
+
+    /// Serve the nodes as consecutive pages of the `connection` field, one response per page.
+    ///
+    /// The end cursor of each page is `cursor-<page number>`, starting from 1.
+    pub fn respond_with_pages(
+        &self,
+        operation_name: &str,
+        connection: &str,
+        pages: Vec<Vec<Value>>,
+    ) {
+        let page_count = pages.len();
+        for (i, nodes) in pages.into_iter().enumerate() {
+            let edges: Vec<Value> = nodes
+                .into_iter()
+                .map(|node| json!({ "node": node }))
+                .collect();
+            self.respond(
+                operation_name,
+                json!({
+                    connection: {
+                        "edges": edges,
+                        "pageInfo": {
+                            "hasPreviousPage": i > 0,
+                            "hasNextPage": i + 1 < page_count,
+                            "startCursor": format!("cursor-{i}"),
+                            "endCursor": format!("cursor-{}", i + 1),
+                        }
+                    }
+                }),
+            );
+        }
+    }
+
+    /// Reject the next call of the operation as Shopify does when the cost limit is exceeded.
+    pub fn throttle(&self, operation_name: &str) {
+        self.respond_with_body(
+            operation_name,
+            json!({
+                "errors": [{
+                    "message": "Throttled",
+                    "extensions": {
+                        "code": "THROTTLED",
+                        "documentation": "https://shopify.dev/api/usage/rate-limits"
+                    }
+                }],
+                "extensions": {
+                    "cost": {
+                        "requestedQueryCost": 100,
+                        "actualQueryCost": null,
+                        "throttleStatus": {
+                            "maximumAvailable": 1000.0,
+                            "currentlyAvailable": 95,
+                            "restoreRate": 50.0
+                        }
+                    }
+                }
+            }),
+        );
+    }
+
+    /// Requests received for the operation, oldest first.
+    pub fn requests(&self, operation_name: &str) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.operation_name == operation_name)
+            .cloned()
+            .collect()
+    }
+
+    async fn handle(
+        request: HttpRequest,
+        body: web::Json<Value>,
+        state: web::Data<StubState>,
+    ) -> HttpResponse {
+        let operation_name = body["operationName"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| Self::operation_name(body["query"].as_str().unwrap_or_default()));
+
+        state.requests.lock().unwrap().push(RecordedRequest {
+            operation_name: operation_name.clone(),
+            access_token: request
+                .headers()
+                .get(Self::ACCESS_TOKEN_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            variables: body["variables"].clone(),
+        });
+
+        match state.next(&operation_name) {
+            Some(StubResponse::Body(body)) => HttpResponse::Ok().json(body),
+            Some(StubResponse::Status(status)) => HttpResponse::build(status).finish(),
+            None => HttpResponse::Ok().json(json!({
+                "errors": [{
+                    "message": format!("No stubbed response for operation '{operation_name}'.")
+                }]
+            })),
+        }
+    }
+
+    /// Name of the operation in the query document, or an empty string for an anonymous one.
+    fn operation_name(query: &str) -> String {
+        let pattern = Regex::new(r"^\s*(?:query|mutation)\s+(\w+)").unwrap();
+        pattern
+            .captures(query)
+            .map(|captures| captures[1].to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for ShopifyStubServer {
+    fn drop(&mut self) {
+        actix_web::rt::spawn(self.handle.stop(false));
+    }
+}
@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use actix_http::{Request, StatusCode};
+use actix_web::{
+    dev::{Service, ServiceResponse},
+    test, web, App, Error, HttpMessage,
+};
+use async_trait::async_trait;
+use backend::{
+    domain::{
+        authorized_resource::authorized_resource::{
+            AuthorizedResource, ResourceAction, ResourceType,
+        },
+        error::error::DomainError,
+        user::user::UserInterface,
+    },
+    infrastructure::{
+        auth::idp_user::IdpUser,
+        db::{
+            sea_orm::sea_orm_manager::SeaOrmTransactionManager,
+            transaction_manager_interface::TransactionManager,
+        },
+        ec::shopify::{
+            client_impl::ShopifyGQLClient,
+            repository::{
+                inventory_level::inventory_level_impl::InventoryLevelRepositoryImpl,
+                location::location_impl::LocationRepositoryImpl,
+            },
+        },
+        router::actix_router,
+    },
+    interface::controller::{
+        controller::Controller, interactor_provider_interface::MockInteractorProvider,
+    },
+    usecase::{
+        auth::authorizer_interface::Authorizer,
+        interactor::{
+            location::location_impl::LocationInteractorImpl,
+            location_interactor_interface::LocationInteractor,
+        },
+    },
+};
+use sea_orm::{DatabaseConnection, DatabaseTransaction};
+use serde_json::{json, Value};
+
+use crate::common::shopify_stub::ShopifyStubServer;
+
+const BASE_URL: &str = "/ec-extension/locations";
+
+/// Authorizer that permits every operation, so that these tests do not need a database.
+struct PermitAllAuthorizer;
+
+#[async_trait]
+impl Authorizer for PermitAllAuthorizer {
+    async fn authorize<'a>(
+        &self,
+        _user: Arc<dyn UserInterface>,
+        _resources: Vec<&'a dyn AuthorizedResource>,
+        _action: &ResourceAction,
+    ) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn authorize_all(
+        &self,
+        _user: Arc<dyn UserInterface>,
+        _resource_type: &ResourceType,
+        _action: &ResourceAction,
+    ) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+/// Build the application with the real location interactor and repositories calling the stub server.
+async fn setup(
+    stub: &ShopifyStubServer,
+) -> impl Service<Request, Response = ServiceResponse, Error = Error> {
+    let client = ShopifyGQLClient::new(stub.config()).unwrap();
+
+    let mut interactor_provider =
+        MockInteractorProvider::<DatabaseTransaction, Arc<DatabaseConnection>>::new();
+    interactor_provider
+        .expect_provide_location_interactor()
+        .returning(move |_, _| {
+            Box::new(LocationInteractorImpl::new(
+                Box::new(LocationRepositoryImpl::new(client.clone())),
+                Box::new(InventoryLevelRepositoryImpl::new(client.clone())),
+                Arc::new(PermitAllAuthorizer),
+            )) as Box<dyn LocationInteractor>
+        });
+
+    let controller = web::Data::new(Controller::new(interactor_provider));
+
+    test::init_service(App::new().app_data(controller).configure(
+        actix_router::configure_routes::<
+            MockInteractorProvider<DatabaseTransaction, Arc<DatabaseConnection>>,
+            DatabaseTransaction,
+            Arc<DatabaseConnection>,
+        >,
+    ))
+    .await
+}
+
+fn add_extensions(req: &Request) {
+    req.extensions_mut()
+        .insert(Arc::new(IdpUser::default()) as Arc<dyn UserInterface>);
+    req.extensions_mut()
+        .insert(Arc::new(SeaOrmTransactionManager::default())
+            as Arc<
+                dyn TransactionManager<DatabaseTransaction, Arc<DatabaseConnection>>,
+            >);
+}
+
+fn location_node(id: u32) -> Value {
+    let address = json!({
+        "address1": format!("{id}-1 Chiyoda"),
+        "address2": null,
+        "city": "Chiyoda-ku",
+        "country": "Japan",
+        "countryCode": "JP",
+        "province": "Tokyo",
+        "provinceCode": "13",
+        "zip": "100-0001",
+        "latitude": 35.6812,
+        "longitude": 139.7671
+    });
+
+    json!({
+        "id": format!("gid://shopify/Location/{id}"),
+        "name": format!("Location {id}"),
+        "isActive": true,
+        "fulfillsOnlineOrders": true,
+        "address": address,
+        "suggestedAddresses": []
+    })
+}
+
+fn post_location_request() -> Request {
+    let req = test::TestRequest::post()
+        .uri(BASE_URL)
+        .set_json(json!({
+            "name": "Location 1",
+            "address": {
+                "address1": "1-1 Chiyoda",
+                "city": "Chiyoda-ku",
+                "country": "Japan",
+                "country_code": "JP",
+                "province": "Tokyo",
+                "province_code": "13",
+                "zip": "100-0001"
+            },
+            "fulfills_online_orders": true
+        }))
+        .to_request();
+    add_extensions(&req);
+    req
+}
+
+#[actix_web::test]
+async fn test_get_locations_follows_pages() {
+    let stub = ShopifyStubServer::start().await;
+    stub.respond_with_pages(
+        "locations",
+        "locations",
+        vec![vec![location_node(1)], vec![location_node(2)]],
+    );
+
+    let req = test::TestRequest::get()
+        .uri(&format!("{BASE_URL}?limit=500"))
+        .to_request();
+    add_extensions(&req);
+
+    let resp = test::call_service(&setup(&stub).await, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["locations"][0]["id"], "1");
+    assert_eq!(body["locations"][1]["id"], "2");
+
+    let requests = stub.requests("locations");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].access_token.as_deref(),
+        Some(ShopifyStubServer::ACCESS_TOKEN)
+    );
+    assert_eq!(requests[0].variables["after"], Value::Null);
+    assert_eq!(requests[1].variables["after"], "cursor-1");
+}
+
+#[actix_web::test]
+async fn test_get_locations_retries_throttled_query() {
+    let stub = ShopifyStubServer::start().await;
+    stub.throttle("locations");
+    stub.respond_with_pages("locations", "locations", vec![vec![location_node(1)]]);
+
+    let req = test::TestRequest::get().uri(BASE_URL).to_request();
+    add_extensions(&req);
+
+    let resp = test::call_service(&setup(&stub).await, req).await;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(body["locations"][0]["id"], "1");
+    assert_eq!(stub.requests("locations").len(), 2);
+}
+
+#[actix_web::test]
+async fn test_post_location_with_user_errors() {
+    let stub = ShopifyStubServer::start().await;
+    stub.respond(
+        "locationAdd",
+        json!({
+            "locationAdd": {
+                "location": null,
+                "userErrors": [{
+                    "code": "TAKEN",
+                    "field": ["input", "name"],
+                    "message": "Name has already been taken"
+                }]
+            }
+        }),
+    );
+
+    let resp = test::call_service(&setup(&stub).await, post_location_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Value = test::read_body_json(resp).await;
+    assert_eq!(
+        body["details"],
+        json!([{
+            "field": "name",
+            "code": "TAKEN",
+            "message": "Name has already been taken"
+        }])
+    );
+
+    let requests = stub.requests("locationAdd");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].variables["input"]["name"], "Location 1");
+}
+
+#[actix_web::test]
+async fn test_post_location_is_not_retried_on_server_error() {
+    let stub = ShopifyStubServer::start().await;
+    stub.respond_with_status("locationAdd", StatusCode::SERVICE_UNAVAILABLE);
+
+    let resp = test::call_service(&setup(&stub).await, post_location_request()).await;
+
+    assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(stub.requests("locationAdd").len(), 1);
+}
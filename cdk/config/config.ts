@@ -44,7 +44,8 @@ export interface EnvConfig {
   githubToken: string;
   appConfig: {
     rustLog: string;
-    storeUrl: string;
+    shopifyShopDomain: string;
+    shopifyApiVersion: string;
     accessToken: string;
   };
 }
@@ -74,8 +75,8 @@ export const envConfig: Record<EnvCode, EnvConfig> = {
     githubToken: "",
     appConfig: {
       rustLog: "debug",
-      storeUrl:
-        "https://pesh-shared-demo.myshopify.com/admin/api/2024-07/graphql.json",
+      shopifyShopDomain: "pesh-shared-demo.myshopify.com",
+      shopifyApiVersion: "2024-07",
       // Receive in cdk deploy argument and update the value.
       accessToken: "",
     },
@@ -104,8 +105,8 @@ export const envConfig: Record<EnvCode, EnvConfig> = {
     githubToken: "",
     appConfig: {
       rustLog: "debug",
-      storeUrl:
-        "https://pesh-shared-demo.myshopify.com/admin/api/2024-07/graphql.json",
+      shopifyShopDomain: "pesh-shared-demo.myshopify.com",
+      shopifyApiVersion: "2024-07",
       accessToken: "",
     },
   },
@@ -133,8 +134,8 @@ export const envConfig: Record<EnvCode, EnvConfig> = {
     githubToken: "",
     appConfig: {
       rustLog: "debug",
-      storeUrl:
-        "https://pesh-shared-demo.myshopify.com/admin/api/2024-07/graphql.json",
+      shopifyShopDomain: "pesh-shared-demo.myshopify.com",
+      shopifyApiVersion: "2024-07",
       accessToken: "",
     },
   },
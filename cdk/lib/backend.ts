@@ -309,7 +309,14 @@ export class BackendStack extends cdk.Stack {
     });
     backendContainer.addEnvironment("ENV", deployEnv);
     backendContainer.addEnvironment("RUST_LOG", config.appConfig.rustLog);
-    backendContainer.addEnvironment("STORE_URL", config.appConfig.storeUrl);
+    backendContainer.addEnvironment(
+      "SHOPIFY_SHOP_DOMAIN",
+      config.appConfig.shopifyShopDomain
+    );
+    backendContainer.addEnvironment(
+      "SHOPIFY_API_VERSION",
+      config.appConfig.shopifyApiVersion
+    );
     backendContainer.addEnvironment(
       "ACCESS_TOKEN",
       config.appConfig.accessToken